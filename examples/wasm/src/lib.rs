@@ -0,0 +1,66 @@
+//! Expose `do_complete` and `do_hover` to JavaScript, build it with
+//! `wasm-pack build --target web examples/wasm`
+//!
+//! The results are the JSON of `CompletionList` and `Hover` of the LSP,
+//! the positions are the zero-based lines and UTF-16 characters such as the ones of Monaco and CodeMirror.
+
+use std::sync::OnceLock;
+
+use html_languageservice::{
+    DefaultDocumentContext, HTMLDataManager, HTMLLanguageService, HTMLLanguageServiceOptions,
+};
+use lsp_textdocument::FullTextDocument;
+use lsp_types::Position;
+use wasm_bindgen::prelude::*;
+
+struct Service {
+    ls: HTMLLanguageService,
+    data_manager: HTMLDataManager,
+}
+
+fn service() -> &'static Service {
+    static SERVICE: OnceLock<Service> = OnceLock::new();
+    SERVICE.get_or_init(|| Service {
+        ls: HTMLLanguageService::new(&HTMLLanguageServiceOptions::default()),
+        data_manager: HTMLDataManager::default(),
+    })
+}
+
+/// The completion list at the position of the HTML, as JSON
+#[wasm_bindgen(js_name = doComplete)]
+pub async fn do_complete(text: String, line: u32, character: u32) -> String {
+    let service = service();
+    let document = FullTextDocument::new("html".to_string(), 0, text);
+    let html_document = HTMLLanguageService::parse_html_document(&document, &service.data_manager);
+    let list = service
+        .ls
+        .do_complete(
+            &document,
+            &Position::new(line, character),
+            &html_document,
+            DefaultDocumentContext,
+            None,
+            &service.data_manager,
+        )
+        .await;
+    serde_json::to_string(&list).unwrap()
+}
+
+/// The hover at the position of the HTML, as JSON, it's `null` if there's nothing to show
+#[wasm_bindgen(js_name = doHover)]
+pub async fn do_hover(text: String, line: u32, character: u32) -> String {
+    let service = service();
+    let document = FullTextDocument::new("html".to_string(), 0, text);
+    let html_document = HTMLLanguageService::parse_html_document(&document, &service.data_manager);
+    let hover = service
+        .ls
+        .do_hover(
+            &document,
+            &Position::new(line, character),
+            &html_document,
+            None,
+            &service.data_manager,
+        )
+        .await;
+    serde_json::to_string(&hover).unwrap()
+}
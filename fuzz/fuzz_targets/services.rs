@@ -0,0 +1,124 @@
+#![no_main]
+
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use html_languageservice::{
+    DefaultDocumentContext, FoldingRangeContext, HTMLDataManager, HTMLFormatConfiguration,
+    HTMLLanguageService, HTMLLanguageServiceOptions,
+};
+use libfuzzer_sys::fuzz_target;
+use lsp_textdocument::FullTextDocument;
+use lsp_types::{Position, Range, Url};
+
+/// The futures of the service are ready without the participants
+fn now<F: Future>(future: F) -> F::Output {
+    match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("the future is pending"),
+    }
+}
+
+fuzz_target!(|text: &str| {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    let uri = Url::parse("file:///fuzz/index.html").unwrap();
+    let document = FullTextDocument::new("html".to_string(), 0, text.to_string());
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+
+    let regions = ls.get_document_regions(&document);
+    for language_id in ["css", "javascript"] {
+        regions.get_embedded_document(&document, language_id);
+    }
+    HTMLLanguageService::find_document_links(
+        &uri,
+        &document,
+        &html_document,
+        &DefaultDocumentContext,
+        &data_manager,
+        ls.position_encoding(),
+    );
+    HTMLLanguageService::find_document_symbols2(
+        &document,
+        &html_document,
+        &data_manager,
+        None,
+        ls.position_encoding(),
+    );
+    HTMLLanguageService::get_folding_ranges(
+        &document,
+        FoldingRangeContext::default(),
+        &data_manager,
+        ls.position_encoding(),
+    );
+    ls.format(
+        &document,
+        None,
+        Some(&HTMLFormatConfiguration::default()),
+        &data_manager,
+    );
+
+    // Every character boundary, and the positions in the middle of the characters
+    let mut positions = vec![];
+    for (offset, c) in text.char_indices() {
+        let position = document.position_at(offset as u32);
+        positions.push(position);
+        if c.len_utf16() > 1 {
+            positions.push(Position::new(position.line, position.character + 1));
+        }
+    }
+    positions.push(document.position_at(text.len() as u32));
+
+    for (i, position) in positions.iter().enumerate() {
+        let position = *position;
+        now(ls.do_complete(
+            &document,
+            &position,
+            &html_document,
+            DefaultDocumentContext,
+            None,
+            &data_manager,
+        ));
+        now(ls.do_hover(&document, &position, &html_document, None, &data_manager));
+        ls.do_quote_complete(&document, &position, &html_document, None);
+        ls.do_tag_complete(&document, &position, &html_document, None, &data_manager);
+        HTMLLanguageService::find_document_highlights(
+            &document,
+            &position,
+            &html_document,
+            ls.position_encoding(),
+        );
+        HTMLLanguageService::do_rename(
+            uri.clone(),
+            &document,
+            position,
+            "x",
+            &html_document,
+            ls.position_encoding(),
+        );
+        HTMLLanguageService::find_linked_editing_ranges(
+            &document,
+            position,
+            &html_document,
+            None,
+            ls.position_encoding(),
+        );
+        if let Some(next) = positions.get(i + 1) {
+            ls.format(
+                &document,
+                Some(Range::new(position, *next)),
+                Some(&HTMLFormatConfiguration::default()),
+                &data_manager,
+            );
+        }
+    }
+    HTMLLanguageService::get_selection_ranges(
+        &document,
+        &positions,
+        &html_document,
+        ls.position_encoding(),
+    );
+});
@@ -0,0 +1,42 @@
+/*
+ * The C ABI of html-languageservice, built with the `ffi` feature:
+ *
+ *     cargo rustc --release --features ffi --crate-type cdylib
+ *
+ * The requests and the responses are JSON strings encoded in UTF-8, such as
+ * {"text": "<div>", "languageId": "html", "position": {"line": 0, "character": 1}}.
+ * The responses are {"result": ...} or {"error": "..."}, and must be freed by html_ls_string_free.
+ * The panics don't unwind into the caller, they become {"error": "..."}.
+ */
+
+#ifndef HTML_LANGUAGESERVICE_H
+#define HTML_LANGUAGESERVICE_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct HtmlLanguageServiceHandle HtmlLanguageServiceHandle;
+
+/* Create the service with the built-in data, it must be freed by html_ls_free,
+   it's NULL if the creation panics */
+HtmlLanguageServiceHandle *html_ls_new(void);
+
+void html_ls_free(HtmlLanguageServiceHandle *handle);
+
+void html_ls_string_free(char *s);
+
+/* The tree of the elements, every node has tag, range, attributes and children */
+char *html_ls_parse(const HtmlLanguageServiceHandle *handle, const char *request);
+
+/* The Hover of the LSP at the position, or null */
+char *html_ls_hover(const HtmlLanguageServiceHandle *handle, const char *request);
+
+/* The CompletionList of the LSP at the position */
+char *html_ls_complete(const HtmlLanguageServiceHandle *handle, const char *request);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -1,126 +1,472 @@
+use std::ops::Range;
+
+use lazy_static::lazy_static;
 use regex::Regex;
 
 use crate::{
-    parse_html_document, parser::html_document::Node,
-    services::html_formatter::HTMLFormatConfiguration, HTMLDataManager,
+    cancellation::is_cancelled,
+    parser::{
+        html_document::Node,
+        html_embedded::get_script_language,
+        html_parse::parse_with_template_syntax,
+        html_scanner::{contains_template, get_template_ranges},
+    },
+    participant::{IFormatterParticipant, ParticipantError},
+    services::html_formatter::{
+        HTMLFormatConfiguration, HtmlAttributeQuotes, HtmlVoidElementStyle, HtmlWrapAttributes,
+    },
+    HTMLDataManager,
 };
 
-pub fn html_beautify(content: &str, options: &HTMLFormatConfiguration) -> String {
-    let html_document = parse_html_document(content, "html", &HTMLDataManager::default());
-    let mut formated = String::new();
-    for root in &html_document.roots {
-        formated.push_str(&beautify_node(content, root, options, 0));
-    }
-    if !formated.ends_with('\n') && options.end_with_newline {
-        formated += "\n";
-    }
-
-    formated
+lazy_static! {
+    static ref REG_WHITESPACE: Regex = Regex::new(r"\s+").unwrap();
 }
 
-fn beautify_node(
+pub fn html_beautify(
     content: &str,
-    node: &Node,
+    language_id: &str,
     options: &HTMLFormatConfiguration,
-    level: usize,
+    data_manager: &HTMLDataManager,
+    participants: &[Box<dyn IFormatterParticipant>],
+    on_error: &dyn Fn(&ParticipantError),
 ) -> String {
-    let tag = node.tag.as_ref().unwrap();
-    let mut attrs_format = String::new();
-    let attrs_is_wrap = node_attrs_is_wrap(&node, level, options);
-    for name in node.attribute_names_by_order() {
-        let mut value = None;
-        if let Some(v) = node.attributes.get(name) {
-            value = v.value.clone();
-        }
-        if let Some(value) = value {
-            if attrs_is_wrap {
-                attrs_format.push_str(&format!(
-                    "\n{}{}={}",
-                    get_attr_indent(options, level),
-                    name,
-                    value
-                ));
-            } else {
-                attrs_format.push_str(&format!(" {}={}", name, value));
-            }
-        } else {
-            if attrs_is_wrap {
-                attrs_format.push_str(&format!("\n{}{}", get_attr_indent(options, level), name));
-            } else {
-                attrs_format.push_str(&format!(" {}", name));
-            }
-        }
+    // The template blocks are kept as they are, instead of being parsed as the markup
+    let template_syntax =
+        data_manager.is_template_language(language_id) || contains_template(content);
+    let html_document =
+        parse_with_template_syntax(content, language_id, data_manager, template_syntax);
+    let void_elements = data_manager.get_void_elements(language_id);
+    let beautifier = Beautifier {
+        content,
+        options,
+        void_elements: &void_elements,
+        participants,
+        on_error,
+    };
+    let formated = beautify_children(&beautifier, &html_document.roots, 0..content.len(), 0);
+    let mut formated = formated.strip_prefix('\n').unwrap_or(&formated).to_string();
+    if !formated.ends_with('\n') && (options.end_with_newline || options.insert_final_newline) {
+        formated += "\n";
     }
+
+    formated
+}
+
+/// The level from which the nodes are kept as they are
+const MAX_FORMAT_DEPTH: usize = 256;
+
+/// The content being formatted and what every node is formatted with
+struct Beautifier<'a> {
+    content: &'a str,
+    options: &'a HTMLFormatConfiguration,
+    void_elements: &'a [String],
+    participants: &'a [Box<dyn IFormatterParticipant>],
+    on_error: &'a dyn Fn(&ParticipantError),
+}
+
+fn beautify_node(beautifier: &Beautifier, node: &Node, level: usize) -> String {
+    let Beautifier {
+        content,
+        options,
+        void_elements,
+        ..
+    } = *beautifier;
     let indent = get_indent(options, level);
+    // `<` that isn't followed by a tag name is kept as it is
+    let Some(tag) = node.tag.as_ref() else {
+        return format!("{}{}", indent, content[node.start..node.end].trim_end());
+    };
+    // The deeply nested nodes are kept as they are, rather than recursing further
+    if level >= MAX_FORMAT_DEPTH {
+        return format!("{}{}", indent, &content[node.start..node.end]);
+    }
+    if is_tag_in(tag, &options.unformatted) {
+        return format!("{}{}", indent, &content[node.start..node.end]);
+    }
+    // The start tag containing the template blocks is kept as it is
+    let start_tag = &content[node.start..node.start_tag_end.unwrap_or(node.end)];
+    let is_template_start_tag = contains_template(start_tag);
+    let (attrs_format, attrs_is_wrap) = if is_template_start_tag {
+        (String::new(), false)
+    } else {
+        beautify_attributes(content, node, options, level)
+    };
     if is_self_closing(&node) {
+        if is_template_start_tag {
+            return format!("{}{}", indent, start_tag);
+        }
+        let has_slash = if void_elements.iter().any(|void_element| void_element == tag) {
+            match options.void_element_style {
+                HtmlVoidElementStyle::SelfClose => true,
+                HtmlVoidElementStyle::NoSlash => false,
+                HtmlVoidElementStyle::Preserve => {
+                    content[..node.start_tag_end.unwrap_or(node.end)].ends_with("/>")
+                }
+            }
+        } else {
+            true
+        };
+        let end = if has_slash { "/>" } else { ">" };
         if attrs_is_wrap {
-            format!("{}<{}{}\n{}/>", indent, tag, attrs_format, indent)
+            format!("{}<{}{}\n{}{}", indent, tag, attrs_format, indent, end)
+        } else if has_slash {
+            format!("{}<{}{} {}", indent, tag, attrs_format, end)
         } else {
-            format!("{}<{}{} />", indent, tag, attrs_format)
+            format!("{}<{}{}{}", indent, tag, attrs_format, end)
         }
     } else {
         let mut children = String::new();
         let start_tag_end = node.start_tag_end.unwrap();
-        let end_tag_start = node.end_tag_start.unwrap();
-        let mut prev_child_end = start_tag_end;
-        for (i, child) in node.children.iter().enumerate() {
-            // before text of each child
-            let text = &content[prev_child_end..child.start];
-            children.push_str(&beautify_text(text, level + 1, options));
-            prev_child_end = child.end;
-            // child
-            children.push_str(&format!(
-                "\n{}",
-                beautify_node(content, &child, options, level + 1)
-            ));
-            // after text of last child
-            if i == node.children.len() - 1 {
-                let text = &content[prev_child_end..node.end_tag_start.unwrap()];
-                children.push_str(&beautify_text(text, level + 1, options));
+        // the node that isn't closed has no end tag, such as `<p>` before another `<p>`
+        let end_tag_start = node.end_tag_start.unwrap_or(node.end);
+        let end_tag = if node.end_tag_start.is_some() {
+            format!("</{}>", tag)
+        } else {
+            String::new()
+        };
+        let open_tag = if is_template_start_tag {
+            start_tag.to_string()
+        } else if attrs_is_wrap {
+            format!("<{}{}\n{}>", tag, attrs_format, indent)
+        } else {
+            format!("<{}{}>", tag, attrs_format)
+        };
+        if let Some(text) = format_embedded(
+            beautifier,
+            node,
+            &content[start_tag_end..end_tag_start],
+            level + 1,
+        ) {
+            if text.trim().is_empty() {
+                return format!("{}{}{}", indent, open_tag, end_tag);
             }
+            return format!("{}{}{}\n{}{}", indent, open_tag, text, indent, end_tag);
+        }
+        if is_tag_in(tag, &options.content_unformatted) {
+            let text = &content[start_tag_end..end_tag_start];
+            return format!("{}{}{}{}", indent, open_tag, text, end_tag);
         }
-        let is_wrap = node_is_wrap(&node, level, content, options);
+        let child_level = if tag == "html" && !options.indent_inner_html {
+            level
+        } else {
+            level + 1
+        };
+        if !node.children.is_empty() {
+            children.push_str(&beautify_children(
+                beautifier,
+                &node.children,
+                start_tag_end..end_tag_start,
+                child_level,
+            ));
+        }
+        let mut is_wrap = node_is_wrap(node, content) || attrs_is_wrap;
         if node.children.len() == 0 && start_tag_end != end_tag_start {
             let text = &content[start_tag_end..end_tag_start];
-            let text = beautify_text(text, level + 1, options);
+            let text = beautify_text(text, child_level, options, &mut 0);
+            let line_len = indent.len() + open_tag.len() + text.trim().len() + tag.len() + 3;
+            is_wrap = is_wrap || is_over_line_length(line_len, options);
             if is_wrap && text.trim().len() > 0 {
                 children.push_str(&format!(
                     "\n{}{}",
-                    get_indent(options, level + 1),
+                    get_indent(options, child_level),
                     text.trim_start()
                 ));
             } else {
                 children.push_str(&text);
             }
         }
-        if attrs_is_wrap {
-            format!(
-                "{}<{}{}\n{}>{}\n{}</{}>",
-                indent, tag, attrs_format, indent, children, indent, tag
-            )
-        } else if is_wrap {
-            format!(
-                "{}<{}{}>{}\n{}</{}>",
-                indent, tag, attrs_format, children, indent, tag
-            )
+        // the end tag is on its own line if the children are, so that formatting again is stable
+        let line_len = indent.len() + open_tag.len() + children.len() + tag.len() + 3;
+        if end_tag.is_empty() {
+            format!("{}{}{}", indent, open_tag, children)
+        } else if is_wrap || children.contains('\n') || is_over_line_length(line_len, options) {
+            if is_tag_in(&format!("/{}", tag), &options.extra_liners) {
+                push_empty_line(&mut children);
+            }
+            format!("{}{}{}\n{}{}", indent, open_tag, children, indent, end_tag)
+        } else {
+            format!("{}{}{}{}", indent, open_tag, children, end_tag)
+        }
+    }
+}
+
+/// Format the nodes and the text around them in the `range` of the content
+fn beautify_children(
+    beautifier: &Beautifier,
+    nodes: &[Node],
+    range: Range<usize>,
+    level: usize,
+) -> String {
+    let Beautifier {
+        content, options, ..
+    } = *beautifier;
+    let mut children = String::new();
+    let mut handlebars_depth = 0;
+    let mut prev_child_end = range.start;
+    let mut prev_is_inline = false;
+    for child in nodes {
+        if is_cancelled(options.cancellation_token.as_ref()) {
+            break;
+        }
+        // before text of each child
+        let text = &content[prev_child_end..child.start];
+        // the inline element stays on the same line as the text or the element before it
+        let is_inline = child
+            .tag
+            .as_ref()
+            .is_some_and(|tag| is_tag_in(tag, &options.inline))
+            && text
+                .rfind('\n')
+                .is_none_or(|index| !text[index..].trim().is_empty());
+        children.push_str(&beautify_text_between(
+            text,
+            prev_is_inline,
+            is_inline,
+            level,
+            options,
+            &mut handlebars_depth,
+        ));
+        prev_child_end = child.end;
+        // child
+        let child_format = beautify_node(beautifier, child, level + handlebars_depth);
+        if is_inline {
+            children.push_str(child_format.trim_start());
         } else {
-            format!("{}<{}{}>{}</{}>", indent, tag, attrs_format, children, tag)
+            if child
+                .tag
+                .as_ref()
+                .is_some_and(|tag| is_tag_in(tag, &options.extra_liners))
+            {
+                push_empty_line(&mut children);
+            }
+            children.push_str(&format!("\n{}", child_format));
+        }
+        prev_is_inline = is_inline;
+    }
+    // after text of last child
+    let text = &content[prev_child_end..range.end];
+    children.push_str(&beautify_text_between(
+        text,
+        prev_is_inline,
+        false,
+        level,
+        options,
+        &mut handlebars_depth,
+    ));
+    children
+}
+
+/// Format the content of `<script>` and `<style>` by the participants,
+/// every line of the result is indented and starts with a line break
+fn format_embedded(
+    beautifier: &Beautifier,
+    node: &Node,
+    text: &str,
+    level: usize,
+) -> Option<String> {
+    let Beautifier {
+        options,
+        participants,
+        on_error,
+        ..
+    } = *beautifier;
+    let language = get_embedded_language(node)?;
+    // The failed participant is reported and the next one is tried
+    let formatted = participants.iter().find_map(|p| {
+        p.format_embedded(&language, text, level, options)
+            .unwrap_or_else(|error| {
+                on_error(&error);
+                None
+            })
+    })?;
+    let indent = get_indent(options, level);
+    let mut result = String::new();
+    for line in formatted.trim_end().lines() {
+        if line.trim().is_empty() {
+            result.push('\n');
+            if options.indent_empty_lines {
+                result.push_str(&indent);
+            }
+        } else {
+            result.push_str(&format!("\n{}{}", indent, line));
+        }
+    }
+    Some(result)
+}
+
+/// The language of the content of `<script>` and `<style>`
+fn get_embedded_language(node: &Node) -> Option<String> {
+    let tag = node.tag.as_ref()?.to_lowercase();
+    if tag == "style" {
+        return Some("css".to_string());
+    } else if tag != "script" {
+        return None;
+    }
+    let script_type = node
+        .attributes
+        .get("type")
+        .and_then(|attr| attr.value.as_ref())
+        .map(|value| value.trim_matches(|c| c == '"' || c == '\''));
+    Some(get_script_language(script_type))
+}
+
+/// Format the attributes of the node by `wrap_attributes`,
+/// and whether the closing bracket of the start tag is on its own line
+fn beautify_attributes(
+    content: &str,
+    node: &Node,
+    options: &HTMLFormatConfiguration,
+    level: usize,
+) -> (String, bool) {
+    let tag = node.tag.as_ref().unwrap();
+    let names = node.attribute_names_by_order();
+    let attrs: Vec<String> = names
+        .iter()
+        .map(|name| {
+            if let Some(value) = &node.attributes.get(*name).unwrap().value {
+                format!("{}={}", name, quote_value(value, options.attribute_quotes))
+            } else {
+                name.to_string()
+            }
+        })
+        .collect();
+    let attr_indent = get_attr_indent(options, level);
+    // `<tag ` of the start tag
+    let aligned_indent = format!(
+        "{}{}",
+        get_indent(options, level),
+        " ".repeat(tag.len() + 2)
+    );
+    // Whether the attribute is on a new line in the content
+    let is_on_new_line = |name: &str| {
+        content[..node.attributes.get(name).unwrap().offset]
+            .chars()
+            .rev()
+            .take_while(|c| c.is_whitespace())
+            .any(|c| c == '\n')
+    };
+
+    let mut attrs_format = String::new();
+    let mut attrs_is_wrap = false;
+    match options.wrap_attributes {
+        HtmlWrapAttributes::Auto => {
+            attrs_is_wrap = node_attrs_is_wrap(node, &attrs, level, options);
+            for attr in &attrs {
+                if attrs_is_wrap {
+                    attrs_format.push_str(&format!("\n{}{}", attr_indent, attr));
+                } else {
+                    attrs_format.push_str(&format!(" {}", attr));
+                }
+            }
+        }
+        HtmlWrapAttributes::Force
+        | HtmlWrapAttributes::ForceAligned
+        | HtmlWrapAttributes::ForceExpandMultiline => {
+            let is_wrap = attrs.len() > 1;
+            attrs_is_wrap =
+                is_wrap && options.wrap_attributes == HtmlWrapAttributes::ForceExpandMultiline;
+            let indent = if options.wrap_attributes == HtmlWrapAttributes::ForceAligned {
+                &aligned_indent
+            } else {
+                &attr_indent
+            };
+            for (i, attr) in attrs.iter().enumerate() {
+                if is_wrap && (i > 0 || attrs_is_wrap) {
+                    attrs_format.push_str(&format!("\n{}{}", indent, attr));
+                } else {
+                    attrs_format.push_str(&format!(" {}", attr));
+                }
+            }
+        }
+        HtmlWrapAttributes::AlignedMultiple => {
+            let is_wrap = node_attrs_is_wrap(node, &attrs, level, options);
+            let mut line_len = get_indent(options, level).len() + 1 + tag.len();
+            for (i, attr) in attrs.iter().enumerate() {
+                if is_wrap
+                    && i > 0
+                    && options
+                        .wrap_line_length
+                        .is_some_and(|max| line_len + 1 + attr.len() > max)
+                {
+                    attrs_format.push_str(&format!("\n{}{}", aligned_indent, attr));
+                    line_len = aligned_indent.len() + attr.len();
+                } else {
+                    attrs_format.push_str(&format!(" {}", attr));
+                    line_len += 1 + attr.len();
+                }
+            }
+        }
+        HtmlWrapAttributes::Preserve | HtmlWrapAttributes::PreserveAligned => {
+            let indent = if options.wrap_attributes == HtmlWrapAttributes::PreserveAligned {
+                &aligned_indent
+            } else {
+                &attr_indent
+            };
+            for (name, attr) in names.iter().zip(&attrs) {
+                if is_on_new_line(name) {
+                    attrs_format.push_str(&format!("\n{}{}", indent, attr));
+                } else {
+                    attrs_format.push_str(&format!(" {}", attr));
+                }
+            }
         }
     }
+    (attrs_format, attrs_is_wrap)
 }
 
-fn beautify_text(text: &str, level: usize, options: &HTMLFormatConfiguration) -> String {
-    let whitespace_reg = Regex::new("\\s+").unwrap();
+/// Quote the attribute value by `attribute_quotes`, escaping the quotes in the value
+fn quote_value(value: &str, quotes: HtmlAttributeQuotes) -> String {
+    let (quote, escaped) = match quotes {
+        HtmlAttributeQuotes::Preserve => return value.to_string(),
+        HtmlAttributeQuotes::Double => ('"', "&quot;"),
+        HtmlAttributeQuotes::Single => ('\'', "&#39;"),
+    };
+    if contains_template(value) {
+        return value.to_string();
+    }
+    let inner = if value.len() >= 2
+        && (value.starts_with('"') && value.ends_with('"')
+            || value.starts_with('\'') && value.ends_with('\''))
+    {
+        &value[1..value.len() - 1]
+    } else {
+        value
+    };
+    format!("{}{}{}", quote, inner.replace(quote, escaped), quote)
+}
 
+/// Format the text, `handlebars_depth` is the depth of the handlebars blocks
+/// that the text is in, and it's updated by the blocks in the text
+fn beautify_text(
+    text: &str,
+    level: usize,
+    options: &HTMLFormatConfiguration,
+    handlebars_depth: &mut usize,
+) -> String {
     if text.contains('\n') {
         let mut result = String::new();
-        let lines = text.lines();
-        let count = lines.clone().count();
+        let lines = split_lines(text);
+        let count = lines.len();
         let mut preserve_count = 0;
-        for (i, line) in lines.enumerate() {
-            let line = whitespace_reg.replace_all(line.trim(), " ");
+        for (i, line) in lines.into_iter().enumerate() {
+            let line = collapse_whitespace(line.trim());
             if line.len() > 0 {
-                result.push_str(&format!("\n{}{}", get_indent(options, level), line));
+                if options.indent_handlebars && is_handlebars_block_end(&line) {
+                    *handlebars_depth = handlebars_depth.saturating_sub(1);
+                }
+                if i == 0 {
+                    // the first line stays on the line of the tag before it
+                    result.push_str(&line);
+                } else {
+                    result.push_str(&format!(
+                        "\n{}{}",
+                        get_indent(options, level + *handlebars_depth),
+                        line
+                    ));
+                }
+                if options.indent_handlebars && is_handlebars_block_start(&line) {
+                    *handlebars_depth += 1;
+                }
                 preserve_count = 0;
             } else if i != 0
                 && (i != count - 1 || text.ends_with("\n"))
@@ -141,10 +487,87 @@ fn beautify_text(text: &str, level: usize, options: &HTMLFormatConfiguration) ->
         }
         result
     } else {
-        whitespace_reg.replace_all(text.trim(), " ").to_string()
+        collapse_whitespace(text.trim())
     }
 }
 
+/// Format the text between the children, the text on the same line as the inline element
+/// before or after it is kept on that line, with the whitespace between them collapsed
+fn beautify_text_between(
+    text: &str,
+    after_inline: bool,
+    before_inline: bool,
+    level: usize,
+    options: &HTMLFormatConfiguration,
+    handlebars_depth: &mut usize,
+) -> String {
+    // the first line follows the inline element before it
+    let (head, rest) = if after_inline {
+        text.split_at(text.find('\n').unwrap_or(text.len()))
+    } else {
+        ("", text)
+    };
+    let mut result = collapse_whitespace(head);
+    if rest.is_empty() {
+        if !before_inline {
+            result.truncate(result.trim_end().len());
+        }
+        return result;
+    }
+    result.truncate(result.trim_end().len());
+    if before_inline && !rest.contains('\n') {
+        result.push_str(&collapse_whitespace(rest.trim_start()));
+    } else {
+        result.push_str(&beautify_text(rest, level, options, handlebars_depth));
+        if before_inline && rest.ends_with(char::is_whitespace) {
+            result.push(' ');
+        }
+    }
+    result
+}
+
+/// Split the text into lines like `str::lines`, but the line breaks in the template blocks are kept
+fn split_lines(text: &str) -> Vec<&str> {
+    let templates = get_template_ranges(text);
+    let mut lines = vec![];
+    let mut start = 0;
+    for (i, _) in text.match_indices('\n') {
+        if templates.iter().any(|range| range.contains(&i)) {
+            continue;
+        }
+        let line = &text[start..i];
+        lines.push(line.strip_suffix('\r').unwrap_or(line));
+        start = i + 1;
+    }
+    if start < text.len() {
+        lines.push(&text[start..]);
+    }
+    lines
+}
+
+/// Collapse the whitespace into a single space, except in the template blocks
+fn collapse_whitespace(text: &str) -> String {
+    let mut result = String::new();
+    let mut start = 0;
+    for range in get_template_ranges(text) {
+        result.push_str(&REG_WHITESPACE.replace_all(&text[start..range.start], " "));
+        result.push_str(&text[range.clone()]);
+        start = range.end;
+    }
+    result.push_str(&REG_WHITESPACE.replace_all(&text[start..], " "));
+    result
+}
+
+/// Whether the line starts a handlebars block, such as `{{#if}}` and `{{else}}`
+fn is_handlebars_block_start(line: &str) -> bool {
+    (line.starts_with("{{#") && !line.contains("{{/")) || line.starts_with("{{else")
+}
+
+/// Whether the line ends a handlebars block, such as `{{/if}}` and `{{else}}`
+fn is_handlebars_block_end(line: &str) -> bool {
+    line.starts_with("{{/") || line.starts_with("{{else")
+}
+
 fn get_indent(options: &HTMLFormatConfiguration, level: usize) -> String {
     if options.insert_spaces {
         " ".repeat(options.tab_size as usize * level)
@@ -171,81 +594,60 @@ fn get_attr_indent(options: &HTMLFormatConfiguration, level: usize) -> String {
     indent
 }
 
-fn is_self_closing(node: &Node) -> bool {
-    node.end_tag_start.is_none()
+fn is_tag_in(tag: &str, tags: &[String]) -> bool {
+    tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
 }
 
-fn node_is_wrap(
-    node: &Node,
-    level: usize,
-    content: &str,
-    options: &HTMLFormatConfiguration,
-) -> bool {
-    if let Some(start_tag_end) = node.start_tag_end {
-        if let Some(end_tag_start) = node.end_tag_start {
-            if content[start_tag_end..end_tag_start].contains('\n') {
-                return true;
-            }
-        }
+/// Push an empty line to the text if it doesn't end with an empty line
+fn push_empty_line(text: &mut String) {
+    if text.is_empty() || !text.trim_end_matches([' ', '\t']).ends_with('\n') {
+        text.push('\n');
     }
-
-    if options.wrap_line_length.is_none() {
-        return false;
-    }
-
-    let tag = if let Some(tag) = &node.tag {
-        tag
-    } else {
-        return false;
-    };
-
-    let left_tag_len = get_left_tag_len(node, level, options).unwrap();
-    let total = if node.is_self_closing() {
-        left_tag_len
-    } else {
-        let content_len = node.end_tag_start.unwrap() - node.start_tag_end.unwrap();
-        let end_left_bracket_len = 2;
-        left_tag_len + content_len + end_left_bracket_len + tag.len() + 2
-    };
-
-    total > options.wrap_line_length.unwrap()
 }
 
-fn node_attrs_is_wrap(node: &Node, level: usize, options: &HTMLFormatConfiguration) -> bool {
-    if options.wrap_line_length.is_none() {
-        return false;
-    }
+fn is_self_closing(node: &Node) -> bool {
+    node.end_tag_start.is_none() && (node.closed || node.start_tag_end.is_none())
+}
 
-    if let Some(total) = get_left_tag_len(node, level, options) {
-        total > options.wrap_line_length.unwrap()
+/// Whether the content of the node has line breaks
+fn node_is_wrap(node: &Node, content: &str) -> bool {
+    if let (Some(start_tag_end), Some(end_tag_start)) = (node.start_tag_end, node.end_tag_start) {
+        content[start_tag_end..end_tag_start].contains('\n')
     } else {
         false
     }
 }
 
-fn get_left_tag_len(node: &Node, level: usize, options: &HTMLFormatConfiguration) -> Option<usize> {
-    let tag = if let Some(tag) = &node.tag {
-        tag
-    } else {
-        return None;
-    };
+fn is_over_line_length(len: usize, options: &HTMLFormatConfiguration) -> bool {
+    options.wrap_line_length.is_some_and(|max| len > max)
+}
+
+fn node_attrs_is_wrap(
+    node: &Node,
+    attrs: &[String],
+    level: usize,
+    options: &HTMLFormatConfiguration,
+) -> bool {
+    is_over_line_length(get_left_tag_len(node, attrs, level, options), options)
+}
 
+/// The length of the start tag with the formatted attributes on one line
+fn get_left_tag_len(
+    node: &Node,
+    attrs: &[String],
+    level: usize,
+    options: &HTMLFormatConfiguration,
+) -> usize {
+    let tag_len = node.tag.as_ref().map_or(0, |tag| tag.len());
     let indent = get_indent(options, level).len();
     let left_bracket_len = 1;
     let right_bracket_len = 1;
     let right_self_closing = 3; // include one space
-    let mut attrs_len = 0;
-    for name in node.attribute_names() {
-        if let Some(value) = &node.attributes.get(name).unwrap().value {
-            attrs_len += 1 + name.len() + 1 + value.len(); // { name="value"}
-        } else {
-            attrs_len += 1 + name.len();
-        }
-    }
+    let attrs_len: usize = attrs.iter().map(|attr| 1 + attr.len()).sum();
 
     if node.is_self_closing() {
-        Some(indent + left_bracket_len + tag.len() + attrs_len + right_self_closing)
+        indent + left_bracket_len + tag_len + attrs_len + right_self_closing
     } else {
-        Some(indent + left_bracket_len + tag.len() + attrs_len + right_bracket_len)
+        indent + left_bracket_len + tag_len + attrs_len + right_bracket_len
     }
 }
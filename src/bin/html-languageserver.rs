@@ -0,0 +1,8 @@
+//! The HTML language server over stdio, see `html_languageservice::server::HTMLLanguageServer`
+
+use html_languageservice::server::HTMLLanguageServer;
+
+#[tokio::main]
+async fn main() {
+    HTMLLanguageServer::run_stdio().await;
+}
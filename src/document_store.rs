@@ -0,0 +1,136 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
+
+use lsp_textdocument::FullTextDocument;
+use lsp_types::{TextDocumentContentChangeEvent, Url};
+
+use crate::{
+    parser::{html_document::HTMLDocument, html_parse::HTMLParser},
+    HTMLDataManager,
+};
+
+/// The open documents keyed by their URIs, with the parsed `HTMLDocument`s
+/// and the caches of the services
+///
+/// The parsed documents and the caches are invalidated when the version of the document
+/// or the version of the `HTMLDataManager` changes.
+#[derive(Default)]
+pub struct DocumentStore {
+    documents: HashMap<Url, StoredDocument>,
+}
+
+struct StoredDocument {
+    document: FullTextDocument,
+    /// The parsed document and the version of `HTMLDataManager` it's parsed with
+    html_document: Option<(HTMLDocument, u64)>,
+    caches: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl StoredDocument {
+    fn invalidate(&mut self) {
+        self.html_document = None;
+        self.caches.clear();
+    }
+}
+
+impl DocumentStore {
+    pub fn new() -> DocumentStore {
+        DocumentStore::default()
+    }
+
+    /// Store the document, it replaces the document of the `uri`
+    pub fn open(&mut self, uri: Url, language_id: String, version: i32, text: String) {
+        self.documents.insert(
+            uri,
+            StoredDocument {
+                document: FullTextDocument::new(language_id, version, text),
+                html_document: None,
+                caches: HashMap::new(),
+            },
+        );
+    }
+
+    /// Apply the full or incremental changes to the document,
+    /// it returns `false` if the document isn't stored or the `version` isn't newer
+    pub fn change(
+        &mut self,
+        uri: &Url,
+        changes: &[TextDocumentContentChangeEvent],
+        version: i32,
+    ) -> bool {
+        let Some(stored) = self.documents.get_mut(uri) else {
+            return false;
+        };
+        if version <= stored.document.version() {
+            return false;
+        }
+        stored.document.update(changes, version);
+        stored.invalidate();
+        true
+    }
+
+    /// Remove the document of the `uri`, return whether it's stored
+    pub fn close(&mut self, uri: &Url) -> bool {
+        self.documents.remove(uri).is_some()
+    }
+
+    /// Invalidate the parsed documents and the caches of all the documents,
+    /// such as when the `HTMLDataManager` is replaced by another one
+    pub fn invalidate(&mut self) {
+        for stored in self.documents.values_mut() {
+            stored.invalidate();
+        }
+    }
+
+    pub fn get_document(&self, uri: &Url) -> Option<&FullTextDocument> {
+        self.documents.get(uri).map(|stored| &stored.document)
+    }
+
+    /// Get the document and the parsed `HTMLDocument`, it's parsed only when it's invalidated
+    pub fn get_html_document(
+        &mut self,
+        uri: &Url,
+        data_manager: &HTMLDataManager,
+    ) -> Option<(&FullTextDocument, &HTMLDocument)> {
+        let stored = self.documents.get_mut(uri)?;
+        let data_version = data_manager.get_version();
+        if stored
+            .html_document
+            .as_ref()
+            .is_some_and(|(_, version)| *version != data_version)
+        {
+            stored.invalidate();
+        }
+        let (html_document, _) = stored.html_document.get_or_insert_with(|| {
+            (
+                HTMLParser::parse_document(&stored.document, data_manager),
+                data_version,
+            )
+        });
+        Some((&stored.document, html_document))
+    }
+
+    /// Get the value of the type `T` cached for the document, it's created by `f`
+    /// when it's not cached, such as the results of a service that are requested repeatedly
+    pub fn get_or_insert_cache<T, F>(
+        &mut self,
+        uri: &Url,
+        data_manager: &HTMLDataManager,
+        f: F,
+    ) -> Option<&T>
+    where
+        T: Any + Send + Sync,
+        F: FnOnce(&FullTextDocument, &HTMLDocument) -> T,
+    {
+        self.get_html_document(uri, data_manager)?;
+        let stored = self.documents.get_mut(uri)?;
+        let (html_document, _) = stored.html_document.as_ref()?;
+        let value = stored
+            .caches
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(f(&stored.document, html_document)));
+        value.downcast_ref()
+    }
+}
@@ -0,0 +1,286 @@
+//! The C ABI of the parser, hover and completion, so that the editors that aren't written in Rust
+//! can embed the service without speaking LSP
+//!
+//! Build it as a C library with `cargo rustc --release --features ffi --crate-type cdylib`
+//! (or `staticlib`), the declarations are in `include/html_languageservice.h`.
+//!
+//! The requests and the responses are JSON strings encoded in UTF-8. The request is an object
+//! with `text`, `languageId` (defaults to `html`) and `position` for hover and completion,
+//! such as `{"text": "<div>", "position": {"line": 0, "character": 1}}`.
+//! The response is `{"result": ...}` with the JSON of the LSP types, or `{"error": "..."}`.
+//! The strings of the responses must be freed by `html_ls_string_free`.
+//! The panics don't unwind into the caller, they become `{"error": "..."}`.
+
+use std::{
+    any::Any,
+    ffi::{c_char, CStr, CString},
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    pin::pin,
+    ptr,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+use lsp_textdocument::FullTextDocument;
+use lsp_types::{Position, Range};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    parser::html_document::Node, DefaultDocumentContext, HTMLDataManager, HTMLLanguageService,
+    HTMLLanguageServiceOptions,
+};
+
+/// The service and the data that the functions share, it's created by `html_ls_new`
+pub struct HtmlLanguageServiceHandle {
+    ls: HTMLLanguageService,
+    data_manager: HTMLDataManager,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Request {
+    text: String,
+    #[serde(default = "default_language_id")]
+    language_id: String,
+    position: Option<Position>,
+}
+
+fn default_language_id() -> String {
+    "html".to_string()
+}
+
+/// Create the service with the built-in data, it must be freed by `html_ls_free`,
+/// it's null if the creation panics
+#[no_mangle]
+pub extern "C" fn html_ls_new() -> *mut HtmlLanguageServiceHandle {
+    panic::catch_unwind(|| {
+        Box::into_raw(Box::new(HtmlLanguageServiceHandle {
+            ls: HTMLLanguageService::new(&HTMLLanguageServiceOptions::default()),
+            data_manager: HTMLDataManager::default(),
+        }))
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+/// Free the service created by `html_ls_new`, it does nothing if `handle` is null
+///
+/// # Safety
+///
+/// `handle` must be null or returned by `html_ls_new`, and it must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn html_ls_free(handle: *mut HtmlLanguageServiceHandle) {
+    if !handle.is_null() {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(Box::from_raw(handle))));
+    }
+}
+
+/// Free the string returned by the other functions, it does nothing if `s` is null
+///
+/// # Safety
+///
+/// `s` must be null or returned by the functions of this module, and it must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn html_ls_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Parse the document, the result is the tree of the elements,
+/// every node has `tag`, `range`, `attributes` and `children`
+///
+/// # Safety
+///
+/// `handle` must be returned by `html_ls_new`, and `request` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn html_ls_parse(
+    handle: *const HtmlLanguageServiceHandle,
+    request: *const c_char,
+) -> *mut c_char {
+    respond(handle, request, |handle, request| {
+        let document = FullTextDocument::new(request.language_id, 0, request.text);
+        let html_document =
+            HTMLLanguageService::parse_html_document(&document, &handle.data_manager);
+        let roots: Vec<Value> = html_document
+            .roots
+            .iter()
+            .map(|node| node_to_json(&document, node))
+            .collect();
+        Ok(json!(roots))
+    })
+}
+
+/// Get the `Hover` at the position, the result is `null` if there's nothing to show
+///
+/// # Safety
+///
+/// `handle` must be returned by `html_ls_new`, and `request` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn html_ls_hover(
+    handle: *const HtmlLanguageServiceHandle,
+    request: *const c_char,
+) -> *mut c_char {
+    respond(handle, request, |handle, request| {
+        let position = request.position.ok_or("`position` is missing")?;
+        let document = FullTextDocument::new(request.language_id, 0, request.text);
+        let html_document =
+            HTMLLanguageService::parse_html_document(&document, &handle.data_manager);
+        let hover = block_on(handle.ls.do_hover(
+            &document,
+            &position,
+            &html_document,
+            None,
+            &handle.data_manager,
+        ));
+        Ok(json!(hover))
+    })
+}
+
+/// Get the `CompletionList` at the position
+///
+/// # Safety
+///
+/// `handle` must be returned by `html_ls_new`, and `request` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn html_ls_complete(
+    handle: *const HtmlLanguageServiceHandle,
+    request: *const c_char,
+) -> *mut c_char {
+    respond(handle, request, |handle, request| {
+        let position = request.position.ok_or("`position` is missing")?;
+        let document = FullTextDocument::new(request.language_id, 0, request.text);
+        let html_document =
+            HTMLLanguageService::parse_html_document(&document, &handle.data_manager);
+        let list = block_on(handle.ls.do_complete(
+            &document,
+            &position,
+            &html_document,
+            DefaultDocumentContext,
+            None,
+            &handle.data_manager,
+        ));
+        Ok(json!(list))
+    })
+}
+
+/// Read the request, call `f` and write the result or the error as the response,
+/// a panic becomes the error
+unsafe fn respond(
+    handle: *const HtmlLanguageServiceHandle,
+    request: *const c_char,
+    f: impl FnOnce(&HtmlLanguageServiceHandle, Request) -> Result<Value, &'static str>,
+) -> *mut c_char {
+    let response = panic::catch_unwind(AssertUnwindSafe(|| read_and_call(handle, request, f)))
+        .unwrap_or_else(|payload| json!({ "error": panic_message(&*payload) }));
+    // The JSON doesn't contain NUL because it's escaped in the strings
+    CString::new(response.to_string()).unwrap().into_raw()
+}
+
+unsafe fn read_and_call(
+    handle: *const HtmlLanguageServiceHandle,
+    request: *const c_char,
+    f: impl FnOnce(&HtmlLanguageServiceHandle, Request) -> Result<Value, &'static str>,
+) -> Value {
+    match (handle.as_ref(), request.is_null()) {
+        (Some(handle), false) => match CStr::from_ptr(request).to_str() {
+            Ok(request) => match serde_json::from_str(request) {
+                Ok(request) => match f(handle, request) {
+                    Ok(result) => json!({ "result": result }),
+                    Err(err) => json!({ "error": err }),
+                },
+                Err(err) => json!({ "error": err.to_string() }),
+            },
+            Err(_) => json!({ "error": "the request isn't UTF-8" }),
+        },
+        _ => json!({ "error": "the handle or the request is null" }),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown"
+    };
+    format!("panicked: {}", message)
+}
+
+fn node_to_json(document: &FullTextDocument, node: &Node) -> Value {
+    // The nodes being converted, with the index of their next child and their converted children
+    let mut stack: Vec<(&Node, usize, Vec<Value>)> = vec![(node, 0, vec![])];
+    loop {
+        let (node, next, _) = stack.last_mut().unwrap();
+        if let Some(child) = node.children.get(*next) {
+            *next += 1;
+            stack.push((child, 0, vec![]));
+            continue;
+        }
+        let (node, _, children) = stack.pop().unwrap();
+        let mut attributes: Vec<_> = node.attributes.iter().collect();
+        attributes.sort_by_key(|(_, attribute)| attribute.offset);
+        let attributes: Map<String, Value> = attributes
+            .into_iter()
+            .map(|(name, attribute)| (name.to_string(), json!(attribute.value)))
+            .collect();
+        let value = json!({
+            "tag": node.tag,
+            "range": Range::new(
+                document.position_at(node.start as u32),
+                document.position_at(node.end as u32),
+            ),
+            "closed": node.closed,
+            "attributes": attributes,
+            "children": children,
+        });
+        match stack.last_mut() {
+            Some((_, _, siblings)) => siblings.push(value),
+            None => return value,
+        }
+    }
+}
+
+/// Run the future on the current thread, the futures of the service only wait for the participants
+/// and the file system provider, so it doesn't need a runtime
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn respond_panic() {
+        let handle = html_ls_new();
+        let request = CString::new(r#"{ "text": "" }"#).unwrap();
+        unsafe {
+            let response = respond(handle, request.as_ptr(), |_, _| panic!("oops"));
+            let value: Value =
+                serde_json::from_str(CStr::from_ptr(response).to_str().unwrap()).unwrap();
+            assert_eq!(value, json!({ "error": "panicked: oops" }));
+            html_ls_string_free(response);
+            html_ls_free(handle);
+        }
+    }
+}
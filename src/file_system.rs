@@ -0,0 +1,229 @@
+#[cfg(any(
+    feature = "fs_provider",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{collections::BTreeMap, sync::RwLock};
+
+use async_trait::async_trait;
+use lsp_types::Url;
+#[cfg(feature = "fs_provider")]
+use tokio::fs;
+
+use crate::{html_language_types::DocumentUri, FileStat, FileSystemProvider, FileType};
+
+/// The `FileSystemProvider` of the local file system, it supports the `file` URIs
+#[cfg(feature = "fs_provider")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalFileSystemProvider;
+
+#[cfg(feature = "fs_provider")]
+#[async_trait]
+impl FileSystemProvider for LocalFileSystemProvider {
+    async fn stat(&self, uri: DocumentUri) -> FileStat {
+        let Some(path) = Url::parse(&uri)
+            .ok()
+            .and_then(|uri| uri.to_file_path().ok())
+        else {
+            return unknown_stat();
+        };
+        let Ok(metadata) = fs::symlink_metadata(&path).await else {
+            return unknown_stat();
+        };
+        let file_type = to_file_type(metadata.file_type());
+        // The size and the times are of the target of the symbolic link
+        let metadata = fs::metadata(&path).await.unwrap_or(metadata);
+        FileStat {
+            file_type,
+            ctime: metadata.created().map_or(0, to_millis),
+            mtime: metadata.modified().map_or(0, to_millis),
+            size: metadata.len() as usize,
+        }
+    }
+
+    async fn read_directory(&self, uri: DocumentUri) -> Vec<(String, FileType)> {
+        let Some(path) = Url::parse(&uri)
+            .ok()
+            .and_then(|uri| uri.to_file_path().ok())
+        else {
+            return vec![];
+        };
+        let Ok(mut entries) = fs::read_dir(path).await else {
+            return vec![];
+        };
+        let mut result = vec![];
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let file_type = entry
+                .file_type()
+                .await
+                .map_or(FileType::Unknown, to_file_type);
+            result.push((entry.file_name().to_string_lossy().to_string(), file_type));
+        }
+        result
+    }
+}
+
+/// The `FileSystemProvider` of the files and directories registered in memory,
+/// such as for the tests and the virtual file systems of the web-based hosts
+///
+/// The parent directories of the registered entries are registered too.
+#[derive(Debug, Default)]
+pub struct MemoryFileSystemProvider {
+    /// The entries keyed by the URIs without the trailing slash
+    entries: RwLock<BTreeMap<String, FileStat>>,
+}
+
+impl MemoryFileSystemProvider {
+    pub fn new() -> MemoryFileSystemProvider {
+        MemoryFileSystemProvider::default()
+    }
+
+    /// Register a file of the `size` at the `uri`, it replaces the entry at the `uri`
+    pub fn add_file(&self, uri: &str, size: usize) {
+        self.add_entry(uri, FileType::File, size);
+    }
+
+    /// Register a directory at the `uri`
+    pub fn add_directory(&self, uri: &str) {
+        self.add_entry(uri, FileType::Directory, 0);
+    }
+
+    /// Remove the entry at the `uri` and the entries in it, return whether it's registered
+    pub fn remove(&self, uri: &str) -> bool {
+        let Some(uri) = Url::parse(uri).ok() else {
+            return false;
+        };
+        let key = to_key(&uri);
+        let mut entries = self.entries.write().unwrap();
+        let prefix = if key.ends_with('/') {
+            key.clone()
+        } else {
+            format!("{}/", key)
+        };
+        entries.retain(|k, _| !k.starts_with(&prefix));
+        entries.remove(&key).is_some()
+    }
+
+    fn add_entry(&self, uri: &str, file_type: FileType, size: usize) {
+        let Some(mut uri) = Url::parse(uri).ok() else {
+            return;
+        };
+        let now = now();
+        let mut entries = self.entries.write().unwrap();
+        entries.insert(
+            to_key(&uri),
+            FileStat {
+                file_type,
+                ctime: now,
+                mtime: now,
+                size,
+            },
+        );
+        while uri.path() != "/" {
+            let Ok(parent) = uri.join("./") else {
+                break;
+            };
+            let key = to_key(&parent);
+            if entries.contains_key(&key) {
+                break;
+            }
+            entries.insert(
+                key,
+                FileStat {
+                    file_type: FileType::Directory,
+                    ctime: now,
+                    mtime: now,
+                    size: 0,
+                },
+            );
+            uri = parent;
+        }
+    }
+}
+
+#[async_trait]
+impl FileSystemProvider for MemoryFileSystemProvider {
+    async fn stat(&self, uri: DocumentUri) -> FileStat {
+        let Some(uri) = Url::parse(&uri).ok() else {
+            return unknown_stat();
+        };
+        self.entries
+            .read()
+            .unwrap()
+            .get(&to_key(&uri))
+            .cloned()
+            .unwrap_or_else(unknown_stat)
+    }
+
+    async fn read_directory(&self, uri: DocumentUri) -> Vec<(String, FileType)> {
+        let Some(uri) = Url::parse(&uri).ok() else {
+            return vec![];
+        };
+        let mut prefix = to_key(&uri);
+        if !prefix.ends_with('/') {
+            prefix.push('/');
+        }
+        self.entries
+            .read()
+            .unwrap()
+            .range(prefix.clone()..)
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .filter(|(key, _)| !key[prefix.len()..].contains('/'))
+            .map(|(key, stat)| (key[prefix.len()..].to_string(), stat.file_type))
+            .collect()
+    }
+}
+
+/// The URI without the trailing slash, except the root
+fn to_key(uri: &Url) -> String {
+    if uri.path() == "/" {
+        uri.to_string()
+    } else {
+        uri.as_str().trim_end_matches('/').to_string()
+    }
+}
+
+fn unknown_stat() -> FileStat {
+    FileStat {
+        file_type: FileType::Unknown,
+        ctime: 0,
+        mtime: 0,
+        size: 0,
+    }
+}
+
+#[cfg(feature = "fs_provider")]
+fn to_file_type(file_type: std::fs::FileType) -> FileType {
+    if file_type.is_symlink() {
+        FileType::SymbolicLink
+    } else if file_type.is_dir() {
+        FileType::Directory
+    } else if file_type.is_file() {
+        FileType::File
+    } else {
+        FileType::Unknown
+    }
+}
+
+/// The current time in milliseconds, it's always `0` on `wasm32-unknown-unknown`,
+/// where `SystemTime::now` panics
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+fn now() -> i128 {
+    to_millis(SystemTime::now())
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+fn now() -> i128 {
+    0
+}
+
+#[cfg(any(
+    feature = "fs_provider",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+fn to_millis(time: SystemTime) -> i128 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_millis() as i128,
+        Err(err) => -(err.duration().as_millis() as i128),
+    }
+}
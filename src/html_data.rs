@@ -1,5 +1,6 @@
 use lsp_types::MarkupContent;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct HTMLDataV1 {
@@ -15,9 +16,13 @@ pub struct HTMLDataV1 {
 pub struct ITagData {
     pub name: String,
     pub description: Option<Description>,
+    #[serde(default)]
     pub attributes: Vec<IAttributeData>,
     pub references: Option<Vec<IReference>>,
     pub void: Option<bool>,
+    pub browsers: Option<Vec<String>>,
+    pub status: Option<BaselineStatus>,
+    pub deprecated: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -28,6 +33,9 @@ pub struct IAttributeData {
     pub value_set: Option<String>,
     pub values: Option<Vec<IValueData>>,
     pub references: Option<Vec<IReference>>,
+    pub browsers: Option<Vec<String>>,
+    pub status: Option<BaselineStatus>,
+    pub deprecated: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -41,6 +49,9 @@ pub struct IValueData {
     pub name: String,
     pub description: Option<Description>,
     pub references: Option<Vec<IReference>>,
+    pub browsers: Option<Vec<String>>,
+    pub status: Option<BaselineStatus>,
+    pub deprecated: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -49,6 +60,46 @@ pub struct IReference {
     pub url: String,
 }
 
+/// The [Baseline](https://web.dev/baseline) status of a tag, attribute or value
+#[derive(Serialize, Deserialize, Clone)]
+pub struct BaselineStatus {
+    pub baseline: Baseline,
+    pub baseline_low_date: Option<String>,
+    pub baseline_high_date: Option<String>,
+}
+
+/// It's `false`, `"low"` or `"high"` in the custom data
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Baseline {
+    False,
+    Low,
+    High,
+}
+
+impl Serialize for Baseline {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Baseline::False => serializer.serialize_bool(false),
+            Baseline::Low => serializer.serialize_str("low"),
+            Baseline::High => serializer.serialize_str("high"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Baseline {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Value::deserialize(deserializer)? {
+            Value::Bool(false) => Ok(Baseline::False),
+            Value::String(s) if s == "low" => Ok(Baseline::Low),
+            Value::String(s) if s == "high" => Ok(Baseline::High),
+            value => Err(de::Error::custom(format!(
+                "expected false, \"low\" or \"high\", found {}",
+                value
+            ))),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 pub enum Description {
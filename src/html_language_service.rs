@@ -1,12 +1,20 @@
-#[cfg(any(feature = "completion", feature = "hover"))]
 use crate::html_language_types::HTMLLanguageServiceOptions;
 use crate::parser::html_document::HTMLDocument;
+use crate::parser::html_embedded::{self, DocumentRegions};
 use crate::parser::html_parse::HTMLParser;
 use crate::parser::html_scanner::{Scanner, ScannerState};
 #[cfg(feature = "completion")]
 use crate::participant::ICompletionParticipant;
+#[cfg(feature = "folding")]
+use crate::participant::IFoldingRangeParticipant;
+#[cfg(feature = "formatter")]
+use crate::participant::IFormatterParticipant;
 #[cfg(feature = "hover")]
 use crate::participant::IHoverParticipant;
+#[cfg(any(feature = "folding", feature = "formatter"))]
+use crate::participant::ParticipantError;
+#[cfg(any(feature = "folding", feature = "formatter"))]
+use crate::participant::ParticipantErrorHandler;
 #[cfg(feature = "completion")]
 use crate::services::html_completion::HTMLCompletion;
 #[cfg(feature = "folding")]
@@ -32,6 +40,8 @@ use crate::services::html_symbols;
 
 #[cfg(feature = "formatter")]
 use crate::HTMLFormatConfiguration;
+#[cfg(feature = "symbols")]
+use crate::SymbolsConfiguration;
 
 #[cfg(feature = "completion")]
 use crate::CompletionConfiguration;
@@ -42,9 +52,9 @@ use crate::FoldingRangeContext;
 use crate::HTMLDataManager;
 #[cfg(feature = "hover")]
 use crate::HoverSettings;
+#[cfg(feature = "linked_editing")]
+use crate::LinkedEditingConfiguration;
 
-#[cfg(feature = "completion")]
-use lsp_types::CompletionList;
 #[cfg(feature = "highlight")]
 use lsp_types::DocumentHighlight;
 #[cfg(feature = "links")]
@@ -54,7 +64,6 @@ use lsp_types::FoldingRange;
 #[cfg(feature = "hover")]
 use lsp_types::Hover;
 #[cfg(any(
-    feature = "formatter",
     feature = "completion",
     feature = "hover",
     feature = "highlight",
@@ -64,19 +73,52 @@ use lsp_types::Hover;
     feature = "linked_editing"
 ))]
 use lsp_types::Position;
-#[cfg(any(feature = "formatter", feature = "linked_editing"))]
+#[cfg(any(
+    feature = "formatter",
+    feature = "linked_editing",
+    feature = "matching_tag_position"
+))]
 use lsp_types::Range;
 #[cfg(feature = "selection_range")]
 use lsp_types::SelectionRange;
 #[cfg(feature = "formatter")]
 use lsp_types::TextEdit;
-#[cfg(any(feature = "links", feature = "symbols", feature = "rename"))]
+#[cfg(any(
+    feature = "completion",
+    feature = "links",
+    feature = "symbols",
+    feature = "rename"
+))]
 use lsp_types::Url;
 #[cfg(feature = "rename")]
 use lsp_types::WorkspaceEdit;
+#[cfg(feature = "completion")]
+use lsp_types::{CompletionItem, CompletionList};
 #[cfg(feature = "symbols")]
 use lsp_types::{DocumentSymbol, SymbolInformation};
 
+#[cfg(feature = "completion")]
+use lsp_types::CompletionOptions;
+#[cfg(feature = "links")]
+use lsp_types::DocumentLinkOptions;
+#[cfg(feature = "folding")]
+use lsp_types::FoldingRangeProviderCapability;
+#[cfg(feature = "hover")]
+use lsp_types::HoverProviderCapability;
+#[cfg(feature = "linked_editing")]
+use lsp_types::LinkedEditingRangeServerCapabilities;
+#[cfg(any(
+    feature = "formatter",
+    feature = "highlight",
+    feature = "symbols",
+    feature = "rename"
+))]
+use lsp_types::OneOf;
+use lsp_types::PositionEncodingKind;
+#[cfg(feature = "selection_range")]
+use lsp_types::SelectionRangeProviderCapability;
+use lsp_types::ServerCapabilities;
+
 use lsp_textdocument::FullTextDocument;
 
 /// This is a collection of features necessary to implement an HTML language server
@@ -96,24 +138,73 @@ use lsp_textdocument::FullTextDocument;
 /// - rename
 /// - matching_tag_position
 /// - linked_editing
+///
+/// # Thread safety
+///
+/// `HTMLLanguageService` is `Send + Sync`, so it can be shared in an `Arc` across the handler tasks
+/// of the server without a `Mutex`. The participants, the data providers, `FileSystemProvider`
+/// and `DocumentContext` are required to be `Send + Sync`, and the futures of `do_complete`
+/// and `do_hover` are `Send`.
 pub struct HTMLLanguageService {
     #[cfg(feature = "completion")]
     html_completion: HTMLCompletion,
+    /// The settings of completion when the requests don't specify them
+    #[cfg(feature = "completion")]
+    completion_settings: Option<CompletionConfiguration>,
     #[cfg(feature = "hover")]
     html_hover: HTMLHover,
+    /// The settings of hover when the requests don't specify them
+    #[cfg(feature = "hover")]
+    hover_settings: Option<HoverSettings>,
+    /// The settings of formatting when the requests don't specify them
+    #[cfg(feature = "formatter")]
+    format_settings: Option<HTMLFormatConfiguration>,
+    #[cfg(feature = "formatter")]
+    formatter_participants: Vec<Box<dyn IFormatterParticipant>>,
+    #[cfg(feature = "folding")]
+    folding_range_participants: Vec<Box<dyn IFoldingRangeParticipant>>,
+    /// The handler of the errors of the participants of the services that aren't async,
+    /// see `HTMLLanguageServiceOptions::participant_error_handler`
+    #[cfg(any(feature = "folding", feature = "formatter"))]
+    participant_error_handler: Option<ParticipantErrorHandler>,
+    /// The encoding of the characters of the positions, see `HTMLLanguageServiceOptions::position_encoding`
+    position_encoding: Option<PositionEncodingKind>,
 }
 
 impl HTMLLanguageService {
-    #[cfg(any(feature = "completion", feature = "hover"))]
     pub fn new(options: &HTMLLanguageServiceOptions) -> HTMLLanguageService {
         HTMLLanguageService {
             #[cfg(feature = "completion")]
             html_completion: HTMLCompletion::new(options),
+            #[cfg(feature = "completion")]
+            completion_settings: None,
             #[cfg(feature = "hover")]
             html_hover: HTMLHover::new(options),
+            #[cfg(feature = "hover")]
+            hover_settings: None,
+            #[cfg(feature = "formatter")]
+            format_settings: None,
+            #[cfg(feature = "formatter")]
+            formatter_participants: vec![],
+            #[cfg(feature = "folding")]
+            folding_range_participants: vec![],
+            #[cfg(any(feature = "folding", feature = "formatter"))]
+            participant_error_handler: options.participant_error_handler.clone(),
+            position_encoding: options.position_encoding.clone(),
         }
     }
 
+    /// Configure the service fluently, with the options, the participants and the default settings
+    pub fn builder() -> HTMLLanguageServiceBuilder {
+        HTMLLanguageServiceBuilder::default()
+    }
+
+    /// The encoding of the characters of the positions of the options,
+    /// to pass to the functions that take `position_encoding`, UTF-16 if it's `None`
+    pub fn position_encoding(&self) -> Option<&PositionEncodingKind> {
+        self.position_encoding.as_ref()
+    }
+
     pub fn create_scanner(input: &str, initial_offset: usize) -> Scanner {
         Scanner::new(input, initial_offset, ScannerState::WithinContent, false)
     }
@@ -125,7 +216,15 @@ impl HTMLLanguageService {
         HTMLParser::parse_document(document, data_manager)
     }
 
-    /// Provide completion proposals for a given location
+    /// Find the regions of the embedded CSS and JavaScript, to build the virtual documents
+    /// for the services of the languages and map their results back to the document
+    pub fn get_document_regions(&self, document: &FullTextDocument) -> DocumentRegions {
+        html_embedded::get_document_regions(document.get_content(None))
+            .with_position_encoding(self.position_encoding.clone())
+    }
+
+    /// Provide completion proposals for a given location,
+    /// the default settings of the builder are used if `settings` is `None`
     #[cfg(feature = "completion")]
     pub async fn do_complete(
         &self,
@@ -142,14 +241,76 @@ impl HTMLLanguageService {
                 position,
                 html_document,
                 document_context,
-                settings,
+                settings.or(self.completion_settings.as_ref()),
+                data_manager,
+            )
+            .await
+    }
+
+    /// Like `do_complete`, and the paths of the attribute values such as `src` and the image candidates
+    /// of `srcset` are completed with the entries of the directories of
+    /// `HTMLLanguageServiceOptions::file_system_provider`, resolved against `uri` by `document_context`
+    #[cfg(feature = "completion")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn do_complete2(
+        &self,
+        uri: &Url,
+        document: &FullTextDocument,
+        position: &Position,
+        html_document: &HTMLDocument,
+        document_context: impl DocumentContext,
+        settings: Option<&CompletionConfiguration>,
+        data_manager: &HTMLDataManager,
+    ) -> CompletionList {
+        self.html_completion
+            .do_complete2(
+                uri.as_str(),
+                document,
+                position,
+                html_document,
+                document_context,
+                settings.or(self.completion_settings.as_ref()),
                 data_manager,
             )
             .await
     }
 
+    /// Like `do_complete` without the participants, for the hosts without an async runtime
+    #[cfg(all(feature = "completion", feature = "sync"))]
+    pub fn do_complete_sync(
+        &self,
+        document: &FullTextDocument,
+        position: &Position,
+        html_document: &HTMLDocument,
+        document_context: impl DocumentContext,
+        settings: Option<&CompletionConfiguration>,
+        data_manager: &HTMLDataManager,
+    ) -> CompletionList {
+        self.html_completion.do_complete_sync(
+            document,
+            position,
+            html_document,
+            document_context,
+            settings.or(self.completion_settings.as_ref()),
+            data_manager,
+        )
+    }
+
+    /// Resolve the documentation of the completion item,
+    /// which is omitted when `CompletionConfiguration::lazy_documentation` is enabled
+    #[cfg(feature = "completion")]
+    pub fn resolve_completion_item(
+        &self,
+        item: CompletionItem,
+        data_manager: &HTMLDataManager,
+    ) -> CompletionItem {
+        self.html_completion
+            .resolve_completion_item(item, data_manager)
+    }
+
     /// Add additional completion items to the completion proposal
     #[cfg(feature = "completion")]
+    #[deprecated(note = "use `HTMLLanguageServiceBuilder::completion_participant`")]
     pub fn set_completion_participants(
         &mut self,
         completion_participants: Vec<Box<dyn ICompletionParticipant>>,
@@ -161,12 +322,18 @@ impl HTMLLanguageService {
     /// Provide quotes completion when `=` is entered
     #[cfg(feature = "completion")]
     pub fn do_quote_complete(
+        &self,
         document: &FullTextDocument,
         position: &Position,
         html_document: &HTMLDocument,
         settings: Option<&CompletionConfiguration>,
     ) -> Option<String> {
-        HTMLCompletion::do_quote_complete(document, position, html_document, settings)
+        self.html_completion.do_quote_complete(
+            document,
+            position,
+            html_document,
+            settings.or(self.completion_settings.as_ref()),
+        )
     }
 
     /// Completes the tag when `>` or `/` is entered
@@ -176,13 +343,20 @@ impl HTMLLanguageService {
         document: &FullTextDocument,
         position: &Position,
         html_document: &HTMLDocument,
+        settings: Option<&CompletionConfiguration>,
         data_manager: &HTMLDataManager,
     ) -> Option<String> {
-        self.html_completion
-            .do_tag_complete(document, position, html_document, data_manager)
+        self.html_completion.do_tag_complete(
+            document,
+            position,
+            html_document,
+            settings.or(self.completion_settings.as_ref()),
+            data_manager,
+        )
     }
 
-    /// Provides hover information at a given location
+    /// Provides hover information at a given location,
+    /// the default settings of the builder are used if `options` is `None`
     #[cfg(feature = "hover")]
     pub async fn do_hover(
         &self,
@@ -192,6 +366,7 @@ impl HTMLLanguageService {
         options: Option<HoverSettings>,
         data_manager: &HTMLDataManager,
     ) -> Option<Hover> {
+        let options = options.or_else(|| self.hover_settings.clone());
         self.html_hover
             .do_hover(document, position, html_document, options, data_manager)
             .await
@@ -199,20 +374,49 @@ impl HTMLLanguageService {
 
     /// Add additional hover to the hover proposal
     #[cfg(feature = "hover")]
+    #[deprecated(note = "use `HTMLLanguageServiceBuilder::hover_participant`")]
     pub fn set_hover_participants(&mut self, hover_participants: Vec<Box<dyn IHoverParticipant>>) {
         self.html_hover.set_hover_participants(hover_participants);
     }
 
-    /// Formats the code at the given range
+    /// Formats the code at the given range,
+    /// the default settings of the builder are used if `options` is `None`.
+    /// The content of `<script>` and `<style>` is formatted by the participants of the builder.
     ///
     /// Note: `format` is not prefect, it's under development
     #[cfg(feature = "formatter")]
     pub fn format(
+        &self,
         document: &FullTextDocument,
         range: Option<Range>,
-        options: &HTMLFormatConfiguration,
+        options: Option<&HTMLFormatConfiguration>,
+        data_manager: &HTMLDataManager,
     ) -> Vec<TextEdit> {
-        html_formatter::format(document, &range, options)
+        let default;
+        let options = match options.or(self.format_settings.as_ref()) {
+            Some(options) => options,
+            None => {
+                default = HTMLFormatConfiguration::default();
+                &default
+            }
+        };
+        html_formatter::format(
+            document,
+            &range,
+            options,
+            data_manager,
+            &self.formatter_participants,
+            &|error| self.report_participant_error(error),
+            self.position_encoding.as_ref(),
+        )
+    }
+
+    /// Report the error of a participant to the handler of the options
+    #[cfg(any(feature = "folding", feature = "formatter"))]
+    fn report_participant_error(&self, error: &ParticipantError) {
+        if let Some(handler) = &self.participant_error_handler {
+            handler(error);
+        }
     }
 
     /// Provides document highlights capability
@@ -221,8 +425,14 @@ impl HTMLLanguageService {
         document: &FullTextDocument,
         position: &Position,
         html_document: &HTMLDocument,
+        position_encoding: Option<&PositionEncodingKind>,
     ) -> Vec<DocumentHighlight> {
-        html_highlight::find_document_highlights(document, position, html_document)
+        html_highlight::find_document_highlights(
+            document,
+            position,
+            html_document,
+            position_encoding,
+        )
     }
 
     /// Finds all links in the document
@@ -230,39 +440,196 @@ impl HTMLLanguageService {
     pub fn find_document_links(
         uri: &Url,
         document: &FullTextDocument,
+        html_document: &HTMLDocument,
         document_context: &impl DocumentContext,
         data_manager: &HTMLDataManager,
+        position_encoding: Option<&PositionEncodingKind>,
     ) -> Vec<DocumentLink> {
-        html_links::find_document_links(uri, document, document_context, data_manager)
+        html_links::find_document_links(
+            uri,
+            document,
+            html_document,
+            document_context,
+            data_manager,
+            position_encoding,
+        )
+    }
+
+    /// Like `find_document_links`, and the links are passed to `on_chunk` in chunks of `chunk_size`
+    /// while the document is scanned, such as for the partial results of LSP
+    #[cfg(feature = "links")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn find_document_links_streaming(
+        uri: &Url,
+        document: &FullTextDocument,
+        html_document: &HTMLDocument,
+        document_context: &impl DocumentContext,
+        data_manager: &HTMLDataManager,
+        position_encoding: Option<&PositionEncodingKind>,
+        chunk_size: usize,
+        on_chunk: impl FnMut(Vec<DocumentLink>),
+    ) {
+        html_links::find_document_links_streaming(
+            uri,
+            document,
+            html_document,
+            document_context,
+            data_manager,
+            position_encoding,
+            chunk_size,
+            on_chunk,
+        )
     }
 
     /// Finds all the symbols in the document, it returns `SymbolInformation`
+    /// and whether the symbols are truncated by `SymbolsConfiguration::max_symbols`
     #[cfg(feature = "symbols")]
     pub fn find_document_symbols(
         uri: &Url,
         document: &FullTextDocument,
         html_document: &HTMLDocument,
-    ) -> Vec<SymbolInformation> {
-        html_symbols::find_document_symbols(uri, document, html_document)
+        data_manager: &HTMLDataManager,
+        settings: Option<&SymbolsConfiguration>,
+        position_encoding: Option<&PositionEncodingKind>,
+    ) -> (Vec<SymbolInformation>, bool) {
+        html_symbols::find_document_symbols(
+            uri,
+            document,
+            html_document,
+            data_manager,
+            settings,
+            position_encoding,
+        )
     }
 
     /// Finds all the symbols in the document, it returns `DocumentSymbol`
+    /// and whether the symbols are truncated by `SymbolsConfiguration::max_symbols`
     #[cfg(feature = "symbols")]
     pub fn find_document_symbols2(
         document: &FullTextDocument,
         html_document: &HTMLDocument,
-    ) -> Vec<DocumentSymbol> {
-        html_symbols::find_document_symbols2(document, html_document)
+        data_manager: &HTMLDataManager,
+        settings: Option<&SymbolsConfiguration>,
+        position_encoding: Option<&PositionEncodingKind>,
+    ) -> (Vec<DocumentSymbol>, bool) {
+        html_symbols::find_document_symbols2(
+            document,
+            html_document,
+            data_manager,
+            settings,
+            position_encoding,
+        )
+    }
+
+    /// Like `find_document_symbols`, and the symbols are passed to `on_chunk` in chunks of `chunk_size`,
+    /// such as for the partial results of LSP, it returns whether the symbols are truncated
+    #[cfg(feature = "symbols")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn find_document_symbols_streaming(
+        uri: &Url,
+        document: &FullTextDocument,
+        html_document: &HTMLDocument,
+        data_manager: &HTMLDataManager,
+        settings: Option<&SymbolsConfiguration>,
+        position_encoding: Option<&PositionEncodingKind>,
+        chunk_size: usize,
+        on_chunk: impl FnMut(Vec<SymbolInformation>),
+    ) -> bool {
+        html_symbols::find_document_symbols_streaming(
+            uri,
+            document,
+            html_document,
+            data_manager,
+            settings,
+            position_encoding,
+            chunk_size,
+            on_chunk,
+        )
+    }
+
+    /// Like `find_document_symbols2`, and the symbols of the root elements are passed to `on_chunk`
+    /// in chunks of `chunk_size`, it returns whether the symbols are truncated
+    #[cfg(feature = "symbols")]
+    pub fn find_document_symbols2_streaming(
+        document: &FullTextDocument,
+        html_document: &HTMLDocument,
+        data_manager: &HTMLDataManager,
+        settings: Option<&SymbolsConfiguration>,
+        position_encoding: Option<&PositionEncodingKind>,
+        chunk_size: usize,
+        on_chunk: impl FnMut(Vec<DocumentSymbol>),
+    ) -> bool {
+        html_symbols::find_document_symbols2_streaming(
+            document,
+            html_document,
+            data_manager,
+            settings,
+            position_encoding,
+            chunk_size,
+            on_chunk,
+        )
     }
 
     /// Get folding ranges for the given document
     #[cfg(feature = "folding")]
     pub fn get_folding_ranges(
-        document: FullTextDocument,
+        document: &FullTextDocument,
+        context: FoldingRangeContext,
+        data_manager: &HTMLDataManager,
+        position_encoding: Option<&PositionEncodingKind>,
+    ) -> Vec<FoldingRange> {
+        html_folding::get_folding_ranges(
+            document,
+            context,
+            data_manager,
+            &[],
+            &|_| {},
+            position_encoding,
+        )
+    }
+
+    /// Like `get_folding_ranges`, and the content of `<script>` and `<style>` is folded
+    /// by the participants of the builder
+    #[cfg(feature = "folding")]
+    pub fn get_folding_ranges_with_participants(
+        &self,
+        document: &FullTextDocument,
         context: FoldingRangeContext,
         data_manager: &HTMLDataManager,
     ) -> Vec<FoldingRange> {
-        html_folding::get_folding_ranges(document, context, data_manager)
+        html_folding::get_folding_ranges(
+            document,
+            context,
+            data_manager,
+            &self.folding_range_participants,
+            &|error| self.report_participant_error(error),
+            self.position_encoding.as_ref(),
+        )
+    }
+
+    /// Like `get_folding_ranges_with_participants`, and the ranges are passed to `on_chunk`
+    /// in chunks of `chunk_size` while the document is scanned, such as for the partial results of LSP
+    ///
+    /// The ranges are passed after the whole document is scanned if `range_limit` is set.
+    #[cfg(feature = "folding")]
+    pub fn get_folding_ranges_streaming(
+        &self,
+        document: &FullTextDocument,
+        context: FoldingRangeContext,
+        data_manager: &HTMLDataManager,
+        chunk_size: usize,
+        on_chunk: impl FnMut(Vec<FoldingRange>),
+    ) {
+        html_folding::get_folding_ranges_streaming(
+            document,
+            context,
+            data_manager,
+            &self.folding_range_participants,
+            &|error| self.report_participant_error(error),
+            self.position_encoding.as_ref(),
+            chunk_size,
+            on_chunk,
+        )
     }
 
     /// Get the selection ranges for the given document
@@ -271,8 +638,14 @@ impl HTMLLanguageService {
         document: &FullTextDocument,
         positions: &Vec<Position>,
         html_document: &HTMLDocument,
+        position_encoding: Option<&PositionEncodingKind>,
     ) -> Vec<SelectionRange> {
-        html_selection_range::get_selection_ranges(document, positions, html_document)
+        html_selection_range::get_selection_ranges(
+            document,
+            positions,
+            html_document,
+            position_encoding,
+        )
     }
 
     /// Rename the matching tag
@@ -283,8 +656,16 @@ impl HTMLLanguageService {
         position: Position,
         new_name: &str,
         html_document: &HTMLDocument,
+        position_encoding: Option<&PositionEncodingKind>,
     ) -> Option<WorkspaceEdit> {
-        html_rename::do_rename(uri, document, position, new_name, html_document)
+        html_rename::do_rename(
+            uri,
+            document,
+            position,
+            new_name,
+            html_document,
+            position_encoding,
+        )
     }
 
     /// Get the location of the matching tag
@@ -293,8 +674,41 @@ impl HTMLLanguageService {
         document: &FullTextDocument,
         position: Position,
         html_document: &HTMLDocument,
+        position_encoding: Option<&PositionEncodingKind>,
     ) -> Option<Position> {
-        html_matching_tag_position::find_matching_tag_position(document, position, html_document)
+        html_matching_tag_position::find_matching_tag_position(
+            document,
+            position,
+            html_document,
+            position_encoding,
+        )
+    }
+
+    /// Get the ranges of the start tag name and the end tag name of the element at the position
+    #[cfg(feature = "matching_tag_position")]
+    pub fn find_matching_tag_ranges(
+        document: &FullTextDocument,
+        position: Position,
+        html_document: &HTMLDocument,
+        position_encoding: Option<&PositionEncodingKind>,
+    ) -> Option<(Range, Range)> {
+        html_matching_tag_position::find_matching_tag_ranges(
+            document,
+            position,
+            html_document,
+            position_encoding,
+        )
+    }
+
+    /// Get the ranges of the start tag names and the end tag names of all the elements,
+    /// such as for the decorations of the tag pairs
+    #[cfg(feature = "matching_tag_position")]
+    pub fn get_all_tag_pairs(
+        document: &FullTextDocument,
+        html_document: &HTMLDocument,
+        position_encoding: Option<&PositionEncodingKind>,
+    ) -> Vec<(Range, Range)> {
+        html_matching_tag_position::get_all_tag_pairs(document, html_document, position_encoding)
     }
 
     /// Provides linked editing range capability
@@ -303,7 +717,202 @@ impl HTMLLanguageService {
         document: &FullTextDocument,
         position: Position,
         html_document: &HTMLDocument,
+        settings: Option<&LinkedEditingConfiguration>,
+        position_encoding: Option<&PositionEncodingKind>,
     ) -> Option<Vec<Range>> {
-        html_linked_editing::find_linked_editing_ranges(document, position, html_document)
+        html_linked_editing::find_linked_editing_ranges(
+            document,
+            position,
+            html_document,
+            settings,
+            position_encoding,
+        )
+    }
+
+    /// The capabilities to answer the client in the `initialize` request,
+    /// with the providers of the activated features and the position encoding of the options.
+    ///
+    /// The completion items are resolved if the default completion settings enable `lazy_documentation`.
+    /// The synchronization of the documents is left to the server.
+    pub fn server_capabilities(&self) -> ServerCapabilities {
+        #[allow(unused_mut)]
+        let mut capabilities = ServerCapabilities {
+            // Every service and the embedded regions count the characters in this encoding
+            position_encoding: self.position_encoding.clone(),
+            ..Default::default()
+        };
+        #[cfg(feature = "completion")]
+        {
+            let resolve_provider = self
+                .completion_settings
+                .as_ref()
+                .is_some_and(|settings| settings.lazy_documentation);
+            capabilities.completion_provider = Some(CompletionOptions {
+                resolve_provider: Some(resolve_provider),
+                trigger_characters: Some(
+                    COMPLETION_TRIGGER_CHARACTERS
+                        .iter()
+                        .map(|c| c.to_string())
+                        .collect(),
+                ),
+                ..Default::default()
+            });
+        }
+        #[cfg(feature = "hover")]
+        {
+            capabilities.hover_provider = Some(HoverProviderCapability::Simple(true));
+        }
+        #[cfg(feature = "formatter")]
+        {
+            capabilities.document_formatting_provider = Some(OneOf::Left(true));
+            capabilities.document_range_formatting_provider = Some(OneOf::Left(true));
+        }
+        #[cfg(feature = "highlight")]
+        {
+            capabilities.document_highlight_provider = Some(OneOf::Left(true));
+        }
+        #[cfg(feature = "links")]
+        {
+            capabilities.document_link_provider = Some(DocumentLinkOptions {
+                resolve_provider: Some(false),
+                work_done_progress_options: Default::default(),
+            });
+        }
+        #[cfg(feature = "symbols")]
+        {
+            capabilities.document_symbol_provider = Some(OneOf::Left(true));
+        }
+        #[cfg(feature = "folding")]
+        {
+            capabilities.folding_range_provider =
+                Some(FoldingRangeProviderCapability::Simple(true));
+        }
+        #[cfg(feature = "selection_range")]
+        {
+            capabilities.selection_range_provider =
+                Some(SelectionRangeProviderCapability::Simple(true));
+        }
+        #[cfg(feature = "rename")]
+        {
+            capabilities.rename_provider = Some(OneOf::Left(true));
+        }
+        #[cfg(feature = "linked_editing")]
+        {
+            capabilities.linked_editing_range_provider =
+                Some(LinkedEditingRangeServerCapabilities::Simple(true));
+        }
+        capabilities
+    }
+}
+
+/// The characters that trigger completion, such as `<` of the tags, `"` and `=` of the attribute values
+/// and `/` of the close tags and the paths
+#[cfg(feature = "completion")]
+const COMPLETION_TRIGGER_CHARACTERS: [char; 6] = ['.', ':', '<', '"', '=', '/'];
+
+/// Builds `HTMLLanguageService` with the options, the participants and the default settings, see `HTMLLanguageService::builder`
+#[derive(Default)]
+pub struct HTMLLanguageServiceBuilder {
+    options: HTMLLanguageServiceOptions,
+    #[cfg(feature = "completion")]
+    completion_participants: Vec<Box<dyn ICompletionParticipant>>,
+    #[cfg(feature = "completion")]
+    completion_settings: Option<CompletionConfiguration>,
+    #[cfg(feature = "hover")]
+    hover_participants: Vec<Box<dyn IHoverParticipant>>,
+    #[cfg(feature = "hover")]
+    hover_settings: Option<HoverSettings>,
+    #[cfg(feature = "formatter")]
+    format_settings: Option<HTMLFormatConfiguration>,
+    #[cfg(feature = "formatter")]
+    formatter_participants: Vec<Box<dyn IFormatterParticipant>>,
+    #[cfg(feature = "folding")]
+    folding_range_participants: Vec<Box<dyn IFoldingRangeParticipant>>,
+}
+
+impl HTMLLanguageServiceBuilder {
+    pub fn options(mut self, options: HTMLLanguageServiceOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Add a participant that provides additional completion items
+    #[cfg(feature = "completion")]
+    pub fn completion_participant(mut self, participant: Box<dyn ICompletionParticipant>) -> Self {
+        self.completion_participants.push(participant);
+        self
+    }
+
+    /// The settings of completion when the requests don't specify them
+    #[cfg(feature = "completion")]
+    pub fn completion_settings(mut self, settings: CompletionConfiguration) -> Self {
+        self.completion_settings = Some(settings);
+        self
+    }
+
+    /// Add a participant that provides additional hover
+    #[cfg(feature = "hover")]
+    pub fn hover_participant(mut self, participant: Box<dyn IHoverParticipant>) -> Self {
+        self.hover_participants.push(participant);
+        self
+    }
+
+    /// The settings of hover when the requests don't specify them
+    #[cfg(feature = "hover")]
+    pub fn hover_settings(mut self, settings: HoverSettings) -> Self {
+        self.hover_settings = Some(settings);
+        self
+    }
+
+    /// The settings of formatting when the requests don't specify them
+    #[cfg(feature = "formatter")]
+    pub fn format_settings(mut self, settings: HTMLFormatConfiguration) -> Self {
+        self.format_settings = Some(settings);
+        self
+    }
+
+    /// Add a participant that formats the content of `<script>` and `<style>`,
+    /// the participants are tried in order until one of them supports the language
+    #[cfg(feature = "formatter")]
+    pub fn formatter_participant(mut self, participant: Box<dyn IFormatterParticipant>) -> Self {
+        self.formatter_participants.push(participant);
+        self
+    }
+
+    /// Add a participant that provides the folding ranges of the content of `<script>` and `<style>`
+    #[cfg(feature = "folding")]
+    pub fn folding_range_participant(
+        mut self,
+        participant: Box<dyn IFoldingRangeParticipant>,
+    ) -> Self {
+        self.folding_range_participants.push(participant);
+        self
+    }
+
+    pub fn build(self) -> HTMLLanguageService {
+        #[allow(unused_mut)]
+        let mut ls = HTMLLanguageService::new(&self.options);
+        #[cfg(feature = "completion")]
+        {
+            ls.html_completion
+                .set_completion_participants(self.completion_participants);
+            ls.completion_settings = self.completion_settings;
+        }
+        #[cfg(feature = "hover")]
+        {
+            ls.html_hover
+                .set_hover_participants(self.hover_participants);
+            ls.hover_settings = self.hover_settings;
+        }
+        #[cfg(feature = "formatter")]
+        {
+            ls.format_settings = self.format_settings;
+            ls.formatter_participants = self.formatter_participants;
+        }
+        #[cfg(feature = "folding")]
+        {
+            ls.folding_range_participants = self.folding_range_participants;
+        }
+        ls
     }
 }
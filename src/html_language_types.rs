@@ -1,4 +1,11 @@
-use lsp_types::ClientCapabilities;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use lsp_types::{ClientCapabilities, PositionEncodingKind, Url, WorkspaceFolder};
+
+use crate::{
+    language_facts::data_provider::DocumentationRenderer, participant::ParticipantErrorHandler,
+};
 
 #[derive(Default)]
 pub struct HTMLLanguageServiceOptions {
@@ -18,23 +25,53 @@ pub struct HTMLLanguageServiceOptions {
     /**
      * Abstract file system access away from the service.
      * Used for path completion, etc.
+     * It's shared with the service, which lists the directories by it in `do_complete2`.
      */
-    pub file_system_provider: Option<Box<dyn FileSystemProvider>>,
+    pub file_system_provider: Option<Arc<dyn FileSystemProvider>>,
 
     /**
      * Describes the LSP capabilities the client supports.
      */
     pub client_capabilities: Option<ClientCapabilities>,
+
+    /**
+     * The position encoding negotiated with the client,
+     * all the services use it to convert between offsets and positions.
+     * Defaults to the conversion of `FullTextDocument`.
+     */
+    pub position_encoding: Option<PositionEncodingKind>,
+
+    /**
+     * Render the documentation of tags, attributes and values for hover and completion,
+     * instead of the built-in layout of the description, references and Baseline status.
+     */
+    pub doc_renderer: Option<DocumentationRenderer>,
+
+    /**
+     * The workspace folders, `WorkspaceDocumentContext` resolves the references starting with `/`
+     * against the folder that contains the document.
+     */
+    pub workspace_folders: Option<Vec<WorkspaceFolder>>,
+
+    /**
+     * Receive the errors of the participants of completion, hover, formatting and folding,
+     * the failed participants are skipped.
+     */
+    pub participant_error_handler: Option<ParticipantErrorHandler>,
 }
 
+#[async_trait]
 pub trait FileSystemProvider: Send + Sync {
-    fn stat(&self, uri: DocumentUri) -> FileStat;
+    /// The metadata of the file, its type is `FileType::Unknown` if it doesn't exist
+    async fn stat(&self, uri: DocumentUri) -> FileStat;
 
-    fn read_directory(&self, uri: DocumentUri) -> (String, FileType);
+    /// The names and the types of the entries of the directory
+    async fn read_directory(&self, uri: DocumentUri) -> Vec<(String, FileType)>;
 }
 
 pub type DocumentUri = String;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FileStat {
     /// The type of the file, e.g. is a regular file, a directory, or symbolic link
     /// to a file.
@@ -47,6 +84,7 @@ pub struct FileStat {
     pub size: usize,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FileType {
     /// The file type is unknown.
     Unknown = 0,
@@ -58,14 +96,67 @@ pub enum FileType {
     SymbolicLink = 64,
 }
 
-pub trait DocumentContext {
+pub trait DocumentContext: Send + Sync {
     fn resolve_reference(&self, reference: &str, base: &str) -> Option<String>;
 }
 
+/// Resolves the references against the base URI, such as the document URI
+#[derive(Debug, Clone, Copy, Default)]
 pub struct DefaultDocumentContext;
 
 impl DocumentContext for DefaultDocumentContext {
-    fn resolve_reference(&self, _reference: &str, _base: &str) -> Option<String> {
-        None
+    fn resolve_reference(&self, reference: &str, base: &str) -> Option<String> {
+        let base = Url::parse(base).ok()?;
+        base.join(reference).ok().map(|uri| uri.to_string())
+    }
+}
+
+/// Resolves the references like `DefaultDocumentContext`,
+/// except the references starting with `/` against the workspace folder that contains the base
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceDocumentContext {
+    pub workspace_folders: Vec<Url>,
+}
+
+impl WorkspaceDocumentContext {
+    pub fn new(workspace_folders: Vec<Url>) -> WorkspaceDocumentContext {
+        WorkspaceDocumentContext { workspace_folders }
+    }
+}
+
+impl From<&HTMLLanguageServiceOptions> for WorkspaceDocumentContext {
+    fn from(options: &HTMLLanguageServiceOptions) -> Self {
+        WorkspaceDocumentContext::new(
+            options
+                .workspace_folders
+                .as_ref()
+                .map_or(vec![], |folders| {
+                    folders.iter().map(|folder| folder.uri.clone()).collect()
+                }),
+        )
+    }
+}
+
+impl DocumentContext for WorkspaceDocumentContext {
+    fn resolve_reference(&self, reference: &str, base: &str) -> Option<String> {
+        if reference.starts_with('/') && !reference.starts_with("//") {
+            let base = Url::parse(base).ok()?;
+            let folder = self
+                .workspace_folders
+                .iter()
+                .map(|folder| {
+                    let mut folder = folder.clone();
+                    if !folder.path().ends_with('/') {
+                        folder.set_path(&format!("{}/", folder.path()));
+                    }
+                    folder
+                })
+                .filter(|folder| base.as_str().starts_with(folder.as_str()))
+                .max_by_key(|folder| folder.as_str().len());
+            if let Some(folder) = folder {
+                return folder.join(&reference[1..]).ok().map(|uri| uri.to_string());
+            }
+        }
+        DefaultDocumentContext.resolve_reference(reference, base)
     }
 }
@@ -0,0 +1,327 @@
+// Angular built-in directives and elements, descriptions are from the Angular API reference
+pub static ANGULAR_DATA: &str = r##"{
+    "version": 1.1,
+    "tags": [
+        {
+            "name": "ng-container",
+            "description": {
+                "kind": "markdown",
+                "value": "A special element that can hold structural directives without adding new elements to the DOM."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/core/ng-container"
+                }
+            ]
+        },
+        {
+            "name": "ng-template",
+            "description": {
+                "kind": "markdown",
+                "value": "Angular's `<ng-template>` element defines a template that is not rendered by default."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/core/ng-template"
+                }
+            ]
+        },
+        {
+            "name": "ng-content",
+            "description": {
+                "kind": "markdown",
+                "value": "Specifies where to project content inside a component template."
+            },
+            "attributes": [
+                {
+                    "name": "select",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "A CSS selector, content matching the selector is projected into this `ng-content`."
+                    },
+                    "references": [
+                        {
+                            "name": "Angular Reference",
+                            "url": "https://angular.dev/api/core/ng-content"
+                        }
+                    ]
+                }
+            ],
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/core/ng-content"
+                }
+            ]
+        },
+        {
+            "name": "router-outlet",
+            "description": {
+                "kind": "markdown",
+                "value": "Acts as a placeholder that Angular dynamically fills based on the current router state."
+            },
+            "attributes": [
+                {
+                    "name": "name",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The name of the outlet."
+                    },
+                    "references": [
+                        {
+                            "name": "Angular Reference",
+                            "url": "https://angular.dev/api/router/RouterOutlet"
+                        }
+                    ]
+                }
+            ],
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/router/RouterOutlet"
+                }
+            ]
+        }
+    ],
+    "globalAttributes": [
+        {
+            "name": "*ngIf",
+            "description": {
+                "kind": "markdown",
+                "value": "A structural directive that conditionally includes a template based on the value of an expression coerced to Boolean."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/common/NgIf"
+                }
+            ]
+        },
+        {
+            "name": "*ngFor",
+            "description": {
+                "kind": "markdown",
+                "value": "A structural directive that renders a template for each item in a collection, such as `let item of items; trackBy: trackById`."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/common/NgFor"
+                }
+            ]
+        },
+        {
+            "name": "[ngSwitch]",
+            "description": {
+                "kind": "markdown",
+                "value": "The `[ngSwitch]` directive on a container specifies an expression to match against, the expressions to match are provided by `ngSwitchCase` directives on views within the container."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/common/NgSwitch"
+                }
+            ]
+        },
+        {
+            "name": "*ngSwitchCase",
+            "description": {
+                "kind": "markdown",
+                "value": "Provides a switch case expression to match against an enclosing `ngSwitch` expression."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/common/NgSwitchCase"
+                }
+            ]
+        },
+        {
+            "name": "*ngSwitchDefault",
+            "description": {
+                "kind": "markdown",
+                "value": "Creates a view that is rendered when no `NgSwitchCase` expressions match the `NgSwitch` expression."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/common/NgSwitchDefault"
+                }
+            ],
+            "valueSet": "v"
+        },
+        {
+            "name": "[ngClass]",
+            "description": {
+                "kind": "markdown",
+                "value": "Adds and removes CSS classes on an HTML element."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/common/NgClass"
+                }
+            ]
+        },
+        {
+            "name": "[ngStyle]",
+            "description": {
+                "kind": "markdown",
+                "value": "An attribute directive that updates styles for the containing HTML element."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/common/NgStyle"
+                }
+            ]
+        },
+        {
+            "name": "*ngTemplateOutlet",
+            "description": {
+                "kind": "markdown",
+                "value": "Inserts an embedded view from a prepared `TemplateRef`."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/common/NgTemplateOutlet"
+                }
+            ]
+        },
+        {
+            "name": "[(ngModel)]",
+            "description": {
+                "kind": "markdown",
+                "value": "Creates a two-way data binding of a form control to a property of the component."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/forms/NgModel"
+                }
+            ]
+        },
+        {
+            "name": "[ngModel]",
+            "description": {
+                "kind": "markdown",
+                "value": "Binds a domain model to a form control."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/forms/NgModel"
+                }
+            ]
+        },
+        {
+            "name": "(ngModelChange)",
+            "description": {
+                "kind": "markdown",
+                "value": "Event emitter for producing the `ngModelChange` event after the view model updates."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/forms/NgModel"
+                }
+            ]
+        },
+        {
+            "name": "[formControl]",
+            "description": {
+                "kind": "markdown",
+                "value": "Synchronizes a standalone `FormControl` instance to a form control element."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/forms/FormControlDirective"
+                }
+            ]
+        },
+        {
+            "name": "formControlName",
+            "description": {
+                "kind": "markdown",
+                "value": "Syncs a `FormControl` in an existing `FormGroup` to a form control element by name."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/forms/FormControlName"
+                }
+            ]
+        },
+        {
+            "name": "[formGroup]",
+            "description": {
+                "kind": "markdown",
+                "value": "Binds an existing `FormGroup` or `FormRecord` to a DOM element."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/forms/FormGroupDirective"
+                }
+            ]
+        },
+        {
+            "name": "(ngSubmit)",
+            "description": {
+                "kind": "markdown",
+                "value": "Emits an event when the form submission has been triggered."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/forms/FormGroupDirective"
+                }
+            ]
+        },
+        {
+            "name": "routerLink",
+            "description": {
+                "kind": "markdown",
+                "value": "When applied to an element in a template, makes that element a link that initiates navigation to a route."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/router/RouterLink"
+                }
+            ]
+        },
+        {
+            "name": "[routerLink]",
+            "description": {
+                "kind": "markdown",
+                "value": "When applied to an element in a template, makes that element a link that initiates navigation to a route."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/router/RouterLink"
+                }
+            ]
+        },
+        {
+            "name": "routerLinkActive",
+            "description": {
+                "kind": "markdown",
+                "value": "Tracks whether the linked route of an element is currently active, and allows you to specify one or more CSS classes to add to the element when the linked route is active."
+            },
+            "references": [
+                {
+                    "name": "Angular Reference",
+                    "url": "https://angular.dev/api/router/RouterLinkActive"
+                }
+            ]
+        }
+    ]
+}"##;
@@ -0,0 +1,233 @@
+use lsp_types::{MarkupContent, MarkupKind};
+
+use crate::html_data::{Description, HTMLDataV1};
+
+/// The states and properties that are supported by all roles
+pub const GLOBAL_ARIA_ATTRIBUTES: [&str; 21] = [
+    "aria-atomic",
+    "aria-busy",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-live",
+    "aria-owns",
+    "aria-relevant",
+    "aria-roledescription",
+];
+
+const TEXTBOX: [&str; 6] = [
+    "aria-activedescendant",
+    "aria-autocomplete",
+    "aria-multiline",
+    "aria-placeholder",
+    "aria-readonly",
+    "aria-required",
+];
+const HEADER_CELL: [&str; 9] = [
+    "aria-colindex",
+    "aria-colspan",
+    "aria-expanded",
+    "aria-readonly",
+    "aria-required",
+    "aria-rowindex",
+    "aria-rowspan",
+    "aria-selected",
+    "aria-sort",
+];
+
+/// WAI-ARIA roles, their descriptions and the states and properties
+/// supported by them besides `GLOBAL_ARIA_ATTRIBUTES`
+///
+/// <https://www.w3.org/TR/wai-aria-1.2/#role_definitions>
+const ROLES: [(&str, &str, &[&str]); 73] = [
+    ("alert", "A type of live region with important, and usually time-sensitive, information.", &[]),
+    ("alertdialog", "A type of dialog that contains an alert message, where initial focus goes to an element within the dialog.", &["aria-modal"]),
+    ("application", "A structure containing one or more focusable elements requiring user input, such as keyboard or gesture events, that do not follow a standard interaction pattern supported by a widget role.", &["aria-activedescendant", "aria-expanded"]),
+    ("article", "A section of a page that consists of a composition that forms an independent part of a document, page, or site.", &["aria-posinset", "aria-setsize"]),
+    ("banner", "A landmark that contains mostly site-oriented content, rather than page-specific content.", &[]),
+    ("button", "An input that allows for user-triggered actions when clicked or pressed.", &["aria-expanded", "aria-pressed"]),
+    ("cell", "A cell in a tabular container.", &["aria-colindex", "aria-colspan", "aria-rowindex", "aria-rowspan"]),
+    ("checkbox", "A checkable input that has three possible values: `true`, `false`, or `mixed`.", &["aria-checked", "aria-expanded", "aria-readonly", "aria-required"]),
+    ("columnheader", "A cell containing header information for a column.", &HEADER_CELL),
+    ("combobox", "An input that controls another element, such as a listbox or grid, that can dynamically pop up to help the user set the value of the input.", &["aria-activedescendant", "aria-autocomplete", "aria-expanded", "aria-readonly", "aria-required"]),
+    ("complementary", "A landmark that is designed to be complementary to the main content at a similar level in the DOM hierarchy, but remains meaningful when separated from the main content.", &[]),
+    ("contentinfo", "A landmark that contains information about the parent document, such as copyrights and links to privacy statements.", &[]),
+    ("definition", "A definition of a term or concept.", &[]),
+    ("dialog", "A descendant window of the primary window of a web application, separated from the rest of the application.", &["aria-modal"]),
+    ("directory", "A list of references to members of a group, such as a static table of contents.", &[]),
+    ("document", "An element containing content that assistive technology users may want to browse in a reading mode.", &["aria-expanded"]),
+    ("feed", "A scrollable list of articles where scrolling may cause articles to be added to or removed from either end of the list.", &[]),
+    ("figure", "A perceivable section of content that typically contains a graphical document, images, code snippets, or example text.", &[]),
+    ("form", "A landmark region that contains a collection of items and objects that, as a whole, combine to create a form.", &[]),
+    ("generic", "A nameless container element that has no semantic meaning on its own.", &[]),
+    ("grid", "A composite widget containing a collection of one or more rows with one or more cells where some or all cells in the grid are focusable by using methods of two-dimensional navigation.", &["aria-activedescendant", "aria-colcount", "aria-multiselectable", "aria-readonly", "aria-rowcount"]),
+    ("gridcell", "A cell in a grid or treegrid.", &["aria-colindex", "aria-colspan", "aria-expanded", "aria-readonly", "aria-required", "aria-rowindex", "aria-rowspan", "aria-selected"]),
+    ("group", "A set of user interface objects that is not intended to be included in a page summary or table of contents by assistive technologies.", &["aria-activedescendant"]),
+    ("heading", "A heading for a section of the page.", &["aria-level"]),
+    ("img", "A container for a collection of elements that form an image.", &[]),
+    ("link", "An interactive reference to an internal or external resource that, when activated, causes the user agent to navigate to that resource.", &["aria-expanded"]),
+    ("list", "A section containing `listitem` elements.", &[]),
+    ("listbox", "A widget that allows the user to select one or more items from a list of choices.", &["aria-activedescendant", "aria-expanded", "aria-multiselectable", "aria-orientation", "aria-readonly", "aria-required"]),
+    ("listitem", "A single item in a list or directory.", &["aria-level", "aria-posinset", "aria-setsize"]),
+    ("log", "A type of live region where new information is added in meaningful order and old information may disappear.", &[]),
+    ("main", "A landmark containing the main content of a document.", &[]),
+    ("marquee", "A type of live region where non-essential information changes frequently.", &[]),
+    ("math", "Content that represents a mathematical expression.", &[]),
+    ("menu", "A type of widget that offers a list of choices to the user.", &["aria-activedescendant", "aria-orientation"]),
+    ("menubar", "A presentation of menu that usually remains visible and is usually presented horizontally.", &["aria-activedescendant", "aria-orientation"]),
+    ("menuitem", "An option in a set of choices contained by a `menu` or `menubar`.", &["aria-expanded", "aria-posinset", "aria-setsize"]),
+    ("menuitemcheckbox", "A `menuitem` with a checkable state whose possible values are `true`, `false`, or `mixed`.", &["aria-checked", "aria-expanded", "aria-posinset", "aria-setsize"]),
+    ("menuitemradio", "A checkable `menuitem` in a set of elements with the same role, only one of which can be checked at a time.", &["aria-checked", "aria-expanded", "aria-posinset", "aria-setsize"]),
+    ("navigation", "A landmark containing a collection of navigational elements (usually links) for navigating the document or related documents.", &[]),
+    ("none", "An element whose implicit native role semantics will not be mapped to the accessibility API, it's a synonym of `presentation`.", &[]),
+    ("note", "A section whose content is parenthetic or ancillary to the main content of the resource.", &[]),
+    ("option", "A selectable item in a `listbox`.", &["aria-checked", "aria-posinset", "aria-selected", "aria-setsize"]),
+    ("presentation", "An element whose implicit native role semantics will not be mapped to the accessibility API.", &[]),
+    ("progressbar", "An element that displays the progress status for tasks that take a long time.", &["aria-valuemax", "aria-valuemin", "aria-valuenow", "aria-valuetext"]),
+    ("radio", "A checkable input in a group of elements with the same role, only one of which can be checked at a time.", &["aria-checked", "aria-posinset", "aria-setsize"]),
+    ("radiogroup", "A group of `radio` buttons.", &["aria-activedescendant", "aria-orientation", "aria-readonly", "aria-required"]),
+    ("region", "A landmark containing content that is relevant to a specific, author-specified purpose and sufficiently important that users will likely want to be able to navigate to the section easily.", &[]),
+    ("row", "A row of cells in a tabular container.", &["aria-activedescendant", "aria-colindex", "aria-expanded", "aria-level", "aria-posinset", "aria-rowindex", "aria-selected", "aria-setsize"]),
+    ("rowgroup", "A structure containing one or more row elements in a tabular container.", &[]),
+    ("rowheader", "A cell containing header information for a row in a grid.", &HEADER_CELL),
+    ("scrollbar", "A graphical object that controls the scrolling of content within a viewing area, regardless of whether the content is fully displayed within the viewing area.", &["aria-orientation", "aria-valuemax", "aria-valuemin", "aria-valuenow"]),
+    ("search", "A landmark region that contains a collection of items and objects that, as a whole, combine to create a search facility.", &[]),
+    ("searchbox", "A type of textbox intended for specifying search criteria.", &TEXTBOX),
+    ("separator", "A divider that separates and distinguishes sections of content or groups of menuitems.", &["aria-orientation", "aria-valuemax", "aria-valuemin", "aria-valuenow", "aria-valuetext"]),
+    ("slider", "An input where the user selects a value from within a given range.", &["aria-orientation", "aria-readonly", "aria-valuemax", "aria-valuemin", "aria-valuenow", "aria-valuetext"]),
+    ("spinbutton", "A form of range that expects the user to select from among discrete choices.", &["aria-activedescendant", "aria-readonly", "aria-required", "aria-valuemax", "aria-valuemin", "aria-valuenow", "aria-valuetext"]),
+    ("status", "A type of live region whose content is advisory information for the user but is not important enough to justify an alert.", &[]),
+    ("switch", "A type of checkbox that represents on/off values, as opposed to checked/unchecked values.", &["aria-checked", "aria-expanded", "aria-readonly", "aria-required"]),
+    ("tab", "A grouping label providing a mechanism for selecting the tab content that is to be rendered to the user.", &["aria-expanded", "aria-posinset", "aria-selected", "aria-setsize"]),
+    ("table", "A section containing data arranged in rows and columns.", &["aria-colcount", "aria-rowcount"]),
+    ("tablist", "A list of `tab` elements, which are references to `tabpanel` elements.", &["aria-activedescendant", "aria-multiselectable", "aria-orientation"]),
+    ("tabpanel", "A container for the resources associated with a `tab`, where each `tab` is contained in a `tablist`.", &[]),
+    ("term", "A word or phrase with an optional corresponding definition.", &[]),
+    ("text", "An element whose content is exposed to assistive technologies as a single text string.", &[]),
+    ("textbox", "A type of input that allows free-form text as its value.", &TEXTBOX),
+    ("timer", "A type of live region containing a numerical counter which indicates an amount of elapsed time from a start point, or the time remaining until an end point.", &[]),
+    ("toolbar", "A collection of commonly used function buttons or controls represented in compact visual form.", &["aria-activedescendant", "aria-orientation"]),
+    ("tooltip", "A contextual popup that displays a description for an element.", &[]),
+    ("tree", "A widget that allows the user to select one or more items from a hierarchically organized collection.", &["aria-activedescendant", "aria-multiselectable", "aria-orientation", "aria-required"]),
+    ("treegrid", "A grid whose rows can be expanded and collapsed in the same manner as for a tree.", &["aria-activedescendant", "aria-colcount", "aria-multiselectable", "aria-orientation", "aria-readonly", "aria-required", "aria-rowcount"]),
+    ("treeitem", "An item in a tree.", &["aria-checked", "aria-expanded", "aria-level", "aria-posinset", "aria-selected", "aria-setsize"]),
+    ("doc-abstract", "A short summary of the principal ideas, concepts, and conclusions of the work, or of a section or excerpt within it.", &[]),
+    ("doc-toc", "A navigational aid that provides an ordered list of links to the major sectional headings in the content.", &[]),
+];
+
+/// Get the description of the role
+pub fn get_role_description(role: &str) -> Option<&'static str> {
+    ROLES
+        .iter()
+        .find(|(name, _, _)| *name == role)
+        .map(|(_, description, _)| *description)
+}
+
+/// Is the `aria-*` attribute supported by the role
+///
+/// It returns `None` if the role is unknown.
+pub fn is_supported_by_role(role: &str, attribute: &str) -> Option<bool> {
+    let (_, _, attributes) = ROLES.iter().find(|(name, _, _)| *name == role)?;
+    Some(GLOBAL_ARIA_ATTRIBUTES.contains(&attribute) || attributes.contains(&attribute))
+}
+
+/// Get the role of the element, the explicit `role` attribute takes precedence over the implicit role
+///
+/// `get_attribute` returns the value of the attribute of the element, without quotes.
+pub fn get_role<'a>(tag: &str, get_attribute: impl Fn(&str) -> Option<&'a str>) -> Option<String> {
+    if let Some(role) = get_attribute("role").and_then(|role| role.split_whitespace().next()) {
+        return Some(role.to_lowercase());
+    }
+    let role = match tag.to_lowercase().as_str() {
+        "a" | "area" => {
+            if get_attribute("href").is_some() {
+                "link"
+            } else {
+                "generic"
+            }
+        }
+        "article" => "article",
+        "aside" => "complementary",
+        "button" => "button",
+        "datalist" => "listbox",
+        "details" | "fieldset" | "optgroup" => "group",
+        "dialog" => "dialog",
+        "figure" => "figure",
+        "footer" => "contentinfo",
+        "form" => "form",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+        "header" => "banner",
+        "hr" => "separator",
+        "img" => "img",
+        "input" => match get_attribute("type")
+            .unwrap_or("text")
+            .to_lowercase()
+            .as_str()
+        {
+            "button" | "image" | "reset" | "submit" => "button",
+            "checkbox" => "checkbox",
+            "number" => "spinbutton",
+            "radio" => "radio",
+            "range" => "slider",
+            "search" => "searchbox",
+            "email" | "tel" | "text" | "url" => "textbox",
+            _ => return None,
+        },
+        "li" => "listitem",
+        "main" => "main",
+        "math" => "math",
+        "menu" | "ol" | "ul" => "list",
+        "nav" => "navigation",
+        "option" => "option",
+        "output" => "status",
+        "progress" => "progressbar",
+        "section" => "region",
+        "select" => {
+            if get_attribute("multiple").is_some() {
+                "listbox"
+            } else {
+                "combobox"
+            }
+        }
+        "table" => "table",
+        "tbody" | "tfoot" | "thead" => "rowgroup",
+        "td" => "cell",
+        "textarea" => "textbox",
+        "th" => "columnheader",
+        "tr" => "row",
+        "b" | "bdi" | "bdo" | "body" | "data" | "div" | "i" | "pre" | "q" | "samp" | "small"
+        | "span" | "u" => "generic",
+        _ => return None,
+    };
+    Some(role.to_string())
+}
+
+/// Add the descriptions of the roles to the values of the `roles` value set
+pub fn describe_roles(data: &mut HTMLDataV1) {
+    if let Some(value_sets) = &mut data.value_sets {
+        for value_set in value_sets.iter_mut().filter(|vs| vs.name == "roles") {
+            for value in &mut value_set.values {
+                if value.description.is_none() {
+                    if let Some(description) = get_role_description(&value.name) {
+                        value.description = Some(Description::MarkupContent(MarkupContent {
+                            kind: MarkupKind::Markdown,
+                            value: description.to_string(),
+                        }));
+                    }
+                }
+            }
+        }
+    }
+}
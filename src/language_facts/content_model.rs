@@ -0,0 +1,163 @@
+/// The content model of an HTML element
+///
+/// <https://html.spec.whatwg.org/multipage/dom.html#content-models>
+pub struct ContentModel {
+    /// The content categories the element belongs to
+    pub categories: &'static str,
+    /// The content that the element can contain
+    pub content: &'static str,
+    /// The elements that can be the parent of the element
+    pub parents: &'static str,
+}
+
+const fn model(
+    categories: &'static str,
+    content: &'static str,
+    parents: &'static str,
+) -> ContentModel {
+    ContentModel {
+        categories,
+        content,
+        parents,
+    }
+}
+
+const FLOW_PARENT: &str = "Any element that accepts flow content.";
+const PHRASING_PARENT: &str = "Any element that accepts phrasing content.";
+const PHRASING_ELEMENT: &str = "Flow content, phrasing content, palpable content.";
+const HEADING: ContentModel = model(
+    "Flow content, heading content, palpable content.",
+    "Phrasing content.",
+    "Any element that accepts flow content, or an `hgroup` element.",
+);
+const SECTIONING: ContentModel = model(
+    "Flow content, sectioning content, palpable content.",
+    "Flow content.",
+    FLOW_PARENT,
+);
+const PHRASING: ContentModel = model(PHRASING_ELEMENT, "Phrasing content.", PHRASING_PARENT);
+const TABLE_SECTION: ContentModel =
+    model("None.", "Zero or more `tr` elements.", "A `table` element.");
+const TABLE_CELL: ContentModel = model("None.", "Flow content.", "A `tr` element.");
+const LIST: ContentModel = model(
+    "Flow content, and palpable content if it has at least one `li` child.",
+    "Zero or more `li`, `script` and `template` elements.",
+    FLOW_PARENT,
+);
+
+static CONTENT_MODELS: &[(&str, ContentModel)] = &[
+    ("html", model("None.", "One `head` element, followed by one `body` element.", "None, it's the root element of a document.")),
+    ("head", model("None.", "Metadata content, at least one `title` element unless the title is provided by a higher-level protocol.", "An `html` element, as its first child.")),
+    ("title", model("Metadata content.", "Text that is not inter-element whitespace.", "A `head` element that contains no other `title` element.")),
+    ("base", model("Metadata content.", "None, it's a void element.", "A `head` element that contains no other `base` element.")),
+    ("link", model("Metadata content, and flow content and phrasing content if `itemprop` is present or it's body-ok.", "None, it's a void element.", "Any element that accepts metadata content, or phrasing content if it's body-ok.")),
+    ("meta", model("Metadata content, and flow content and phrasing content if `itemprop` is present.", "None, it's a void element.", "A `head` element, or any element that accepts metadata content or phrasing content, depending on its attributes.")),
+    ("style", model("Metadata content.", "Text content matching the `text/css` type.", "Any element that accepts metadata content.")),
+    ("body", model("Sectioning root.", "Flow content.", "An `html` element, as its second child.")),
+    ("article", SECTIONING),
+    ("section", SECTIONING),
+    ("nav", SECTIONING),
+    ("aside", SECTIONING),
+    ("h1", HEADING),
+    ("h2", HEADING),
+    ("h3", HEADING),
+    ("h4", HEADING),
+    ("h5", HEADING),
+    ("h6", HEADING),
+    ("hgroup", model("Flow content, heading content, palpable content.", "Zero or more `p` elements, followed by one `h1`-`h6` element, followed by zero or more `p` elements.", FLOW_PARENT)),
+    ("header", model("Flow content, palpable content.", "Flow content, but with no `header` or `footer` descendant.", "Any element that accepts flow content, but not an `address`, `footer` or another `header` element.")),
+    ("footer", model("Flow content, palpable content.", "Flow content, but with no `header` or `footer` descendant.", "Any element that accepts flow content, but not an `address`, `header` or another `footer` element.")),
+    ("address", model("Flow content, palpable content.", "Flow content, but with no heading content, sectioning content, `header`, `footer` or `address` descendant.", FLOW_PARENT)),
+    ("p", model("Flow content, palpable content.", "Phrasing content.", FLOW_PARENT)),
+    ("hr", model("Flow content.", "None, it's a void element.", "Any element that accepts flow content, or a `select` element.")),
+    ("pre", model("Flow content, palpable content.", "Phrasing content.", FLOW_PARENT)),
+    ("blockquote", model("Flow content, sectioning root, palpable content.", "Flow content.", FLOW_PARENT)),
+    ("ol", LIST),
+    ("ul", LIST),
+    ("menu", LIST),
+    ("li", model("None.", "Flow content.", "An `ol`, `ul` or `menu` element.")),
+    ("dl", model("Flow content, and palpable content if it has at least one name-value group.", "Zero or more groups of one or more `dt` elements followed by one or more `dd` elements, optionally wrapped in `div` elements.", FLOW_PARENT)),
+    ("dt", model("None.", "Flow content, but with no `header`, `footer`, sectioning content or heading content descendant.", "A `dl` element, or a `div` element that is a child of a `dl` element.")),
+    ("dd", model("None.", "Flow content.", "A `dl` element, or a `div` element that is a child of a `dl` element.")),
+    ("figure", model("Flow content, sectioning root, palpable content.", "A `figcaption` element followed by flow content, or flow content followed by a `figcaption` element, or flow content.", FLOW_PARENT)),
+    ("figcaption", model("None.", "Flow content.", "A `figure` element, as its first or last child.")),
+    ("main", model("Flow content, palpable content.", "Flow content.", "Any element that accepts flow content, but only if it's a hierarchically correct `main` element.")),
+    ("search", model("Flow content, palpable content.", "Flow content.", FLOW_PARENT)),
+    ("div", model("Flow content, palpable content.", "Flow content, or one or more `dt` elements followed by one or more `dd` elements if it's a child of a `dl` element.", "Any element that accepts flow content, or a `dl` element.")),
+    ("a", model("Flow content, phrasing content, interactive content, palpable content.", "Transparent, but with no interactive content or `a` element descendant.", PHRASING_PARENT)),
+    ("em", PHRASING),
+    ("strong", PHRASING),
+    ("small", PHRASING),
+    ("s", PHRASING),
+    ("cite", PHRASING),
+    ("q", PHRASING),
+    ("dfn", model(PHRASING_ELEMENT, "Phrasing content, but with no `dfn` element descendant.", PHRASING_PARENT)),
+    ("abbr", PHRASING),
+    ("ruby", model(PHRASING_ELEMENT, "Phrasing content, and `rt` and `rp` elements.", PHRASING_PARENT)),
+    ("rt", model("None.", "Phrasing content.", "A `ruby` element.")),
+    ("rp", model("None.", "Text.", "A `ruby` element, immediately before or after an `rt` element.")),
+    ("data", PHRASING),
+    ("time", model(PHRASING_ELEMENT, "Phrasing content, or text if the `datetime` attribute is absent.", PHRASING_PARENT)),
+    ("code", PHRASING),
+    ("var", PHRASING),
+    ("samp", PHRASING),
+    ("kbd", PHRASING),
+    ("sub", PHRASING),
+    ("sup", PHRASING),
+    ("i", PHRASING),
+    ("b", PHRASING),
+    ("u", PHRASING),
+    ("mark", PHRASING),
+    ("bdi", PHRASING),
+    ("bdo", PHRASING),
+    ("span", PHRASING),
+    ("br", model("Flow content, phrasing content.", "None, it's a void element.", PHRASING_PARENT)),
+    ("wbr", model("Flow content, phrasing content.", "None, it's a void element.", PHRASING_PARENT)),
+    ("ins", model("Flow content, phrasing content, palpable content.", "Transparent.", PHRASING_PARENT)),
+    ("del", model("Flow content, phrasing content.", "Transparent.", PHRASING_PARENT)),
+    ("picture", model("Flow content, phrasing content, embedded content.", "Zero or more `source` elements, followed by one `img` element, optionally intermixed with script-supporting elements.", "Any element that accepts embedded content.")),
+    ("source", model("None.", "None, it's a void element.", "A `picture` element before the `img` element, or an `audio` or `video` element before any flow content or `track` element.")),
+    ("img", model("Flow content, phrasing content, embedded content, palpable content, and interactive content if it has the `usemap` attribute.", "None, it's a void element.", "Any element that accepts embedded content.")),
+    ("iframe", model("Flow content, phrasing content, embedded content, interactive content, palpable content.", "None.", "Any element that accepts embedded content.")),
+    ("video", model("Flow content, phrasing content, embedded content, palpable content, and interactive content if it has the `controls` attribute.", "Zero or more `source` elements if it has no `src` attribute, followed by zero or more `track` elements, followed by transparent content with no media element descendant.", "Any element that accepts embedded content.")),
+    ("audio", model("Flow content, phrasing content, embedded content, and interactive content and palpable content if it has the `controls` attribute.", "Zero or more `source` elements if it has no `src` attribute, followed by zero or more `track` elements, followed by transparent content with no media element descendant.", "Any element that accepts embedded content.")),
+    ("track", model("None.", "None, it's a void element.", "An `audio` or `video` element, before any flow content.")),
+    ("table", model("Flow content, palpable content.", "An optional `caption` element, followed by zero or more `colgroup` elements, followed by an optional `thead` element, followed by zero or more `tbody` elements or one or more `tr` elements, followed by an optional `tfoot` element.", FLOW_PARENT)),
+    ("caption", model("None.", "Flow content, but with no `table` element descendant.", "A `table` element, as its first child.")),
+    ("colgroup", model("None.", "Zero or more `col` and `template` elements if the `span` attribute is absent, otherwise nothing.", "A `table` element, after any `caption` element and before any `thead`, `tbody`, `tfoot` and `tr` element.")),
+    ("col", model("None.", "None, it's a void element.", "A `colgroup` element that has no `span` attribute.")),
+    ("thead", TABLE_SECTION),
+    ("tbody", TABLE_SECTION),
+    ("tfoot", TABLE_SECTION),
+    ("tr", model("None.", "Zero or more `td` and `th` elements, optionally intermixed with script-supporting elements.", "A `table`, `thead`, `tbody` or `tfoot` element.")),
+    ("td", TABLE_CELL),
+    ("th", model("None.", "Flow content, but with no `header`, `footer`, sectioning content or heading content descendant.", "A `tr` element.")),
+    ("form", model("Flow content, palpable content.", "Flow content, but with no `form` element descendant.", FLOW_PARENT)),
+    ("label", model("Flow content, phrasing content, interactive content, form-associated element, palpable content.", "Phrasing content, but with no labelable element other than the labeled control, and no `label` element descendant.", PHRASING_PARENT)),
+    ("input", model("Flow content, phrasing content, form-associated element, and interactive content and palpable content if the `type` attribute is not `hidden`.", "None, it's a void element.", PHRASING_PARENT)),
+    ("button", model("Flow content, phrasing content, interactive content, form-associated element, palpable content.", "Phrasing content, but with no interactive content descendant and no descendant with the `tabindex` attribute.", PHRASING_PARENT)),
+    ("select", model("Flow content, phrasing content, interactive content, form-associated element, palpable content.", "Zero or more `option`, `optgroup` and `hr` elements, optionally intermixed with script-supporting elements.", PHRASING_PARENT)),
+    ("datalist", model("Flow content, phrasing content.", "Phrasing content, or zero or more `option` elements, optionally intermixed with script-supporting elements.", PHRASING_PARENT)),
+    ("optgroup", model("None.", "An optional `legend` element, followed by zero or more `option` elements, optionally intermixed with script-supporting elements.", "A `select` element.")),
+    ("option", model("None.", "Text, or phrasing content with no interactive content descendant if it's a child of a `select` element.", "A `select`, `datalist` or `optgroup` element.")),
+    ("textarea", model("Flow content, phrasing content, interactive content, form-associated element, palpable content.", "Text.", PHRASING_PARENT)),
+    ("output", model("Flow content, phrasing content, form-associated element, palpable content.", "Phrasing content.", PHRASING_PARENT)),
+    ("progress", model("Flow content, phrasing content, labelable element, palpable content.", "Phrasing content, but with no `progress` element descendant.", PHRASING_PARENT)),
+    ("meter", model("Flow content, phrasing content, labelable element, palpable content.", "Phrasing content, but with no `meter` element descendant.", PHRASING_PARENT)),
+    ("fieldset", model("Flow content, sectioning root, form-associated element, palpable content.", "An optional `legend` element, followed by flow content.", FLOW_PARENT)),
+    ("legend", model("None.", "Phrasing content and heading content.", "A `fieldset` element, as its first child.")),
+    ("details", model("Flow content, sectioning root, interactive content, palpable content.", "One `summary` element, followed by flow content.", FLOW_PARENT)),
+    ("summary", model("None.", "Phrasing content, optionally intermixed with heading content.", "A `details` element, as its first child.")),
+    ("dialog", model("Flow content, sectioning root.", "Flow content.", FLOW_PARENT)),
+    ("script", model("Metadata content, flow content, phrasing content, script-supporting element.", "Text matching the script type.", "Any element that accepts metadata content, phrasing content or script-supporting elements.")),
+    ("template", model("Metadata content, flow content, phrasing content, script-supporting element.", "Nothing, its contents are parsed into a separate document fragment.", "Any element that accepts metadata content, phrasing content or script-supporting elements, or a `colgroup` element that has no `span` attribute.")),
+];
+
+/// Get the content model of the HTML element `tag`
+pub fn get_content_model(tag: &str) -> Option<&'static ContentModel> {
+    let tag = tag.to_lowercase();
+    CONTENT_MODELS
+        .iter()
+        .find(|(name, _)| *name == tag)
+        .map(|(_, model)| model)
+}
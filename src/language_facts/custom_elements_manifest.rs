@@ -0,0 +1,189 @@
+use lsp_types::{MarkupContent, MarkupKind};
+use serde::Deserialize;
+
+use crate::html_data::{Description, HTMLDataV1, IAttributeData, ITagData, IValueData};
+
+/// The [Custom Elements Manifest](https://github.com/webcomponents/custom-elements-manifest),
+/// only the parts used by the completion and hover are deserialized
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CustomElementsManifest {
+    #[serde(default)]
+    modules: Vec<Module>,
+}
+
+#[derive(Deserialize)]
+struct Module {
+    #[serde(default)]
+    declarations: Vec<Declaration>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Declaration {
+    #[serde(default)]
+    custom_element: bool,
+    tag_name: Option<String>,
+    description: Option<String>,
+    summary: Option<String>,
+    #[serde(default)]
+    attributes: Vec<Attribute>,
+    #[serde(default)]
+    slots: Vec<NamedItem>,
+    #[serde(default)]
+    events: Vec<NamedItem>,
+}
+
+#[derive(Deserialize)]
+struct Attribute {
+    name: String,
+    description: Option<String>,
+    summary: Option<String>,
+    #[serde(rename = "type")]
+    attribute_type: Option<Type>,
+    default: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Type {
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct NamedItem {
+    #[serde(default)]
+    name: String,
+    description: Option<String>,
+    summary: Option<String>,
+}
+
+/// Convert the Custom Elements Manifest to `HTMLDataV1`
+///
+/// The custom elements are converted to tags, and their slots and events are added to the description.
+/// The values of an attribute are taken from the string literals of its type, such as `'small' | 'large'`.
+pub fn parse_custom_elements_manifest(content: &str) -> serde_json::Result<HTMLDataV1> {
+    let manifest: CustomElementsManifest = serde_json::from_str(content)?;
+    let mut tags = vec![];
+    for declaration in manifest
+        .modules
+        .into_iter()
+        .flat_map(|module| module.declarations)
+    {
+        if !declaration.custom_element {
+            continue;
+        }
+        if let Some(name) = declaration.tag_name {
+            let mut description = declaration
+                .description
+                .or(declaration.summary)
+                .unwrap_or_default();
+            append_section(&mut description, "Slots", &declaration.slots);
+            append_section(&mut description, "Events", &declaration.events);
+            tags.push(ITagData {
+                name,
+                description: to_description(description),
+                attributes: declaration
+                    .attributes
+                    .into_iter()
+                    .map(to_attribute_data)
+                    .collect(),
+                references: None,
+                void: None,
+                browsers: None,
+                status: None,
+                deprecated: None,
+            });
+        }
+    }
+    Ok(HTMLDataV1 {
+        version: 1.1,
+        tags: Some(tags),
+        global_attributes: None,
+        value_sets: None,
+    })
+}
+
+fn to_attribute_data(attribute: Attribute) -> IAttributeData {
+    let mut description = attribute
+        .description
+        .or(attribute.summary)
+        .unwrap_or_default();
+    if let Some(default) = &attribute.default {
+        if !description.is_empty() {
+            description += "\n\n";
+        }
+        description += &format!("Default: `{}`", default);
+    }
+    let values: Vec<IValueData> = attribute
+        .attribute_type
+        .map(|t| {
+            t.text
+                .split('|')
+                .filter_map(|v| {
+                    let v = v.trim();
+                    let quoted = v.len() >= 2
+                        && (v.starts_with('\'') && v.ends_with('\'')
+                            || v.starts_with('"') && v.ends_with('"'));
+                    if quoted {
+                        Some(IValueData {
+                            name: v[1..v.len() - 1].to_string(),
+                            description: None,
+                            references: None,
+                            browsers: None,
+                            status: None,
+                            deprecated: None,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    IAttributeData {
+        name: attribute.name,
+        description: to_description(description),
+        value_set: None,
+        values: if values.is_empty() {
+            None
+        } else {
+            Some(values)
+        },
+        references: None,
+        browsers: None,
+        status: None,
+        deprecated: None,
+    }
+}
+
+fn append_section(description: &mut String, title: &str, items: &Vec<NamedItem>) {
+    if items.is_empty() {
+        return;
+    }
+    if !description.is_empty() {
+        *description += "\n\n";
+    }
+    *description += &format!("**{}**\n", title);
+    for item in items {
+        let name = if item.name.is_empty() {
+            "(default)".to_string()
+        } else {
+            format!("`{}`", item.name)
+        };
+        *description += &format!("\n- {}", name);
+        if let Some(text) = item.description.as_ref().or(item.summary.as_ref()) {
+            *description += &format!(": {}", text);
+        }
+    }
+}
+
+fn to_description(value: String) -> Option<Description> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(Description::MarkupContent(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }))
+    }
+}
@@ -1,16 +1,59 @@
+use std::{collections::HashMap, fmt, fs, io, sync::Mutex};
+
 use lazy_static::lazy_static;
+use lsp_types::MarkupContent;
 use serde_json::{json, Value};
 
+#[cfg(feature = "angular_data")]
+use super::angular_custom_data::ANGULAR_DATA;
+#[cfg(feature = "mathml_data")]
+use super::mathml_custom_data::{MATHML_DATA, MATHML_GLOBAL_ATTRIBUTES};
+#[cfg(feature = "svelte_data")]
+use super::svelte_data_provider::SvelteDataProvider;
+#[cfg(feature = "svg_data")]
+use super::svg_custom_data::{SVG_DATA, SVG_PRESENTATION_ATTRIBUTES};
+#[cfg(feature = "vue_data")]
+use super::vue_data_provider::VueDataProvider;
 use super::{
-    data_provider::{HTMLDataProvider, IHTMLDataProvider},
+    aria::describe_roles,
+    custom_elements_manifest::parse_custom_elements_manifest,
+    data_provider::{
+        self, GenerateDocumentationItem, GenerateDocumentationSetting, HTMLDataProvider,
+        IHTMLDataProvider,
+    },
     web_custom_data::HTML_DATA,
 };
+use crate::html_data::{HTMLDataV1, IAttributeData, IValueData};
+use crate::parser::html_parse::ParserLimits;
 
 /// Provides tags, attributes, and attribute value and so on,
 /// for completion proposals and hover information.
 /// It has standard data built-in and can be customized
+///
+/// It's `Send + Sync`, the caches are behind a `Mutex` so that it's shared by reference.
 pub struct HTMLDataManager {
     data_providers: Vec<Box<dyn IHTMLDataProvider>>,
+    /// The language ids of the data providers registered by `set_scoped_data_provider`
+    language_ids: HashMap<String, Vec<String>>,
+    /// The tags of the elements in whose subtrees the data providers apply, see `set_element_scope`
+    element_scopes: HashMap<String, String>,
+    /// The value sets registered by `register_value_set`
+    value_sets: HashMap<String, Vec<IValueData>>,
+    /// The language ids of the documents that use the template syntax, see `set_template_languages`
+    template_languages: Vec<String>,
+    /// The language ids of the documents whose tag names are case-sensitive, see `set_case_sensitive_languages`
+    case_sensitive_languages: Vec<String>,
+    /// The tags and the attributes whose values are URLs, see `set_path_attributes`
+    path_attributes: Vec<(String, String)>,
+    /// See `set_parser_limits`
+    parser_limits: ParserLimits,
+    version: u64,
+    /// The number of the data providers added from the JSON content, the IDs of them are `custom-data-{n}`
+    inline_data_count: usize,
+    /// The documentation generated by `generate_documentation`,
+    /// keyed by the ID of the data provider and the names of the item, and the setting
+    documentation_cache:
+        Mutex<HashMap<(String, GenerateDocumentationSetting), Option<MarkupContent>>>,
 }
 
 impl HTMLDataManager {
@@ -20,6 +63,16 @@ impl HTMLDataManager {
     ) -> HTMLDataManager {
         let mut data_manager = HTMLDataManager {
             data_providers: vec![],
+            language_ids: HashMap::new(),
+            element_scopes: HashMap::new(),
+            value_sets: HashMap::new(),
+            template_languages: TEMPLATE_LANGUAGES.iter().map(|id| id.to_string()).collect(),
+            case_sensitive_languages: vec![],
+            path_attributes: vec![],
+            parser_limits: ParserLimits::default(),
+            version: 0,
+            inline_data_count: 0,
+            documentation_cache: Mutex::new(HashMap::new()),
         };
         data_manager.set_data_providers(
             use_default_data_provider,
@@ -29,42 +82,388 @@ impl HTMLDataManager {
     }
 
     /// Set up a data provider, and the old data will be cleaned
+    ///
+    /// If `built_in` is true, the built-in data providers are added, their IDs are:
+    ///
+    /// - "html5": the HTML5 data
+    /// - "svg": the SVG data in `<svg>`, if the `svg_data` feature is activated
+    /// - "mathml": the MathML data in `<math>`, if the `mathml_data` feature is activated
+    /// - "angular": the Angular template data, if the `angular_data` feature is activated
+    /// - "vue": the Vue template data for the `vue` language id, if the `vue_data` feature is activated
+    /// - "svelte": the Svelte data for the `svelte` language id, if the `svelte_data` feature is activated
     pub fn set_data_providers(
         &mut self,
         built_in: bool,
         mut providers: Vec<Box<dyn IHTMLDataProvider>>,
     ) {
         self.data_providers.clear();
+        self.language_ids.clear();
+        self.element_scopes.clear();
         if built_in {
-            let data = serde_json::from_str(HTML_DATA).unwrap();
+            let mut data: HTMLDataV1 = serde_json::from_str(HTML_DATA).unwrap();
+            describe_roles(&mut data);
+            #[cfg(feature = "vue_data")]
+            let vue_data_provider = VueDataProvider::new(&data);
+            #[cfg(feature = "svelte_data")]
+            let svelte_data_provider = SvelteDataProvider::new(&data);
             self.data_providers
                 .push(Box::new(HTMLDataProvider::new("html5".to_string(), data)));
+            #[cfg(feature = "svg_data")]
+            {
+                self.data_providers.push(Box::new(HTMLDataProvider::new(
+                    "svg".to_string(),
+                    with_shared_attributes(SVG_DATA, SVG_PRESENTATION_ATTRIBUTES),
+                )));
+                self.element_scopes
+                    .insert("svg".to_string(), "svg".to_string());
+            }
+            #[cfg(feature = "mathml_data")]
+            {
+                self.data_providers.push(Box::new(HTMLDataProvider::new(
+                    "mathml".to_string(),
+                    with_shared_attributes(MATHML_DATA, MATHML_GLOBAL_ATTRIBUTES),
+                )));
+                self.element_scopes
+                    .insert("mathml".to_string(), "math".to_string());
+            }
+            #[cfg(feature = "angular_data")]
+            self.data_providers.push(Box::new(HTMLDataProvider::new(
+                "angular".to_string(),
+                serde_json::from_str(ANGULAR_DATA).unwrap(),
+            )));
+            #[cfg(feature = "vue_data")]
+            {
+                self.data_providers.push(Box::new(vue_data_provider));
+                self.language_ids
+                    .insert("vue".to_string(), vec!["vue".to_string()]);
+            }
+            #[cfg(feature = "svelte_data")]
+            {
+                self.data_providers.push(Box::new(svelte_data_provider));
+                self.language_ids
+                    .insert("svelte".to_string(), vec!["svelte".to_string()]);
+            }
         }
         self.data_providers.append(&mut providers);
+        self.changed();
+    }
+
+    /// Replace the data provider whose ID is `id` with `provider`,
+    /// it's added to the end if there is no such data provider
+    ///
+    /// It returns the replaced data provider.
+    pub fn set_data_provider(
+        &mut self,
+        id: &str,
+        provider: Box<dyn IHTMLDataProvider>,
+    ) -> Option<Box<dyn IHTMLDataProvider>> {
+        self.changed();
+        self.language_ids.remove(id);
+        self.element_scopes.remove(id);
+        if let Some(index) = self.data_providers.iter().position(|p| p.get_id() == id) {
+            Some(std::mem::replace(&mut self.data_providers[index], provider))
+        } else {
+            self.data_providers.push(provider);
+            None
+        }
+    }
+
+    /// Like `set_data_provider`, but the data provider is only applicable to `language_ids`,
+    /// and its `is_applicable` is not used
+    pub fn set_scoped_data_provider(
+        &mut self,
+        id: &str,
+        provider: Box<dyn IHTMLDataProvider>,
+        language_ids: Vec<String>,
+    ) -> Option<Box<dyn IHTMLDataProvider>> {
+        let old = self.set_data_provider(id, provider);
+        self.language_ids.insert(id.to_string(), language_ids);
+        old
+    }
+
+    /// Remove the data provider whose ID is `id`, and return it
+    pub fn remove_data_provider(&mut self, id: &str) -> Option<Box<dyn IHTMLDataProvider>> {
+        let index = self.data_providers.iter().position(|p| p.get_id() == id)?;
+        self.changed();
+        self.language_ids.remove(id);
+        Some(self.data_providers.remove(index))
+    }
+
+    /// Register a value set that the attributes of any data provider can reference by `valueSet`
+    ///
+    /// The value set of the data provider itself takes precedence over the registered one.
+    /// It returns the values of the replaced value set.
+    pub fn register_value_set(
+        &mut self,
+        name: &str,
+        values: Vec<IValueData>,
+    ) -> Option<Vec<IValueData>> {
+        self.changed();
+        self.value_sets.insert(name.to_string(), values)
+    }
+
+    /// Remove the value set registered by `register_value_set`, and return its values
+    pub fn unregister_value_set(&mut self, name: &str) -> Option<Vec<IValueData>> {
+        let values = self.value_sets.remove(name)?;
+        self.changed();
+        Some(values)
+    }
+
+    /// The version of the data, it's increased every time the data providers are changed
+    ///
+    /// The data cached from the data providers, such as `get_void_elements`,
+    /// should be invalidated when the version changes.
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+
+    /// Add a data provider from the JSON of the VS Code `html.customData` format
+    ///
+    /// `path_or_str` is the JSON content if it starts with `{`, otherwise it's the path of the JSON file.
+    /// The path is used as the ID of the data provider if it's a file.
+    pub fn add_data_provider_from_json(
+        &mut self,
+        path_or_str: &str,
+    ) -> Result<(), CustomDataError> {
+        let (id, content) = self.read_path_or_str(path_or_str)?;
+        let data = serde_json::from_str(&content)?;
+        self.data_providers
+            .push(Box::new(HTMLDataProvider::new(id, data)));
+        self.changed();
+        Ok(())
+    }
+
+    /// Add a data provider from the `custom-elements.json` of the Custom Elements Manifest format
+    ///
+    /// `path_or_str` is handled in the same way as `add_data_provider_from_json`.
+    pub fn add_data_provider_from_custom_elements_manifest(
+        &mut self,
+        path_or_str: &str,
+    ) -> Result<(), CustomDataError> {
+        let (id, content) = self.read_path_or_str(path_or_str)?;
+        let data = parse_custom_elements_manifest(&content)?;
+        self.data_providers
+            .push(Box::new(HTMLDataProvider::new(id, data)));
+        self.changed();
+        Ok(())
+    }
+
+    /// Get the ID of the data provider and the JSON content from `path_or_str`
+    ///
+    /// The ID of the JSON content is unique even if the data providers are removed.
+    fn read_path_or_str(&mut self, path_or_str: &str) -> io::Result<(String, String)> {
+        if path_or_str.trim_start().starts_with('{') {
+            self.inline_data_count += 1;
+            Ok((
+                format!("custom-data-{}", self.inline_data_count),
+                path_or_str.to_string(),
+            ))
+        } else {
+            Ok((path_or_str.to_string(), fs::read_to_string(path_or_str)?))
+        }
     }
 
     pub fn get_data_providers(&self) -> &Vec<Box<dyn IHTMLDataProvider>> {
         &self.data_providers
     }
 
+    /// Is the data provider applicable to the `language_id`
+    ///
+    /// The language ids of `set_scoped_data_provider` are used if it's scoped,
+    /// otherwise it's decided by the `is_applicable` of the data provider.
+    pub fn is_applicable(&self, provider: &dyn IHTMLDataProvider, language_id: &str) -> bool {
+        if let Some(language_ids) = self.language_ids.get(provider.get_id()) {
+            language_ids.iter().any(|id| id == language_id)
+        } else {
+            provider.is_applicable(language_id)
+        }
+    }
+
+    /// Scope the data provider whose ID is `id` to the subtrees of the elements of `tag`,
+    /// such as `svg` for the SVG data. Its tags and attributes are only provided in these elements,
+    /// outside of them only the tag `tag` itself is proposed. It applies everywhere if `tag` is `None`.
+    pub fn set_element_scope(&mut self, id: &str, tag: Option<String>) {
+        if let Some(tag) = tag {
+            self.element_scopes.insert(id.to_string(), tag);
+        } else {
+            self.element_scopes.remove(id);
+        }
+        self.changed();
+    }
+
+    /// The tag of the elements in whose subtrees the data provider whose ID is `id` applies
+    pub fn get_element_scope(&self, id: &str) -> Option<&str> {
+        self.element_scopes.get(id).map(|tag| tag.as_str())
+    }
+
+    /// Does the data provider apply in the element whose tag and the tags of its ancestors are `tags`,
+    /// see `set_element_scope`
+    pub fn is_in_element_scope(&self, provider: &dyn IHTMLDataProvider, tags: &[&str]) -> bool {
+        self.get_element_scope(provider.get_id())
+            .is_none_or(|scope| tags.iter().any(|tag| tag.eq_ignore_ascii_case(scope)))
+    }
+
+    /// Like `data_provider::generate_documentation`, but the result is cached by the ID of the data provider
+    /// of `item` and `names`, the names that identify the item in the data provider, such as
+    /// `["input"]` for the tag `<input>` and `["input", "type", "text"]` for the value `text` of its attribute `type`
+    ///
+    /// The cache is cleared when the data providers are changed.
+    pub fn generate_documentation(
+        &self,
+        provider_id: &str,
+        names: &[&str],
+        item: impl Into<GenerateDocumentationItem>,
+        setting: GenerateDocumentationSetting,
+    ) -> Option<MarkupContent> {
+        // The names don't contain the NUL character, so the keys of different items don't collide
+        let key = (format!("{}\0{}", provider_id, names.join("\0")), setting);
+        if let Some(documentation) = self.documentation_cache.lock().unwrap().get(&key) {
+            return documentation.clone();
+        }
+        let documentation = data_provider::generate_documentation(item.into(), setting);
+        self.documentation_cache
+            .lock()
+            .unwrap()
+            .insert(key, documentation.clone());
+        documentation
+    }
+
+    /// Set the language ids of the documents that use the template syntax,
+    /// such as `{{ }}`, `{% %}` and `<% %>`, the blocks of which are scanned as opaque.
+    /// By default they are the ids of Handlebars, Jinja, ERB and the like.
+    pub fn set_template_languages(&mut self, language_ids: Vec<String>) {
+        self.template_languages = language_ids;
+        self.changed();
+    }
+
+    /// Whether the documents of the `language_id` use the template syntax
+    pub fn is_template_language(&self, language_id: &str) -> bool {
+        self.template_languages.iter().any(|id| id == language_id)
+    }
+
+    /// Set the language ids of the documents whose tag names are case-sensitive,
+    /// such as the components `<MyButton>` of Vue and Svelte.
+    /// In these documents, the end tags only close the start tags of the same case,
+    /// and the tags of a different case from the data, such as `<Button>`, are not the HTML elements.
+    /// By default it's empty, and the tag names are case-insensitive as HTML.
+    pub fn set_case_sensitive_languages(&mut self, language_ids: Vec<String>) {
+        self.case_sensitive_languages = language_ids;
+        self.changed();
+    }
+
+    /// Whether the tag names of the documents of the `language_id` are case-sensitive
+    pub fn is_case_sensitive(&self, language_id: &str) -> bool {
+        self.case_sensitive_languages
+            .iter()
+            .any(|id| id == language_id)
+    }
+
+    /// Set the tags and the attributes whose values are URLs in addition to the built-in ones,
+    /// such as `("img", "data-src")` for lazy loading and `("my-player", "poster-url")`
+    /// for a component, the tag `*` means every tag.
+    /// The URLs in them are found as document links.
+    pub fn set_path_attributes(&mut self, attributes: Vec<(String, String)>) {
+        self.path_attributes = attributes;
+        self.changed();
+    }
+
+    /// Set the limits of the parser, such as for the documents from the untrusted sources,
+    /// so that a pathological document can't take unbounded memory and recursion
+    pub fn set_parser_limits(&mut self, limits: ParserLimits) {
+        self.parser_limits = limits;
+        self.changed();
+    }
+
+    pub fn get_parser_limits(&self) -> ParserLimits {
+        self.parser_limits
+    }
+
+    /// Increase the version and clear the caches when the data is changed
+    fn changed(&mut self) {
+        self.version += 1;
+        self.documentation_cache.get_mut().unwrap().clear();
+    }
+
+    /// Get the data providers that are applicable to the `language_id`
+    pub fn get_applicable_data_providers(
+        &self,
+        language_id: &str,
+    ) -> Vec<&Box<dyn IHTMLDataProvider>> {
+        self.data_providers
+            .iter()
+            .filter(|provider| self.is_applicable(provider.as_ref(), language_id))
+            .collect()
+    }
+
+    /// Get the global attributes of the data providers that are applicable to the `language_id`
+    pub fn get_global_attributes(&self, language_id: &str) -> Vec<&IAttributeData> {
+        self.get_applicable_data_providers(language_id)
+            .into_iter()
+            .flat_map(|provider| provider.provide_global_attributes())
+            .collect()
+    }
+
+    /// Get the values of the value set named `name` from the data providers
+    /// that are applicable to the `language_id`,
+    /// the registered value set is used if none of them has it
+    pub fn get_value_set(&self, name: &str, language_id: &str) -> Vec<&IValueData> {
+        let values: Vec<&IValueData> = self
+            .get_applicable_data_providers(language_id)
+            .into_iter()
+            .filter_map(|provider| provider.get_value_set(name))
+            .flatten()
+            .collect();
+        if values.is_empty() {
+            self.value_sets.get(name).into_iter().flatten().collect()
+        } else {
+            values
+        }
+    }
+
+    /// Get the values of the `attribute` of `tag` from the `provider`,
+    /// and the values of the registered value set that the attribute references
+    /// if the `provider` doesn't have it
+    pub fn provide_values<'a>(
+        &'a self,
+        provider: &'a dyn IHTMLDataProvider,
+        tag: &str,
+        attribute: &str,
+    ) -> Vec<&'a IValueData> {
+        let mut values = provider.provide_values(tag, attribute);
+        if self.value_sets.is_empty() {
+            return values;
+        }
+        for attr in provider.provide_attributes(tag) {
+            if !attr.name.eq_ignore_ascii_case(attribute) {
+                continue;
+            }
+            if let Some(name) = &attr.value_set {
+                if provider.get_value_set(name).is_none() {
+                    if let Some(value_set) = self.value_sets.get(name) {
+                        values.extend(value_set);
+                    }
+                }
+            }
+        }
+        values
+    }
+
     /// Is the tag void element
     ///
     /// `void_elements` is from `get_void_elements`, and you should cache it to avoid duplicate void_elements generation
     pub fn is_void_element(&self, tag: &str, void_elements: &Vec<String>) -> bool {
-        void_elements.contains(&tag.to_string())
+        void_elements.iter().any(|void_element| void_element == tag)
     }
 
-    /// Get `void_elements` from data_provider and you should cache it if you make sure it doesn't change
+    /// Get `void_elements` from data_provider and you should cache it until `get_version` changes
     pub fn get_void_elements(&self, language_id: &str) -> Vec<String> {
         let mut void_tags: Vec<String> = vec![];
-        for provider in &self.data_providers {
-            if provider.is_applicable(language_id) {
-                provider
-                    .provide_tags()
-                    .iter()
-                    .filter(|tag| tag.void.is_some_and(|v| v))
-                    .for_each(|tag| void_tags.push(tag.name.clone()))
-            }
+        for provider in self.get_applicable_data_providers(language_id) {
+            provider
+                .provide_tags()
+                .iter()
+                .filter(|tag| tag.void.is_some_and(|v| v))
+                .for_each(|tag| void_tags.push(tag.name.clone()))
         }
         void_tags.sort();
         void_tags
@@ -75,6 +474,12 @@ impl HTMLDataManager {
         if ["src", "href"].contains(&attr) {
             return true;
         }
+        if self.path_attributes.iter().any(|(path_tag, path_attr)| {
+            (path_tag == "*" || path_tag.eq_ignore_ascii_case(tag))
+                && path_attr.eq_ignore_ascii_case(attr)
+        }) {
+            return true;
+        }
         let value = PATH_TAG_AND_ATTR.as_object().unwrap().get(tag);
         if let Some(value) = value {
             if value.is_array() {
@@ -89,6 +494,60 @@ impl HTMLDataManager {
             false
         }
     }
+
+    /// Is the `attr` of `tag` a list of image candidates, such as `srcset` of `img`,
+    /// whose value contains multiple URLs with descriptors
+    pub fn is_srcset_attribute(&self, tag: &str, attr: &str) -> bool {
+        matches!(
+            (tag, attr),
+            ("img" | "source", "srcset") | ("link", "imagesrcset")
+        )
+    }
+}
+
+/// Parse the built-in `data`, and add the shared `attributes` to every tag of it
+///
+/// The shared attributes of SVG and MathML aren't global attributes, since they don't apply to HTML elements.
+#[cfg(any(feature = "svg_data", feature = "mathml_data"))]
+fn with_shared_attributes(data: &str, attributes: &str) -> HTMLDataV1 {
+    let mut data: HTMLDataV1 = serde_json::from_str(data).unwrap();
+    let attributes: Vec<IAttributeData> = serde_json::from_str(attributes).unwrap();
+    if let Some(tags) = &mut data.tags {
+        for tag in tags {
+            tag.attributes.extend(attributes.iter().cloned());
+        }
+    }
+    data
+}
+
+/// The error of loading custom data, such as `HTMLDataManager::add_data_provider_from_json`
+#[derive(Debug)]
+pub enum CustomDataError {
+    Io(io::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for CustomDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CustomDataError::Io(error) => write!(f, "failed to read custom data: {}", error),
+            CustomDataError::Json(error) => write!(f, "failed to parse custom data: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for CustomDataError {}
+
+impl From<io::Error> for CustomDataError {
+    fn from(error: io::Error) -> Self {
+        CustomDataError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for CustomDataError {
+    fn from(error: serde_json::Error) -> Self {
+        CustomDataError::Json(error)
+    }
 }
 
 impl Default for HTMLDataManager {
@@ -97,6 +556,22 @@ impl Default for HTMLDataManager {
     }
 }
 
+/// The language ids that use the template syntax by default
+const TEMPLATE_LANGUAGES: [&str; 12] = [
+    "django-html",
+    "ejs",
+    "erb",
+    "handlebars",
+    "jinja",
+    "jinja-html",
+    "liquid",
+    "mustache",
+    "nunjucks",
+    "php",
+    "twig",
+    "html.erb",
+];
+
 lazy_static! {
     static ref PATH_TAG_AND_ATTR: Value = json!({
         // HTML 4
@@ -1,17 +1,22 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use lsp_types::{MarkupContent, MarkupKind};
+use smol_str::{SmolStr, StrExt};
 
 use crate::{
-    html_data::{Description, HTMLDataV1, IAttributeData, IReference, ITagData, IValueData},
-    utils::markup,
+    html_data::{
+        Baseline, BaselineStatus, Description, HTMLDataV1, IAttributeData, IReference, ITagData,
+        IValueData,
+    },
+    utils::{markup, strings},
 };
 
 /// Built-in data provider that provides information for `HTMLDataManager`
 pub struct HTMLDataProvider {
     id: String,
     tags: Vec<ITagData>,
-    tag_map: HashMap<String, usize>,
+    /// The lowercase names of the tags, the short ones are stored inline
+    tag_map: HashMap<SmolStr, usize>,
     global_attributes: Vec<IAttributeData>,
     value_set_map: HashMap<String, Vec<IValueData>>,
 }
@@ -25,6 +30,14 @@ pub trait IHTMLDataProvider: Send + Sync {
     fn provide_tags(&self) -> &Vec<ITagData>;
     fn provide_attributes(&self, tag: &str) -> Vec<&IAttributeData>;
     fn provide_values(&self, tag: &str, attribute: &str) -> Vec<&IValueData>;
+    /// The attributes that apply to every tag
+    fn provide_global_attributes(&self) -> Vec<&IAttributeData> {
+        vec![]
+    }
+    /// The values of the value set that attributes reference by `valueSet`
+    fn get_value_set(&self, _name: &str) -> Option<&Vec<IValueData>> {
+        None
+    }
 }
 
 impl HTMLDataProvider {
@@ -32,7 +45,7 @@ impl HTMLDataProvider {
         let mut tag_map = HashMap::new();
         if let Some(tags) = &custom_data.tags {
             for (i, tag) in tags.iter().enumerate() {
-                tag_map.insert(tag.name.clone(), i);
+                tag_map.insert(tag.name.to_lowercase_smolstr(), i);
             }
         }
 
@@ -70,7 +83,7 @@ impl IHTMLDataProvider for HTMLDataProvider {
     fn provide_attributes(&self, tag: &str) -> Vec<&IAttributeData> {
         let mut attributes = vec![];
 
-        let tag_entry_index = self.tag_map.get(&tag.to_lowercase());
+        let tag_entry_index = self.tag_map.get(&tag.to_lowercase_smolstr());
         if let Some(tag_entry_index) = tag_entry_index {
             let tag_entry = &self.tags[*tag_entry_index];
             for attribute in &tag_entry.attributes {
@@ -87,13 +100,13 @@ impl IHTMLDataProvider for HTMLDataProvider {
     fn provide_values(&self, tag: &str, attribute: &str) -> Vec<&IValueData> {
         let mut values = vec![];
 
-        let attribute = attribute.to_lowercase();
+        let attribute = attribute.to_lowercase_smolstr();
 
-        let tag_entry = self.tag_map.get(&tag.to_lowercase());
+        let tag_entry = self.tag_map.get(&tag.to_lowercase_smolstr());
         if let Some(tag_entry_index) = tag_entry {
             let tag_entry = &self.tags[*tag_entry_index];
             for a in &tag_entry.attributes {
-                if a.name.to_lowercase() == attribute {
+                if strings::eq_lowercase(&a.name, &attribute) {
                     if let Some(a_values) = &a.values {
                         for value in a_values {
                             values.push(value);
@@ -110,7 +123,7 @@ impl IHTMLDataProvider for HTMLDataProvider {
             }
         }
         for a in &self.global_attributes {
-            if a.name.to_lowercase() == attribute {
+            if strings::eq_lowercase(&a.name, &attribute) {
                 if let Some(a_values) = &a.values {
                     for value in a_values {
                         values.push(value);
@@ -128,6 +141,14 @@ impl IHTMLDataProvider for HTMLDataProvider {
 
         values
     }
+
+    fn provide_global_attributes(&self) -> Vec<&IAttributeData> {
+        self.global_attributes.iter().collect()
+    }
+
+    fn get_value_set(&self, name: &str) -> Option<&Vec<IValueData>> {
+        self.value_set_map.get(name)
+    }
 }
 
 /// Generate Documentation used in hover/complete From documentation and references
@@ -144,7 +165,18 @@ pub fn generate_documentation(
         value: String::new(),
     };
 
+    if item.deprecated {
+        result.value += if setting.does_support_markdown {
+            "**Deprecated**"
+        } else {
+            "Deprecated"
+        };
+    }
+
     if item.description.is_some() && setting.documentation {
+        if result.value.len() > 0 {
+            result.value += "\n\n";
+        }
         let normalized_description = markup::normalize_markup_content(item.description.unwrap());
         result.value += &normalized_description.value;
     }
@@ -169,6 +201,27 @@ pub fn generate_documentation(
         }
     }
 
+    if setting.baseline {
+        let compatibility = if let Some(status) = &item.status {
+            Some(get_baseline_description(status, item.browsers.as_deref()))
+        } else {
+            item.browsers
+                .as_deref()
+                .filter(|browsers| browsers.len() > 0)
+                .map(|browsers| format!("Supported in {}", get_browser_labels(browsers)))
+        };
+        if let Some(compatibility) = compatibility {
+            if result.value.len() > 0 {
+                result.value += "\n\n";
+            }
+            if setting.does_support_markdown {
+                result.value += &format!("_{}_", compatibility);
+            } else {
+                result.value += &compatibility;
+            }
+        }
+    }
+
     if result.value.len() > 0 {
         Some(result)
     } else {
@@ -176,13 +229,143 @@ pub fn generate_documentation(
     }
 }
 
+/// Render the documentation of a tag, attribute or value, see `HTMLLanguageServiceOptions::doc_renderer`
+pub type DocumentationRenderer =
+    Arc<dyn Fn(GenerateDocumentationItem) -> MarkupContent + Send + Sync>;
+
 pub struct GenerateDocumentationItem {
     pub description: Option<Description>,
     pub references: Option<Vec<IReference>>,
+    pub browsers: Option<Vec<String>>,
+    pub status: Option<BaselineStatus>,
+    pub deprecated: bool,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct GenerateDocumentationSetting {
     pub documentation: bool,
     pub references: bool,
+    /// Whether to render the Baseline status or the browser support
+    pub baseline: bool,
     pub does_support_markdown: bool,
 }
+
+impl From<&ITagData> for GenerateDocumentationItem {
+    fn from(tag: &ITagData) -> Self {
+        GenerateDocumentationItem {
+            description: tag.description.clone(),
+            references: tag.references.clone(),
+            browsers: tag.browsers.clone(),
+            status: tag.status.clone(),
+            deprecated: tag.deprecated.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&IAttributeData> for GenerateDocumentationItem {
+    fn from(attribute: &IAttributeData) -> Self {
+        GenerateDocumentationItem {
+            description: attribute.description.clone(),
+            references: attribute.references.clone(),
+            browsers: attribute.browsers.clone(),
+            status: attribute.status.clone(),
+            deprecated: attribute.deprecated.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&IValueData> for GenerateDocumentationItem {
+    fn from(value: &IValueData) -> Self {
+        GenerateDocumentationItem {
+            description: value.description.clone(),
+            references: value.references.clone(),
+            browsers: value.browsers.clone(),
+            status: value.status.clone(),
+            deprecated: value.deprecated.unwrap_or_default(),
+        }
+    }
+}
+
+/// The browsers of the Baseline, and the abbreviations used in `browsers`
+const BASELINE_BROWSERS: [(&str, &str); 7] = [
+    ("C", "Chrome"),
+    ("CA", "Chrome (Android)"),
+    ("E", "Edge"),
+    ("FF", "Firefox"),
+    ("FFA", "Firefox (Android)"),
+    ("S", "Safari"),
+    ("SM", "Safari (iOS)"),
+];
+
+/// Split the browser compatibility such as `FF12` to the abbreviation and the version
+fn split_browser(browser: &str) -> (&str, &str) {
+    let index = browser
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(browser.len());
+    (&browser[..index], &browser[index..])
+}
+
+fn get_browser_name(abbreviation: &str) -> &str {
+    match abbreviation {
+        "IE" => "IE",
+        "O" => "Opera",
+        _ => BASELINE_BROWSERS
+            .iter()
+            .find(|(a, _)| *a == abbreviation)
+            .map_or(abbreviation, |(_, name)| name),
+    }
+}
+
+fn get_browser_labels(browsers: &[String]) -> String {
+    browsers
+        .iter()
+        .map(|browser| {
+            let (abbreviation, version) = split_browser(browser);
+            let name = get_browser_name(abbreviation);
+            if version.is_empty() {
+                name.to_string()
+            } else {
+                format!("{} {}", name, version)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn get_baseline_description(status: &BaselineStatus, browsers: Option<&[String]>) -> String {
+    match status.baseline {
+        Baseline::False => {
+            let mut description = "Limited availability across major browsers".to_string();
+            if let Some(browsers) = browsers {
+                let missing: Vec<&str> = BASELINE_BROWSERS
+                    .iter()
+                    .filter(|(abbreviation, _)| {
+                        !browsers
+                            .iter()
+                            .any(|browser| split_browser(browser).0 == *abbreviation)
+                    })
+                    .map(|(_, name)| *name)
+                    .collect();
+                if missing.len() > 0 {
+                    description += &format!(" (Not fully implemented in {})", missing.join(", "));
+                }
+            }
+            description
+        }
+        Baseline::Low | Baseline::High => {
+            let mut description = if status.baseline == Baseline::Low {
+                "Newly available across major browsers".to_string()
+            } else {
+                "Widely available across major browsers".to_string()
+            };
+            if let Some(year) = status
+                .baseline_low_date
+                .as_ref()
+                .and_then(|date| date.split('-').next())
+            {
+                description += &format!(" (Baseline since {})", year);
+            }
+            description
+        }
+    }
+}
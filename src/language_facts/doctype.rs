@@ -0,0 +1,186 @@
+/// The rendering mode of a document, decided by its DOCTYPE declaration
+///
+/// <https://html.spec.whatwg.org/multipage/parsing.html#the-initial-insertion-mode>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentMode {
+    /// Standards mode
+    NoQuirks,
+    /// Almost standards mode, only the sizing of table cells with images is quirky
+    LimitedQuirks,
+    /// Quirks mode, the layout emulates the behavior of legacy browsers
+    Quirks,
+}
+
+/// The parts of a DOCTYPE declaration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Doctype {
+    pub name: Option<String>,
+    pub public_id: Option<String>,
+    pub system_id: Option<String>,
+}
+
+const QUIRKS_PUBLIC_ID_PREFIXES: [&str; 16] = [
+    "+//silmaril//dtd html pro v0r11 19970101//",
+    "-//as//dtd html 3.0 aswedit + extensions//",
+    "-//advasoft ltd//dtd html 3.0 aswedit + extensions//",
+    "-//ietf//dtd html",
+    "-//metrius//dtd metrius presentational//",
+    "-//microsoft//dtd internet explorer",
+    "-//netscape comm. corp.//dtd",
+    "-//o'reilly and associates//dtd html",
+    "-//softquad software//dtd hotmetal pro",
+    "-//softquad//dtd hotmetal pro",
+    "-//spyglass//dtd html 2.0 extended//",
+    "-//w3c//dtd html 3",
+    "-//w3c//dtd html 4.0 frameset//",
+    "-//w3c//dtd html 4.0 transitional//",
+    "-//w3c//dtd w3 html//",
+    "-//w3o//dtd w3 html",
+];
+
+impl Doctype {
+    /// Parse the content of the DOCTYPE declaration, that is the text between `<!DOCTYPE` and `>`
+    pub fn parse(content: &str) -> Doctype {
+        let content = content.trim_start();
+        let name_end = content
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(content.len());
+        let name = &content[..name_end];
+        let mut rest = content[name_end..].trim_start();
+
+        let mut public_id = None;
+        let mut system_id = None;
+        if starts_with_ignore_case(rest, "public") {
+            rest = &rest[6..];
+            public_id = take_quoted(&mut rest);
+            system_id = take_quoted(&mut rest);
+        } else if starts_with_ignore_case(rest, "system") {
+            rest = &rest[6..];
+            system_id = take_quoted(&mut rest);
+        }
+
+        Doctype {
+            name: if name.is_empty() {
+                None
+            } else {
+                Some(name.to_lowercase())
+            },
+            public_id,
+            system_id,
+        }
+    }
+
+    /// Get the document mode that the browsers render the document in
+    pub fn get_document_mode(&self) -> DocumentMode {
+        if self.name.as_deref() != Some("html") {
+            return DocumentMode::Quirks;
+        }
+        let public_id = self.public_id.as_deref().unwrap_or_default().to_lowercase();
+        let system_id = self.system_id.as_deref().unwrap_or_default().to_lowercase();
+        let html4_frameset_or_transitional = public_id
+            .starts_with("-//w3c//dtd html 4.01 frameset//")
+            || public_id.starts_with("-//w3c//dtd html 4.01 transitional//");
+
+        if public_id == "-//w3o//dtd w3 html strict 3.0//en//"
+            || public_id == "-/w3c/dtd html 4.0 transitional/en"
+            || public_id == "html"
+            || system_id == "http://www.ibm.com/data/dtd/v11/ibmxhtml1-transitional.dtd"
+            || QUIRKS_PUBLIC_ID_PREFIXES
+                .iter()
+                .any(|prefix| public_id.starts_with(prefix))
+            || self.system_id.is_none() && html4_frameset_or_transitional
+        {
+            DocumentMode::Quirks
+        } else if public_id.starts_with("-//w3c//dtd xhtml 1.0 frameset//")
+            || public_id.starts_with("-//w3c//dtd xhtml 1.0 transitional//")
+            || html4_frameset_or_transitional
+        {
+            DocumentMode::LimitedQuirks
+        } else {
+            DocumentMode::NoQuirks
+        }
+    }
+
+    /// Is it the `<!DOCTYPE html>` of HTML5, without the public and system identifiers
+    pub fn is_html5(&self) -> bool {
+        self.name.as_deref() == Some("html")
+            && self.public_id.is_none()
+            && (self.system_id.is_none()
+                || self.system_id.as_deref() == Some("about:legacy-compat"))
+    }
+}
+
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    text.len() >= prefix.len()
+        && text.is_char_boundary(prefix.len())
+        && text[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+/// Take the quoted string at the start of `text`, and move `text` after it
+fn take_quoted(text: &mut &str) -> Option<String> {
+    let trimmed = text.trim_start();
+    let quote = trimmed.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let end = trimmed[1..].find(quote).map_or(trimmed.len(), |i| i + 1);
+    let value = trimmed[1..end].to_string();
+    *text = &trimmed[(end + 1).min(trimmed.len())..];
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn document_mode() {
+        for (content, mode) in [
+            (" html", DocumentMode::NoQuirks),
+            (" HTML", DocumentMode::NoQuirks),
+            (
+                r#" html SYSTEM "about:legacy-compat""#,
+                DocumentMode::NoQuirks,
+            ),
+            (
+                r#" HTML PUBLIC "-//W3C//DTD HTML 4.01//EN" "http://www.w3.org/TR/html4/strict.dtd""#,
+                DocumentMode::NoQuirks,
+            ),
+            (
+                r#" html PUBLIC "-//W3C//DTD HTML 4.01 Transitional//EN" "http://www.w3.org/TR/html4/loose.dtd""#,
+                DocumentMode::LimitedQuirks,
+            ),
+            (
+                r#" html PUBLIC "-//W3C//DTD XHTML 1.0 Transitional//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd""#,
+                DocumentMode::LimitedQuirks,
+            ),
+            (
+                r#" HTML PUBLIC "-//W3C//DTD HTML 4.01 Transitional//EN""#,
+                DocumentMode::Quirks,
+            ),
+            (
+                r#" HTML PUBLIC "-//W3C//DTD HTML 3.2 Final//EN""#,
+                DocumentMode::Quirks,
+            ),
+            ("", DocumentMode::Quirks),
+            (" svg", DocumentMode::Quirks),
+        ] {
+            assert_eq!(
+                Doctype::parse(content).get_document_mode(),
+                mode,
+                "{}",
+                content
+            );
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let doctype = Doctype::parse(r#" html PUBLIC '-//W3C//DTD HTML 4.01//EN'"#);
+        assert_eq!(doctype.name.as_deref(), Some("html"));
+        assert_eq!(
+            doctype.public_id.as_deref(),
+            Some("-//W3C//DTD HTML 4.01//EN")
+        );
+        assert_eq!(doctype.system_id, None);
+        assert!(!doctype.is_html5());
+        assert!(Doctype::parse(" html ").is_html5());
+    }
+}
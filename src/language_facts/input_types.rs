@@ -0,0 +1,106 @@
+/// The types of `<input>` that accept text
+const TEXT: [&str; 6] = ["text", "search", "url", "tel", "email", "password"];
+/// The types of `<input>` that accept a date or time
+const DATE_TIME: [&str; 5] = ["date", "month", "week", "time", "datetime-local"];
+
+/// The attributes of `<input>` that only apply to some types, and the types they apply to
+///
+/// <https://html.spec.whatwg.org/multipage/input.html#concept-input-apply>
+const ATTRIBUTES: [(&str, &[&[&str]]); 25] = [
+    ("accept", &[&["file"]]),
+    ("alt", &[&["image"]]),
+    (
+        "autocomplete",
+        &[&TEXT, &DATE_TIME, &["number", "range", "color", "hidden"]],
+    ),
+    ("checked", &[&["checkbox", "radio"]]),
+    ("dirname", &[&TEXT]),
+    ("formaction", &[&["submit", "image"]]),
+    ("formenctype", &[&["submit", "image"]]),
+    ("formmethod", &[&["submit", "image"]]),
+    ("formnovalidate", &[&["submit", "image"]]),
+    ("formtarget", &[&["submit", "image"]]),
+    ("height", &[&["image"]]),
+    (
+        "list",
+        &[
+            &["text", "search", "url", "tel", "email"],
+            &DATE_TIME,
+            &["number", "range", "color"],
+        ],
+    ),
+    ("max", &[&DATE_TIME, &["number", "range"]]),
+    ("maxlength", &[&TEXT]),
+    ("min", &[&DATE_TIME, &["number", "range"]]),
+    ("minlength", &[&TEXT]),
+    ("multiple", &[&["email", "file"]]),
+    ("pattern", &[&TEXT]),
+    ("placeholder", &[&TEXT, &["number"]]),
+    ("readonly", &[&TEXT, &DATE_TIME, &["number"]]),
+    (
+        "required",
+        &[&TEXT, &DATE_TIME, &["number", "checkbox", "radio", "file"]],
+    ),
+    ("size", &[&TEXT]),
+    ("src", &[&["image"]]),
+    ("step", &[&DATE_TIME, &["number", "range"]]),
+    ("width", &[&["image"]]),
+];
+
+/// The values of the `type` attribute of `<input>`
+const TYPES: [&str; 22] = [
+    "button",
+    "checkbox",
+    "color",
+    "date",
+    "datetime-local",
+    "email",
+    "file",
+    "hidden",
+    "image",
+    "month",
+    "number",
+    "password",
+    "radio",
+    "range",
+    "reset",
+    "search",
+    "submit",
+    "tel",
+    "text",
+    "time",
+    "url",
+    "week",
+];
+
+/// Whether the `attribute` applies to `<input>` of the `input_type`,
+/// it's `None` if the attribute applies to all types or the type is unknown
+pub fn is_applicable(input_type: &str, attribute: &str) -> Option<bool> {
+    let input_type = input_type.to_lowercase();
+    if !TYPES.contains(&input_type.as_str()) {
+        return None;
+    }
+    let attribute = attribute.to_lowercase();
+    let (_, types) = ATTRIBUTES.iter().find(|(name, _)| *name == attribute)?;
+    Some(
+        types
+            .iter()
+            .any(|types| types.contains(&input_type.as_str())),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applicable() {
+        assert_eq!(is_applicable("number", "min"), Some(true));
+        assert_eq!(is_applicable("Number", "step"), Some(true));
+        assert_eq!(is_applicable("number", "maxlength"), Some(false));
+        assert_eq!(is_applicable("checkbox", "checked"), Some(true));
+        assert_eq!(is_applicable("text", "checked"), Some(false));
+        assert_eq!(is_applicable("number", "name"), None);
+        assert_eq!(is_applicable("unknown", "min"), None);
+    }
+}
@@ -0,0 +1,181 @@
+/// The language subtags of BCP 47 that are commonly used,
+/// with their English names and the regions that they are usually combined with
+///
+/// <https://www.iana.org/assignments/language-subtag-registry/language-subtag-registry>
+pub const LANGUAGES: &[(&str, &str, &[&str])] = &[
+    ("ar", "Arabic", &["AE", "EG", "MA", "SA"]),
+    ("bg", "Bulgarian", &["BG"]),
+    ("bn", "Bengali", &["BD", "IN"]),
+    ("ca", "Catalan", &["ES"]),
+    ("cs", "Czech", &["CZ"]),
+    ("da", "Danish", &["DK"]),
+    ("de", "German", &["AT", "CH", "DE"]),
+    ("el", "Greek", &["GR"]),
+    (
+        "en",
+        "English",
+        &["AU", "CA", "GB", "IE", "IN", "NZ", "US", "ZA"],
+    ),
+    ("es", "Spanish", &["AR", "CO", "ES", "MX", "US"]),
+    ("et", "Estonian", &["EE"]),
+    ("fa", "Persian", &["IR"]),
+    ("fi", "Finnish", &["FI"]),
+    ("fil", "Filipino", &["PH"]),
+    ("fr", "French", &["BE", "CA", "CH", "FR"]),
+    ("he", "Hebrew", &["IL"]),
+    ("hi", "Hindi", &["IN"]),
+    ("hr", "Croatian", &["HR"]),
+    ("hu", "Hungarian", &["HU"]),
+    ("id", "Indonesian", &["ID"]),
+    ("it", "Italian", &["CH", "IT"]),
+    ("ja", "Japanese", &["JP"]),
+    ("ko", "Korean", &["KR"]),
+    ("lt", "Lithuanian", &["LT"]),
+    ("lv", "Latvian", &["LV"]),
+    ("ms", "Malay", &["MY"]),
+    ("nb", "Norwegian Bokmål", &["NO"]),
+    ("nl", "Dutch", &["BE", "NL"]),
+    ("pl", "Polish", &["PL"]),
+    ("pt", "Portuguese", &["BR", "PT"]),
+    ("ro", "Romanian", &["RO"]),
+    ("ru", "Russian", &["RU"]),
+    ("sk", "Slovak", &["SK"]),
+    ("sl", "Slovenian", &["SI"]),
+    ("sr", "Serbian", &["RS"]),
+    ("sv", "Swedish", &["FI", "SE"]),
+    ("sw", "Swahili", &["KE", "TZ"]),
+    ("ta", "Tamil", &["IN", "LK"]),
+    ("th", "Thai", &["TH"]),
+    ("tr", "Turkish", &["TR"]),
+    ("uk", "Ukrainian", &["UA"]),
+    ("ur", "Urdu", &["PK"]),
+    ("vi", "Vietnamese", &["VN"]),
+    ("zh", "Chinese", &["CN", "HK", "SG", "TW"]),
+];
+
+/// The region subtags used in `LANGUAGES` and their English names
+pub const REGIONS: &[(&str, &str)] = &[
+    ("AE", "United Arab Emirates"),
+    ("AR", "Argentina"),
+    ("AT", "Austria"),
+    ("AU", "Australia"),
+    ("BD", "Bangladesh"),
+    ("BE", "Belgium"),
+    ("BG", "Bulgaria"),
+    ("BR", "Brazil"),
+    ("CA", "Canada"),
+    ("CH", "Switzerland"),
+    ("CN", "China"),
+    ("CO", "Colombia"),
+    ("CZ", "Czechia"),
+    ("DE", "Germany"),
+    ("DK", "Denmark"),
+    ("EE", "Estonia"),
+    ("EG", "Egypt"),
+    ("ES", "Spain"),
+    ("FI", "Finland"),
+    ("FR", "France"),
+    ("GB", "United Kingdom"),
+    ("GR", "Greece"),
+    ("HK", "Hong Kong"),
+    ("HR", "Croatia"),
+    ("HU", "Hungary"),
+    ("ID", "Indonesia"),
+    ("IE", "Ireland"),
+    ("IL", "Israel"),
+    ("IN", "India"),
+    ("IR", "Iran"),
+    ("IT", "Italy"),
+    ("JP", "Japan"),
+    ("KE", "Kenya"),
+    ("KR", "South Korea"),
+    ("LK", "Sri Lanka"),
+    ("LT", "Lithuania"),
+    ("LV", "Latvia"),
+    ("MA", "Morocco"),
+    ("MX", "Mexico"),
+    ("MY", "Malaysia"),
+    ("NL", "Netherlands"),
+    ("NO", "Norway"),
+    ("NZ", "New Zealand"),
+    ("PH", "Philippines"),
+    ("PK", "Pakistan"),
+    ("PL", "Poland"),
+    ("PT", "Portugal"),
+    ("RO", "Romania"),
+    ("RS", "Serbia"),
+    ("RU", "Russia"),
+    ("SA", "Saudi Arabia"),
+    ("SE", "Sweden"),
+    ("SG", "Singapore"),
+    ("SI", "Slovenia"),
+    ("SK", "Slovakia"),
+    ("TH", "Thailand"),
+    ("TR", "Türkiye"),
+    ("TW", "Taiwan"),
+    ("TZ", "Tanzania"),
+    ("UA", "Ukraine"),
+    ("US", "United States"),
+    ("VN", "Vietnam"),
+    ("ZA", "South Africa"),
+];
+
+/// Get the English name of the language subtag
+pub fn get_language_name(language: &str) -> Option<&'static str> {
+    LANGUAGES
+        .iter()
+        .find(|(subtag, _, _)| subtag.eq_ignore_ascii_case(language))
+        .map(|(_, name, _)| *name)
+}
+
+/// Get the English name of the region subtag
+pub fn get_region_name(region: &str) -> Option<&'static str> {
+    REGIONS
+        .iter()
+        .find(|(subtag, _)| subtag.eq_ignore_ascii_case(region))
+        .map(|(_, name)| *name)
+}
+
+/// Get the language tags made of the language subtag and its usual regions, such as `en-US`,
+/// with their English names
+pub fn get_region_variants(language: &str) -> Vec<(String, String)> {
+    if let Some((subtag, name, regions)) = LANGUAGES
+        .iter()
+        .find(|(subtag, _, _)| subtag.eq_ignore_ascii_case(language))
+    {
+        regions
+            .iter()
+            .map(|region| {
+                (
+                    format!("{}-{}", subtag, region),
+                    format!("{} ({})", name, get_region_name(region).unwrap_or(region)),
+                )
+            })
+            .collect()
+    } else {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regions() {
+        for (language, _, regions) in LANGUAGES {
+            for region in *regions {
+                assert!(get_region_name(region).is_some(), "{}-{}", language, region);
+            }
+        }
+        assert_eq!(get_language_name("EN"), Some("English"));
+        assert_eq!(
+            get_region_variants("pt"),
+            vec![
+                ("pt-BR".to_string(), "Portuguese (Brazil)".to_string()),
+                ("pt-PT".to_string(), "Portuguese (Portugal)".to_string()),
+            ]
+        );
+        assert!(get_region_variants("xx").is_empty());
+    }
+}
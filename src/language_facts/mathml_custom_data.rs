@@ -0,0 +1,631 @@
+// MathML elements, their attributes and value sets, descriptions are from MDN
+pub static MATHML_DATA: &str = r##"{
+    "version": 1.1,
+    "tags": [
+        {
+            "name": "math",
+            "description": {
+                "kind": "markdown",
+                "value": "The `math` element is the top-level MathML element, used to write a single mathematical formula. It can be placed in HTML content where flow content is permitted."
+            },
+            "attributes": [
+                {
+                    "name": "display",
+                    "description": "How the enclosed MathML markup should be rendered.",
+                    "valueSet": "mathDisplay"
+                },
+                {
+                    "name": "alttext",
+                    "description": "The alternative text of the formula."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/math"
+                }
+            ]
+        },
+        {
+            "name": "mi",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mi` element indicates that the content should be rendered as an identifier, such as a function name, variable or symbolic constant."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mi"
+                }
+            ]
+        },
+        {
+            "name": "mn",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mn` element represents a numeric literal which is normally a sequence of digits with a possible separator (a dot or a comma)."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mn"
+                }
+            ]
+        },
+        {
+            "name": "mo",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mo` element represents an operator in a broad sense. Besides operators in strict mathematical meaning, this element also includes \"operators\" like parentheses, separators like comma and semicolon, or \"absolute value\" bars."
+            },
+            "attributes": [
+                {
+                    "name": "fence",
+                    "description": "Whether the operator is a fence (such as parentheses).",
+                    "valueSet": "b"
+                },
+                {
+                    "name": "form",
+                    "description": "How the operator is to be presented.",
+                    "valueSet": "moForm"
+                },
+                {
+                    "name": "largeop",
+                    "description": "Whether the operator should be drawn bigger when `displaystyle` is true.",
+                    "valueSet": "b"
+                },
+                {
+                    "name": "lspace",
+                    "description": "The amount of space before the operator."
+                },
+                {
+                    "name": "rspace",
+                    "description": "The amount of space after the operator."
+                },
+                {
+                    "name": "maxsize",
+                    "description": "The maximum size of the operator when it is stretchy."
+                },
+                {
+                    "name": "minsize",
+                    "description": "The minimum size of the operator when it is stretchy."
+                },
+                {
+                    "name": "movablelimits",
+                    "description": "Whether attached under- and overscripts move to sub- and superscript positions when `displaystyle` is false.",
+                    "valueSet": "b"
+                },
+                {
+                    "name": "separator",
+                    "description": "Whether the operator is a separator (such as commas).",
+                    "valueSet": "b"
+                },
+                {
+                    "name": "stretchy",
+                    "description": "Whether the operator stretches to the size of the adjacent element.",
+                    "valueSet": "b"
+                },
+                {
+                    "name": "symmetric",
+                    "description": "Whether a stretchy operator should be vertically symmetric around the imaginary math axis.",
+                    "valueSet": "b"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mo"
+                }
+            ]
+        },
+        {
+            "name": "ms",
+            "description": {
+                "kind": "markdown",
+                "value": "The `ms` element represents a string literal meant to be interpreted by programming languages and computer algebra systems."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/ms"
+                }
+            ]
+        },
+        {
+            "name": "mtext",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mtext` element is used to render arbitrary text with no notational meaning, such as comments or annotations."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mtext"
+                }
+            ]
+        },
+        {
+            "name": "mspace",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mspace` element is used to display a blank space, whose size is set by its attributes."
+            },
+            "attributes": [
+                {
+                    "name": "width",
+                    "description": "The desired width of the space."
+                },
+                {
+                    "name": "height",
+                    "description": "The desired height (above the baseline) of the space."
+                },
+                {
+                    "name": "depth",
+                    "description": "The desired depth (below the baseline) of the space."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mspace"
+                }
+            ]
+        },
+        {
+            "name": "mrow",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mrow` element is used to group sub-expressions, which usually contain one or more operators with their respective operands."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mrow"
+                }
+            ]
+        },
+        {
+            "name": "mfrac",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mfrac` element is used to display fractions. It can also be used to mark up fraction-like objects such as binomial coefficients and Legendre symbols."
+            },
+            "attributes": [
+                {
+                    "name": "linethickness",
+                    "description": "The thickness of the horizontal fraction line."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mfrac"
+                }
+            ]
+        },
+        {
+            "name": "msqrt",
+            "description": {
+                "kind": "markdown",
+                "value": "The `msqrt` element is used to display square roots (no index is displayed)."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/msqrt"
+                }
+            ]
+        },
+        {
+            "name": "mroot",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mroot` element is used to display roots with an explicit index. Two arguments are accepted, which leads to the syntax: `<mroot> base index </mroot>`."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mroot"
+                }
+            ]
+        },
+        {
+            "name": "mstyle",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mstyle` element is used to change the style of its children."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mstyle"
+                }
+            ]
+        },
+        {
+            "name": "merror",
+            "description": {
+                "kind": "markdown",
+                "value": "The `merror` element is used to display contents as error messages."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/merror"
+                }
+            ]
+        },
+        {
+            "name": "mpadded",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mpadded` element is used to add extra padding and to set the general adjustment of position and size of enclosed contents."
+            },
+            "attributes": [
+                {
+                    "name": "width",
+                    "description": "The desired width of the element."
+                },
+                {
+                    "name": "height",
+                    "description": "The desired height (above the baseline) of the element."
+                },
+                {
+                    "name": "depth",
+                    "description": "The desired depth (below the baseline) of the element."
+                },
+                {
+                    "name": "lspace",
+                    "description": "The horizontal location of the positioning point of the child content with respect to the positioning point of the element."
+                },
+                {
+                    "name": "voffset",
+                    "description": "The vertical location of the positioning point of the child content with respect to the positioning point of the element."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mpadded"
+                }
+            ]
+        },
+        {
+            "name": "mphantom",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mphantom` element is rendered invisibly, but dimensions (such as height, width, and depth) and baseline position are still kept."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mphantom"
+                }
+            ]
+        },
+        {
+            "name": "msub",
+            "description": {
+                "kind": "markdown",
+                "value": "The `msub` element is used to attach a subscript to an expression. It uses the following syntax: `<msub> base subscript </msub>`."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/msub"
+                }
+            ]
+        },
+        {
+            "name": "msup",
+            "description": {
+                "kind": "markdown",
+                "value": "The `msup` element is used to attach a superscript to an expression. It uses the following syntax: `<msup> base superscript </msup>`."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/msup"
+                }
+            ]
+        },
+        {
+            "name": "msubsup",
+            "description": {
+                "kind": "markdown",
+                "value": "The `msubsup` element is used to attach both a subscript and a superscript, together, to an expression."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/msubsup"
+                }
+            ]
+        },
+        {
+            "name": "munder",
+            "description": {
+                "kind": "markdown",
+                "value": "The `munder` element is used to attach an accent or a limit under an expression. It uses the following syntax: `<munder> base underscript </munder>`."
+            },
+            "attributes": [
+                {
+                    "name": "accentunder",
+                    "description": "Whether the underscript is an accent or a limit.",
+                    "valueSet": "b"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/munder"
+                }
+            ]
+        },
+        {
+            "name": "mover",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mover` element is used to attach an accent or a limit over an expression. Use the following syntax: `<mover> base overscript </mover>`."
+            },
+            "attributes": [
+                {
+                    "name": "accent",
+                    "description": "Whether the overscript is an accent or a limit.",
+                    "valueSet": "b"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mover"
+                }
+            ]
+        },
+        {
+            "name": "munderover",
+            "description": {
+                "kind": "markdown",
+                "value": "The `munderover` element is used to attach accents or limits both under and over an expression."
+            },
+            "attributes": [
+                {
+                    "name": "accent",
+                    "description": "Whether the overscript is an accent or a limit.",
+                    "valueSet": "b"
+                },
+                {
+                    "name": "accentunder",
+                    "description": "Whether the underscript is an accent or a limit.",
+                    "valueSet": "b"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/munderover"
+                }
+            ]
+        },
+        {
+            "name": "mmultiscripts",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mmultiscripts` element is used to attach an arbitrary number of subscripts and superscripts to an expression at once, generalizing the `msubsup` element."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mmultiscripts"
+                }
+            ]
+        },
+        {
+            "name": "mprescripts",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mprescripts` element is used within an `mmultiscripts` element to separate postscripts from prescripts."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mprescripts"
+                }
+            ]
+        },
+        {
+            "name": "mtable",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mtable` element allows you to create tables or matrices. Its children are `mtr` elements (representing rows), each of them having `mtd` elements as its children (representing cells)."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mtable"
+                }
+            ]
+        },
+        {
+            "name": "mtr",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mtr` element represents a row in a table or a matrix. It may only appear in a `mtable` element and its children are `mtd` elements representing cells."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mtr"
+                }
+            ]
+        },
+        {
+            "name": "mtd",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mtd` element represents a cell in a table or a matrix. It may only appear in a `mtr` element."
+            },
+            "attributes": [
+                {
+                    "name": "columnspan",
+                    "description": "A non-negative integer value that indicates on how many columns does the cell extend."
+                },
+                {
+                    "name": "rowspan",
+                    "description": "A non-negative integer value that indicates on how many rows does the cell extend."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/mtd"
+                }
+            ]
+        },
+        {
+            "name": "semantics",
+            "description": {
+                "kind": "markdown",
+                "value": "The `semantics` element associates annotations with a MathML expression, for example its text source as a lightweight markup language or mathematical meaning expressed in a special XML dialect."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/semantics"
+                }
+            ]
+        },
+        {
+            "name": "annotation",
+            "description": {
+                "kind": "markdown",
+                "value": "The `annotation` element contains an annotation to the MathML expression in a textual format."
+            },
+            "attributes": [
+                {
+                    "name": "encoding",
+                    "description": "The encoding of the semantic information in the annotation."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/annotation"
+                }
+            ]
+        },
+        {
+            "name": "annotation-xml",
+            "description": {
+                "kind": "markdown",
+                "value": "The `annotation-xml` element contains an annotation to the MathML expression in an XML format."
+            },
+            "attributes": [
+                {
+                    "name": "encoding",
+                    "description": "The encoding of the semantic information in the annotation."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/MathML/Element/annotation-xml"
+                }
+            ]
+        }
+    ],
+    "valueSets": [
+        {
+            "name": "mathDisplay",
+            "values": [
+                {
+                    "name": "block"
+                },
+                {
+                    "name": "inline"
+                }
+            ]
+        },
+        {
+            "name": "moForm",
+            "values": [
+                {
+                    "name": "prefix"
+                },
+                {
+                    "name": "infix"
+                },
+                {
+                    "name": "postfix"
+                }
+            ]
+        },
+        {
+            "name": "mathvariant",
+            "values": [
+                {
+                    "name": "normal"
+                }
+            ]
+        },
+        {
+            "name": "b",
+            "values": [
+                {
+                    "name": "true"
+                },
+                {
+                    "name": "false"
+                }
+            ]
+        }
+    ]
+}"##;
+
+/// The attributes shared by all MathML elements
+pub static MATHML_GLOBAL_ATTRIBUTES: &str = r##"[
+    {
+        "name": "displaystyle",
+        "description": "Whether formulas are rendered in display mode or in inline mode.",
+        "valueSet": "b"
+    },
+    {
+        "name": "scriptlevel",
+        "description": "The math-depth of the element, it's used to scale the font size of sub-expressions."
+    },
+    {
+        "name": "mathvariant",
+        "description": "The logical class of token elements, which varies in typography.",
+        "valueSet": "mathvariant"
+    },
+    {
+        "name": "mathcolor",
+        "description": "The foreground color of the element."
+    },
+    {
+        "name": "mathbackground",
+        "description": "The background color of the element."
+    },
+    {
+        "name": "mathsize",
+        "description": "The font size of the element."
+    }
+]"##;
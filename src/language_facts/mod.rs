@@ -1,3 +1,23 @@
+#[cfg(feature = "angular_data")]
+pub mod angular_custom_data;
+pub mod aria;
+pub mod content_model;
+pub mod custom_elements_manifest;
 pub mod data_manager;
 pub mod data_provider;
+pub mod doctype;
+pub mod input_types;
+pub mod language_tags;
+#[cfg(feature = "mathml_data")]
+pub mod mathml_custom_data;
+#[cfg(feature = "svelte_data")]
+pub mod svelte_custom_data;
+#[cfg(feature = "svelte_data")]
+pub mod svelte_data_provider;
+#[cfg(feature = "svg_data")]
+pub mod svg_custom_data;
+#[cfg(feature = "vue_data")]
+pub mod vue_custom_data;
+#[cfg(feature = "vue_data")]
+pub mod vue_data_provider;
 pub mod web_custom_data;
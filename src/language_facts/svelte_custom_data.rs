@@ -0,0 +1,600 @@
+// Svelte directives and special elements, descriptions are from the Svelte docs
+pub static SVELTE_DATA: &str = r##"{
+    "version": 1.1,
+    "tags": [
+        {
+            "name": "svelte:self",
+            "description": {
+                "kind": "markdown",
+                "value": "Allows a component to include itself, recursively."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/special-elements#svelte-self"
+                }
+            ]
+        },
+        {
+            "name": "svelte:component",
+            "description": {
+                "kind": "markdown",
+                "value": "Renders a component dynamically, using the component constructor specified as the `this` property."
+            },
+            "attributes": [
+                {
+                    "name": "this",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The component constructor to render."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-component"
+                        }
+                    ]
+                }
+            ],
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/special-elements#svelte-component"
+                }
+            ]
+        },
+        {
+            "name": "svelte:element",
+            "description": {
+                "kind": "markdown",
+                "value": "Renders an element of a dynamically specified type."
+            },
+            "attributes": [
+                {
+                    "name": "this",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The tag name of the element to render."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-element"
+                        }
+                    ]
+                }
+            ],
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/special-elements#svelte-element"
+                }
+            ]
+        },
+        {
+            "name": "svelte:window",
+            "description": {
+                "kind": "markdown",
+                "value": "Adds event listeners to the `window` object without worrying about removing them when the component is destroyed."
+            },
+            "attributes": [
+                {
+                    "name": "bind:innerWidth",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Readonly binding of `window.innerWidth`."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-window"
+                        }
+                    ]
+                },
+                {
+                    "name": "bind:innerHeight",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Readonly binding of `window.innerHeight`."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-window"
+                        }
+                    ]
+                },
+                {
+                    "name": "bind:outerWidth",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Readonly binding of `window.outerWidth`."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-window"
+                        }
+                    ]
+                },
+                {
+                    "name": "bind:outerHeight",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Readonly binding of `window.outerHeight`."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-window"
+                        }
+                    ]
+                },
+                {
+                    "name": "bind:scrollX",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Binding of `window.scrollX`."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-window"
+                        }
+                    ]
+                },
+                {
+                    "name": "bind:scrollY",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Binding of `window.scrollY`."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-window"
+                        }
+                    ]
+                },
+                {
+                    "name": "bind:online",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Readonly binding of `window.navigator.onLine`."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-window"
+                        }
+                    ]
+                },
+                {
+                    "name": "bind:devicePixelRatio",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Readonly binding of `window.devicePixelRatio`."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-window"
+                        }
+                    ]
+                }
+            ],
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/special-elements#svelte-window"
+                }
+            ]
+        },
+        {
+            "name": "svelte:document",
+            "description": {
+                "kind": "markdown",
+                "value": "Adds event listeners to the `document` object, such as `visibilitychange`."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/special-elements#svelte-document"
+                }
+            ]
+        },
+        {
+            "name": "svelte:body",
+            "description": {
+                "kind": "markdown",
+                "value": "Adds event listeners to `document.body`, such as `mouseenter` and `mouseleave`."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/special-elements#svelte-body"
+                }
+            ]
+        },
+        {
+            "name": "svelte:head",
+            "description": {
+                "kind": "markdown",
+                "value": "Inserts elements into `document.head`."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/special-elements#svelte-head"
+                }
+            ]
+        },
+        {
+            "name": "svelte:options",
+            "description": {
+                "kind": "markdown",
+                "value": "Specifies per-component compiler options."
+            },
+            "attributes": [
+                {
+                    "name": "immutable",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Never use mutable data, so the compiler can do simple referential equality checks."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-options"
+                        }
+                    ]
+                },
+                {
+                    "name": "accessors",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Adds getters and setters for the component's props."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-options"
+                        }
+                    ]
+                },
+                {
+                    "name": "namespace",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The namespace where this component will be used."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-options"
+                        }
+                    ],
+                    "values": [
+                        {
+                            "name": "html"
+                        },
+                        {
+                            "name": "svg"
+                        },
+                        {
+                            "name": "mathml"
+                        }
+                    ]
+                },
+                {
+                    "name": "customElement",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The options to use when compiling this component as a custom element."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-options"
+                        }
+                    ]
+                }
+            ],
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/special-elements#svelte-options"
+                }
+            ]
+        },
+        {
+            "name": "svelte:fragment",
+            "description": {
+                "kind": "markdown",
+                "value": "Allows you to place content in a named slot without wrapping it in a container DOM element."
+            },
+            "attributes": [
+                {
+                    "name": "slot",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The name of the slot."
+                    },
+                    "references": [
+                        {
+                            "name": "Svelte Reference",
+                            "url": "https://svelte.dev/docs/special-elements#svelte-fragment"
+                        }
+                    ]
+                }
+            ],
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/special-elements#svelte-fragment"
+                }
+            ]
+        }
+    ],
+    "globalAttributes": [
+        {
+            "name": "bind:this",
+            "description": {
+                "kind": "markdown",
+                "value": "Get a reference to the DOM node."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#bind-this"
+                }
+            ]
+        },
+        {
+            "name": "bind:value",
+            "description": {
+                "kind": "markdown",
+                "value": "Bind the `value` property of the element, such as `<input bind:value={name}>`."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#bind-property"
+                }
+            ]
+        },
+        {
+            "name": "bind:checked",
+            "description": {
+                "kind": "markdown",
+                "value": "Bind the `checked` property of a checkbox."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#bind-property"
+                }
+            ]
+        },
+        {
+            "name": "bind:group",
+            "description": {
+                "kind": "markdown",
+                "value": "Bind the inputs that work together, such as radio buttons or checkboxes of a group."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#bind-group"
+                }
+            ]
+        },
+        {
+            "name": "bind:files",
+            "description": {
+                "kind": "markdown",
+                "value": "Bind the `files` of `<input type=\"file\">`."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#bind-property"
+                }
+            ]
+        },
+        {
+            "name": "bind:innerHTML",
+            "description": {
+                "kind": "markdown",
+                "value": "Bind the `innerHTML` of a `contenteditable` element."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#bind-property"
+                }
+            ]
+        },
+        {
+            "name": "bind:textContent",
+            "description": {
+                "kind": "markdown",
+                "value": "Bind the `textContent` of a `contenteditable` element."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#bind-property"
+                }
+            ]
+        },
+        {
+            "name": "bind:clientWidth",
+            "description": {
+                "kind": "markdown",
+                "value": "Readonly binding of the `clientWidth` of the element."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#bind-property"
+                }
+            ]
+        },
+        {
+            "name": "bind:clientHeight",
+            "description": {
+                "kind": "markdown",
+                "value": "Readonly binding of the `clientHeight` of the element."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#bind-property"
+                }
+            ]
+        },
+        {
+            "name": "bind:offsetWidth",
+            "description": {
+                "kind": "markdown",
+                "value": "Readonly binding of the `offsetWidth` of the element."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#bind-property"
+                }
+            ]
+        },
+        {
+            "name": "bind:offsetHeight",
+            "description": {
+                "kind": "markdown",
+                "value": "Readonly binding of the `offsetHeight` of the element."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#bind-property"
+                }
+            ]
+        },
+        {
+            "name": "class:",
+            "description": {
+                "kind": "markdown",
+                "value": "Toggle a class on the element, such as `class:active={isActive}`."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#class-name"
+                }
+            ],
+            "valueSet": "v"
+        },
+        {
+            "name": "style:",
+            "description": {
+                "kind": "markdown",
+                "value": "Set a style property on the element, such as `style:color={color}`."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#style-property"
+                }
+            ],
+            "valueSet": "v"
+        },
+        {
+            "name": "use:",
+            "description": {
+                "kind": "markdown",
+                "value": "Call an action when the element is created, such as `use:tooltip={options}`."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#use-action"
+                }
+            ],
+            "valueSet": "v"
+        },
+        {
+            "name": "transition:",
+            "description": {
+                "kind": "markdown",
+                "value": "Apply a transition when the element enters or leaves the DOM as a result of a state change."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#transition-fn"
+                }
+            ],
+            "valueSet": "v"
+        },
+        {
+            "name": "in:",
+            "description": {
+                "kind": "markdown",
+                "value": "Apply a transition only when the element enters the DOM."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#in-fn-out-fn"
+                }
+            ],
+            "valueSet": "v"
+        },
+        {
+            "name": "out:",
+            "description": {
+                "kind": "markdown",
+                "value": "Apply a transition only when the element leaves the DOM."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#in-fn-out-fn"
+                }
+            ],
+            "valueSet": "v"
+        },
+        {
+            "name": "animate:",
+            "description": {
+                "kind": "markdown",
+                "value": "Apply an animation when the contents of a keyed each block are re-ordered."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/element-directives#animate-fn"
+                }
+            ],
+            "valueSet": "v"
+        },
+        {
+            "name": "let:",
+            "description": {
+                "kind": "markdown",
+                "value": "Expose the props of a slot to the parent, such as `let:item`."
+            },
+            "references": [
+                {
+                    "name": "Svelte Reference",
+                    "url": "https://svelte.dev/docs/special-elements#slot-slot-key-value"
+                }
+            ],
+            "valueSet": "v"
+        }
+    ]
+}"##;
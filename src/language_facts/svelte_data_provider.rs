@@ -0,0 +1,77 @@
+use crate::html_data::{HTMLDataV1, IAttributeData, ITagData, IValueData};
+
+use super::{
+    data_provider::{HTMLDataProvider, IHTMLDataProvider},
+    svelte_custom_data::SVELTE_DATA,
+};
+
+/// Data provider of Svelte components, it's only applicable to the `svelte` language id
+///
+/// It provides the directives and special elements of Svelte,
+/// and the `on:event` directives of the event handler attributes in `html_data`.
+pub struct SvelteDataProvider {
+    provider: HTMLDataProvider,
+    events: Vec<IAttributeData>,
+}
+
+impl SvelteDataProvider {
+    pub fn new(html_data: &HTMLDataV1) -> SvelteDataProvider {
+        let data = serde_json::from_str(SVELTE_DATA).unwrap();
+        let events = html_data
+            .global_attributes
+            .iter()
+            .flatten()
+            .filter_map(|attribute| {
+                let event = attribute.name.strip_prefix("on")?;
+                Some(IAttributeData {
+                    name: format!("on:{}", event),
+                    description: attribute.description.clone(),
+                    value_set: None,
+                    values: None,
+                    references: attribute.references.clone(),
+                    browsers: None,
+                    status: None,
+                    deprecated: attribute.deprecated,
+                })
+            })
+            .collect();
+        SvelteDataProvider {
+            provider: HTMLDataProvider::new("svelte".to_string(), data),
+            events,
+        }
+    }
+}
+
+impl IHTMLDataProvider for SvelteDataProvider {
+    fn get_id(&self) -> &str {
+        self.provider.get_id()
+    }
+
+    fn is_applicable(&self, language_id: &str) -> bool {
+        language_id == "svelte"
+    }
+
+    fn provide_tags(&self) -> &Vec<ITagData> {
+        self.provider.provide_tags()
+    }
+
+    fn provide_attributes(&self, tag: &str) -> Vec<&IAttributeData> {
+        let mut attributes = self.provider.provide_attributes(tag);
+        attributes.extend(&self.events);
+        attributes
+    }
+
+    fn provide_values(&self, tag: &str, attribute: &str) -> Vec<&IValueData> {
+        self.provider.provide_values(tag, attribute)
+    }
+
+    fn provide_global_attributes(&self) -> Vec<&IAttributeData> {
+        let mut attributes = self.provider.provide_global_attributes();
+        attributes.extend(&self.events);
+        attributes
+    }
+
+    fn get_value_set(&self, name: &str) -> Option<&Vec<IValueData>> {
+        self.provider.get_value_set(name)
+    }
+}
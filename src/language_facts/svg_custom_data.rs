@@ -0,0 +1,2204 @@
+// SVG elements, their attributes and value sets, descriptions are from MDN
+pub static SVG_DATA: &str = r##"{
+    "version": 1.1,
+    "tags": [
+        {
+            "name": "svg",
+            "description": {
+                "kind": "markdown",
+                "value": "The `svg` element is a container that defines a new coordinate system and viewport. It is used as the outermost element of SVG documents, but it can also be used to embed an SVG fragment inside an SVG or HTML document."
+            },
+            "attributes": [
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "viewBox",
+                    "description": "The position and dimension, in user space, of the SVG viewport, as `min-x min-y width height`."
+                },
+                {
+                    "name": "preserveAspectRatio",
+                    "description": "How the element must be deformed if it has a different aspect ratio than the viewport.",
+                    "valueSet": "preserveAspectRatio"
+                },
+                {
+                    "name": "xmlns",
+                    "description": "The XML namespace, it's `http://www.w3.org/2000/svg` for SVG."
+                },
+                {
+                    "name": "version",
+                    "description": "The version of SVG used for the inner content of the element."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/svg"
+                }
+            ]
+        },
+        {
+            "name": "g",
+            "description": {
+                "kind": "markdown",
+                "value": "The `g` element is a container used to group other SVG elements."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/g"
+                }
+            ]
+        },
+        {
+            "name": "defs",
+            "description": {
+                "kind": "markdown",
+                "value": "The `defs` element is used to store graphical objects that will be used at a later time. Objects created inside a `defs` element are not rendered directly."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/defs"
+                }
+            ]
+        },
+        {
+            "name": "desc",
+            "description": {
+                "kind": "markdown",
+                "value": "The `desc` element provides an accessible, long-text description of any SVG container element or graphics element."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/desc"
+                }
+            ]
+        },
+        {
+            "name": "metadata",
+            "description": {
+                "kind": "markdown",
+                "value": "The `metadata` element adds metadata to SVG content."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/metadata"
+                }
+            ]
+        },
+        {
+            "name": "symbol",
+            "description": {
+                "kind": "markdown",
+                "value": "The `symbol` element is used to define graphical template objects which can be instantiated by a `use` element."
+            },
+            "attributes": [
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "viewBox",
+                    "description": "The position and dimension, in user space, of the SVG viewport, as `min-x min-y width height`."
+                },
+                {
+                    "name": "preserveAspectRatio",
+                    "description": "How the element must be deformed if it has a different aspect ratio than the viewport.",
+                    "valueSet": "preserveAspectRatio"
+                },
+                {
+                    "name": "refX",
+                    "description": "The x coordinate of the reference point of the symbol."
+                },
+                {
+                    "name": "refY",
+                    "description": "The y coordinate of the reference point of the symbol."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/symbol"
+                }
+            ]
+        },
+        {
+            "name": "use",
+            "description": {
+                "kind": "markdown",
+                "value": "The `use` element takes nodes from within the SVG document, and duplicates them somewhere else."
+            },
+            "attributes": [
+                {
+                    "name": "href",
+                    "description": "The URL of the referenced element or resource."
+                },
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/use"
+                }
+            ]
+        },
+        {
+            "name": "image",
+            "description": {
+                "kind": "markdown",
+                "value": "The `image` element includes images inside SVG documents. It can display raster image files or other SVG files."
+            },
+            "attributes": [
+                {
+                    "name": "href",
+                    "description": "The URL of the referenced element or resource."
+                },
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "preserveAspectRatio",
+                    "description": "How the element must be deformed if it has a different aspect ratio than the viewport.",
+                    "valueSet": "preserveAspectRatio"
+                },
+                {
+                    "name": "crossorigin",
+                    "description": "The CORS setting of the image request.",
+                    "valueSet": "crossorigin"
+                },
+                {
+                    "name": "decoding",
+                    "description": "A hint about how to decode the image.",
+                    "valueSet": "decoding"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/image"
+                }
+            ]
+        },
+        {
+            "name": "switch",
+            "description": {
+                "kind": "markdown",
+                "value": "The `switch` element evaluates any `requiredFeatures`, `requiredExtensions` and `systemLanguage` attributes on its direct child elements in order, and then renders the first child where these attributes evaluate to true."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/switch"
+                }
+            ]
+        },
+        {
+            "name": "foreignObject",
+            "description": {
+                "kind": "markdown",
+                "value": "The `foreignObject` element includes elements from a different XML namespace. In the context of a browser, it is most likely (X)HTML."
+            },
+            "attributes": [
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/foreignObject"
+                }
+            ]
+        },
+        {
+            "name": "view",
+            "description": {
+                "kind": "markdown",
+                "value": "The `view` element defines a particular view of an SVG document, it can be linked to with a fragment identifier."
+            },
+            "attributes": [
+                {
+                    "name": "viewBox",
+                    "description": "The position and dimension, in user space, of the SVG viewport, as `min-x min-y width height`."
+                },
+                {
+                    "name": "preserveAspectRatio",
+                    "description": "How the element must be deformed if it has a different aspect ratio than the viewport.",
+                    "valueSet": "preserveAspectRatio"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/view"
+                }
+            ]
+        },
+        {
+            "name": "path",
+            "description": {
+                "kind": "markdown",
+                "value": "The `path` element is the generic element to define a shape. All the basic shapes can be created with a path element."
+            },
+            "attributes": [
+                {
+                    "name": "d",
+                    "description": "The shape of the path, defined by a list of path commands."
+                },
+                {
+                    "name": "pathLength",
+                    "description": "The total length for the path, in user units."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/path"
+                }
+            ]
+        },
+        {
+            "name": "rect",
+            "description": {
+                "kind": "markdown",
+                "value": "The `rect` element is a basic SVG shape that draws rectangles, defined by their position, width, and height. The rectangles may have their corners rounded."
+            },
+            "attributes": [
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "rx",
+                    "description": "The horizontal corner radius of the rect."
+                },
+                {
+                    "name": "ry",
+                    "description": "The vertical corner radius of the rect."
+                },
+                {
+                    "name": "pathLength",
+                    "description": "The total length for the path, in user units."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/rect"
+                }
+            ]
+        },
+        {
+            "name": "circle",
+            "description": {
+                "kind": "markdown",
+                "value": "The `circle` element is an SVG basic shape, used to draw circles based on a center point and a radius."
+            },
+            "attributes": [
+                {
+                    "name": "cx",
+                    "description": "The x-axis coordinate of the center of the circle."
+                },
+                {
+                    "name": "cy",
+                    "description": "The y-axis coordinate of the center of the circle."
+                },
+                {
+                    "name": "r",
+                    "description": "The radius of the circle."
+                },
+                {
+                    "name": "pathLength",
+                    "description": "The total length for the path, in user units."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/circle"
+                }
+            ]
+        },
+        {
+            "name": "ellipse",
+            "description": {
+                "kind": "markdown",
+                "value": "The `ellipse` element is an SVG basic shape, used to create ellipses based on a center coordinate, and both their x and y radius."
+            },
+            "attributes": [
+                {
+                    "name": "cx",
+                    "description": "The x position of the ellipse."
+                },
+                {
+                    "name": "cy",
+                    "description": "The y position of the ellipse."
+                },
+                {
+                    "name": "rx",
+                    "description": "The radius of the ellipse on the x axis."
+                },
+                {
+                    "name": "ry",
+                    "description": "The radius of the ellipse on the y axis."
+                },
+                {
+                    "name": "pathLength",
+                    "description": "The total length for the path, in user units."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/ellipse"
+                }
+            ]
+        },
+        {
+            "name": "line",
+            "description": {
+                "kind": "markdown",
+                "value": "The `line` element is an SVG basic shape used to create a line connecting two points."
+            },
+            "attributes": [
+                {
+                    "name": "x1",
+                    "description": "The x-axis coordinate of the line starting point."
+                },
+                {
+                    "name": "y1",
+                    "description": "The y-axis coordinate of the line starting point."
+                },
+                {
+                    "name": "x2",
+                    "description": "The x-axis coordinate of the line ending point."
+                },
+                {
+                    "name": "y2",
+                    "description": "The y-axis coordinate of the line ending point."
+                },
+                {
+                    "name": "pathLength",
+                    "description": "The total length for the path, in user units."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/line"
+                }
+            ]
+        },
+        {
+            "name": "polyline",
+            "description": {
+                "kind": "markdown",
+                "value": "The `polyline` element is an SVG basic shape that creates straight lines connecting several points. Typically a polyline is used to create open shapes as the last point doesn't have to be connected to the first point."
+            },
+            "attributes": [
+                {
+                    "name": "points",
+                    "description": "The list of points (pairs of x,y absolute coordinates) required to draw the polyline."
+                },
+                {
+                    "name": "pathLength",
+                    "description": "The total length for the path, in user units."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/polyline"
+                }
+            ]
+        },
+        {
+            "name": "polygon",
+            "description": {
+                "kind": "markdown",
+                "value": "The `polygon` element defines a closed shape consisting of a set of connected straight line segments. The last point is connected to the first point."
+            },
+            "attributes": [
+                {
+                    "name": "points",
+                    "description": "The list of points (pairs of x,y absolute coordinates) required to draw the polygon."
+                },
+                {
+                    "name": "pathLength",
+                    "description": "The total length for the path, in user units."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/polygon"
+                }
+            ]
+        },
+        {
+            "name": "text",
+            "description": {
+                "kind": "markdown",
+                "value": "The `text` element draws a graphics element consisting of text."
+            },
+            "attributes": [
+                {
+                    "name": "x",
+                    "description": "The x coordinates of the starting point of the text baseline."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinates of the starting point of the text baseline."
+                },
+                {
+                    "name": "dx",
+                    "description": "Shifts the text position horizontally from a previous text element."
+                },
+                {
+                    "name": "dy",
+                    "description": "Shifts the text position vertically from a previous text element."
+                },
+                {
+                    "name": "rotate",
+                    "description": "Rotates orientation of each individual glyph."
+                },
+                {
+                    "name": "textLength",
+                    "description": "The width of the space into which the text will draw."
+                },
+                {
+                    "name": "lengthAdjust",
+                    "description": "How the text is stretched or compressed into the length defined by the `textLength` attribute.",
+                    "valueSet": "lengthAdjust"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/text"
+                }
+            ]
+        },
+        {
+            "name": "tspan",
+            "description": {
+                "kind": "markdown",
+                "value": "The `tspan` element defines a subtext within a `text` element or another `tspan` element. It allows for adjustment of the style and/or position of that subtext as needed."
+            },
+            "attributes": [
+                {
+                    "name": "x"
+                },
+                {
+                    "name": "y"
+                },
+                {
+                    "name": "dx"
+                },
+                {
+                    "name": "dy"
+                },
+                {
+                    "name": "rotate"
+                },
+                {
+                    "name": "textLength"
+                },
+                {
+                    "name": "lengthAdjust",
+                    "valueSet": "lengthAdjust"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/tspan"
+                }
+            ]
+        },
+        {
+            "name": "textPath",
+            "description": {
+                "kind": "markdown",
+                "value": "The `textPath` element renders text along the shape of a `path`."
+            },
+            "attributes": [
+                {
+                    "name": "href",
+                    "description": "The URL of the referenced element or resource."
+                },
+                {
+                    "name": "path",
+                    "description": "The path data along which the characters will be rendered."
+                },
+                {
+                    "name": "startOffset",
+                    "description": "How far the beginning of the text should be offset from the beginning of the path."
+                },
+                {
+                    "name": "method",
+                    "description": "Which method to render individual glyphs along the path.",
+                    "valueSet": "textPathMethod"
+                },
+                {
+                    "name": "side",
+                    "description": "Which side of the path the text should be rendered.",
+                    "valueSet": "textPathSide"
+                },
+                {
+                    "name": "spacing",
+                    "description": "How space between glyphs should be handled.",
+                    "valueSet": "textPathSpacing"
+                },
+                {
+                    "name": "textLength"
+                },
+                {
+                    "name": "lengthAdjust",
+                    "valueSet": "lengthAdjust"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/textPath"
+                }
+            ]
+        },
+        {
+            "name": "linearGradient",
+            "description": {
+                "kind": "markdown",
+                "value": "The `linearGradient` element lets authors define linear gradients to apply to other SVG elements."
+            },
+            "attributes": [
+                {
+                    "name": "x1"
+                },
+                {
+                    "name": "y1"
+                },
+                {
+                    "name": "x2"
+                },
+                {
+                    "name": "y2"
+                },
+                {
+                    "name": "gradientUnits",
+                    "description": "The coordinate system for the attributes `x1`, `x2`, `y1`, `y2`.",
+                    "valueSet": "svgUnits"
+                },
+                {
+                    "name": "gradientTransform",
+                    "description": "Additional transformation from the gradient coordinate system onto the target coordinate system."
+                },
+                {
+                    "name": "spreadMethod",
+                    "description": "How the gradient behaves if it starts or ends inside the bounds of the shape containing the gradient.",
+                    "valueSet": "spreadMethod"
+                },
+                {
+                    "name": "href",
+                    "description": "The URL of the referenced element or resource."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/linearGradient"
+                }
+            ]
+        },
+        {
+            "name": "radialGradient",
+            "description": {
+                "kind": "markdown",
+                "value": "The `radialGradient` element lets authors define radial gradients that can be applied to fill or stroke of graphical elements."
+            },
+            "attributes": [
+                {
+                    "name": "cx"
+                },
+                {
+                    "name": "cy"
+                },
+                {
+                    "name": "r"
+                },
+                {
+                    "name": "fx",
+                    "description": "The x coordinate of the focal point."
+                },
+                {
+                    "name": "fy",
+                    "description": "The y coordinate of the focal point."
+                },
+                {
+                    "name": "fr",
+                    "description": "The radius of the focal point."
+                },
+                {
+                    "name": "gradientUnits",
+                    "description": "The coordinate system for the attributes `cx`, `cy`, `r`, `fx`, `fy`, `fr`.",
+                    "valueSet": "svgUnits"
+                },
+                {
+                    "name": "gradientTransform"
+                },
+                {
+                    "name": "spreadMethod",
+                    "valueSet": "spreadMethod"
+                },
+                {
+                    "name": "href",
+                    "description": "The URL of the referenced element or resource."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/radialGradient"
+                }
+            ]
+        },
+        {
+            "name": "stop",
+            "description": {
+                "kind": "markdown",
+                "value": "The `stop` element defines a color and its position to use on a gradient. This element is always a child of a `linearGradient` or `radialGradient` element."
+            },
+            "attributes": [
+                {
+                    "name": "offset",
+                    "description": "Where the gradient stop is placed along the gradient vector."
+                },
+                {
+                    "name": "stop-color",
+                    "description": "The color of the gradient stop."
+                },
+                {
+                    "name": "stop-opacity",
+                    "description": "The opacity of the gradient stop."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/stop"
+                }
+            ]
+        },
+        {
+            "name": "pattern",
+            "description": {
+                "kind": "markdown",
+                "value": "The `pattern` element defines a graphics object which can be redrawn at repeated x- and y-coordinate intervals (\"tiled\") to cover an area."
+            },
+            "attributes": [
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "patternUnits",
+                    "description": "The coordinate system for the attributes `x`, `y`, `width` and `height`.",
+                    "valueSet": "svgUnits"
+                },
+                {
+                    "name": "patternContentUnits",
+                    "description": "The coordinate system for the contents of the pattern.",
+                    "valueSet": "svgUnits"
+                },
+                {
+                    "name": "patternTransform",
+                    "description": "Additional transformation from the pattern coordinate system onto the target coordinate system."
+                },
+                {
+                    "name": "viewBox",
+                    "description": "The position and dimension, in user space, of the SVG viewport, as `min-x min-y width height`."
+                },
+                {
+                    "name": "preserveAspectRatio",
+                    "description": "How the element must be deformed if it has a different aspect ratio than the viewport.",
+                    "valueSet": "preserveAspectRatio"
+                },
+                {
+                    "name": "href",
+                    "description": "The URL of the referenced element or resource."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/pattern"
+                }
+            ]
+        },
+        {
+            "name": "clipPath",
+            "description": {
+                "kind": "markdown",
+                "value": "The `clipPath` element defines a clipping path, to be used by the `clip-path` property."
+            },
+            "attributes": [
+                {
+                    "name": "clipPathUnits",
+                    "description": "The coordinate system for the contents of the `clipPath` element.",
+                    "valueSet": "svgUnits"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/clipPath"
+                }
+            ]
+        },
+        {
+            "name": "mask",
+            "description": {
+                "kind": "markdown",
+                "value": "The `mask` element defines an alpha mask for compositing the current object into the background. A mask is used/referenced using the `mask` property."
+            },
+            "attributes": [
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "maskUnits",
+                    "description": "The coordinate system for the attributes `x`, `y`, `width` and `height`.",
+                    "valueSet": "svgUnits"
+                },
+                {
+                    "name": "maskContentUnits",
+                    "description": "The coordinate system for the contents of the mask.",
+                    "valueSet": "svgUnits"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/mask"
+                }
+            ]
+        },
+        {
+            "name": "marker",
+            "description": {
+                "kind": "markdown",
+                "value": "The `marker` element defines a graphic used for drawing arrowheads or polymarkers on a given `path`, `line`, `polyline` or `polygon` element."
+            },
+            "attributes": [
+                {
+                    "name": "markerWidth",
+                    "description": "The width of the marker viewport."
+                },
+                {
+                    "name": "markerHeight",
+                    "description": "The height of the marker viewport."
+                },
+                {
+                    "name": "markerUnits",
+                    "description": "The coordinate system for the attributes `markerWidth`, `markerHeight` and the contents of the marker.",
+                    "valueSet": "markerUnits"
+                },
+                {
+                    "name": "orient",
+                    "description": "How the marker is rotated when it is placed at its position on the shape."
+                },
+                {
+                    "name": "refX",
+                    "description": "The x coordinate of the reference point of the marker."
+                },
+                {
+                    "name": "refY",
+                    "description": "The y coordinate of the reference point of the marker."
+                },
+                {
+                    "name": "viewBox",
+                    "description": "The position and dimension, in user space, of the SVG viewport, as `min-x min-y width height`."
+                },
+                {
+                    "name": "preserveAspectRatio",
+                    "description": "How the element must be deformed if it has a different aspect ratio than the viewport.",
+                    "valueSet": "preserveAspectRatio"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/marker"
+                }
+            ]
+        },
+        {
+            "name": "filter",
+            "description": {
+                "kind": "markdown",
+                "value": "The `filter` element defines a custom filter effect by grouping atomic filter primitives. It is never rendered itself, but must be used by the `filter` attribute on SVG elements, or the `filter` CSS property for SVG/HTML elements."
+            },
+            "attributes": [
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "filterUnits",
+                    "description": "The coordinate system for the attributes `x`, `y`, `width` and `height`.",
+                    "valueSet": "svgUnits"
+                },
+                {
+                    "name": "primitiveUnits",
+                    "description": "The coordinate system for the various length values within the filter primitives.",
+                    "valueSet": "svgUnits"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/filter"
+                }
+            ]
+        },
+        {
+            "name": "feBlend",
+            "description": {
+                "kind": "markdown",
+                "value": "The `feBlend` SVG filter primitive composes two objects together ruled by a certain blending mode."
+            },
+            "attributes": [
+                {
+                    "name": "in",
+                    "description": "The input for the filter primitive."
+                },
+                {
+                    "name": "result",
+                    "description": "The assigned name for this filter primitive, it can be referenced by `in` of the following filter primitives."
+                },
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "in2",
+                    "description": "The second input for the blending."
+                },
+                {
+                    "name": "mode",
+                    "description": "The blending mode.",
+                    "valueSet": "blendMode"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/feBlend"
+                }
+            ]
+        },
+        {
+            "name": "feColorMatrix",
+            "description": {
+                "kind": "markdown",
+                "value": "The `feColorMatrix` SVG filter element changes colors based on a transformation matrix."
+            },
+            "attributes": [
+                {
+                    "name": "in",
+                    "description": "The input for the filter primitive."
+                },
+                {
+                    "name": "result",
+                    "description": "The assigned name for this filter primitive, it can be referenced by `in` of the following filter primitives."
+                },
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "type",
+                    "description": "The type of matrix operation.",
+                    "valueSet": "colorMatrixType"
+                },
+                {
+                    "name": "values",
+                    "description": "The values for the matrix operation."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/feColorMatrix"
+                }
+            ]
+        },
+        {
+            "name": "feComposite",
+            "description": {
+                "kind": "markdown",
+                "value": "The `feComposite` SVG filter primitive performs the combination of two input images pixel-wise in image space using one of the Porter-Duff compositing operations."
+            },
+            "attributes": [
+                {
+                    "name": "in",
+                    "description": "The input for the filter primitive."
+                },
+                {
+                    "name": "result",
+                    "description": "The assigned name for this filter primitive, it can be referenced by `in` of the following filter primitives."
+                },
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "in2",
+                    "description": "The second input for the compositing."
+                },
+                {
+                    "name": "operator",
+                    "description": "The compositing operation.",
+                    "valueSet": "compositeOperator"
+                },
+                {
+                    "name": "k1"
+                },
+                {
+                    "name": "k2"
+                },
+                {
+                    "name": "k3"
+                },
+                {
+                    "name": "k4"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/feComposite"
+                }
+            ]
+        },
+        {
+            "name": "feFlood",
+            "description": {
+                "kind": "markdown",
+                "value": "The `feFlood` SVG filter primitive fills the filter subregion with the color and opacity defined by `flood-color` and `flood-opacity`."
+            },
+            "attributes": [
+                {
+                    "name": "in",
+                    "description": "The input for the filter primitive."
+                },
+                {
+                    "name": "result",
+                    "description": "The assigned name for this filter primitive, it can be referenced by `in` of the following filter primitives."
+                },
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "flood-color",
+                    "description": "The color of the flood."
+                },
+                {
+                    "name": "flood-opacity",
+                    "description": "The opacity of the flood."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/feFlood"
+                }
+            ]
+        },
+        {
+            "name": "feGaussianBlur",
+            "description": {
+                "kind": "markdown",
+                "value": "The `feGaussianBlur` SVG filter primitive blurs the input image by the amount specified in `stdDeviation`, which defines the bell-curve."
+            },
+            "attributes": [
+                {
+                    "name": "in",
+                    "description": "The input for the filter primitive."
+                },
+                {
+                    "name": "result",
+                    "description": "The assigned name for this filter primitive, it can be referenced by `in` of the following filter primitives."
+                },
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "stdDeviation",
+                    "description": "The standard deviation for the blur operation."
+                },
+                {
+                    "name": "edgeMode",
+                    "description": "How to extend the input image as necessary with color values so that the matrix operations can be applied when the kernel is positioned at or near the edge of the input image.",
+                    "valueSet": "edgeMode"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/feGaussianBlur"
+                }
+            ]
+        },
+        {
+            "name": "feMerge",
+            "description": {
+                "kind": "markdown",
+                "value": "The `feMerge` SVG element allows filter effects to be applied concurrently instead of sequentially."
+            },
+            "attributes": [
+                {
+                    "name": "in",
+                    "description": "The input for the filter primitive."
+                },
+                {
+                    "name": "result",
+                    "description": "The assigned name for this filter primitive, it can be referenced by `in` of the following filter primitives."
+                },
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/feMerge"
+                }
+            ]
+        },
+        {
+            "name": "feMergeNode",
+            "description": {
+                "kind": "markdown",
+                "value": "The `feMergeNode` takes the result of another filter to be processed by its parent `feMerge`."
+            },
+            "attributes": [
+                {
+                    "name": "in"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/feMergeNode"
+                }
+            ]
+        },
+        {
+            "name": "feOffset",
+            "description": {
+                "kind": "markdown",
+                "value": "The `feOffset` SVG filter primitive allows to offset the input image."
+            },
+            "attributes": [
+                {
+                    "name": "in",
+                    "description": "The input for the filter primitive."
+                },
+                {
+                    "name": "result",
+                    "description": "The assigned name for this filter primitive, it can be referenced by `in` of the following filter primitives."
+                },
+                {
+                    "name": "x",
+                    "description": "The x coordinate."
+                },
+                {
+                    "name": "y",
+                    "description": "The y coordinate."
+                },
+                {
+                    "name": "width",
+                    "description": "The width."
+                },
+                {
+                    "name": "height",
+                    "description": "The height."
+                },
+                {
+                    "name": "dx",
+                    "description": "The amount to offset the input graphic along the x-axis."
+                },
+                {
+                    "name": "dy",
+                    "description": "The amount to offset the input graphic along the y-axis."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/feOffset"
+                }
+            ]
+        },
+        {
+            "name": "animate",
+            "description": {
+                "kind": "markdown",
+                "value": "The `animate` SVG element provides a way to animate an attribute of an element over time."
+            },
+            "attributes": [
+                {
+                    "name": "attributeName",
+                    "description": "The name of the attribute of the target element that is going to be changed."
+                },
+                {
+                    "name": "from",
+                    "description": "The initial value of the attribute."
+                },
+                {
+                    "name": "to",
+                    "description": "The final value of the attribute."
+                },
+                {
+                    "name": "by",
+                    "description": "A relative offset value for the attribute."
+                },
+                {
+                    "name": "values",
+                    "description": "A list of values, separated by `;`, of the animation."
+                },
+                {
+                    "name": "dur",
+                    "description": "The simple duration of the animation."
+                },
+                {
+                    "name": "begin",
+                    "description": "When the animation begins."
+                },
+                {
+                    "name": "end",
+                    "description": "When the animation ends."
+                },
+                {
+                    "name": "repeatCount",
+                    "description": "The number of times the animation will take place.",
+                    "valueSet": "repeatCount"
+                },
+                {
+                    "name": "additive",
+                    "description": "Whether the animation is additive.",
+                    "valueSet": "additive"
+                },
+                {
+                    "name": "accumulate",
+                    "description": "Whether the animation is cumulative.",
+                    "valueSet": "accumulate"
+                },
+                {
+                    "name": "href",
+                    "description": "The URL of the referenced element or resource."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/animate"
+                }
+            ]
+        },
+        {
+            "name": "animateMotion",
+            "description": {
+                "kind": "markdown",
+                "value": "The `animateMotion` SVG element provides a way to define how an element moves along a motion path."
+            },
+            "attributes": [
+                {
+                    "name": "attributeName",
+                    "description": "The name of the attribute of the target element that is going to be changed."
+                },
+                {
+                    "name": "from",
+                    "description": "The initial value of the attribute."
+                },
+                {
+                    "name": "to",
+                    "description": "The final value of the attribute."
+                },
+                {
+                    "name": "by",
+                    "description": "A relative offset value for the attribute."
+                },
+                {
+                    "name": "values",
+                    "description": "A list of values, separated by `;`, of the animation."
+                },
+                {
+                    "name": "dur",
+                    "description": "The simple duration of the animation."
+                },
+                {
+                    "name": "begin",
+                    "description": "When the animation begins."
+                },
+                {
+                    "name": "end",
+                    "description": "When the animation ends."
+                },
+                {
+                    "name": "repeatCount",
+                    "description": "The number of times the animation will take place.",
+                    "valueSet": "repeatCount"
+                },
+                {
+                    "name": "additive",
+                    "description": "Whether the animation is additive.",
+                    "valueSet": "additive"
+                },
+                {
+                    "name": "accumulate",
+                    "description": "Whether the animation is cumulative.",
+                    "valueSet": "accumulate"
+                },
+                {
+                    "name": "href",
+                    "description": "The URL of the referenced element or resource."
+                },
+                {
+                    "name": "path",
+                    "description": "The motion path."
+                },
+                {
+                    "name": "keyPoints",
+                    "description": "How far along the motion path the object shall move at the moment in time specified by corresponding `keyTimes` value."
+                },
+                {
+                    "name": "rotate",
+                    "description": "How the animated element rotates as it travels along a path."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/animateMotion"
+                }
+            ]
+        },
+        {
+            "name": "animateTransform",
+            "description": {
+                "kind": "markdown",
+                "value": "The `animateTransform` element animates a transformation attribute on its target element, thereby allowing animations to control translation, scaling, rotation, and/or skewing."
+            },
+            "attributes": [
+                {
+                    "name": "attributeName",
+                    "description": "The name of the attribute of the target element that is going to be changed."
+                },
+                {
+                    "name": "from",
+                    "description": "The initial value of the attribute."
+                },
+                {
+                    "name": "to",
+                    "description": "The final value of the attribute."
+                },
+                {
+                    "name": "by",
+                    "description": "A relative offset value for the attribute."
+                },
+                {
+                    "name": "values",
+                    "description": "A list of values, separated by `;`, of the animation."
+                },
+                {
+                    "name": "dur",
+                    "description": "The simple duration of the animation."
+                },
+                {
+                    "name": "begin",
+                    "description": "When the animation begins."
+                },
+                {
+                    "name": "end",
+                    "description": "When the animation ends."
+                },
+                {
+                    "name": "repeatCount",
+                    "description": "The number of times the animation will take place.",
+                    "valueSet": "repeatCount"
+                },
+                {
+                    "name": "additive",
+                    "description": "Whether the animation is additive.",
+                    "valueSet": "additive"
+                },
+                {
+                    "name": "accumulate",
+                    "description": "Whether the animation is cumulative.",
+                    "valueSet": "accumulate"
+                },
+                {
+                    "name": "href",
+                    "description": "The URL of the referenced element or resource."
+                },
+                {
+                    "name": "type",
+                    "description": "The type of transformation.",
+                    "valueSet": "transformType"
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/animateTransform"
+                }
+            ]
+        },
+        {
+            "name": "set",
+            "description": {
+                "kind": "markdown",
+                "value": "The `set` SVG element provides a simple means of just setting the value of an attribute for a specified duration."
+            },
+            "attributes": [
+                {
+                    "name": "attributeName"
+                },
+                {
+                    "name": "to",
+                    "description": "The value to be applied to the attribute."
+                },
+                {
+                    "name": "begin"
+                },
+                {
+                    "name": "dur"
+                },
+                {
+                    "name": "end"
+                },
+                {
+                    "name": "href",
+                    "description": "The URL of the referenced element or resource."
+                }
+            ],
+            "references": [
+                {
+                    "name": "MDN Reference",
+                    "url": "https://developer.mozilla.org/docs/Web/SVG/Element/set"
+                }
+            ]
+        }
+    ],
+    "valueSets": [
+        {
+            "name": "preserveAspectRatio",
+            "values": [
+                {
+                    "name": "none"
+                },
+                {
+                    "name": "xMinYMin"
+                },
+                {
+                    "name": "xMidYMin"
+                },
+                {
+                    "name": "xMaxYMin"
+                },
+                {
+                    "name": "xMinYMid"
+                },
+                {
+                    "name": "xMidYMid"
+                },
+                {
+                    "name": "xMaxYMid"
+                },
+                {
+                    "name": "xMinYMax"
+                },
+                {
+                    "name": "xMidYMax"
+                },
+                {
+                    "name": "xMaxYMax"
+                },
+                {
+                    "name": "xMidYMid meet"
+                },
+                {
+                    "name": "xMidYMid slice"
+                }
+            ]
+        },
+        {
+            "name": "svgUnits",
+            "values": [
+                {
+                    "name": "userSpaceOnUse"
+                },
+                {
+                    "name": "objectBoundingBox"
+                }
+            ]
+        },
+        {
+            "name": "markerUnits",
+            "values": [
+                {
+                    "name": "strokeWidth"
+                },
+                {
+                    "name": "userSpaceOnUse"
+                }
+            ]
+        },
+        {
+            "name": "spreadMethod",
+            "values": [
+                {
+                    "name": "pad"
+                },
+                {
+                    "name": "reflect"
+                },
+                {
+                    "name": "repeat"
+                }
+            ]
+        },
+        {
+            "name": "lengthAdjust",
+            "values": [
+                {
+                    "name": "spacing"
+                },
+                {
+                    "name": "spacingAndGlyphs"
+                }
+            ]
+        },
+        {
+            "name": "textPathMethod",
+            "values": [
+                {
+                    "name": "align"
+                },
+                {
+                    "name": "stretch"
+                }
+            ]
+        },
+        {
+            "name": "textPathSide",
+            "values": [
+                {
+                    "name": "left"
+                },
+                {
+                    "name": "right"
+                }
+            ]
+        },
+        {
+            "name": "textPathSpacing",
+            "values": [
+                {
+                    "name": "auto"
+                },
+                {
+                    "name": "exact"
+                }
+            ]
+        },
+        {
+            "name": "blendMode",
+            "values": [
+                {
+                    "name": "normal"
+                },
+                {
+                    "name": "multiply"
+                },
+                {
+                    "name": "screen"
+                },
+                {
+                    "name": "overlay"
+                },
+                {
+                    "name": "darken"
+                },
+                {
+                    "name": "lighten"
+                },
+                {
+                    "name": "color-dodge"
+                },
+                {
+                    "name": "color-burn"
+                },
+                {
+                    "name": "hard-light"
+                },
+                {
+                    "name": "soft-light"
+                },
+                {
+                    "name": "difference"
+                },
+                {
+                    "name": "exclusion"
+                },
+                {
+                    "name": "hue"
+                },
+                {
+                    "name": "saturation"
+                },
+                {
+                    "name": "color"
+                },
+                {
+                    "name": "luminosity"
+                }
+            ]
+        },
+        {
+            "name": "colorMatrixType",
+            "values": [
+                {
+                    "name": "matrix"
+                },
+                {
+                    "name": "saturate"
+                },
+                {
+                    "name": "hueRotate"
+                },
+                {
+                    "name": "luminanceToAlpha"
+                }
+            ]
+        },
+        {
+            "name": "compositeOperator",
+            "values": [
+                {
+                    "name": "over"
+                },
+                {
+                    "name": "in"
+                },
+                {
+                    "name": "out"
+                },
+                {
+                    "name": "atop"
+                },
+                {
+                    "name": "xor"
+                },
+                {
+                    "name": "lighter"
+                },
+                {
+                    "name": "arithmetic"
+                }
+            ]
+        },
+        {
+            "name": "edgeMode",
+            "values": [
+                {
+                    "name": "duplicate"
+                },
+                {
+                    "name": "wrap"
+                },
+                {
+                    "name": "none"
+                }
+            ]
+        },
+        {
+            "name": "repeatCount",
+            "values": [
+                {
+                    "name": "indefinite"
+                }
+            ]
+        },
+        {
+            "name": "additive",
+            "values": [
+                {
+                    "name": "replace"
+                },
+                {
+                    "name": "sum"
+                }
+            ]
+        },
+        {
+            "name": "accumulate",
+            "values": [
+                {
+                    "name": "none"
+                },
+                {
+                    "name": "sum"
+                }
+            ]
+        },
+        {
+            "name": "transformType",
+            "values": [
+                {
+                    "name": "translate"
+                },
+                {
+                    "name": "scale"
+                },
+                {
+                    "name": "rotate"
+                },
+                {
+                    "name": "skewX"
+                },
+                {
+                    "name": "skewY"
+                }
+            ]
+        },
+        {
+            "name": "crossorigin",
+            "values": [
+                {
+                    "name": "anonymous"
+                },
+                {
+                    "name": "use-credentials"
+                }
+            ]
+        },
+        {
+            "name": "decoding",
+            "values": [
+                {
+                    "name": "sync"
+                },
+                {
+                    "name": "async"
+                },
+                {
+                    "name": "auto"
+                }
+            ]
+        },
+        {
+            "name": "fillRule",
+            "values": [
+                {
+                    "name": "nonzero"
+                },
+                {
+                    "name": "evenodd"
+                }
+            ]
+        },
+        {
+            "name": "strokeLinecap",
+            "values": [
+                {
+                    "name": "butt"
+                },
+                {
+                    "name": "round"
+                },
+                {
+                    "name": "square"
+                }
+            ]
+        },
+        {
+            "name": "strokeLinejoin",
+            "values": [
+                {
+                    "name": "arcs"
+                },
+                {
+                    "name": "bevel"
+                },
+                {
+                    "name": "miter"
+                },
+                {
+                    "name": "miter-clip"
+                },
+                {
+                    "name": "round"
+                }
+            ]
+        },
+        {
+            "name": "visibility",
+            "values": [
+                {
+                    "name": "visible"
+                },
+                {
+                    "name": "hidden"
+                },
+                {
+                    "name": "collapse"
+                }
+            ]
+        },
+        {
+            "name": "textAnchor",
+            "values": [
+                {
+                    "name": "start"
+                },
+                {
+                    "name": "middle"
+                },
+                {
+                    "name": "end"
+                }
+            ]
+        },
+        {
+            "name": "dominantBaseline",
+            "values": [
+                {
+                    "name": "auto"
+                },
+                {
+                    "name": "text-bottom"
+                },
+                {
+                    "name": "alphabetic"
+                },
+                {
+                    "name": "ideographic"
+                },
+                {
+                    "name": "middle"
+                },
+                {
+                    "name": "central"
+                },
+                {
+                    "name": "mathematical"
+                },
+                {
+                    "name": "hanging"
+                },
+                {
+                    "name": "text-top"
+                }
+            ]
+        },
+        {
+            "name": "pointerEvents",
+            "values": [
+                {
+                    "name": "bounding-box"
+                },
+                {
+                    "name": "visiblePainted"
+                },
+                {
+                    "name": "visibleFill"
+                },
+                {
+                    "name": "visibleStroke"
+                },
+                {
+                    "name": "visible"
+                },
+                {
+                    "name": "painted"
+                },
+                {
+                    "name": "fill"
+                },
+                {
+                    "name": "stroke"
+                },
+                {
+                    "name": "all"
+                },
+                {
+                    "name": "none"
+                }
+            ]
+        },
+        {
+            "name": "shapeRendering",
+            "values": [
+                {
+                    "name": "auto"
+                },
+                {
+                    "name": "optimizeSpeed"
+                },
+                {
+                    "name": "crispEdges"
+                },
+                {
+                    "name": "geometricPrecision"
+                }
+            ]
+        },
+        {
+            "name": "vectorEffect",
+            "values": [
+                {
+                    "name": "none"
+                },
+                {
+                    "name": "non-scaling-stroke"
+                },
+                {
+                    "name": "non-scaling-size"
+                },
+                {
+                    "name": "non-rotation"
+                },
+                {
+                    "name": "fixed-position"
+                }
+            ]
+        }
+    ]
+}"##;
+
+/// The presentation attributes shared by all SVG elements
+pub static SVG_PRESENTATION_ATTRIBUTES: &str = r##"[
+    {
+        "name": "fill",
+        "description": "The color (or any SVG paint servers like gradients or patterns) used to paint the element."
+    },
+    {
+        "name": "fill-opacity",
+        "description": "The opacity of the paint server (color, gradient, pattern, etc.) applied to a shape."
+    },
+    {
+        "name": "fill-rule",
+        "description": "The algorithm to use to determine the inside part of a shape.",
+        "valueSet": "fillRule"
+    },
+    {
+        "name": "stroke",
+        "description": "The color (or any SVG paint servers like gradients or patterns) used to paint the outline of the shape."
+    },
+    {
+        "name": "stroke-width",
+        "description": "The width of the stroke to be applied to the shape."
+    },
+    {
+        "name": "stroke-opacity",
+        "description": "The opacity of the paint server (color, gradient, pattern, etc.) applied to the stroke of a shape."
+    },
+    {
+        "name": "stroke-linecap",
+        "description": "The shape to be used at the end of open subpaths when they are stroked.",
+        "valueSet": "strokeLinecap"
+    },
+    {
+        "name": "stroke-linejoin",
+        "description": "The shape to be used at the corners of paths when they are stroked.",
+        "valueSet": "strokeLinejoin"
+    },
+    {
+        "name": "stroke-miterlimit",
+        "description": "A limit on the ratio of the miter length to the `stroke-width` used to draw a miter join."
+    },
+    {
+        "name": "stroke-dasharray",
+        "description": "The pattern of dashes and gaps used to paint the outline of the shape."
+    },
+    {
+        "name": "stroke-dashoffset",
+        "description": "An offset on the rendering of the associated dash array."
+    },
+    {
+        "name": "opacity",
+        "description": "The transparency of an object or of a group of objects."
+    },
+    {
+        "name": "transform",
+        "description": "A list of transform definitions that are applied to an element and the element's children."
+    },
+    {
+        "name": "clip-path",
+        "description": "Binds the element it is applied to with a given `clipPath` element."
+    },
+    {
+        "name": "clip-rule",
+        "description": "Which algorithm to use to determine the inside part of a shape for the clipping path.",
+        "valueSet": "fillRule"
+    },
+    {
+        "name": "mask",
+        "description": "Binds the element it is applied to with a given `mask` element."
+    },
+    {
+        "name": "filter",
+        "description": "The filter effects defined by the `filter` element that shall be applied to its element."
+    },
+    {
+        "name": "color",
+        "description": "Provides a potential indirect value, `currentcolor`, for the `fill`, `stroke`, `stop-color`, `flood-color` and `lighting-color` attributes."
+    },
+    {
+        "name": "display",
+        "description": "Controls the rendering of graphical or container elements."
+    },
+    {
+        "name": "visibility",
+        "description": "The visibility of graphical elements.",
+        "valueSet": "visibility"
+    },
+    {
+        "name": "font-family",
+        "description": "Which font family will be used to render the text."
+    },
+    {
+        "name": "font-size",
+        "description": "The size of the font from baseline to baseline when multiple lines of text are set solid in a multiline layout environment."
+    },
+    {
+        "name": "font-weight",
+        "description": "The weight (or boldness) of the font."
+    },
+    {
+        "name": "text-anchor",
+        "description": "Aligns a string of pre-formatted text or auto-wrapped text where the wrapping area is determined from the `inline-size` property relative to a given point.",
+        "valueSet": "textAnchor"
+    },
+    {
+        "name": "dominant-baseline",
+        "description": "The specific baseline used to align the box's text and inline-level contents.",
+        "valueSet": "dominantBaseline"
+    },
+    {
+        "name": "pointer-events",
+        "description": "Whether or when an element may be the target of a mouse event.",
+        "valueSet": "pointerEvents"
+    },
+    {
+        "name": "shape-rendering",
+        "description": "Hints about what tradeoffs to make as the browser renders `path` element or basic shapes.",
+        "valueSet": "shapeRendering"
+    },
+    {
+        "name": "vector-effect",
+        "description": "The vector effect to use when drawing an object.",
+        "valueSet": "vectorEffect"
+    }
+]"##;
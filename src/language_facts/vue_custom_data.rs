@@ -0,0 +1,538 @@
+// Vue built-in directives, special attributes and components, descriptions are from the Vue API reference
+pub static VUE_DATA: &str = r##"{
+    "version": 1.1,
+    "tags": [
+        {
+            "name": "transition",
+            "description": {
+                "kind": "markdown",
+                "value": "Provides animated transition effects to a **single** element or component."
+            },
+            "attributes": [
+                {
+                    "name": "name",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Used to automatically generate transition CSS class names, e.g. `name: 'fade'` will auto expand to `.fade-enter`, `.fade-enter-active`, etc."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#transition"
+                        }
+                    ]
+                },
+                {
+                    "name": "appear",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Whether to apply transition on initial render."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#transition"
+                        }
+                    ],
+                    "valueSet": "v"
+                },
+                {
+                    "name": "css",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Whether to apply CSS transition classes."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#transition"
+                        }
+                    ]
+                },
+                {
+                    "name": "type",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The type of transition events to wait for to determine transition end timing."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#transition"
+                        }
+                    ],
+                    "values": [
+                        {
+                            "name": "transition"
+                        },
+                        {
+                            "name": "animation"
+                        }
+                    ]
+                },
+                {
+                    "name": "mode",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Controls the timing sequence of leaving/entering transitions."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#transition"
+                        }
+                    ],
+                    "values": [
+                        {
+                            "name": "in-out"
+                        },
+                        {
+                            "name": "out-in"
+                        },
+                        {
+                            "name": "default"
+                        }
+                    ]
+                },
+                {
+                    "name": "duration",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The explicit durations of the transition."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#transition"
+                        }
+                    ]
+                }
+            ],
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-components.html#transition"
+                }
+            ]
+        },
+        {
+            "name": "transition-group",
+            "description": {
+                "kind": "markdown",
+                "value": "Provides transition effects for **multiple** elements or components in a list."
+            },
+            "attributes": [
+                {
+                    "name": "tag",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The tag to render, if not defined, it renders no wrapper element."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#transitiongroup"
+                        }
+                    ]
+                },
+                {
+                    "name": "move-class",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The class applied during moving transition."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#transitiongroup"
+                        }
+                    ]
+                }
+            ],
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-components.html#transitiongroup"
+                }
+            ]
+        },
+        {
+            "name": "keep-alive",
+            "description": {
+                "kind": "markdown",
+                "value": "Caches dynamically toggled components wrapped inside."
+            },
+            "attributes": [
+                {
+                    "name": "include",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Only components with matching names will be cached."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#keepalive"
+                        }
+                    ]
+                },
+                {
+                    "name": "exclude",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "Any component with a matching name will not be cached."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#keepalive"
+                        }
+                    ]
+                },
+                {
+                    "name": "max",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The maximum number of component instances to cache."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#keepalive"
+                        }
+                    ]
+                }
+            ],
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-components.html#keepalive"
+                }
+            ]
+        },
+        {
+            "name": "teleport",
+            "description": {
+                "kind": "markdown",
+                "value": "Renders its slot content to another part of the DOM."
+            },
+            "attributes": [
+                {
+                    "name": "to",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The target container, can be either a selector or an actual element."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#teleport"
+                        }
+                    ]
+                },
+                {
+                    "name": "disabled",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "When `true`, the content will remain in its original location instead of moved into the target container."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#teleport"
+                        }
+                    ],
+                    "valueSet": "v"
+                }
+            ],
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-components.html#teleport"
+                }
+            ]
+        },
+        {
+            "name": "suspense",
+            "description": {
+                "kind": "markdown",
+                "value": "Used for orchestrating nested async dependencies in a component tree."
+            },
+            "attributes": [
+                {
+                    "name": "timeout",
+                    "description": {
+                        "kind": "markdown",
+                        "value": "The time to wait before showing the fallback content."
+                    },
+                    "references": [
+                        {
+                            "name": "Vue Reference",
+                            "url": "https://vuejs.org/api/built-in-components.html#suspense"
+                        }
+                    ]
+                }
+            ],
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-components.html#suspense"
+                }
+            ]
+        },
+        {
+            "name": "component",
+            "description": {
+                "kind": "markdown",
+                "value": "A \"meta component\" for rendering dynamic components or elements. The actual component to render is determined by the `is` prop."
+            },
+            "attributes": [],
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-components.html#component"
+                }
+            ]
+        }
+    ],
+    "globalAttributes": [
+        {
+            "name": "v-if",
+            "description": {
+                "kind": "markdown",
+                "value": "Conditionally render the element or the template fragment based on the truthy-ness of the expression value."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-if"
+                }
+            ]
+        },
+        {
+            "name": "v-else-if",
+            "description": {
+                "kind": "markdown",
+                "value": "Denote the \"else if block\" for `v-if`. Can be chained."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-else-if"
+                }
+            ]
+        },
+        {
+            "name": "v-else",
+            "description": {
+                "kind": "markdown",
+                "value": "Denote the \"else block\" for `v-if` or a `v-if` / `v-else-if` chain."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-else"
+                }
+            ],
+            "valueSet": "v"
+        },
+        {
+            "name": "v-for",
+            "description": {
+                "kind": "markdown",
+                "value": "Render the element or template block multiple times based on the source data, such as `item in items`."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-for"
+                }
+            ]
+        },
+        {
+            "name": "v-show",
+            "description": {
+                "kind": "markdown",
+                "value": "Toggle the element's visibility based on the truthy-ness of the expression value."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-show"
+                }
+            ]
+        },
+        {
+            "name": "v-model",
+            "description": {
+                "kind": "markdown",
+                "value": "Create a two-way binding on a form input element or a component."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-model"
+                }
+            ]
+        },
+        {
+            "name": "v-bind",
+            "description": {
+                "kind": "markdown",
+                "value": "Dynamically bind one or more attributes, or a component prop to an expression. Shorthand: `:`"
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-bind"
+                }
+            ]
+        },
+        {
+            "name": "v-on",
+            "description": {
+                "kind": "markdown",
+                "value": "Attach an event listener to the element. Shorthand: `@`"
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-on"
+                }
+            ]
+        },
+        {
+            "name": "v-slot",
+            "description": {
+                "kind": "markdown",
+                "value": "Denote named slots or scoped slots that expect to receive props. Shorthand: `#`"
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-slot"
+                }
+            ]
+        },
+        {
+            "name": "v-html",
+            "description": {
+                "kind": "markdown",
+                "value": "Update the element's `innerHTML`."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-html"
+                }
+            ]
+        },
+        {
+            "name": "v-text",
+            "description": {
+                "kind": "markdown",
+                "value": "Update the element's text content."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-text"
+                }
+            ]
+        },
+        {
+            "name": "v-once",
+            "description": {
+                "kind": "markdown",
+                "value": "Render the element and component once only, and skip future updates."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-once"
+                }
+            ],
+            "valueSet": "v"
+        },
+        {
+            "name": "v-pre",
+            "description": {
+                "kind": "markdown",
+                "value": "Skip compilation for this element and all its children."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-pre"
+                }
+            ],
+            "valueSet": "v"
+        },
+        {
+            "name": "v-cloak",
+            "description": {
+                "kind": "markdown",
+                "value": "Used to hide un-compiled template until it is ready."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-cloak"
+                }
+            ],
+            "valueSet": "v"
+        },
+        {
+            "name": "v-memo",
+            "description": {
+                "kind": "markdown",
+                "value": "Memoize a sub-tree of the template, it expects a fixed-length array of dependency values."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-directives.html#v-memo"
+                }
+            ]
+        },
+        {
+            "name": "key",
+            "description": {
+                "kind": "markdown",
+                "value": "The `key` special attribute is primarily used as a hint for Vue's virtual DOM algorithm to identify vnodes when diffing the new list of nodes against the old list."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-special-attributes.html#key"
+                }
+            ]
+        },
+        {
+            "name": "ref",
+            "description": {
+                "kind": "markdown",
+                "value": "Denotes a template ref."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-special-attributes.html#ref"
+                }
+            ]
+        },
+        {
+            "name": "is",
+            "description": {
+                "kind": "markdown",
+                "value": "Used for binding dynamic components."
+            },
+            "references": [
+                {
+                    "name": "Vue Reference",
+                    "url": "https://vuejs.org/api/built-in-special-attributes.html#is"
+                }
+            ]
+        }
+    ]
+}"##;
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use crate::html_data::{HTMLDataV1, IAttributeData, ITagData, IValueData};
+
+use super::{
+    data_provider::{HTMLDataProvider, IHTMLDataProvider},
+    vue_custom_data::VUE_DATA,
+};
+
+/// Data provider of Vue templates, it's only applicable to the `vue` language id
+///
+/// It provides the built-in directives and components of Vue,
+/// and the `:prop` and `@event` shorthands of the attributes in `html_data`.
+pub struct VueDataProvider {
+    provider: HTMLDataProvider,
+    bindings: HashMap<String, Vec<IAttributeData>>,
+    global_bindings: Vec<IAttributeData>,
+}
+
+impl VueDataProvider {
+    pub fn new(html_data: &HTMLDataV1) -> VueDataProvider {
+        let data = serde_json::from_str(VUE_DATA).unwrap();
+        let mut bindings = HashMap::new();
+        if let Some(tags) = &html_data.tags {
+            for tag in tags {
+                bindings.insert(tag.name.to_lowercase(), to_bindings(&tag.attributes));
+            }
+        }
+        VueDataProvider {
+            provider: HTMLDataProvider::new("vue".to_string(), data),
+            bindings,
+            global_bindings: html_data
+                .global_attributes
+                .as_deref()
+                .map(to_bindings)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl IHTMLDataProvider for VueDataProvider {
+    fn get_id(&self) -> &str {
+        self.provider.get_id()
+    }
+
+    fn is_applicable(&self, language_id: &str) -> bool {
+        language_id == "vue"
+    }
+
+    fn provide_tags(&self) -> &Vec<ITagData> {
+        self.provider.provide_tags()
+    }
+
+    fn provide_attributes(&self, tag: &str) -> Vec<&IAttributeData> {
+        let mut attributes = self.provider.provide_attributes(tag);
+        if let Some(bindings) = self.bindings.get(&tag.to_lowercase()) {
+            attributes.extend(bindings);
+        }
+        attributes.extend(&self.global_bindings);
+        attributes
+    }
+
+    fn provide_values(&self, tag: &str, attribute: &str) -> Vec<&IValueData> {
+        self.provider.provide_values(tag, attribute)
+    }
+
+    fn provide_global_attributes(&self) -> Vec<&IAttributeData> {
+        let mut attributes = self.provider.provide_global_attributes();
+        attributes.extend(&self.global_bindings);
+        attributes
+    }
+
+    fn get_value_set(&self, name: &str) -> Option<&Vec<IValueData>> {
+        self.provider.get_value_set(name)
+    }
+}
+
+/// Event handler attributes such as `onclick` become `@click`, and the others become `:name`
+fn to_bindings(attributes: &[IAttributeData]) -> Vec<IAttributeData> {
+    attributes
+        .iter()
+        .map(|attribute| {
+            let name = if let Some(event) = attribute.name.strip_prefix("on") {
+                format!("@{}", event)
+            } else {
+                format!(":{}", attribute.name)
+            };
+            IAttributeData {
+                name,
+                description: attribute.description.clone(),
+                value_set: None,
+                values: None,
+                references: attribute.references.clone(),
+                browsers: None,
+                status: None,
+                deprecated: attribute.deprecated,
+            }
+        })
+        .collect()
+}
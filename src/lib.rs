@@ -25,31 +25,52 @@
 
 #[cfg(feature = "formatter")]
 mod beautify;
+mod cancellation;
+mod document_store;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod file_system;
 pub mod html_data;
 mod html_language_service;
 mod html_language_types;
 pub mod language_facts;
 pub mod parser;
 pub mod participant;
+#[cfg(feature = "server")]
+pub mod server;
 mod services;
 mod utils;
 
 pub use language_facts::data_manager::HTMLDataManager;
-pub use parser::html_parse::parse_html_document;
+pub use parser::html_parse::{parse_html_document, ParserLimits};
+pub use smol_str::SmolStr;
 
 #[cfg(feature = "completion")]
 pub use services::html_completion::{CompletionConfiguration, Quotes};
 
 #[cfg(feature = "folding")]
-pub use services::html_folding::FoldingRangeContext;
+pub use services::html_folding::{FoldingRangeContext, FoldingRegionMarkers};
 
 #[cfg(feature = "formatter")]
-pub use services::html_formatter::HTMLFormatConfiguration;
+pub use services::html_formatter::{
+    HTMLFormatConfiguration, HtmlAttributeQuotes, HtmlEndOfLine, HtmlVoidElementStyle,
+    HtmlWrapAttributes,
+};
 #[cfg(feature = "hover")]
 pub use services::html_hover::HoverSettings;
+#[cfg(feature = "linked_editing")]
+pub use services::html_linked_editing::LinkedEditingConfiguration;
+#[cfg(feature = "symbols")]
+pub use services::html_symbols::{SymbolsConfiguration, WorkspaceDocuments};
 
+pub use cancellation::CancellationToken;
+pub use document_store::DocumentStore;
+#[cfg(feature = "fs_provider")]
+pub use file_system::LocalFileSystemProvider;
+pub use file_system::MemoryFileSystemProvider;
 pub use html_language_service::HTMLLanguageService;
+pub use html_language_service::HTMLLanguageServiceBuilder;
 pub use html_language_types::{
     DefaultDocumentContext, DocumentContext, FileStat, FileSystemProvider, FileType,
-    HTMLLanguageServiceOptions,
+    HTMLLanguageServiceOptions, WorkspaceDocumentContext,
 };
@@ -1,11 +1,23 @@
-use std::collections::HashMap;
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    collections::HashMap,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, Range},
+    sync::{Arc, OnceLock},
+};
 
-use super::html_scanner::TokenType;
+use serde::{Serialize, Serializer};
+use smol_str::SmolStr;
+
+use super::{html_index::HTMLDocumentIndex, html_scanner::TokenType};
+use crate::utils::strings;
 
 #[derive(Debug, Clone)]
 pub struct Node {
-    /// It's None only when new
-    pub tag: Option<String>,
+    /// It's None only when new, the short names such as `div` are stored inline without allocating
+    pub tag: Option<SmolStr>,
     pub start: usize,
     pub end: usize,
     pub children: Vec<Node>,
@@ -15,23 +27,138 @@ pub struct Node {
     pub start_tag_end: Option<usize>,
     /// It's None only when it's self-closing tag or it miss part of end tag, it equals start of end tag
     pub end_tag_start: Option<usize>,
-    pub attributes: HashMap<String, NodeAttribute>,
+    pub attributes: HashMap<SmolStr, NodeAttribute>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct NodeAttribute {
     /// include quote
-    pub value: Option<String>,
+    pub value: Option<SourceStr>,
     /// start offset of attribute name
     pub offset: usize,
 }
 
 impl NodeAttribute {
-    pub fn new(value: Option<String>, offset: usize) -> NodeAttribute {
+    pub fn new(value: Option<SourceStr>, offset: usize) -> NodeAttribute {
         NodeAttribute { value, offset }
     }
 }
 
+/// A span of the source text, it shares the text of the document instead of copying the span
+///
+/// It dereferences to `str`, and it compares and hashes as the text of the span.
+#[derive(Clone)]
+pub struct SourceStr {
+    source: Arc<str>,
+    start: usize,
+    end: usize,
+}
+
+impl SourceStr {
+    /// `range` must be on the char boundaries of `source`
+    pub fn new(source: Arc<str>, range: Range<usize>) -> SourceStr {
+        assert!(source.is_char_boundary(range.start) && source.is_char_boundary(range.end));
+        assert!(range.start <= range.end && range.end <= source.len());
+        SourceStr {
+            source,
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    /// The byte range of the span in the source text
+    pub fn span(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source[self.start..self.end]
+    }
+}
+
+impl Deref for SourceStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SourceStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for SourceStr {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for SourceStr {
+    fn from(value: &str) -> Self {
+        let len = value.len();
+        SourceStr::new(value.into(), 0..len)
+    }
+}
+
+impl From<String> for SourceStr {
+    fn from(value: String) -> Self {
+        SourceStr::from(value.as_str())
+    }
+}
+
+impl PartialEq for SourceStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SourceStr {}
+
+impl PartialEq<str> for SourceStr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SourceStr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for SourceStr {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl Hash for SourceStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl fmt::Debug for SourceStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for SourceStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Serialize for SourceStr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 impl Node {
     pub fn new(start: usize, end: usize, children: Vec<Node>) -> Node {
         Node {
@@ -46,11 +173,24 @@ impl Node {
         }
     }
 
-    pub fn attribute_names(&self) -> Vec<&String> {
+    pub fn attribute_names(&self) -> Vec<&SmolStr> {
         self.attributes.keys().collect()
     }
 
-    pub fn attribute_names_by_order(&self) -> Vec<&String> {
+    /// The class names in the `class` attribute of the node
+    pub fn get_class_names(&self) -> Vec<&str> {
+        self.attributes
+            .get("class")
+            .and_then(|attr| attr.value.as_deref())
+            .map_or(vec![], |value| {
+                value
+                    .trim_matches(|c| c == '"' || c == '\'')
+                    .split_ascii_whitespace()
+                    .collect()
+            })
+    }
+
+    pub fn attribute_names_by_order(&self) -> Vec<&SmolStr> {
         let mut attributes = self.attribute_names();
         attributes.sort_by(|a, b| {
             let a = self.attributes.get(*a).unwrap().offset;
@@ -70,7 +210,7 @@ impl Node {
         } else {
             let tag: &str = &self.tag.as_ref().unwrap();
             tag_in_lowercase.is_some_and(|tag_in_lowercase| {
-                tag.len() == tag_in_lowercase.len() && tag.to_lowercase() == tag_in_lowercase
+                tag.len() == tag_in_lowercase.len() && strings::eq_lowercase(tag, tag_in_lowercase)
             })
         }
     }
@@ -88,31 +228,32 @@ impl Node {
         offset: usize,
         parent_list: &mut Vec<&'a Node>,
     ) -> &'a Node {
-        let mut idx = node.children.len();
-        for (i, child) in node.children.iter().enumerate() {
-            if offset <= child.start {
-                idx = i;
-                break;
+        let mut node = node;
+        loop {
+            let mut idx = node.children.len();
+            for (i, child) in node.children.iter().enumerate() {
+                if offset <= child.start {
+                    idx = i;
+                    break;
+                }
+            }
+            if idx == 0 {
+                return node;
             }
-        }
-        if idx > 0 {
             let child = &node.children[idx - 1];
-            if offset > child.start {
-                if offset < child.end {
-                    parent_list.push(&node);
-                    return Node::find_node_before(child, offset, parent_list);
-                }
-                if let Some(last_child) = child.last_child() {
-                    if last_child.end == child.end {
-                        parent_list.push(&node);
-                        return Node::find_node_before(child, offset, parent_list);
-                    }
-                }
-                parent_list.push(&node);
+            if offset <= child.start {
+                return node;
+            }
+            parent_list.push(node);
+            let descend = offset < child.end
+                || child
+                    .last_child()
+                    .is_some_and(|last_child| last_child.end == child.end);
+            if !descend {
                 return child;
             }
+            node = child;
         }
-        node
     }
 
     pub fn find_node_at<'a>(
@@ -120,22 +261,25 @@ impl Node {
         offset: usize,
         parent_list: &mut Vec<&'a Node>,
     ) -> &'a Node {
-        let mut idx = node.children.len();
-        for (i, child) in node.children.iter().enumerate() {
-            if offset < child.start {
-                idx = i;
-                break;
+        let mut node = node;
+        loop {
+            let mut idx = node.children.len();
+            for (i, child) in node.children.iter().enumerate() {
+                if offset < child.start {
+                    idx = i;
+                    break;
+                }
+            }
+            if idx == 0 {
+                return node;
             }
-        }
-
-        if idx > 0 {
             let child = &node.children[idx - 1];
-            if offset >= child.start && offset < child.end {
-                parent_list.push(&node);
-                return Node::find_node_at(child, offset, parent_list);
+            if offset < child.start || offset >= child.end {
+                return node;
             }
+            parent_list.push(node);
+            node = child;
         }
-        node
     }
 
     /// Find TokenType in node at offset
@@ -200,22 +344,45 @@ impl Node {
 ///
 /// let mut parent_list = vec![];
 /// let node = html_document.find_node_at(9, &mut parent_list);
-/// assert_eq!(node.unwrap().tag, Some("h1".to_string()));
+/// assert_eq!(node.unwrap().tag.as_deref(), Some("h1"));
 ///
 /// let parent = parent_list.pop();
-/// assert_eq!(parent.unwrap().tag, Some("div".to_string()));
+/// assert_eq!(parent.unwrap().tag.as_deref(), Some("div"));
 ///
 /// let parent = parent_list.pop();
 /// assert!(parent.is_none());
 /// ```
 ///
 /// If 'parent' is 'None', then its parent node is HTMLDocument.
+///
+/// The roots are shared behind an `Arc`, so cloning an `HTMLDocument` is cheap
+/// and doesn't copy the nodes.
 #[derive(Clone)]
 pub struct HTMLDocument {
-    pub roots: Vec<Node>,
+    pub roots: Arc<Vec<Node>>,
+    /// Whether the tree is truncated by the `ParserLimits` of the data manager
+    pub truncated: bool,
+    index: Arc<OnceLock<HTMLDocumentIndex>>,
 }
 
 impl HTMLDocument {
+    pub fn new(roots: Vec<Node>) -> HTMLDocument {
+        HTMLDocument {
+            roots: Arc::new(roots),
+            truncated: false,
+            index: Arc::default(),
+        }
+    }
+
+    /// The index of the ids and the class names of the document
+    ///
+    /// It's built on the first call and shared by the clones of the document,
+    /// so the services don't scan the document again to find them.
+    pub fn index(&self) -> &HTMLDocumentIndex {
+        self.index
+            .get_or_init(|| HTMLDocumentIndex::new(&self.roots))
+    }
+
     /// Find the node before the node where the given 'offset' is located
     ///
     /// `parent_list` is a list of parent nodes and the previous node is the parent node of the latter node.
@@ -275,8 +442,65 @@ impl HTMLDocument {
         None
     }
 
+    /// Collect the `data-*` attributes used in the document,
+    /// and the offsets of the attribute names in document order
+    pub fn get_data_attributes(&self) -> HashMap<String, Vec<usize>> {
+        let mut data_attributes: HashMap<String, Vec<usize>> = HashMap::new();
+        self.for_each_node(&mut |node| {
+            for (name, attr) in &node.attributes {
+                if name.starts_with("data-") {
+                    data_attributes
+                        .entry(name.to_string())
+                        .or_default()
+                        .push(attr.offset);
+                }
+            }
+        });
+        for offsets in data_attributes.values_mut() {
+            offsets.sort();
+        }
+        data_attributes
+    }
+
+    /// Collect the class names used in the document, without duplicates and in document order
+    pub fn get_class_names(&self) -> Vec<String> {
+        self.index()
+            .class_names()
+            .into_iter()
+            .map(|class_name| class_name.to_string())
+            .collect()
+    }
+
+    /// The quote that most of the quoted attribute values use, it's `None` if there is no majority
+    pub fn get_dominant_quote(&self) -> Option<char> {
+        let (mut single, mut double) = (0, 0);
+        self.for_each_node(&mut |node| {
+            for attr in node.attributes.values() {
+                match attr.value.as_deref().and_then(|value| value.chars().next()) {
+                    Some('\'') => single += 1,
+                    Some('"') => double += 1,
+                    _ => {}
+                }
+            }
+        });
+        match single.cmp(&double) {
+            Ordering::Greater => Some('\''),
+            Ordering::Less => Some('"'),
+            Ordering::Equal => None,
+        }
+    }
+
+    /// Visit the nodes of the document in document order
+    fn for_each_node<'a>(&'a self, f: &mut impl FnMut(&'a Node)) {
+        let mut stack: Vec<&Node> = self.roots.iter().rev().collect();
+        while let Some(node) = stack.pop() {
+            f(node);
+            stack.extend(node.children.iter().rev());
+        }
+    }
+
     pub fn find_root_at(&self, offset: usize) -> Option<&Node> {
-        for root in &self.roots {
+        for root in self.roots.iter() {
             if offset <= root.end {
                 return Some(root);
             }
@@ -0,0 +1,416 @@
+use lsp_textdocument::FullTextDocument;
+use lsp_types::{Position, PositionEncodingKind, Range};
+
+use super::html_scanner::{Scanner, ScannerState, TokenType};
+use crate::utils::position;
+
+/// A region of the document in another language, such as the content of `<script>` and `<style>`,
+/// and the values of the `style` and event handler attributes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedRegion {
+    /// `css`, or the language of the `type` of `<script>` such as `javascript` and `json`,
+    /// see `get_script_language`
+    pub language_id: String,
+    /// The byte offset of the start of the content, the quotes of the attribute values are excluded
+    pub start: usize,
+    pub end: usize,
+    /// Whether it's the value of an attribute, such as `style="color: red"`
+    pub attribute_value: bool,
+}
+
+/// The embedded regions of the document, see `get_document_regions`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentRegions {
+    regions: Vec<EmbeddedRegion>,
+    /// The encoding of the characters of the positions, the conversion of `FullTextDocument` is used if it's `None`
+    position_encoding: Option<PositionEncodingKind>,
+}
+
+/// Find the embedded regions of the document, in the order of the document
+pub fn get_document_regions(text: &str) -> DocumentRegions {
+    let mut regions = vec![];
+    let mut scanner = Scanner::new(text, 0, ScannerState::WithinContent, false);
+    let mut last_attribute_name = None;
+    let mut last_tag_name = String::new();
+    let mut script_type = None;
+
+    let mut token = scanner.scan();
+    while token != TokenType::EOS {
+        match token {
+            TokenType::StartTag => {
+                last_tag_name = scanner.get_token_text().to_lowercase();
+                script_type = None;
+            }
+            TokenType::Script | TokenType::Styles => {
+                regions.push(EmbeddedRegion {
+                    language_id: if token == TokenType::Script {
+                        get_script_language(script_type.as_deref())
+                    } else {
+                        "css".to_string()
+                    },
+                    start: scanner.get_token_offset(),
+                    end: scanner.get_token_end(),
+                    attribute_value: false,
+                });
+            }
+            TokenType::AttributeName => {
+                last_attribute_name = Some(scanner.get_token_text().to_lowercase());
+            }
+            TokenType::AttributeValue if last_tag_name == "script" => {
+                if last_attribute_name.as_deref() == Some("type") {
+                    let value = scanner.get_token_text();
+                    script_type = Some(value.trim_matches(|c| c == '"' || c == '\'').to_string());
+                }
+                last_attribute_name = None;
+            }
+            TokenType::AttributeValue => {
+                let language_id = match last_attribute_name.as_deref() {
+                    Some("style") => Some("css"),
+                    Some(name) if name.starts_with("on") && name.len() > 2 => Some("javascript"),
+                    _ => None,
+                };
+                if let Some(language_id) = language_id {
+                    let value = scanner.get_token_text();
+                    let mut start = scanner.get_token_offset();
+                    let mut end = scanner.get_token_end();
+                    if let Some(&first @ (b'"' | b'\'')) = value.as_bytes().first() {
+                        start += 1;
+                        if value.len() > 1 && value.as_bytes()[value.len() - 1] == first {
+                            end -= 1;
+                        }
+                    }
+                    regions.push(EmbeddedRegion {
+                        language_id: language_id.to_string(),
+                        start,
+                        end,
+                        attribute_value: true,
+                    });
+                }
+                last_attribute_name = None;
+            }
+            _ => {}
+        }
+        token = scanner.scan();
+    }
+    DocumentRegions {
+        regions,
+        position_encoding: None,
+    }
+}
+
+/// The language of the content of `<script>` by its `type`, like vscode-html-languageservice,
+/// and `json` for the JSON types. The other types are the language themselves, such as `text/x-template`.
+pub fn get_script_language(script_type: Option<&str>) -> String {
+    let script_type = script_type.map(|t| t.trim().to_lowercase());
+    match script_type.as_deref() {
+        None
+        | Some("")
+        | Some("module")
+        | Some("text/javascript")
+        | Some("application/javascript")
+        | Some("text/ecmascript")
+        | Some("application/ecmascript")
+        | Some("text/babel") => "javascript".to_string(),
+        Some("text/typescript") => "typescript".to_string(),
+        Some("application/json") | Some("application/ld+json") | Some("importmap") => {
+            "json".to_string()
+        }
+        Some(script_type) => script_type.to_string(),
+    }
+}
+
+impl DocumentRegions {
+    pub fn regions(&self) -> &[EmbeddedRegion] {
+        &self.regions
+    }
+
+    /// Count the characters of the positions in `position_encoding`, such as the encoding negotiated with the client
+    pub fn with_position_encoding(
+        mut self,
+        position_encoding: Option<PositionEncodingKind>,
+    ) -> Self {
+        self.position_encoding = position_encoding;
+        self
+    }
+
+    /// The region that contains the offset, including its end,
+    /// so that the end of the content such as `<style>a {|</style>` is in the region
+    pub fn region_at(&self, offset: usize) -> Option<&EmbeddedRegion> {
+        self.regions
+            .iter()
+            .find(|region| region.start <= offset && offset <= region.end)
+    }
+
+    pub fn region_at_position(
+        &self,
+        document: &FullTextDocument,
+        position: Position,
+    ) -> Option<&EmbeddedRegion> {
+        self.region_at(position::offset_at(
+            document,
+            position,
+            self.position_encoding.as_ref(),
+        ))
+    }
+
+    /// The language at the position, it's the language of the document out of the regions
+    pub fn language_at_position<'a>(
+        &'a self,
+        document: &'a FullTextDocument,
+        position: Position,
+    ) -> &'a str {
+        self.region_at_position(document, position)
+            .map_or(document.language_id(), |region| &region.language_id)
+    }
+
+    /// The virtual document of the language made of its regions, to delegate to the service
+    /// of the language
+    ///
+    /// The regions are separated by newlines, and the `css` attribute values are wrapped
+    /// as `__{ ... }` so that they are declarations of a rule.
+    pub fn get_embedded_document(
+        &self,
+        document: &FullTextDocument,
+        language_id: &str,
+    ) -> EmbeddedDocument {
+        let text = document.get_content(None);
+        let mut content = String::new();
+        let mut segments = vec![];
+        for region in self
+            .regions
+            .iter()
+            .filter(|region| region.language_id == language_id)
+        {
+            if !content.is_empty() {
+                content.push('\n');
+            }
+            let wrap = region.attribute_value && language_id == "css";
+            if wrap {
+                content.push_str("__{");
+            }
+            segments.push(Segment {
+                host_start: region.start,
+                virtual_start: content.len(),
+                len: region.end - region.start,
+            });
+            content.push_str(&text[region.start..region.end]);
+            if wrap {
+                content.push('}');
+            }
+        }
+        EmbeddedDocument {
+            document: FullTextDocument::new(language_id.to_string(), document.version(), content),
+            segments,
+            position_encoding: self.position_encoding.clone(),
+        }
+    }
+}
+
+/// The virtual document of an embedded language, with the mapping between its offsets
+/// and the offsets of the host document
+pub struct EmbeddedDocument {
+    document: FullTextDocument,
+    segments: Vec<Segment>,
+    position_encoding: Option<PositionEncodingKind>,
+}
+
+/// A region copied from the host document to the virtual document
+struct Segment {
+    host_start: usize,
+    virtual_start: usize,
+    len: usize,
+}
+
+impl EmbeddedDocument {
+    pub fn document(&self) -> &FullTextDocument {
+        &self.document
+    }
+
+    /// Map the offset of the host document, it's `None` if it's out of the regions
+    pub fn to_virtual_offset(&self, host_offset: usize) -> Option<usize> {
+        self.segments
+            .iter()
+            .find(|s| s.host_start <= host_offset && host_offset <= s.host_start + s.len)
+            .map(|s| s.virtual_start + host_offset - s.host_start)
+    }
+
+    /// Map the offset of the virtual document, it's `None` if it's in the separators
+    /// or the wrappers of the regions
+    pub fn to_host_offset(&self, virtual_offset: usize) -> Option<usize> {
+        self.segments
+            .iter()
+            .find(|s| {
+                s.virtual_start <= virtual_offset && virtual_offset <= s.virtual_start + s.len
+            })
+            .map(|s| s.host_start + virtual_offset - s.virtual_start)
+    }
+
+    pub fn to_virtual_position(
+        &self,
+        host: &FullTextDocument,
+        position: Position,
+    ) -> Option<Position> {
+        let offset = self.to_virtual_offset(self.offset_at(host, position))?;
+        Some(self.position_at(&self.document, offset))
+    }
+
+    pub fn to_host_position(
+        &self,
+        host: &FullTextDocument,
+        position: Position,
+    ) -> Option<Position> {
+        let offset = self.to_host_offset(self.offset_at(&self.document, position))?;
+        Some(self.position_at(host, offset))
+    }
+
+    /// Map the range of the virtual document, such as the ranges of the diagnostics and the edits,
+    /// it's `None` if either end can't be mapped or the ends are in different regions
+    pub fn to_host_range(&self, host: &FullTextDocument, range: Range) -> Option<Range> {
+        let start = self.offset_at(&self.document, range.start);
+        let end = self.offset_at(&self.document, range.end);
+        let segment = self
+            .segments
+            .iter()
+            .find(|s| s.virtual_start <= start && end <= s.virtual_start + s.len)?;
+        Some(Range::new(
+            self.position_at(host, segment.host_start + start - segment.virtual_start),
+            self.position_at(host, segment.host_start + end - segment.virtual_start),
+        ))
+    }
+
+    fn position_at(&self, document: &FullTextDocument, offset: usize) -> Position {
+        position::position_at(document, offset, self.position_encoding.as_ref())
+    }
+
+    fn offset_at(&self, document: &FullTextDocument, position: Position) -> usize {
+        position::offset_at(document, position, self.position_encoding.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regions() {
+        let text = r#"<style>a { color: red; }</style><div style="top: 0" onclick='go()'></div><script>let a;</script>"#;
+        let regions = get_document_regions(text);
+        let regions: Vec<(&str, &str, bool)> = regions
+            .regions()
+            .iter()
+            .map(|r| {
+                (
+                    r.language_id.as_str(),
+                    &text[r.start..r.end],
+                    r.attribute_value,
+                )
+            })
+            .collect();
+        assert_eq!(
+            regions,
+            vec![
+                ("css", "a { color: red; }", false),
+                ("css", "top: 0", true),
+                ("javascript", "go()", true),
+                ("javascript", "let a;", false),
+            ]
+        );
+
+        let text = r#"<script type="application/json">{}</script><script type='text/x-template'><div></div></script><script type=module>a;</script><script src="a.js" type="text/typescript">b;</script>"#;
+        let languages: Vec<String> = get_document_regions(text)
+            .regions()
+            .iter()
+            .map(|r| r.language_id.clone())
+            .collect();
+        assert_eq!(
+            languages,
+            vec!["json", "text/x-template", "javascript", "typescript"]
+        );
+    }
+
+    #[test]
+    fn mapping() {
+        let host = FullTextDocument::new(
+            "html".to_string(),
+            0,
+            "<style>\na {}\n</style>\n<p style=\"b: c\"></p>".to_string(),
+        );
+        let regions = get_document_regions(host.get_content(None));
+        assert_eq!(
+            regions.language_at_position(&host, Position::new(1, 1)),
+            "css"
+        );
+        assert_eq!(
+            regions.language_at_position(&host, Position::new(3, 1)),
+            "html"
+        );
+
+        let css = regions.get_embedded_document(&host, "css");
+        assert_eq!(css.document().get_content(None), "\na {}\n\n__{b: c}");
+        assert_eq!(
+            css.to_virtual_position(&host, Position::new(1, 2)),
+            Some(Position::new(1, 2))
+        );
+        assert_eq!(
+            css.to_virtual_position(&host, Position::new(3, 11)),
+            Some(Position::new(3, 4))
+        );
+        assert_eq!(css.to_virtual_position(&host, Position::new(3, 1)), None);
+        assert_eq!(
+            css.to_host_position(&host, Position::new(3, 4)),
+            Some(Position::new(3, 11))
+        );
+        assert_eq!(css.to_host_position(&host, Position::new(3, 1)), None);
+        assert_eq!(
+            css.to_host_range(&host, Range::new(Position::new(3, 3), Position::new(3, 7))),
+            Some(Range::new(Position::new(3, 10), Position::new(3, 14)))
+        );
+        assert_eq!(
+            css.to_host_range(&host, Range::new(Position::new(1, 0), Position::new(3, 4))),
+            None
+        );
+    }
+
+    #[test]
+    fn mapping_position_encoding() {
+        // `中` and `😀` are 1 and 2 UTF-16 code units, 3 and 4 UTF-8 bytes
+        let host = FullTextDocument::new(
+            "html".to_string(),
+            0,
+            "中😀<p style=\"b: c\"></p>".to_string(),
+        );
+        for (encoding, p) in [
+            (PositionEncodingKind::UTF8, 7),
+            (PositionEncodingKind::UTF16, 3),
+            (PositionEncodingKind::UTF32, 2),
+        ] {
+            let regions =
+                get_document_regions(host.get_content(None)).with_position_encoding(Some(encoding));
+            assert_eq!(
+                regions.language_at_position(&host, Position::new(0, p + 11)),
+                "css"
+            );
+            assert_eq!(
+                regions.language_at_position(&host, Position::new(0, p + 1)),
+                "html"
+            );
+
+            let css = regions.get_embedded_document(&host, "css");
+            assert_eq!(
+                css.to_virtual_position(&host, Position::new(0, p + 11)),
+                Some(Position::new(0, 4))
+            );
+            assert_eq!(
+                css.to_host_position(&host, Position::new(0, 4)),
+                Some(Position::new(0, p + 11))
+            );
+            assert_eq!(
+                css.to_host_range(&host, Range::new(Position::new(0, 3), Position::new(0, 7))),
+                Some(Range::new(
+                    Position::new(0, p + 10),
+                    Position::new(0, p + 14)
+                ))
+            );
+        }
+    }
+}
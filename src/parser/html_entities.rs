@@ -2238,3 +2238,20 @@ lazy_static! {
         ("zwnj;", "\u{200C}"),
     ]);
 }
+
+/// Resolve the numeric character reference without `&` and `;`, such as `#169` or `#x27`
+pub fn decode_numeric_reference(reference: &str) -> Option<char> {
+    let reference = reference.strip_prefix('#')?;
+    let code = if let Some(hex) = reference.strip_prefix(['x', 'X']) {
+        if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        u32::from_str_radix(hex, 16).ok()?
+    } else {
+        if !reference.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        reference.parse::<u32>().ok()?
+    };
+    char::from_u32(code)
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use super::html_scanner::{Scanner, ScannerState, TokenType};
+
+use crate::utils::strings::unquote;
+
+/// Find the ids declared in the document, and the offsets of their attribute values
+///
+/// It scans the text without parsing it, when the document is parsed use `HTMLDocument::index` instead.
+pub fn find_id_locations(text: &str) -> HashMap<String, usize> {
+    find_locations(text, false)
+}
+
+/// Find the fragment targets of the document, that are the ids and the names of `<a>`,
+/// and the offsets of their attribute values
+pub fn find_fragment_locations(text: &str) -> HashMap<String, usize> {
+    find_locations(text, true)
+}
+
+fn find_locations(text: &str, include_anchor_names: bool) -> HashMap<String, usize> {
+    let mut locations = HashMap::new();
+    let mut scanner = Scanner::new(text, 0, ScannerState::WithinContent, false);
+    let mut last_tag_name = None;
+    let mut last_attribute_name = None;
+
+    let mut token = scanner.scan();
+    while token != TokenType::EOS {
+        match token {
+            TokenType::StartTag => {
+                last_tag_name = Some(scanner.get_token_text().to_lowercase());
+            }
+            TokenType::AttributeName => {
+                last_attribute_name = Some(scanner.get_token_text().to_lowercase());
+            }
+            TokenType::AttributeValue => {
+                let is_target = match last_attribute_name.as_deref() {
+                    Some("id") => true,
+                    Some("name") => include_anchor_names && last_tag_name.as_deref() == Some("a"),
+                    _ => false,
+                };
+                if is_target {
+                    let id = unquote(scanner.get_token_text());
+                    locations.insert(id.to_string(), scanner.get_token_offset());
+                }
+                last_attribute_name = None;
+            }
+            _ => {}
+        }
+        token = scanner.scan();
+    }
+    locations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn id_locations() {
+        let id_locations =
+            find_id_locations(r#"<div id="a"><p ID='b' class="c"></p><span id=d></span></div>"#);
+        assert_eq!(id_locations.len(), 3);
+        assert_eq!(id_locations.get("a"), Some(&8));
+        assert_eq!(id_locations.get("b"), Some(&18));
+        assert_eq!(id_locations.get("d"), Some(&45));
+    }
+
+    #[test]
+    fn fragment_locations() {
+        let text = r#"<a name="top"></a><div name="box" id="main"></div>"#;
+        let id_locations = find_id_locations(text);
+        assert_eq!(id_locations.len(), 1);
+        let fragment_locations = find_fragment_locations(text);
+        assert_eq!(fragment_locations.len(), 2);
+        assert_eq!(fragment_locations.get("top"), Some(&8));
+        assert_eq!(fragment_locations.get("main"), Some(&37));
+    }
+}
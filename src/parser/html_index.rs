@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use crate::utils::strings::unquote;
+
+use super::html_document::Node;
+
+/// A node that declares an id or a class name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexedNode {
+    /// The start offset of the node, `HTMLDocument::find_node_at` finds the node by it
+    pub start: usize,
+    /// The offset of the attribute value that declares the name, the quote included
+    pub offset: usize,
+}
+
+/// The ids, the names of `<a>` and the class names of the document, and the nodes that declare them
+///
+/// It's built from the tree once and shared by the services, see `HTMLDocument::index`.
+#[derive(Debug, Default)]
+pub struct HTMLDocumentIndex {
+    ids: Names,
+    anchor_names: Names,
+    classes: Names,
+}
+
+/// The nodes by their names, and the order in which the names first appear
+type Names = HashMap<String, (usize, Vec<IndexedNode>)>;
+
+impl HTMLDocumentIndex {
+    pub fn new(roots: &[Node]) -> HTMLDocumentIndex {
+        let mut index = HTMLDocumentIndex::default();
+        let mut stack: Vec<&Node> = roots.iter().rev().collect();
+        while let Some(node) = stack.pop() {
+            index.add(node);
+            stack.extend(node.children.iter().rev());
+        }
+        index
+    }
+
+    fn add(&mut self, node: &Node) {
+        for (name, attr) in &node.attributes {
+            let Some(value) = &attr.value else {
+                continue;
+            };
+            let indexed = IndexedNode {
+                start: node.start,
+                offset: value.span().start,
+            };
+            if name.eq_ignore_ascii_case("id") {
+                push(&mut self.ids, unquote(value), indexed);
+            } else if name.eq_ignore_ascii_case("class") {
+                for class_name in unquote(value).split_ascii_whitespace() {
+                    push(&mut self.classes, class_name, indexed);
+                }
+            } else if name.eq_ignore_ascii_case("name")
+                && node
+                    .tag
+                    .as_ref()
+                    .is_some_and(|tag| tag.eq_ignore_ascii_case("a"))
+            {
+                push(&mut self.anchor_names, unquote(value), indexed);
+            }
+        }
+    }
+
+    /// The first node with the id
+    pub fn find_id(&self, id: &str) -> Option<&IndexedNode> {
+        self.ids.get(id).and_then(|(_, nodes)| nodes.first())
+    }
+
+    /// The nodes with the class name in document order
+    pub fn find_class(&self, class_name: &str) -> &[IndexedNode] {
+        self.classes.get(class_name).map_or(&[], |(_, nodes)| nodes)
+    }
+
+    /// The first node with the id, or the first `<a>` with the name if there is no such id
+    pub fn find_fragment(&self, fragment: &str) -> Option<&IndexedNode> {
+        self.find_id(fragment).or_else(|| {
+            self.anchor_names
+                .get(fragment)
+                .and_then(|(_, nodes)| nodes.first())
+        })
+    }
+
+    /// The ids in document order, without duplicates
+    pub fn ids(&self) -> Vec<&str> {
+        names_in_order(&self.ids)
+    }
+
+    /// The class names in document order, without duplicates
+    pub fn class_names(&self) -> Vec<&str> {
+        names_in_order(&self.classes)
+    }
+
+    /// The ids and the names of `<a>` in document order, without duplicates
+    pub fn fragments(&self) -> Vec<&str> {
+        let mut fragments: Vec<(&str, usize)> = self
+            .ids
+            .iter()
+            .chain(
+                self.anchor_names
+                    .iter()
+                    .filter(|(name, _)| !self.ids.contains_key(*name)),
+            )
+            .map(|(name, (_, nodes))| (name.as_str(), nodes[0].offset))
+            .collect();
+        fragments.sort_by_key(|(_, offset)| *offset);
+        fragments.into_iter().map(|(name, _)| name).collect()
+    }
+}
+
+fn push(names: &mut Names, name: &str, node: IndexedNode) {
+    match names.get_mut(name) {
+        Some((_, nodes)) => nodes.push(node),
+        None => {
+            let order = names.len();
+            names.insert(name.to_string(), (order, vec![node]));
+        }
+    }
+}
+
+fn names_in_order(names: &Names) -> Vec<&str> {
+    let mut names: Vec<(&str, usize)> = names
+        .iter()
+        .map(|(name, (order, _))| (name.as_str(), *order))
+        .collect();
+    names.sort_by_key(|(_, order)| *order);
+    names.into_iter().map(|(name, _)| name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_html_document, HTMLDataManager};
+
+    #[test]
+    fn index() {
+        let html_document = parse_html_document(
+            r#"<div id="a" class="x y"><p ID='b' class=y></p><a name="top"></a><span id="a"></span></div>"#,
+            "html",
+            &HTMLDataManager::default(),
+        );
+        let index = html_document.index();
+        assert_eq!(index.ids(), vec!["a", "b"]);
+        assert_eq!(index.class_names(), vec!["x", "y"]);
+        assert_eq!(index.fragments(), vec!["a", "b", "top"]);
+
+        let a = index.find_id("a").unwrap();
+        assert_eq!((a.start, a.offset), (0, 8));
+        assert_eq!(index.find_id("b").unwrap().offset, 30);
+        assert_eq!(index.find_id("top"), None);
+        assert_eq!(index.find_fragment("top").unwrap().start, 46);
+
+        let y: Vec<usize> = index.find_class("y").iter().map(|n| n.start).collect();
+        assert_eq!(y, vec![0, 24]);
+        assert!(index.find_class("z").is_empty());
+    }
+}
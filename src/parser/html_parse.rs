@@ -2,15 +2,60 @@ use crate::{
     language_facts::data_manager::HTMLDataManager,
     parser::html_scanner::{Scanner, TokenType},
 };
+use std::{collections::HashMap, sync::Arc};
+
 use lsp_textdocument::FullTextDocument;
+use smol_str::{SmolStr, StrExt};
 
 use super::{
-    html_document::{HTMLDocument, Node, NodeAttribute},
+    html_document::{HTMLDocument, Node, NodeAttribute, SourceStr},
     html_scanner::ScannerState,
 };
 
 pub struct HTMLParser;
 
+/// The limits of the parser for the untrusted documents, see `HTMLDataManager::set_parser_limits`
+///
+/// When a limit is reached, the elements beyond it are dropped with their descendants,
+/// the rest of the document is still parsed and `HTMLDocument::truncated` is true.
+/// By default only the depth is limited, to `ParserLimits::DEFAULT_MAX_DEPTH`,
+/// unlike the versions before it which didn't limit anything, use `ParserLimits::unlimited` for them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParserLimits {
+    /// The maximum depth of the elements, the roots are at depth 1.
+    /// The deeper elements are dropped with their descendants.
+    pub max_depth: Option<usize>,
+    /// The maximum number of the elements.
+    /// The elements after the number are dropped with their descendants.
+    pub max_nodes: Option<usize>,
+    /// The maximum number of the attributes of an element, the following attributes are ignored
+    pub max_attributes: Option<usize>,
+}
+
+impl ParserLimits {
+    /// The depth of the elements by default, deeper than any real document
+    pub const DEFAULT_MAX_DEPTH: usize = 2048;
+
+    /// No limit, for the trusted documents
+    pub fn unlimited() -> ParserLimits {
+        ParserLimits {
+            max_depth: None,
+            max_nodes: None,
+            max_attributes: None,
+        }
+    }
+}
+
+impl Default for ParserLimits {
+    fn default() -> Self {
+        ParserLimits {
+            max_depth: Some(ParserLimits::DEFAULT_MAX_DEPTH),
+            max_nodes: None,
+            max_attributes: None,
+        }
+    }
+}
+
 impl HTMLParser {
     pub fn parse_document(
         document: &FullTextDocument,
@@ -32,9 +77,30 @@ pub fn parse_html_document(
     text: &str,
     language_id: &str,
     data_manager: &HTMLDataManager,
+) -> HTMLDocument {
+    parse_with_template_syntax(
+        text,
+        language_id,
+        data_manager,
+        data_manager.is_template_language(language_id),
+    )
+}
+
+/// Like `parse_html_document`, and whether the template blocks are scanned is given
+/// rather than by the template languages of the data manager
+pub(crate) fn parse_with_template_syntax(
+    text: &str,
+    language_id: &str,
+    data_manager: &HTMLDataManager,
+    template_syntax: bool,
 ) -> HTMLDocument {
     let void_elements = data_manager.get_void_elements(language_id);
+    let case_sensitive = data_manager.is_case_sensitive(language_id);
+    let limits = data_manager.get_parser_limits();
+    // The attribute values are spans of the shared text rather than copies
+    let source: Arc<str> = text.into();
     let mut scanner = Scanner::new(text, 0, ScannerState::WithinContent, true);
+    scanner.set_template_syntax(template_syntax);
 
     let mut html_document = Node::new(0, scanner.get_source_len(), vec![]);
     let mut cur = &mut html_document as *mut Node;
@@ -42,20 +108,67 @@ pub fn parse_html_document(
     let mut end_tag_start = None;
     let mut end_tag_name = None;
     let mut pending_attribute = None;
+    let mut node_count = 0;
+    let mut truncated = false;
+    // The dropped elements beyond the limits, whose start tag is being scanned
+    // and the tags of the open ones, so that their end tags don't close the parsed elements
+    let mut dropping_start_tag = false;
+    let mut dropped_tag: Option<SmolStr> = None;
+    let mut dropped: Vec<Option<SmolStr>> = vec![];
+    let mut dropped_counts: HashMap<Option<SmolStr>, usize> = HashMap::new();
     let mut token = scanner.scan();
     unsafe {
         while token != TokenType::EOS {
+            if dropping_start_tag {
+                match token {
+                    TokenType::StartTag => {
+                        let text = scanner.get_token_text();
+                        dropped_tag = Some(if case_sensitive {
+                            SmolStr::new(text)
+                        } else {
+                            text.to_lowercase_smolstr()
+                        });
+                    }
+                    TokenType::StartTagClose | TokenType::StartTagSelfClose => {
+                        dropping_start_tag = false;
+                        let tag = dropped_tag.take();
+                        let is_void = tag
+                            .as_ref()
+                            .is_some_and(|tag| data_manager.is_void_element(tag, &void_elements));
+                        if token == TokenType::StartTagClose
+                            && scanner.get_token_length() > 0
+                            && !is_void
+                        {
+                            *dropped_counts.entry(tag.clone()).or_default() += 1;
+                            dropped.push(tag);
+                        }
+                    }
+                    _ => {}
+                }
+                token = scanner.scan();
+                continue;
+            }
             match token {
                 TokenType::StartTagOpen => {
+                    if limits.max_depth.is_some_and(|max| parent_list.len() >= max)
+                        || limits.max_nodes.is_some_and(|max| node_count >= max)
+                    {
+                        truncated = true;
+                        dropping_start_tag = true;
+                        dropped_tag = None;
+                        token = scanner.scan();
+                        continue;
+                    }
+                    node_count += 1;
                     let child =
                         Node::new(scanner.get_token_offset(), scanner.get_source_len(), vec![]);
                     let length = (*cur).children.len();
                     (*cur).children.push(child);
                     parent_list.push(cur);
-                    cur = &mut (*cur).children[length];
+                    cur = &mut (&mut (*cur).children)[length];
                 }
                 TokenType::StartTag => {
-                    (*cur).tag = Some(scanner.get_token_text().to_string());
+                    (*cur).tag = Some(SmolStr::new(scanner.get_token_text()));
                 }
                 TokenType::StartTagClose => {
                     if !parent_list.is_empty() {
@@ -88,18 +201,44 @@ pub fn parse_html_document(
                     end_tag_name = None;
                 }
                 TokenType::EndTag => {
-                    end_tag_name = Some(scanner.get_token_text().to_string().to_lowercase());
+                    let text = scanner.get_token_text();
+                    end_tag_name = Some(if case_sensitive {
+                        SmolStr::new(text)
+                    } else {
+                        text.to_lowercase_smolstr()
+                    });
                 }
                 TokenType::EndTagClose => {
+                    // The end tag of a dropped element closes it and the dropped elements in it
+                    let end_tag = end_tag_name.clone();
+                    if dropped_counts.get(&end_tag).is_some_and(|count| *count > 0) {
+                        while let Some(tag) = dropped.pop() {
+                            *dropped_counts.get_mut(&tag).unwrap() -= 1;
+                            if tag == end_tag {
+                                break;
+                            }
+                        }
+                        token = scanner.scan();
+                        continue;
+                    }
                     let mut node = cur;
                     let mut node_parent_list_length = parent_list.len();
                     let end_tag_name = end_tag_name.as_deref();
+                    let is_same_tag = |node: &Node| {
+                        if case_sensitive {
+                            node.tag.as_deref() == end_tag_name
+                        } else {
+                            node.is_same_tag(end_tag_name)
+                        }
+                    };
                     // see if we can find a matching tag
-                    while !(*node).is_same_tag(end_tag_name) && node_parent_list_length > 0 {
+                    while !is_same_tag(&*node) && node_parent_list_length > 0 {
                         node_parent_list_length -= 1;
                         node = parent_list[node_parent_list_length];
                     }
                     if node_parent_list_length > 0 {
+                        dropped.clear();
+                        dropped_counts.clear();
                         while node_parent_list_length != parent_list.len() {
                             (*cur).end = end_tag_start.unwrap();
                             (*cur).closed = false;
@@ -113,19 +252,38 @@ pub fn parse_html_document(
                 }
                 TokenType::AttributeName => {
                     let text = scanner.get_token_text();
-                    pending_attribute = Some(text.to_string());
+                    if limits
+                        .max_attributes
+                        .is_some_and(|max| (*cur).attributes.len() >= max)
+                        && !(*cur).attributes.contains_key(text)
+                    {
+                        truncated = true;
+                        pending_attribute = None;
+                        token = scanner.scan();
+                        continue;
+                    }
+                    pending_attribute = Some(SmolStr::new(text));
                     (*cur).attributes.insert(
-                        text.to_string(),
+                        SmolStr::new(text),
                         NodeAttribute::new(None, scanner.get_token_offset()),
                     ); // Support valueless attributes such as 'checked'
                 }
                 TokenType::AttributeValue => {
-                    let text = scanner.get_token_text();
                     if let Some(attr) = pending_attribute {
-                        let offset = scanner.get_token_offset() - 1 - attr.len();
+                        // `=` may be surrounded by whitespace
+                        let offset = (*cur)
+                            .attributes
+                            .get(&attr)
+                            .map_or(scanner.get_token_offset() - 1 - attr.len(), |attribute| {
+                                attribute.offset
+                            });
+                        let value = SourceStr::new(
+                            source.clone(),
+                            scanner.get_token_offset()..scanner.get_token_end(),
+                        );
                         (*cur)
                             .attributes
-                            .insert(attr, NodeAttribute::new(Some(text.to_string()), offset));
+                            .insert(attr, NodeAttribute::new(Some(value), offset));
                         pending_attribute = None;
                     }
                 }
@@ -139,9 +297,7 @@ pub fn parse_html_document(
             cur = parent_list.pop().unwrap();
         }
     }
-    let mut roots = vec![];
-    for root in html_document.children {
-        roots.push(root);
-    }
-    HTMLDocument { roots }
+    let mut html_document = HTMLDocument::new(html_document.children);
+    html_document.truncated = truncated;
+    html_document
 }
@@ -1,29 +1,71 @@
+use std::ops::Range;
+
 use lazy_static::lazy_static;
+use memchr::{memchr2_iter, memmem};
 use regex::Regex;
+use smol_str::{SmolStr, StrExt};
+
+use crate::utils::strings::byte_at;
 
+// Only used when non-ASCII characters are met, ASCII input is matched byte by byte
 lazy_static! {
-    static ref REG_DOCTYPE: Regex = Regex::new(r"^!(?i)doctype").unwrap();
     static ref REG_NON_SPECIAL_START: Regex = Regex::new(r#"^[^\s"'`=<>]+"#).unwrap();
-    static ref REG_SCRIPT_COMMENT: Regex = Regex::new(r"<!--|-->|<\/?script\s*\/?>?").unwrap();
     static ref REG_ELEMENT_NAME: Regex = Regex::new(r"^[_:\w][_:\w\-.\d]*").unwrap();
     static ref REG_NON_ELEMENT_NAME: Regex =
         Regex::new(r#"^[^\s"'></=\x00-\x0F\x7F\x80-\x9F]*"#).unwrap();
-    static ref REG_STYLE: Regex = Regex::new(r"<\/style").unwrap();
 }
 
+/// The delimiters of the template languages, such as Handlebars, Jinja, ERB and PHP
+const TEMPLATE_DELIMITERS: [(&str, &str); 6] = [
+    ("{{{", "}}}"),
+    ("{{", "}}"),
+    ("{%", "%}"),
+    ("{#", "#}"),
+    ("<%", "%>"),
+    ("<?", "?>"),
+];
+
+/// Whether the text contains the blocks of the template languages, such as `{{ foo }}`
+pub fn contains_template(text: &str) -> bool {
+    TEMPLATE_DELIMITERS
+        .iter()
+        .any(|(start, _)| text.contains(start))
+}
+
+/// Get the ranges of the template blocks in the text, the last block may be not closed
+pub fn get_template_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+    let mut stream = MultiLineStream::new(text, 0);
+    while !stream.eos() {
+        let start = stream.pos();
+        if stream.advance_if_template() {
+            ranges.push(start..stream.pos());
+        } else {
+            stream.advance_char();
+        }
+    }
+    ranges
+}
+
+/// The types of `<script>` whose content is HTML
+const HTML_SCRIPT_CONTENTS: [&str; 2] = ["text/x-handlebars-template", "text/html"];
+
 /// Scan the input string with byte as the base unit to generate a token stream
 pub struct Scanner<'a> {
     state: ScannerState,
     token_type: TokenType,
     token_offset: usize,
     token_error: Option<&'static str>,
+    collect_errors: bool,
+    errors: Vec<ScanError>,
     stream: MultiLineStream<'a>,
 
     emit_pseudo_close_tags: bool,
     has_space_after_tag: bool,
-    last_tag: Option<String>,
-    last_attribute_name: Option<String>,
+    last_tag: Option<SmolStr>,
+    last_attribute_name: Option<SmolStr>,
     last_type_value: Option<String>,
+    template_syntax: bool,
 }
 
 impl Scanner<'_> {
@@ -41,30 +83,51 @@ impl Scanner<'_> {
             token_type,
             token_offset,
             token_error: None,
+            collect_errors: false,
+            errors: vec![],
             stream,
             emit_pseudo_close_tags,
             has_space_after_tag: false,
             last_tag: None,
             last_attribute_name: None,
             last_type_value: None,
+            template_syntax: false,
         }
     }
 
+    /// Scan the blocks of the template languages, such as `{{ foo > bar }}`, `{% if %}` and `<% %>`,
+    /// as `TokenType::Template`, and skip them in the attribute values,
+    /// so that the characters in them don't break the tags
+    pub fn set_template_syntax(&mut self, template_syntax: bool) {
+        self.template_syntax = template_syntax;
+    }
+
+    /// Collect the errors of the tokens into `get_errors`, it's off by default
+    pub fn set_collect_errors(&mut self, collect_errors: bool) {
+        self.collect_errors = collect_errors;
+    }
+
+    /// Scan to the end and return all errors, in the order they were scanned
+    pub fn scan_with_errors(mut self) -> Vec<ScanError> {
+        self.collect_errors = true;
+        while self.scan() != TokenType::EOS {}
+        self.errors
+    }
+
     pub fn scan(&mut self) -> TokenType {
         let offset = self.stream.pos();
-        let old_state = &self.state.clone();
         self.internal_scan();
         if self.token_type != TokenType::EOS
             && offset == self.stream.pos()
             && !(self.emit_pseudo_close_tags
                 && [TokenType::StartTagClose, TokenType::EndTagClose].contains(&self.token_type))
         {
-            eprintln!(
-                "Scanner.scan has not advanced at offset {}, state before: {:?} after: {:?}",
-                offset, old_state, self.state,
+            self.stream.advance_char();
+            return self.finish_token(
+                offset,
+                TokenType::Unknown,
+                Some("Scanner has not advanced."),
             );
-            self.stream.advance(1);
-            return self.finish_token(offset, TokenType::Unknown, None);
         }
         self.token_type
     }
@@ -93,10 +156,42 @@ impl Scanner<'_> {
         self.state
     }
 
+    /// Capture the state that the scanning depends on, to resume the scanning later by `restore`,
+    /// such as at the start of a line for the line-incremental tokenization of the editors
+    pub fn snapshot(&self) -> ScannerSnapshot {
+        ScannerSnapshot {
+            state: self.state,
+            has_space_after_tag: self.has_space_after_tag,
+            last_tag: self.last_tag.clone(),
+            last_attribute_name: self.last_attribute_name.clone(),
+            last_type_value: self.last_type_value.clone(),
+        }
+    }
+
+    /// Resume from the `snapshot`, the scanner is expected to start at the offset
+    /// where the snapshot is taken
+    pub fn restore(&mut self, snapshot: ScannerSnapshot) {
+        self.state = snapshot.state;
+        self.has_space_after_tag = snapshot.has_space_after_tag;
+        self.last_tag = snapshot.last_tag;
+        self.last_attribute_name = snapshot.last_attribute_name;
+        self.last_type_value = snapshot.last_type_value;
+    }
+
     pub fn get_token_error(&self) -> Option<&'static str> {
         self.token_error
     }
 
+    /// All errors found so far, in the order they were scanned, see `set_collect_errors`
+    pub fn get_errors(&self) -> &Vec<ScanError> {
+        &self.errors
+    }
+
+    /// Take the errors found so far, leaving the list empty
+    pub fn take_errors(&mut self) -> Vec<ScanError> {
+        std::mem::take(&mut self.errors)
+    }
+
     pub fn get_source_len(&self) -> usize {
         self.stream.len
     }
@@ -129,6 +224,9 @@ impl Scanner<'_> {
             }
 
             ScannerState::WithinContent => {
+                if self.template_syntax && self.stream.advance_if_template() {
+                    return self.finish_token(offset, TokenType::Template, None);
+                }
                 if self.stream.advance_if_char(b'<') {
                     // <
                     if !self.stream.eos() && self.stream.peek_char(0) == Some(b'!') {
@@ -138,7 +236,7 @@ impl Scanner<'_> {
                             self.state = ScannerState::WithinComment;
                             return self.finish_token(offset, TokenType::StartCommentTag, None);
                         }
-                        if self.stream.advance_if_regexp(&REG_DOCTYPE) != "" {
+                        if self.stream.advance_if_chars_ignore_case("!doctype") {
                             self.state = ScannerState::WithinDoctype;
                             return self.finish_token(offset, TokenType::StartDoctypeTag, None);
                         }
@@ -151,7 +249,11 @@ impl Scanner<'_> {
                     self.state = ScannerState::AfterOpeningStartTag;
                     return self.finish_token(offset, TokenType::StartTagOpen, None);
                 }
-                self.stream.advance_until_char(b'<');
+                if self.template_syntax {
+                    self.stream.advance_until_char_or_template(b'<');
+                } else {
+                    self.stream.advance_until_char(b'<');
+                }
                 return self.finish_token(offset, TokenType::Content, None);
             }
 
@@ -237,6 +339,11 @@ impl Scanner<'_> {
                     self.has_space_after_tag = true; // remember that we have seen a whitespace
                     return self.finish_token(offset, TokenType::Whitespace, None);
                 }
+                if self.template_syntax && self.stream.advance_if_template() {
+                    // such as `<input {{ disabled }}>`, an attribute may follow it
+                    self.has_space_after_tag = true;
+                    return self.finish_token(offset, TokenType::Template, None);
+                }
                 if self.has_space_after_tag {
                     self.last_attribute_name = self.next_attribute_name();
                     if self.last_attribute_name.is_some() {
@@ -252,14 +359,18 @@ impl Scanner<'_> {
                 }
                 if self.stream.advance_if_char(b'>') {
                     // >
-                    if self.last_tag == Some("script".to_string()) {
-                        if self.last_type_value.is_some() {
+                    if self.last_tag.as_deref() == Some("script") {
+                        if self
+                            .last_type_value
+                            .as_deref()
+                            .is_some_and(|t| HTML_SCRIPT_CONTENTS.contains(&t))
+                        {
                             // stay in html
                             self.state = ScannerState::WithinContent;
                         } else {
                             self.state = ScannerState::WithinScriptContent;
                         }
-                    } else if self.last_tag == Some("style".to_string()) {
+                    } else if self.last_tag.as_deref() == Some("style") {
                         self.state = ScannerState::WithinStyleContent;
                     } else {
                         self.state = ScannerState::WithinContent;
@@ -275,7 +386,7 @@ impl Scanner<'_> {
                         Some("Closing bracket missing."),
                     );
                 }
-                self.stream.advance(1);
+                self.stream.advance_char();
                 return self.finish_token(
                     offset,
                     TokenType::Unknown,
@@ -303,7 +414,15 @@ impl Scanner<'_> {
                 }
                 let cur_char = self.stream.peek_char(0);
                 let prev_char = self.stream.peek_char(-1);
-                let mut attribute_value = self.stream.advance_if_regexp(&REG_NON_SPECIAL_START);
+                let mut attribute_value = if self.template_syntax {
+                    self.stream.advance_unquoted_template_value()
+                } else {
+                    self.stream.advance_if_ascii_or_regexp(
+                        |ch| !is_whitespace(ch) && !b"\"'`=<>".contains(&ch),
+                        |ch| !is_whitespace(ch) && !b"\"'`=<>".contains(&ch),
+                        &REG_NON_SPECIAL_START,
+                    )
+                };
                 if attribute_value.len() > 0 {
                     let mut is_go_back = false;
                     if cur_char == Some(b'>') && prev_char == Some(b'/') {
@@ -311,7 +430,7 @@ impl Scanner<'_> {
                         is_go_back = true;
                         attribute_value = &attribute_value[..attribute_value.len() - 1];
                     }
-                    if self.last_attribute_name == Some("type".to_string()) {
+                    if self.last_attribute_name.as_deref() == Some("type") {
                         let s = attribute_value.to_string();
                         self.last_type_value = if s.len() != 0 { Some(s) } else { None };
                     }
@@ -329,10 +448,15 @@ impl Scanner<'_> {
                 if let Some(ch) = ch {
                     if ch == b'\'' || ch == b'"' {
                         self.stream.advance(1); // consume quote
-                        if self.stream.advance_until_char(ch) {
+                        let is_closed = if self.template_syntax {
+                            self.stream.advance_until_char_or_template_end(ch)
+                        } else {
+                            self.stream.advance_until_char(ch)
+                        };
+                        if is_closed {
                             self.stream.advance(1); // consume quote
                         }
-                        if self.last_attribute_name == Some("type".to_string()) {
+                        if self.last_attribute_name.as_deref() == Some("type") {
                             let s =
                                 self.stream.get_source()[if offset + 1 > self.stream.pos() - 1 {
                                     self.stream.pos() - 1..offset + 1
@@ -356,7 +480,7 @@ impl Scanner<'_> {
                 // see http://stackoverflow.com/questions/14574471/how-do-browsers-parse-a-script-tag-exactly
                 let mut script_state: u8 = 1;
                 while !self.stream.eos() {
-                    let m = self.stream.advance_if_regexp(&REG_SCRIPT_COMMENT);
+                    let m = self.stream.advance_past_script_comment();
                     if m.len() == 0 {
                         self.stream.go_to_end();
                         return self.finish_token(offset, TokenType::Script, None);
@@ -390,8 +514,11 @@ impl Scanner<'_> {
             }
 
             ScannerState::WithinStyleContent => {
-                self.stream.advance_until_regexp(&REG_STYLE);
-                self.state = ScannerState::WithinContent;
+                // stay in the style content at the end, like the script content,
+                // so that the scanning can be resumed from the end
+                if self.stream.advance_until_chars("</style") {
+                    self.state = ScannerState::WithinContent;
+                }
                 if offset < self.stream.pos() {
                     return self.finish_token(offset, TokenType::Styles, None);
                 }
@@ -399,7 +526,7 @@ impl Scanner<'_> {
             }
         }
 
-        self.stream.advance(1);
+        self.stream.advance_char();
         self.state = ScannerState::WithinContent;
         return self.finish_token(offset, TokenType::Unknown, error_message);
     }
@@ -413,14 +540,24 @@ impl Scanner<'_> {
         self.token_type = token_type;
         self.token_offset = offset;
         self.token_error = error_message;
+        if let Some(message) = error_message.filter(|_| self.collect_errors) {
+            self.errors.push(ScanError {
+                message,
+                range: offset..self.stream.pos(),
+            });
+        }
         self.token_type
     }
 
-    fn next_element_name(&mut self) -> Option<String> {
+    fn next_element_name(&mut self) -> Option<SmolStr> {
         let s = self
             .stream
-            .advance_if_regexp(&REG_ELEMENT_NAME)
-            .to_lowercase();
+            .advance_if_ascii_or_regexp(
+                |ch| ch == b'_' || ch == b':' || ch.is_ascii_alphanumeric(),
+                |ch| b"_:-.".contains(&ch) || ch.is_ascii_alphanumeric(),
+                &REG_ELEMENT_NAME,
+            )
+            .to_lowercase_smolstr();
         if s.len() != 0 {
             Some(s)
         } else {
@@ -428,11 +565,15 @@ impl Scanner<'_> {
         }
     }
 
-    fn next_attribute_name(&mut self) -> Option<String> {
+    fn next_attribute_name(&mut self) -> Option<SmolStr> {
         let s = self
             .stream
-            .advance_if_regexp(&REG_NON_ELEMENT_NAME)
-            .to_lowercase();
+            .advance_if_ascii_or_regexp(
+                is_attribute_name_char,
+                is_attribute_name_char,
+                &REG_NON_ELEMENT_NAME,
+            )
+            .to_lowercase_smolstr();
         if s.len() != 0 {
             Some(s)
         } else {
@@ -441,6 +582,67 @@ impl Scanner<'_> {
     }
 }
 
+impl<'a> IntoIterator for Scanner<'a> {
+    type Item = Token<'a>;
+    type IntoIter = Tokens<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Tokens { scanner: self }
+    }
+}
+
+/// An error found while scanning
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanError {
+    pub message: &'static str,
+    /// byte offsets of the token that caused the error
+    pub range: Range<usize>,
+}
+
+/// A token produced by [`Tokens`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token<'a> {
+    pub kind: TokenType,
+    /// byte offsets of the token in the source
+    pub range: Range<usize>,
+    pub error: Option<&'static str>,
+    pub text: &'a str,
+}
+
+/// Iterator over the tokens of a [`Scanner`], it stops before `TokenType::EOS`
+///
+/// ```rust
+/// use html_languageservice::parser::html_scanner::{Scanner, ScannerState, TokenType};
+///
+/// let scanner = Scanner::new("<div>", 0, ScannerState::WithinContent, false);
+/// let kinds: Vec<TokenType> = scanner.into_iter().map(|token| token.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     vec![TokenType::StartTagOpen, TokenType::StartTag, TokenType::StartTagClose]
+/// );
+/// ```
+pub struct Tokens<'a> {
+    scanner: Scanner<'a>,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let kind = self.scanner.scan();
+        if kind == TokenType::EOS {
+            return None;
+        }
+        let range = self.scanner.get_token_offset()..self.scanner.get_token_end();
+        Some(Token {
+            kind,
+            text: &self.scanner.stream.source[range.clone()],
+            range,
+            error: self.scanner.get_token_error(),
+        })
+    }
+}
+
 struct MultiLineStream<'a> {
     source: &'a str,
     len: usize,
@@ -476,6 +678,15 @@ impl MultiLineStream<'_> {
         self.position += n;
     }
 
+    /// Advance over the char at the position, so that the token doesn't end in the middle of it
+    pub fn advance_char(&mut self) {
+        self.position += self
+            .source
+            .get(self.position..)
+            .and_then(|rest| rest.chars().next())
+            .map_or(1, char::len_utf8);
+    }
+
     pub fn go_to_end(&mut self) {
         self.position = self.len;
     }
@@ -486,11 +697,11 @@ impl MultiLineStream<'_> {
         } else {
             self.position - (-n) as usize
         };
-        Some(self.source.bytes().nth(index)?)
+        byte_at(self.source, index)
     }
 
     pub fn advance_if_char(&mut self, ch: u8) -> bool {
-        if let Some(char) = self.source.bytes().nth(self.position) {
+        if let Some(char) = byte_at(self.source, self.position) {
             if char == ch {
                 self.position += 1;
                 return true;
@@ -527,45 +738,170 @@ impl MultiLineStream<'_> {
         }
     }
 
-    pub fn advance_until_regexp(&mut self, regexp: &Regex) -> &str {
-        let haystack = &self.source[self.position..];
-        if let Some(captures) = regexp.captures(haystack) {
-            let m = captures.get(0).unwrap();
-            self.position += m.start();
-            m.as_str()
-        } else {
-            self.go_to_end();
-            ""
+    /// Like `advance_if_regexp`, but ASCII bytes are matched by `first` and `rest` without regex.
+    ///
+    /// `regexp` is only used if a non-ASCII byte is met, so it must match the same as `first` and `rest`.
+    pub fn advance_if_ascii_or_regexp<F, R>(&mut self, first: F, rest: R, regexp: &Regex) -> &str
+    where
+        F: Fn(u8) -> bool,
+        R: Fn(u8) -> bool,
+    {
+        let bytes = &self.source.as_bytes()[self.position..];
+        let mut n = 0;
+        while let Some(ch) = bytes.get(n) {
+            if !ch.is_ascii() {
+                return self.advance_if_regexp(regexp);
+            }
+            if !(if n == 0 { first(*ch) } else { rest(*ch) }) {
+                break;
+            }
+            n += 1;
         }
+        let start = self.position;
+        self.advance(n);
+        &self.source[start..self.position]
     }
 
-    pub fn advance_until_char(&mut self, ch: u8) -> bool {
-        while self.position < self.len {
-            if self.source.bytes().nth(self.position) == Some(ch) {
-                return true;
+    /// Advance past the first `<!--`, `-->`, `<script` or `</script` (followed by `\s*\/?>?`)
+    /// and return it, it returns `""` if none found.
+    pub fn advance_past_script_comment(&mut self) -> &str {
+        let bytes = &self.source.as_bytes()[self.position..];
+        for i in memchr2_iter(b'<', b'-', bytes) {
+            let rest = &bytes[i..];
+            let mut end = 0;
+            if rest.starts_with(b"<!--") {
+                end = 4;
+            } else if rest.starts_with(b"-->") {
+                end = 3;
+            } else if rest.starts_with(b"<script") {
+                end = 7;
+            } else if rest.starts_with(b"</script") {
+                end = 8;
             }
-            self.advance(1);
+            if end == 0 {
+                continue;
+            }
+            if end > 4 {
+                end += self.source[self.position + i + end..]
+                    .chars()
+                    .take_while(|c| c.is_whitespace())
+                    .map(|c| c.len_utf8())
+                    .sum::<usize>();
+                if rest.get(end) == Some(&b'/') {
+                    end += 1;
+                }
+                if rest.get(end) == Some(&b'>') {
+                    end += 1;
+                }
+            }
+            let start = self.position + i;
+            self.position = start + end;
+            return &self.source[start..self.position];
         }
+        ""
+    }
+
+    pub fn advance_until_char(&mut self, ch: u8) -> bool {
+        if let Some(index) = self.source.as_bytes()[self.position..]
+            .iter()
+            .position(|c| *c == ch)
+        {
+            self.advance(index);
+            return true;
+        }
+        self.go_to_end();
         false
     }
 
     pub fn advance_until_chars(&mut self, ch: &str) -> bool {
-        while self.position + ch.len() <= self.len {
-            if self
-                .source
-                .get(self.position..self.position + ch.len())
-                .is_some_and(|v| v == ch)
-            {
+        if let Some(index) = memmem::find(&self.source.as_bytes()[self.position..], ch.as_bytes()) {
+            self.advance(index);
+            return true;
+        }
+        self.go_to_end();
+        false
+    }
+
+    /// The length of the start delimiter and the end delimiter of the template block at the position
+    fn get_template_delimiters(&self, position: usize) -> Option<(usize, &'static str)> {
+        let rest = self.source.as_bytes().get(position..)?;
+        TEMPLATE_DELIMITERS
+            .iter()
+            .find(|(start, _)| rest.starts_with(start.as_bytes()))
+            .map(|(start, end)| (start.len(), *end))
+    }
+
+    /// Advance past the template block if it starts here, to the end if it's not closed
+    pub fn advance_if_template(&mut self) -> bool {
+        if let Some((start_len, end)) = self.get_template_delimiters(self.position) {
+            self.advance(start_len);
+            if self.advance_until_chars(end) {
+                self.advance(end.len());
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Like `advance_until_char`, but it also stops at the start of the template blocks
+    pub fn advance_until_char_or_template(&mut self, ch: u8) -> bool {
+        let bytes = self.source.as_bytes();
+        while let Some(c) = bytes.get(self.position) {
+            if self.get_template_delimiters(self.position).is_some() {
+                return false;
+            }
+            if *c == ch {
                 return true;
             }
             self.advance(1);
         }
-        self.go_to_end();
         false
     }
 
+    /// Like `advance_until_char`, but the template blocks are skipped as a whole
+    pub fn advance_until_char_or_template_end(&mut self, ch: u8) -> bool {
+        loop {
+            let found = self.advance_until_char_or_template(ch);
+            if found || !self.advance_if_template() {
+                return found;
+            }
+        }
+    }
+
+    /// Advance past the unquoted attribute value that may contain the template blocks,
+    /// such as `{{ size }}px`
+    pub fn advance_unquoted_template_value(&mut self) -> &str {
+        let start = self.position;
+        let bytes = self.source.as_bytes();
+        loop {
+            if self.advance_if_template() {
+                continue;
+            }
+            match bytes.get(self.position) {
+                Some(ch) if !is_whitespace(*ch) && !b"\"'`=<>".contains(ch) => self.advance(1),
+                _ => break,
+            }
+        }
+        &self.source[start..self.position]
+    }
+
+    pub fn advance_if_chars_ignore_case(&mut self, ch: &str) -> bool {
+        if !self
+            .source
+            .as_bytes()
+            .get(self.position..self.position + ch.len())
+            .is_some_and(|v| v.eq_ignore_ascii_case(ch.as_bytes()))
+        {
+            return false;
+        }
+
+        self.advance(ch.len());
+        true
+    }
+
     pub fn skip_whitespace(&mut self) -> bool {
-        let n = self.advance_while_char(|ch| vec![b' ', b'\t', b'\n', 12, b'\r'].contains(&ch));
+        let n = self.advance_while_char(|ch| [b' ', b'\t', b'\n', 12, b'\r'].contains(&ch));
         n > 0
     }
 
@@ -574,14 +910,22 @@ impl MultiLineStream<'_> {
         F: Fn(u8) -> bool,
     {
         let pos_now = self.position;
-        while self.position < self.len && condition(self.source.bytes().nth(self.position).unwrap())
-        {
+        while byte_at(self.source, self.position).is_some_and(|ch| condition(ch)) {
             self.advance(1);
         }
         self.position - pos_now
     }
 }
 
+/// ASCII `\s` of regex
+fn is_whitespace(ch: u8) -> bool {
+    matches!(ch, b' ' | b'\t' | b'\n' | 0x0B | 0x0C | b'\r')
+}
+
+fn is_attribute_name_char(ch: u8) -> bool {
+    !is_whitespace(ch) && !b"\"'></=".contains(&ch) && ch > 0x0F && ch != 0x7F
+}
+
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub enum TokenType {
     StartCommentTag,
@@ -605,10 +949,31 @@ pub enum TokenType {
     Unknown,
     Script,
     Styles,
+    /// The block of the template languages, see `Scanner::set_template_syntax`
+    Template,
     EOS,
 }
 
-#[derive(Debug, Clone, Copy)]
+/// The state of `Scanner` between the tokens, see `Scanner::snapshot`
+///
+/// The snapshots taken at the same offset of the same text are equal,
+/// so the editors can stop rescanning once the snapshot at a line is unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScannerSnapshot {
+    state: ScannerState,
+    has_space_after_tag: bool,
+    last_tag: Option<SmolStr>,
+    last_attribute_name: Option<SmolStr>,
+    last_type_value: Option<String>,
+}
+
+impl ScannerSnapshot {
+    pub fn get_scanner_state(&self) -> ScannerState {
+        self.state
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScannerState {
     WithinContent,
     AfterOpeningStartTag,
@@ -688,6 +1053,213 @@ mod tests {
         }]);
     }
 
+    #[test]
+    fn token_iterator() {
+        let scanner = Scanner::new(r#"<a href="x">"#, 0, ScannerState::WithinContent, false);
+        let tokens: Vec<(TokenType, &str)> = scanner
+            .into_iter()
+            .map(|token| (token.kind, token.text))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (TokenType::StartTagOpen, "<"),
+                (TokenType::StartTag, "a"),
+                (TokenType::Whitespace, " "),
+                (TokenType::AttributeName, "href"),
+                (TokenType::DelimiterAssign, "="),
+                (TokenType::AttributeValue, r#""x""#),
+                (TokenType::StartTagClose, ">"),
+            ]
+        );
+    }
+
+    #[test]
+    fn collect_errors() {
+        let mut scanner = Scanner::new("<div </ >", 0, ScannerState::WithinContent, true);
+        while scanner.scan() != TokenType::EOS {}
+        assert!(scanner.get_errors().is_empty());
+
+        let mut scanner = Scanner::new("<div </ >", 0, ScannerState::WithinContent, true);
+        scanner.set_collect_errors(true);
+        while scanner.scan() != TokenType::EOS {}
+        assert_eq!(
+            scanner.get_errors(),
+            &vec![
+                ScanError {
+                    message: "Closing bracket missing.",
+                    range: 5..5,
+                },
+                ScanError {
+                    message: "Tag name must directly follow the open bracket.",
+                    range: 7..8,
+                },
+            ]
+        );
+        assert_eq!(scanner.take_errors().len(), 2);
+        assert!(scanner.get_errors().is_empty());
+
+        let scanner = Scanner::new("<div </ >", 0, ScannerState::WithinContent, true);
+        assert_eq!(scanner.scan_with_errors().len(), 2);
+    }
+
+    #[test]
+    fn binding_attribute_names() {
+        let scanner = Scanner::new(
+            r#"<div [prop]="x" (click)="y()" *ngIf="z" #ref [(ngModel)]="m" @click="b">"#,
+            0,
+            ScannerState::WithinContent,
+            false,
+        );
+        let names: Vec<&str> = scanner
+            .into_iter()
+            .filter(|token| token.kind == TokenType::AttributeName)
+            .map(|token| token.text)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "[prop]",
+                "(click)",
+                "*ngIf",
+                "#ref",
+                "[(ngModel)]",
+                "@click"
+            ]
+        );
+    }
+
+    #[test]
+    fn ascii_fast_path() {
+        let scanner = Scanner::new(
+            "<!DOCTYPE html><中文 数据=值><script><!-- <script></script> --></script>",
+            0,
+            ScannerState::WithinContent,
+            false,
+        );
+        let tokens: Vec<(TokenType, &str)> = scanner
+            .into_iter()
+            .map(|token| (token.kind, token.text))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (TokenType::StartDoctypeTag, "<!DOCTYPE"),
+                (TokenType::Doctype, " html"),
+                (TokenType::EndDoctypeTag, ">"),
+                (TokenType::StartTagOpen, "<"),
+                (TokenType::StartTag, "中文"),
+                (TokenType::Whitespace, " "),
+                (TokenType::AttributeName, "数据"),
+                (TokenType::DelimiterAssign, "="),
+                (TokenType::AttributeValue, "值"),
+                (TokenType::StartTagClose, ">"),
+                (TokenType::StartTagOpen, "<"),
+                (TokenType::StartTag, "script"),
+                (TokenType::StartTagClose, ">"),
+                (TokenType::Script, "<!-- <script></script> -->"),
+                (TokenType::EndTagOpen, "</"),
+                (TokenType::EndTag, "script"),
+                (TokenType::EndTagClose, ">"),
+            ]
+        );
+    }
+
+    #[test]
+    fn template_syntax() {
+        let input =
+            r#"<div class="{{ "a" }}" {{ attrs }} id={{ id }}-x>{{ a > b }}<% if x %></div>"#;
+        let mut scanner = Scanner::new(input, 0, ScannerState::WithinContent, false);
+        scanner.set_template_syntax(true);
+        let tokens: Vec<(TokenType, &str)> = scanner
+            .into_iter()
+            .map(|token| (token.kind, token.text))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (TokenType::StartTagOpen, "<"),
+                (TokenType::StartTag, "div"),
+                (TokenType::Whitespace, " "),
+                (TokenType::AttributeName, "class"),
+                (TokenType::DelimiterAssign, "="),
+                (TokenType::AttributeValue, r#""{{ "a" }}""#),
+                (TokenType::Whitespace, " "),
+                (TokenType::Template, "{{ attrs }}"),
+                (TokenType::Whitespace, " "),
+                (TokenType::AttributeName, "id"),
+                (TokenType::DelimiterAssign, "="),
+                (TokenType::AttributeValue, "{{ id }}-x"),
+                (TokenType::StartTagClose, ">"),
+                (TokenType::Template, "{{ a > b }}"),
+                (TokenType::Template, "<% if x %>"),
+                (TokenType::EndTagOpen, "</"),
+                (TokenType::EndTag, "div"),
+                (TokenType::EndTagClose, ">"),
+            ]
+        );
+
+        let scanner = Scanner::new("<p>{{ a > b }}</p>", 0, ScannerState::WithinContent, false);
+        assert!(scanner
+            .into_iter()
+            .all(|token| token.kind != TokenType::Template));
+    }
+
+    #[test]
+    fn snapshot() {
+        let input = "<script>\nlet a = '<div>';\n</script>\n<style>\na {}\n</style>\n<p\nclass=\"a\">\n</p>";
+        let tokens = |scanner: Scanner| -> Vec<(TokenType, usize)> {
+            scanner
+                .into_iter()
+                .map(|token| (token.kind, token.range.start))
+                .collect()
+        };
+        let expected = tokens(Scanner::new(input, 0, ScannerState::WithinContent, false));
+
+        // scan line by line, resuming from the snapshot at the end of the previous line
+        let mut actual = vec![];
+        let mut snapshot = None;
+        let mut offset = 0;
+        for line in input.split_inclusive('\n') {
+            let end = offset + line.len();
+            let mut scanner =
+                Scanner::new(&input[..end], offset, ScannerState::WithinContent, false);
+            if let Some(snapshot) = snapshot.take() {
+                scanner.restore(snapshot);
+            }
+            let mut token = scanner.scan();
+            while token != TokenType::EOS {
+                actual.push((token, scanner.get_token_offset()));
+                token = scanner.scan();
+            }
+            snapshot = Some(scanner.snapshot());
+            offset = end;
+        }
+        // the tokens spanning lines are split at the line ends
+        actual.dedup_by(|b, a| {
+            a.0 == b.0
+                && [
+                    TokenType::Script,
+                    TokenType::Styles,
+                    TokenType::Whitespace,
+                    TokenType::Content,
+                ]
+                .contains(&a.0)
+        });
+        assert_eq!(actual, expected);
+
+        let mut scanner = Scanner::new(input, 0, ScannerState::WithinContent, false);
+        scanner.scan();
+        scanner.scan();
+        scanner.scan();
+        let snapshot = scanner.snapshot();
+        assert_eq!(
+            snapshot.get_scanner_state(),
+            ScannerState::WithinScriptContent
+        );
+        assert_eq!(snapshot, scanner.snapshot());
+    }
+
     struct TestItem {
         input: String,
         tokens: Vec<Token>,
@@ -1,4 +1,7 @@
 pub mod html_document;
+pub mod html_embedded;
 pub mod html_entities;
+pub mod html_ids;
+pub mod html_index;
 pub mod html_parse;
 pub mod html_scanner;
@@ -1,22 +1,161 @@
+use std::{fmt, sync::Arc};
+
 use async_trait::async_trait;
 use lsp_textdocument::FullTextDocument;
+#[cfg(feature = "folding")]
+use lsp_types::FoldingRange;
 use lsp_types::{CompletionItem, Hover, Position, Range};
 
 use crate::parser::html_document::HTMLDocument;
+#[cfg(feature = "formatter")]
+use crate::HTMLFormatConfiguration;
 
+/// Provide additional completion items
+///
+/// The participants are called in descending order of `priority`. The items of the participants
+/// are merged with the built-in items in the same order and deduplicated by label, the first item is kept.
 #[async_trait]
 pub trait ICompletionParticipant: Send + Sync {
     async fn on_html_attribute_value(
         &self,
         context: HtmlAttributeValueContext,
-    ) -> Vec<CompletionItem>;
-    async fn on_html_content(&self, context: HtmlContentContext) -> Vec<CompletionItem>;
+    ) -> Result<Vec<CompletionItem>, ParticipantError>;
+    async fn on_html_content(
+        &self,
+        context: HtmlContentContext,
+    ) -> Result<Vec<CompletionItem>, ParticipantError>;
+    /// The items of the participants with a negative priority are placed after the built-in items,
+    /// the others before them
+    fn priority(&self) -> i32 {
+        0
+    }
+    /// Hide the built-in values of the attribute, such as the values of the data providers and the ids
+    fn hide_builtin_attribute_values(&self, _context: &HtmlAttributeValueContext) -> bool {
+        false
+    }
+    /// Hide the built-in items in the content, such as the character entities
+    fn hide_builtin_content(&self, _context: &HtmlContentContext) -> bool {
+        false
+    }
 }
 
 #[async_trait]
 pub trait IHoverParticipant: Send + Sync {
-    async fn on_html_attribute_value(&self, context: HtmlAttributeValueContext) -> Option<Hover>;
-    async fn on_html_content(&self, context: HtmlContentContext) -> Option<Hover>;
+    async fn on_html_attribute_value(
+        &self,
+        context: HtmlAttributeValueContext,
+    ) -> Result<Option<Hover>, ParticipantError>;
+    async fn on_html_content(
+        &self,
+        context: HtmlContentContext,
+    ) -> Result<Option<Hover>, ParticipantError>;
+    /// It's called before the attribute name is looked up in the data providers
+    async fn on_html_attribute_name(
+        &self,
+        _context: HtmlAttributeNameContext,
+    ) -> Result<Option<Hover>, ParticipantError> {
+        Ok(None)
+    }
+    /// It's called before the tag name is looked up in the data providers,
+    /// such as for the components that aren't in the data
+    async fn on_html_tag(
+        &self,
+        _context: HtmlTagContext,
+    ) -> Result<Option<Hover>, ParticipantError> {
+        Ok(None)
+    }
+}
+
+/// Format the embedded content of `<script>` and `<style>`
+#[cfg(feature = "formatter")]
+pub trait IFormatterParticipant: Send + Sync {
+    /// Format the `content` of the `language` such as `javascript`, `json` and `css`,
+    /// return `None` if the language isn't supported
+    ///
+    /// The result shouldn't be indented, every line of it is indented to `indent_level` by the formatter
+    fn format_embedded(
+        &self,
+        language: &str,
+        content: &str,
+        indent_level: usize,
+        options: &HTMLFormatConfiguration,
+    ) -> Result<Option<String>, ParticipantError>;
+}
+
+/// Provide the folding ranges of the embedded content of `<script>` and `<style>`
+#[cfg(feature = "folding")]
+pub trait IFoldingRangeParticipant: Send + Sync {
+    /// Get the folding ranges of the `content` of the `language` such as `javascript`, `json` and `css`,
+    /// the lines and characters of the ranges are relative to the start of `content`
+    fn get_folding_ranges(
+        &self,
+        language: &str,
+        content: &str,
+    ) -> Result<Vec<FoldingRange>, ParticipantError>;
+}
+
+/// The failure of a participant
+///
+/// The services report it to `HTMLLanguageServiceOptions::participant_error_handler`
+/// and continue as if the participant provided nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParticipantError {
+    message: String,
+}
+
+impl ParticipantError {
+    pub fn new(message: impl Into<String>) -> ParticipantError {
+        ParticipantError {
+            message: message.into(),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParticipantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "participant failed: {}", self.message)
+    }
+}
+
+impl std::error::Error for ParticipantError {}
+
+impl From<String> for ParticipantError {
+    fn from(message: String) -> Self {
+        ParticipantError::new(message)
+    }
+}
+
+impl From<&str> for ParticipantError {
+    fn from(message: &str) -> Self {
+        ParticipantError::new(message)
+    }
+}
+
+/// Receive the errors of the participants, such as to write them to the log of the server
+pub type ParticipantErrorHandler = Arc<dyn Fn(&ParticipantError) + Send + Sync>;
+
+pub struct HtmlTagContext {
+    pub document: FullTextDocument,
+    pub html_document: HTMLDocument,
+    pub position: Position,
+    pub tag: String,
+    /// Whether the name is of the start tag, otherwise of the end tag
+    pub open: bool,
+    /// The range of the tag name
+    pub range: Range,
+}
+
+pub struct HtmlAttributeNameContext {
+    pub document: FullTextDocument,
+    pub html_document: HTMLDocument,
+    pub position: Position,
+    pub tag: String,
+    pub attribute: String,
+    pub range: Range,
 }
 
 pub struct HtmlAttributeValueContext {
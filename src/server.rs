@@ -0,0 +1,746 @@
+use std::{collections::HashMap, sync::Arc};
+
+use lsp_textdocument::FullTextDocument;
+use lsp_types::*;
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::sync::{Mutex, RwLock};
+use tower_lsp::{jsonrpc::Result, Client, ClientSocket, LanguageServer, LspService, Server};
+
+use crate::{
+    html_data::HTMLDataV1,
+    language_facts::data_provider::{HTMLDataProvider, IHTMLDataProvider},
+    parser::html_document::HTMLDocument,
+    participant::ParticipantError,
+    CompletionConfiguration, DocumentStore, FoldingRangeContext, HTMLDataManager,
+    HTMLFormatConfiguration, HTMLLanguageService, HTMLLanguageServiceOptions, HoverSettings,
+    HtmlWrapAttributes, LocalFileSystemProvider, Quotes, WorkspaceDocumentContext,
+};
+
+/// The HTML language server that wires all the services to `tower-lsp`,
+/// it's the server of the `html-languageserver` binary
+///
+/// The documents are synchronized incrementally and kept in a `DocumentStore`.
+/// The settings are read from `initializationOptions` and `workspace/didChangeConfiguration`,
+/// either as they are or in the `html` section, with the same names as the `html.*` settings of VS Code.
+///
+/// In addition to the standard requests, `html/autoInsert` returns the text to insert
+/// after a `=` of an attribute (`autoQuote`) or a `>` of a start tag (`autoClose`).
+pub struct HTMLLanguageServer {
+    client: Client,
+    state: RwLock<ServerState>,
+    documents: Mutex<DocumentStore>,
+}
+
+/// The state of a request, see `HTMLLanguageServer::snapshot`
+struct RequestSnapshot {
+    ls: Arc<HTMLLanguageService>,
+    data_manager: Arc<HTMLDataManager>,
+    document_context: WorkspaceDocumentContext,
+    settings: ServerSettings,
+    document: FullTextDocument,
+    html_document: HTMLDocument,
+}
+
+struct ServerState {
+    ls: Arc<HTMLLanguageService>,
+    data_manager: Arc<HTMLDataManager>,
+    document_context: WorkspaceDocumentContext,
+    settings: ServerSettings,
+    hierarchical_symbols: bool,
+    folding_range_limit: Option<usize>,
+}
+
+impl HTMLLanguageServer {
+    pub fn new(client: Client) -> HTMLLanguageServer {
+        HTMLLanguageServer {
+            client,
+            state: RwLock::new(ServerState {
+                ls: Arc::new(HTMLLanguageService::new(
+                    &HTMLLanguageServiceOptions::default(),
+                )),
+                data_manager: Arc::new(HTMLDataManager::default()),
+                document_context: WorkspaceDocumentContext::default(),
+                settings: ServerSettings::default(),
+                hierarchical_symbols: false,
+                folding_range_limit: None,
+            }),
+            documents: Mutex::new(DocumentStore::new()),
+        }
+    }
+
+    /// Create the service of the server, with the custom methods
+    pub fn service() -> (LspService<HTMLLanguageServer>, ClientSocket) {
+        LspService::build(HTMLLanguageServer::new)
+            .custom_method("html/autoInsert", HTMLLanguageServer::auto_insert)
+            .finish()
+    }
+
+    /// Serve the client over stdin and stdout until it exits
+    pub async fn run_stdio() {
+        let (service, socket) = HTMLLanguageServer::service();
+        Server::new(tokio::io::stdin(), tokio::io::stdout(), socket)
+            .serve(service)
+            .await;
+    }
+
+    /// Handle `html/autoInsert`, the quotes of an attribute value or the end tag of an element
+    pub async fn auto_insert(&self, params: AutoInsertParams) -> Result<Option<String>> {
+        let Some(snapshot) = self.snapshot(&params.text_document.uri).await else {
+            return Ok(None);
+        };
+        let settings = snapshot.settings.completion_configuration();
+        Ok(match params.kind {
+            AutoInsertKind::AutoQuote if snapshot.settings.auto_create_quotes => {
+                snapshot.ls.do_quote_complete(
+                    &snapshot.document,
+                    &params.position,
+                    &snapshot.html_document,
+                    Some(&settings),
+                )
+            }
+            AutoInsertKind::AutoClose if snapshot.settings.auto_closing_tags => {
+                snapshot.ls.do_tag_complete(
+                    &snapshot.document,
+                    &params.position,
+                    &snapshot.html_document,
+                    Some(&settings),
+                    &snapshot.data_manager,
+                )
+            }
+            _ => None,
+        })
+    }
+
+    /// Copy the services, the settings and the document of the `uri`,
+    /// so that the requests that await don't hold the locks of the state and the documents
+    async fn snapshot(&self, uri: &Url) -> Option<RequestSnapshot> {
+        let state = self.state.read().await;
+        let mut documents = self.documents.lock().await;
+        let (document, html_document) = documents.get_html_document(uri, &state.data_manager)?;
+        Some(RequestSnapshot {
+            ls: state.ls.clone(),
+            data_manager: state.data_manager.clone(),
+            document_context: state.document_context.clone(),
+            settings: state.settings.clone(),
+            document: FullTextDocument::new(
+                document.language_id().to_string(),
+                document.version(),
+                document.get_content(None).to_string(),
+            ),
+            html_document: html_document.clone(),
+        })
+    }
+
+    /// Call `f` with the document of the `uri` and its parsed `HTMLDocument`
+    async fn with_document<T>(
+        &self,
+        uri: &Url,
+        f: impl FnOnce(&ServerState, &FullTextDocument, &HTMLDocument) -> Option<T>,
+    ) -> Option<T> {
+        let state = self.state.read().await;
+        let mut documents = self.documents.lock().await;
+        let (document, html_document) = documents.get_html_document(uri, &state.data_manager)?;
+        f(&state, document, html_document)
+    }
+
+    async fn format(
+        &self,
+        uri: &Url,
+        range: Option<Range>,
+        options: &FormattingOptions,
+    ) -> Option<Vec<TextEdit>> {
+        let state = self.state.read().await;
+        if !state.settings.format.enable {
+            return None;
+        }
+        let documents = self.documents.lock().await;
+        let document = documents.get_document(uri)?;
+        let edits = state.ls.format(
+            document,
+            range,
+            Some(&state.settings.format.to_configuration(options)),
+            &state.data_manager,
+        );
+        Some(edits)
+    }
+
+    /// Create the data manager of the built-in data and the custom data at the `paths`,
+    /// the files are read without holding the locks
+    async fn load_custom_data(
+        &self,
+        paths: &[String],
+        workspace_folders: &[Url],
+    ) -> HTMLDataManager {
+        let mut providers: Vec<Box<dyn IHTMLDataProvider>> = vec![];
+        for path in paths {
+            match read_custom_data(path, workspace_folders).await {
+                Ok(data) => providers.push(Box::new(HTMLDataProvider::new(path.clone(), data))),
+                Err(err) => {
+                    self.client
+                        .log_message(
+                            MessageType::WARNING,
+                            format!("Failed to load the custom data {}: {}", path, err),
+                        )
+                        .await
+                }
+            }
+        }
+        let mut data_manager = HTMLDataManager::default();
+        data_manager.set_data_providers(true, providers);
+        data_manager
+    }
+
+    /// Replace the data manager, the documents parsed with the previous one are parsed again
+    async fn set_data_manager(&self, state: &mut ServerState, data_manager: HTMLDataManager) {
+        state.data_manager = Arc::new(data_manager);
+        self.documents.lock().await.invalidate();
+    }
+}
+
+#[tower_lsp::async_trait]
+impl LanguageServer for HTMLLanguageServer {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        let mut workspace_folders = params.workspace_folders.unwrap_or_default();
+        #[allow(deprecated)]
+        if let (true, Some(root_uri)) = (workspace_folders.is_empty(), params.root_uri) {
+            workspace_folders.push(WorkspaceFolder {
+                name: root_uri.to_string(),
+                uri: root_uri,
+            });
+        }
+        let text_document = params.capabilities.text_document.as_ref();
+        let hierarchical_symbols = text_document
+            .and_then(|c| c.document_symbol.as_ref())
+            .and_then(|c| c.hierarchical_document_symbol_support)
+            .unwrap_or(false);
+        let folding_range_limit = text_document
+            .and_then(|c| c.folding_range.as_ref())
+            .and_then(|c| c.range_limit)
+            .map(|limit| limit as usize);
+
+        let options = HTMLLanguageServiceOptions {
+            file_system_provider: Some(Arc::new(LocalFileSystemProvider)),
+            client_capabilities: Some(params.capabilities),
+            workspace_folders: Some(workspace_folders),
+            participant_error_handler: Some(Arc::new({
+                let client = self.client.clone();
+                move |error: &ParticipantError| {
+                    let client = client.clone();
+                    let message = error.to_string();
+                    tokio::spawn(async move {
+                        client.log_message(MessageType::ERROR, message).await;
+                    });
+                }
+            })),
+            ..Default::default()
+        };
+
+        let settings = params
+            .initialization_options
+            .map_or_else(ServerSettings::default, |value| {
+                ServerSettings::from_value(&value)
+            });
+        let document_context = WorkspaceDocumentContext::from(&options);
+        let data_manager = self
+            .load_custom_data(&settings.custom_data, &document_context.workspace_folders)
+            .await;
+        let ls = HTMLLanguageService::new(&options);
+        let mut capabilities = ls.server_capabilities();
+
+        let mut state = self.state.write().await;
+        state.ls = Arc::new(ls);
+        state.document_context = document_context;
+        state.hierarchical_symbols = hierarchical_symbols;
+        state.folding_range_limit = folding_range_limit;
+        state.settings = settings;
+        self.set_data_manager(&mut state, data_manager).await;
+
+        capabilities.text_document_sync = Some(TextDocumentSyncCapability::Kind(
+            TextDocumentSyncKind::INCREMENTAL,
+        ));
+        Ok(InitializeResult {
+            capabilities,
+            server_info: Some(ServerInfo {
+                name: "html-languageserver".to_string(),
+                version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            }),
+        })
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
+        let settings = ServerSettings::from_value(&params.settings);
+        let (reload, workspace_folders) = {
+            let state = self.state.read().await;
+            (
+                settings.custom_data != state.settings.custom_data,
+                state.document_context.workspace_folders.clone(),
+            )
+        };
+        let data_manager = if reload {
+            Some(
+                self.load_custom_data(&settings.custom_data, &workspace_folders)
+                    .await,
+            )
+        } else {
+            None
+        };
+        let mut state = self.state.write().await;
+        state.settings = settings;
+        if let Some(data_manager) = data_manager {
+            self.set_data_manager(&mut state, data_manager).await;
+        }
+    }
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let document = params.text_document;
+        self.documents.lock().await.open(
+            document.uri,
+            document.language_id,
+            document.version,
+            document.text,
+        );
+    }
+
+    async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        self.documents.lock().await.change(
+            &params.text_document.uri,
+            &params.content_changes,
+            params.text_document.version,
+        );
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        self.documents.lock().await.close(&params.text_document.uri);
+    }
+
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let position = params.text_document_position;
+        let Some(snapshot) = self.snapshot(&position.text_document.uri).await else {
+            return Ok(None);
+        };
+        let list = snapshot
+            .ls
+            .do_complete2(
+                &position.text_document.uri,
+                &snapshot.document,
+                &position.position,
+                &snapshot.html_document,
+                snapshot.document_context,
+                Some(&snapshot.settings.completion_configuration()),
+                &snapshot.data_manager,
+            )
+            .await;
+        Ok(Some(CompletionResponse::List(list)))
+    }
+
+    async fn completion_resolve(&self, item: CompletionItem) -> Result<CompletionItem> {
+        let state = self.state.read().await;
+        Ok(state.ls.resolve_completion_item(item, &state.data_manager))
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let position = params.text_document_position_params;
+        let Some(snapshot) = self.snapshot(&position.text_document.uri).await else {
+            return Ok(None);
+        };
+        Ok(snapshot
+            .ls
+            .do_hover(
+                &snapshot.document,
+                &position.position,
+                &snapshot.html_document,
+                Some(snapshot.settings.hover.to_settings()),
+                &snapshot.data_manager,
+            )
+            .await)
+    }
+
+    async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        Ok(self
+            .format(&params.text_document.uri, None, &params.options)
+            .await)
+    }
+
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        Ok(self
+            .format(
+                &params.text_document.uri,
+                Some(params.range),
+                &params.options,
+            )
+            .await)
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> Result<Option<Vec<DocumentHighlight>>> {
+        let position = params.text_document_position_params;
+        Ok(self
+            .with_document(
+                &position.text_document.uri,
+                |state, document, html_document| {
+                    Some(HTMLLanguageService::find_document_highlights(
+                        document,
+                        &position.position,
+                        html_document,
+                        state.ls.position_encoding(),
+                    ))
+                },
+            )
+            .await)
+    }
+
+    async fn document_link(&self, params: DocumentLinkParams) -> Result<Option<Vec<DocumentLink>>> {
+        let uri = params.text_document.uri;
+        Ok(self
+            .with_document(&uri, |state, document, html_document| {
+                Some(HTMLLanguageService::find_document_links(
+                    &uri,
+                    document,
+                    html_document,
+                    &state.document_context,
+                    &state.data_manager,
+                    state.ls.position_encoding(),
+                ))
+            })
+            .await)
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let uri = params.text_document.uri;
+        Ok(self
+            .with_document(&uri, |state, document, html_document| {
+                Some(if state.hierarchical_symbols {
+                    let (symbols, _) = HTMLLanguageService::find_document_symbols2(
+                        document,
+                        html_document,
+                        &state.data_manager,
+                        None,
+                        state.ls.position_encoding(),
+                    );
+                    DocumentSymbolResponse::Nested(symbols)
+                } else {
+                    let (symbols, _) = HTMLLanguageService::find_document_symbols(
+                        &uri,
+                        document,
+                        html_document,
+                        &state.data_manager,
+                        None,
+                        state.ls.position_encoding(),
+                    );
+                    DocumentSymbolResponse::Flat(symbols)
+                })
+            })
+            .await)
+    }
+
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        Ok(self
+            .with_document(&params.text_document.uri, |state, document, _| {
+                Some(HTMLLanguageService::get_folding_ranges(
+                    document,
+                    FoldingRangeContext {
+                        range_limit: state.folding_range_limit,
+                        ..Default::default()
+                    },
+                    &state.data_manager,
+                    state.ls.position_encoding(),
+                ))
+            })
+            .await)
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> Result<Option<Vec<SelectionRange>>> {
+        Ok(self
+            .with_document(
+                &params.text_document.uri,
+                |state, document, html_document| {
+                    Some(HTMLLanguageService::get_selection_ranges(
+                        document,
+                        &params.positions,
+                        html_document,
+                        state.ls.position_encoding(),
+                    ))
+                },
+            )
+            .await)
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let position = params.text_document_position;
+        let uri = position.text_document.uri;
+        Ok(self
+            .with_document(&uri, |state, document, html_document| {
+                HTMLLanguageService::do_rename(
+                    uri.clone(),
+                    document,
+                    position.position,
+                    &params.new_name,
+                    html_document,
+                    state.ls.position_encoding(),
+                )
+            })
+            .await)
+    }
+
+    async fn linked_editing_range(
+        &self,
+        params: LinkedEditingRangeParams,
+    ) -> Result<Option<LinkedEditingRanges>> {
+        let position = params.text_document_position_params;
+        Ok(self
+            .with_document(
+                &position.text_document.uri,
+                |state, document, html_document| {
+                    let ranges = HTMLLanguageService::find_linked_editing_ranges(
+                        document,
+                        position.position,
+                        html_document,
+                        None,
+                        state.ls.position_encoding(),
+                    )?;
+                    Some(LinkedEditingRanges {
+                        ranges,
+                        word_pattern: None,
+                    })
+                },
+            )
+            .await)
+    }
+}
+
+/// The parameters of `html/autoInsert`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoInsertParams {
+    pub kind: AutoInsertKind,
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AutoInsertKind {
+    /// The quotes after the `=` of an attribute
+    AutoQuote,
+    /// The end tag after the `>` of a start tag
+    AutoClose,
+}
+
+/// The `html.*` settings of VS Code that the server supports
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct ServerSettings {
+    /// The paths of the JSON files of the custom data, relative to the workspace folder
+    custom_data: Vec<String>,
+    /// Whether to propose the tags, attributes and values of the data providers, such as `html5`
+    suggest: HashMap<String, bool>,
+    completion: CompletionSettings,
+    hover: HoverSettingsJson,
+    format: FormatSettings,
+    auto_closing_tags: bool,
+    auto_create_quotes: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct CompletionSettings {
+    /// `doublequotes`, `singlequotes` or `empty`
+    attribute_default_value: String,
+    /// Hide the proposals of the end tags and the quotes that `html/autoInsert` inserts
+    hide_auto_complete_proposals: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct HoverSettingsJson {
+    documentation: bool,
+    references: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct FormatSettings {
+    enable: bool,
+    /// No wrapping if it's `0`
+    wrap_line_length: usize,
+    /// The tags separated by commas, they're the defaults of `HTMLFormatConfiguration` if `None`
+    unformatted: Option<String>,
+    content_unformatted: Option<String>,
+    extra_liners: Option<String>,
+    indent_inner_html: bool,
+    preserve_new_lines: bool,
+    max_preserve_new_lines: Option<usize>,
+    indent_handlebars: bool,
+    end_with_newline: bool,
+    /// The same values as `wrap_attributes` of js-beautify, such as `force-aligned`
+    wrap_attributes: String,
+    wrap_attributes_indent_size: Option<u8>,
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        ServerSettings {
+            custom_data: vec![],
+            suggest: HashMap::new(),
+            completion: CompletionSettings::default(),
+            hover: HoverSettingsJson::default(),
+            format: FormatSettings::default(),
+            auto_closing_tags: true,
+            auto_create_quotes: true,
+        }
+    }
+}
+
+impl Default for CompletionSettings {
+    fn default() -> Self {
+        CompletionSettings {
+            attribute_default_value: "doublequotes".to_string(),
+            hide_auto_complete_proposals: false,
+        }
+    }
+}
+
+impl Default for HoverSettingsJson {
+    fn default() -> Self {
+        HoverSettingsJson {
+            documentation: true,
+            references: true,
+        }
+    }
+}
+
+impl Default for FormatSettings {
+    fn default() -> Self {
+        FormatSettings {
+            enable: true,
+            wrap_line_length: 120,
+            unformatted: None,
+            content_unformatted: None,
+            extra_liners: None,
+            indent_inner_html: false,
+            preserve_new_lines: true,
+            max_preserve_new_lines: None,
+            indent_handlebars: false,
+            end_with_newline: false,
+            wrap_attributes: "auto".to_string(),
+            wrap_attributes_indent_size: None,
+        }
+    }
+}
+
+impl ServerSettings {
+    /// Read the settings in the `html` section of `value` or `value` itself,
+    /// the defaults are used if they're invalid
+    fn from_value(value: &Value) -> ServerSettings {
+        let value = value.get("html").unwrap_or(value);
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+
+    fn completion_configuration(&self) -> CompletionConfiguration {
+        CompletionConfiguration {
+            hide_auto_complete_proposals: self.completion.hide_auto_complete_proposals,
+            attribute_default_value: match self.completion.attribute_default_value.as_str() {
+                "singlequotes" => Quotes::Single,
+                "empty" => Quotes::None,
+                _ => Quotes::Double,
+            },
+            provider: self.suggest.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl HoverSettingsJson {
+    fn to_settings(&self) -> HoverSettings {
+        HoverSettings::new(self.documentation, self.references)
+    }
+}
+
+impl FormatSettings {
+    fn to_configuration(&self, options: &FormattingOptions) -> HTMLFormatConfiguration {
+        let mut configuration = HTMLFormatConfiguration {
+            tab_size: options.tab_size as u8,
+            insert_spaces: options.insert_spaces,
+            wrap_line_length: Some(self.wrap_line_length).filter(|length| *length > 0),
+            indent_inner_html: self.indent_inner_html,
+            preserve_new_lines: self.preserve_new_lines,
+            indent_handlebars: self.indent_handlebars,
+            end_with_newline: self.end_with_newline,
+            insert_final_newline: options.insert_final_newline.unwrap_or_default(),
+            trim_trailing_whitespace: options.trim_trailing_whitespace.unwrap_or_default(),
+            wrap_attributes: match self.wrap_attributes.as_str() {
+                "force" => HtmlWrapAttributes::Force,
+                "force-aligned" => HtmlWrapAttributes::ForceAligned,
+                "force-expand-multiline" => HtmlWrapAttributes::ForceExpandMultiline,
+                "aligned-multiple" => HtmlWrapAttributes::AlignedMultiple,
+                "preserve" => HtmlWrapAttributes::Preserve,
+                "preserve-aligned" => HtmlWrapAttributes::PreserveAligned,
+                _ => HtmlWrapAttributes::Auto,
+            },
+            wrap_attributes_indent_size: self.wrap_attributes_indent_size,
+            ..Default::default()
+        };
+        if self.max_preserve_new_lines.is_some() {
+            configuration.max_preserve_new_lines = self.max_preserve_new_lines;
+        }
+        if let Some(unformatted) = &self.unformatted {
+            configuration.unformatted = split_tags(unformatted);
+        }
+        if let Some(content_unformatted) = &self.content_unformatted {
+            configuration.content_unformatted = split_tags(content_unformatted);
+        }
+        if let Some(extra_liners) = &self.extra_liners {
+            configuration.extra_liners = split_tags(extra_liners);
+        }
+        configuration
+    }
+}
+
+/// Split the tags separated by commas, such as `head, body, /html`
+fn split_tags(tags: &str) -> Vec<String> {
+    tags.split(',')
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+/// Read the custom data at the `path`, which is a URI, an absolute path
+/// or a path relative to the first workspace folder
+async fn read_custom_data(
+    path: &str,
+    workspace_folders: &[Url],
+) -> std::result::Result<HTMLDataV1, String> {
+    let file_path = if let Ok(uri) = Url::parse(path) {
+        uri.to_file_path()
+            .map_err(|_| "not a file URI".to_string())?
+    } else {
+        let file_path = std::path::PathBuf::from(path);
+        match workspace_folders
+            .first()
+            .and_then(|uri| uri.to_file_path().ok())
+        {
+            Some(folder) if file_path.is_relative() => folder.join(file_path),
+            _ => file_path,
+        }
+    };
+    let content = tokio::fs::read_to_string(file_path)
+        .await
+        .map_err(|err| err.to_string())?;
+    serde_json::from_str(&content).map_err(|err| err.to_string())
+}
@@ -1,52 +1,158 @@
-use std::collections::HashMap;
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+#[cfg(feature = "sync")]
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
 
-use lazy_static::lazy_static;
 use lsp_textdocument::FullTextDocument;
 use lsp_types::{
-    Command, CompletionItem, CompletionItemKind, CompletionList, CompletionTextEdit, Documentation,
-    InsertTextFormat, Position, Range, TextEdit,
+    Command, CompletionItem, CompletionItemKind, CompletionItemLabelDetails, CompletionItemTag,
+    CompletionList, CompletionTextEdit, Documentation, InsertTextFormat, MarkupContent, Position,
+    PositionEncodingKind, Range, TextEdit,
 };
-use regex::Regex;
+use serde_json::{json, Value};
 
 use crate::{
+    cancellation::is_cancelled,
     language_facts::{
+        aria,
         data_manager::HTMLDataManager,
         data_provider::{
-            self, GenerateDocumentationItem, GenerateDocumentationSetting, IHTMLDataProvider,
+            DocumentationRenderer, GenerateDocumentationItem, GenerateDocumentationSetting,
+            IHTMLDataProvider,
         },
+        input_types, language_tags,
     },
     parser::{
         html_document::{HTMLDocument, Node},
         html_entities,
         html_scanner::{Scanner, ScannerState, TokenType},
     },
-    participant::{HtmlAttributeValueContext, HtmlContentContext, ICompletionParticipant},
-    utils::{markdown, strings},
-    DocumentContext, HTMLLanguageServiceOptions,
+    participant::{
+        HtmlAttributeValueContext, HtmlContentContext, ICompletionParticipant, ParticipantError,
+        ParticipantErrorHandler,
+    },
+    services::html_path_completion::{
+        get_path_completion_target, provide_path_suggestions, PathCompletion,
+    },
+    utils::{markdown, position, strings},
+    CancellationToken, DocumentContext, FileSystemProvider, HTMLLanguageServiceOptions,
 };
 
-lazy_static! {
-    static ref REG_WHITE_SPACE: Regex = Regex::new(r"^\s*$").unwrap();
-    static ref REG_QUOTE: Regex = Regex::new(r#"^["']*$"#).unwrap();
-}
+/// The attributes whose values reference the ids of the elements in the document
+const ID_REFERENCE_ATTRIBUTES: [&str; 13] = [
+    "for",
+    "form",
+    "list",
+    "headers",
+    "popovertarget",
+    "aria-activedescendant",
+    "aria-controls",
+    "aria-describedby",
+    "aria-details",
+    "aria-errormessage",
+    "aria-flowto",
+    "aria-labelledby",
+    "aria-owns",
+];
+
+/// The attributes that are needed when another attribute of the element has the value,
+/// that are the tag, the attribute, the value, whether they are required and the attributes
+const DEPENDENT_ATTRIBUTES: [(&str, &str, &str, bool, &[&str]); 6] = [
+    ("link", "rel", "preload", true, &["as"]),
+    ("link", "rel", "prefetch", false, &["as"]),
+    ("link", "rel", "modulepreload", false, &["as"]),
+    ("link", "rel", "icon", false, &["sizes", "type"]),
+    ("link", "rel", "apple-touch-icon", false, &["sizes"]),
+    ("link", "rel", "alternate", false, &["hreflang", "type"]),
+];
+
+/// The templates of the `content` of `<meta>`, by the value of `name` or `http-equiv`
+const META_CONTENT_TEMPLATES: [(&str, &str, &str); 8] = [
+    (
+        "viewport",
+        "width=device-width, initial-scale=1.0",
+        "width=${1:device-width}, initial-scale=${2:1.0}",
+    ),
+    (
+        "robots",
+        "index, follow",
+        "${1|index,noindex|}, ${2|follow,nofollow|}",
+    ),
+    (
+        "color-scheme",
+        "light dark",
+        "${1|light dark,light,dark,only light|}",
+    ),
+    ("theme-color", "#ffffff", "#${1:ffffff}"),
+    (
+        "referrer",
+        "strict-origin-when-cross-origin",
+        "${1:strict-origin-when-cross-origin}",
+    ),
+    (
+        "content-type",
+        "text/html; charset=utf-8",
+        "text/html; charset=${1:utf-8}",
+    ),
+    ("x-ua-compatible", "IE=edge", "IE=edge"),
+    ("refresh", "0; url=", "${1:0}; url=${2}"),
+];
+
+/// The elements whose content is text, where the tags are not closed automatically
+const RAW_TEXT_ELEMENTS: [&str; 4] = ["script", "style", "textarea", "title"];
+
+/// The attributes that are inserted with the tag by `CompletionConfiguration::element_snippets`
+const ELEMENT_SNIPPET_ATTRIBUTES: [(&str, &[&str]); 12] = [
+    ("a", &["href"]),
+    ("area", &["alt", "href"]),
+    ("base", &["href"]),
+    ("embed", &["src"]),
+    ("form", &["action"]),
+    ("iframe", &["src"]),
+    ("img", &["src", "alt"]),
+    ("label", &["for"]),
+    ("link", &["rel", "href"]),
+    ("optgroup", &["label"]),
+    ("source", &["src"]),
+    ("track", &["src"]),
+];
 
 pub struct HTMLCompletion {
     supports_markdown: bool,
+    supports_label_details: bool,
+    position_encoding: Option<PositionEncodingKind>,
+    doc_renderer: Option<DocumentationRenderer>,
     completion_participants: Vec<Box<dyn ICompletionParticipant>>,
+    participant_error_handler: Option<ParticipantErrorHandler>,
+    file_system_provider: Option<Arc<dyn FileSystemProvider>>,
 }
 
 impl HTMLCompletion {
     pub fn new(ls_options: &HTMLLanguageServiceOptions) -> HTMLCompletion {
         HTMLCompletion {
             supports_markdown: markdown::does_support_markdown(&ls_options),
+            supports_label_details: does_support_label_details(ls_options),
+            position_encoding: ls_options.position_encoding.clone(),
+            doc_renderer: ls_options.doc_renderer.clone(),
             completion_participants: vec![],
+            participant_error_handler: ls_options.participant_error_handler.clone(),
+            file_system_provider: ls_options.file_system_provider.clone(),
         }
     }
 
     pub fn set_completion_participants(
         &mut self,
-        completion_participants: Vec<Box<dyn ICompletionParticipant>>,
+        mut completion_participants: Vec<Box<dyn ICompletionParticipant>>,
     ) {
+        // Stable, the participants of the same priority are called in registration order
+        completion_participants.sort_by_key(|participant| Reverse(participant.priority()));
         self.completion_participants = completion_participants;
     }
 
@@ -59,34 +165,168 @@ impl HTMLCompletion {
         settings: Option<&CompletionConfiguration>,
         data_manager: &HTMLDataManager,
     ) -> CompletionList {
-        let mut result = CompletionList::default();
-        let mut data_providers = vec![];
-        for provider in data_manager.get_data_providers() {
-            if provider.is_applicable(document.language_id()) {
-                if settings.is_none() {
-                    data_providers.push(provider);
-                } else {
-                    let s = settings.unwrap();
-                    let v = s.provider.get(provider.get_id());
-                    if v.is_none() || *v.unwrap() {
-                        data_providers.push(provider);
-                    }
-                }
+        self.complete(
+            document,
+            position,
+            html_document,
+            settings,
+            data_manager,
+            &self.completion_participants,
+            None,
+        )
+        .await
+    }
+
+    /// Like `do_complete`, and the paths of the attribute values are completed
+    /// with the entries of the directories of `FileSystemProvider` resolved against `uri`
+    #[allow(clippy::too_many_arguments)]
+    pub async fn do_complete2(
+        &self,
+        uri: &str,
+        document: &FullTextDocument,
+        position: &Position,
+        html_document: &HTMLDocument,
+        document_context: impl DocumentContext,
+        settings: Option<&CompletionConfiguration>,
+        data_manager: &HTMLDataManager,
+    ) -> CompletionList {
+        let path_completion = self
+            .file_system_provider
+            .as_deref()
+            .map(|file_system_provider| PathCompletion {
+                uri,
+                document_context: &document_context,
+                file_system_provider,
+            });
+        self.complete(
+            document,
+            position,
+            html_document,
+            settings,
+            data_manager,
+            &self.completion_participants,
+            path_completion.as_ref(),
+        )
+        .await
+    }
+
+    /// Like `do_complete` without the participants, which are the only ones to be awaited,
+    /// so it's done at the first poll without an executor
+    #[cfg(feature = "sync")]
+    pub fn do_complete_sync(
+        &self,
+        document: &FullTextDocument,
+        position: &Position,
+        html_document: &HTMLDocument,
+        _document_context: impl DocumentContext,
+        settings: Option<&CompletionConfiguration>,
+        data_manager: &HTMLDataManager,
+    ) -> CompletionList {
+        let future = pin!(self.complete(
+            document,
+            position,
+            html_document,
+            settings,
+            data_manager,
+            &[],
+            None,
+        ));
+        match future.poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(result) => result,
+            Poll::Pending => unreachable!("the completion without the participants doesn't wait"),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn complete(
+        &self,
+        document: &FullTextDocument,
+        position: &Position,
+        html_document: &HTMLDocument,
+        settings: Option<&CompletionConfiguration>,
+        data_manager: &HTMLDataManager,
+        completion_participants: &[Box<dyn ICompletionParticipant>],
+        path_completion: Option<&PathCompletion<'_>>,
+    ) -> CompletionList {
+        let mut result = self
+            .collect_completion(
+                document,
+                position,
+                html_document,
+                settings,
+                data_manager,
+                completion_participants,
+                path_completion,
+            )
+            .await;
+        // The items collected before the cancellation are dropped, the clients re-query if needed
+        if is_cancelled(settings.and_then(|s| s.cancellation_token.as_ref())) {
+            return CompletionList {
+                is_incomplete: true,
+                items: vec![],
+            };
+        }
+        if let Some(max_items) = settings.and_then(|s| s.max_items) {
+            if result.items.len() > max_items {
+                result.items.sort_by(|a, b| {
+                    let a_sort_text = a.sort_text.as_ref().unwrap_or(&a.label);
+                    let b_sort_text = b.sort_text.as_ref().unwrap_or(&b.label);
+                    a_sort_text
+                        .cmp(b_sort_text)
+                        .then_with(|| a.label.cmp(&b.label))
+                });
+                result.items.truncate(max_items);
+                result.is_incomplete = true;
             }
         }
+        result
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn collect_completion(
+        &self,
+        document: &FullTextDocument,
+        position: &Position,
+        html_document: &HTMLDocument,
+        settings: Option<&CompletionConfiguration>,
+        data_manager: &HTMLDataManager,
+        completion_participants: &[Box<dyn ICompletionParticipant>],
+        path_completion: Option<&PathCompletion<'_>>,
+    ) -> CompletionList {
+        let mut result = CompletionList::default();
 
         let void_elements = data_manager.get_void_elements(document.language_id());
 
         let text = document.get_content(None);
-        let offset = document.offset_at(*position).try_into().unwrap();
+        let offset = position::offset_at(document, *position, self.position_encoding.as_ref());
 
         let mut parent_list = vec![];
-        let node = html_document.find_node_before(offset, &mut parent_list);
+        // Before the first element, such as in the comments, it's scanned from the start of the document
+        let start_node = Node::new(0, 0, vec![]);
+        let node = html_document
+            .find_node_before(offset, &mut parent_list)
+            .unwrap_or(&start_node);
 
-        if node.is_none() {
-            return result;
+        // The tags of the elements around the offset, for the data providers scoped to an element such as `<svg>`
+        let tags: Vec<&str> = parent_list
+            .iter()
+            .copied()
+            .chain([node])
+            .filter(|node| node.start < offset && (!node.closed || offset < node.end))
+            .filter_map(|node| node.tag.as_deref())
+            .collect();
+        let mut data_providers = vec![];
+        let mut out_of_scope_providers = vec![];
+        for provider in data_manager.get_applicable_data_providers(document.language_id()) {
+            if settings.is_some_and(|s| s.provider.get(provider.get_id()) == Some(&false)) {
+                continue;
+            }
+            if data_manager.is_in_element_scope(provider.as_ref(), &tags) {
+                data_providers.push(provider);
+            } else {
+                out_of_scope_providers.push(provider);
+            }
         }
-        let node = node.unwrap();
 
         let mut content = CompletionContext {
             offset,
@@ -94,20 +334,27 @@ impl HTMLCompletion {
             document,
             result: &mut result,
             data_providers,
+            out_of_scope_providers,
             void_elements,
             settings,
-            node: &node,
+            node,
             parent_list,
             current_tag: None,
             does_support_markdown: self.supports_markdown,
+            does_support_label_details: self.supports_label_details,
             html_document,
             current_attribute_name: String::new(),
-            completion_participants: &self.completion_participants,
+            completion_participants,
+            participant_error_handler: self.participant_error_handler.as_ref(),
             position,
+            position_encoding: self.position_encoding.as_ref(),
+            doc_renderer: self.doc_renderer.as_ref(),
             data_manager,
+            path_completion,
         };
 
         let mut scanner = Scanner::new(text, node.start, ScannerState::WithinContent, true);
+        scanner.set_template_syntax(data_manager.is_template_language(document.language_id()));
 
         let mut token = scanner.scan();
 
@@ -243,6 +490,20 @@ impl HTMLCompletion {
                         return result;
                     }
                 }
+                TokenType::StartCommentTag => {
+                    if offset == scanner.get_token_end() {
+                        content.collect_region_suggestions(scanner.get_token_end());
+                        return result;
+                    } else if offset < scanner.get_token_end() {
+                        return result;
+                    }
+                }
+                TokenType::Comment => {
+                    if offset <= scanner.get_token_end() {
+                        content.collect_region_suggestions(scanner.get_token_offset());
+                        return result;
+                    }
+                }
                 TokenType::EndTagOpen => {
                     if offset <= scanner.get_token_end() {
                         let after_open_bracket = scanner.get_token_offset() + 1;
@@ -264,15 +525,15 @@ impl HTMLCompletion {
                     if offset <= scanner.get_token_end() {
                         let mut start = scanner.get_token_offset() - 1;
                         while start > 0 {
-                            let ch = text.get(start..start + 1);
-                            if ch == Some("/") {
+                            let ch = strings::byte_at(text, start);
+                            if ch == Some(b'/') {
                                 content.collect_close_tag_suggestions(
                                     start,
                                     false,
                                     scanner.get_token_end(),
                                 );
                                 return result;
-                            } else if !is_white_space(&ch.unwrap().to_string()) {
+                            } else if !ch.is_some_and(is_white_space) {
                                 break;
                             }
                             start -= 1;
@@ -291,24 +552,110 @@ impl HTMLCompletion {
         result
     }
 
+    /// Resolve the documentation of the completion item
+    /// that was omitted by `CompletionConfiguration::lazy_documentation`
+    pub fn resolve_completion_item(
+        &self,
+        mut item: CompletionItem,
+        data_manager: &HTMLDataManager,
+    ) -> CompletionItem {
+        if item.documentation.is_none() {
+            if let Some(data) = &item.data {
+                item.documentation = self
+                    .resolve_documentation(data, data_manager)
+                    .map(Documentation::MarkupContent);
+            }
+        }
+        item
+    }
+
+    fn resolve_documentation(
+        &self,
+        data: &Value,
+        data_manager: &HTMLDataManager,
+    ) -> Option<MarkupContent> {
+        let provider_id = data.get("provider")?.as_str()?;
+        let provider = data_manager
+            .get_data_providers()
+            .iter()
+            .find(|provider| provider.get_id() == provider_id)?;
+        let tag = data.get("tag")?.as_str()?;
+        let setting = GenerateDocumentationSetting {
+            documentation: true,
+            references: data
+                .get("references")
+                .and_then(Value::as_bool)
+                .unwrap_or(true),
+            baseline: data
+                .get("baseline")
+                .and_then(Value::as_bool)
+                .unwrap_or(true),
+            does_support_markdown: self.supports_markdown,
+        };
+        let doc_renderer = self.doc_renderer.as_ref();
+
+        let attribute = data.get("attribute").and_then(|v| v.as_str());
+        let value = data.get("value").and_then(|v| v.as_str());
+        match (attribute, value) {
+            (Some(attribute), Some(value)) => {
+                let item = data_manager
+                    .provide_values(provider.as_ref(), tag, attribute)
+                    .into_iter()
+                    .find(|v| v.name == value)?;
+                let names = [tag, attribute, value];
+                generate_documentation(
+                    doc_renderer,
+                    data_manager,
+                    provider_id,
+                    &names,
+                    item,
+                    setting,
+                )
+            }
+            (Some(attribute), None) => {
+                let item = provider
+                    .provide_attributes(tag)
+                    .into_iter()
+                    .find(|a| a.name == attribute)?;
+                let names = [tag, attribute];
+                generate_documentation(
+                    doc_renderer,
+                    data_manager,
+                    provider_id,
+                    &names,
+                    item,
+                    setting,
+                )
+            }
+            _ => {
+                let item = provider.provide_tags().iter().find(|t| t.name == tag)?;
+                generate_documentation(
+                    doc_renderer,
+                    data_manager,
+                    provider_id,
+                    &[tag],
+                    item,
+                    setting,
+                )
+            }
+        }
+    }
+
     pub fn do_quote_complete(
+        &self,
         document: &FullTextDocument,
         position: &Position,
         html_document: &HTMLDocument,
         settings: Option<&CompletionConfiguration>,
     ) -> Option<String> {
-        let offset = document.offset_at(*position) as usize;
+        let offset = position::offset_at(document, *position, self.position_encoding.as_ref());
         if offset == 0 {
             return None;
         }
         if document.get_content(None).get(offset - 1..offset) != Some("=") {
             return None;
         }
-        let default_value = if let Some(settings) = settings {
-            settings.attribute_default_value
-        } else {
-            Quotes::Double
-        };
+        let default_value = get_attribute_quotes(settings, html_document);
         if default_value == Quotes::None {
             return None;
         }
@@ -355,17 +702,29 @@ impl HTMLCompletion {
         document: &FullTextDocument,
         position: &Position,
         html_document: &HTMLDocument,
+        settings: Option<&CompletionConfiguration>,
         data_manager: &HTMLDataManager,
     ) -> Option<String> {
-        let offset = document.offset_at(*position) as usize;
+        let offset = position::offset_at(document, *position, self.position_encoding.as_ref());
         if offset == 0 {
             return None;
         }
+        let is_excluded = |tag: &str| {
+            settings.is_some_and(|s| {
+                s.auto_close_exclusions
+                    .iter()
+                    .any(|t| t.eq_ignore_ascii_case(tag))
+            })
+        };
         let char = document.get_content(None).get(offset - 1..offset);
         if char == Some(">") {
             let void_elements = data_manager.get_void_elements(document.language_id());
-            let node = html_document.find_node_before(offset, &mut vec![])?;
+            let mut parent_list = vec![];
+            let node = html_document.find_node_before(offset, &mut parent_list)?;
             let node_tag = node.tag.as_ref()?;
+            if is_excluded(node_tag) || is_in_raw_text(&parent_list) {
+                return None;
+            }
             if !data_manager.is_void_element(&node_tag, &void_elements)
                 && node.start < offset
                 && !node
@@ -378,6 +737,8 @@ impl HTMLCompletion {
                     ScannerState::WithinContent,
                     false,
                 );
+                scanner
+                    .set_template_syntax(data_manager.is_template_language(document.language_id()));
                 let mut token = scanner.scan();
                 while token != TokenType::EOS && scanner.get_token_end() <= offset {
                     if token == TokenType::StartTagClose && scanner.get_token_end() == offset {
@@ -400,19 +761,23 @@ impl HTMLCompletion {
                 node = parent_list.pop()?;
             }
             let node_tag = node.tag.as_ref()?;
+            if is_excluded(node_tag) || is_in_raw_text(&parent_list) {
+                return None;
+            }
             let mut scanner = Scanner::new(
                 document.get_content(None),
                 node.start,
                 ScannerState::WithinContent,
                 false,
             );
+            scanner.set_template_syntax(data_manager.is_template_language(document.language_id()));
             let mut token = scanner.scan();
             while token != TokenType::EOS && scanner.get_token_end() <= offset {
                 if token == TokenType::EndTagOpen && scanner.get_token_end() == offset {
-                    if document.get_content(None).get(offset..offset + 1) != Some(">") {
+                    if strings::byte_at(document.get_content(None), offset) != Some(b'>') {
                         return Some(format!("{}>", node_tag));
                     } else {
-                        return Some(node_tag.clone());
+                        return Some(node_tag.to_string());
                     }
                 }
                 token = scanner.scan();
@@ -428,31 +793,117 @@ struct CompletionContext<'a> {
     offset: usize,
     document: &'a FullTextDocument,
     data_providers: Vec<&'a Box<dyn IHTMLDataProvider>>,
+    /// The data providers scoped to the elements that don't contain the offset, see `HTMLDataManager::set_element_scope`
+    out_of_scope_providers: Vec<&'a Box<dyn IHTMLDataProvider>>,
     void_elements: Vec<String>,
     settings: Option<&'a CompletionConfiguration>,
     node: &'a Node,
     parent_list: Vec<&'a Node>,
     current_tag: Option<String>,
     does_support_markdown: bool,
+    does_support_label_details: bool,
     html_document: &'a HTMLDocument,
     current_attribute_name: String,
-    completion_participants: &'a Vec<Box<dyn ICompletionParticipant>>,
+    completion_participants: &'a [Box<dyn ICompletionParticipant>],
+    participant_error_handler: Option<&'a ParticipantErrorHandler>,
     position: &'a Position,
+    position_encoding: Option<&'a PositionEncodingKind>,
+    doc_renderer: Option<&'a DocumentationRenderer>,
     data_manager: &'a HTMLDataManager,
+    path_completion: Option<&'a PathCompletion<'a>>,
 }
 
 impl CompletionContext<'_> {
+    /// Generate the documentation by the `doc_renderer` of the options if it's provided,
+    /// otherwise by the data manager
+    fn generate_documentation(
+        &self,
+        provider_id: &str,
+        names: &[&str],
+        item: impl Into<GenerateDocumentationItem>,
+        setting: GenerateDocumentationSetting,
+    ) -> Option<MarkupContent> {
+        generate_documentation(
+            self.doc_renderer,
+            self.data_manager,
+            provider_id,
+            names,
+            item,
+            setting,
+        )
+    }
+
+    /// The setting of the documentation with the references and the Baseline status of the settings
+    fn documentation_setting(&self, does_support_markdown: bool) -> GenerateDocumentationSetting {
+        GenerateDocumentationSetting {
+            documentation: true,
+            references: self.settings.is_none_or(|s| s.references),
+            baseline: self.settings.is_none_or(|s| s.baseline),
+            does_support_markdown,
+        }
+    }
+
+    /// Generate the documentation of the completion item, `names` identify the item in the data provider,
+    /// see `HTMLDataManager::generate_documentation`.
+    ///
+    /// Only the `data` is attached to resolve it later if `lazy_documentation` is enabled,
+    /// the `data` keeps the references and the Baseline status that are hidden
+    fn get_documentation(
+        &self,
+        item: impl Into<GenerateDocumentationItem>,
+        provider_id: &str,
+        names: &[&str],
+        setting: GenerateDocumentationSetting,
+    ) -> (Option<Documentation>, Option<Value>) {
+        if self.settings.is_some_and(|s| s.lazy_documentation) {
+            let mut data = json!({ "provider": provider_id });
+            for (key, name) in ["tag", "attribute", "value"].into_iter().zip(names) {
+                data[key] = Value::from(*name);
+            }
+            if !setting.references {
+                data["references"] = Value::Bool(false);
+            }
+            if !setting.baseline {
+                data["baseline"] = Value::Bool(false);
+            }
+            (None, Some(data))
+        } else {
+            (
+                self.generate_documentation(provider_id, names, item, setting)
+                    .map(Documentation::MarkupContent),
+                None,
+            )
+        }
+    }
+
+    /// The items of the participant, or none if it failed and the error is reported
+    fn participant_items(
+        &self,
+        items: Result<Vec<CompletionItem>, ParticipantError>,
+    ) -> Vec<CompletionItem> {
+        items.unwrap_or_else(|error| {
+            if let Some(handler) = self.participant_error_handler {
+                handler(&error);
+            }
+            vec![]
+        })
+    }
+
     fn get_replace_range(&self, replace_start: usize, replace_end: usize) -> Range {
         let mut replace_start = replace_start;
         if replace_start > self.offset {
             replace_start = self.offset;
         }
         Range {
-            start: self.document.position_at(replace_start.try_into().unwrap()),
-            end: self.document.position_at(replace_end.try_into().unwrap()),
+            start: self.position_at(replace_start),
+            end: self.position_at(replace_end),
         }
     }
 
+    fn position_at(&self, offset: usize) -> Position {
+        position::position_at(self.document, offset, self.position_encoding)
+    }
+
     fn scan_next_for_end_pos(
         &mut self,
         scanner: &mut Scanner,
@@ -476,46 +927,106 @@ impl CompletionContext<'_> {
 
     fn collect_open_tag_suggestions(&mut self, after_open_bracket: usize, tag_name_end: usize) {
         let range = self.get_replace_range(after_open_bracket, tag_name_end);
-        for provider in &self.data_providers {
+        // The attributes and the end of the tag can only be inserted if they have not been written
+        let element_snippets = self.settings.is_some_and(|s| s.element_snippets)
+            && ![
+                TokenType::StartTagClose,
+                TokenType::StartTagSelfClose,
+                TokenType::AttributeName,
+            ]
+            .into_iter()
+            .any(|token| {
+                is_followed_by(
+                    self.document.get_content(None),
+                    tag_name_end,
+                    ScannerState::WithinTag,
+                    token,
+                )
+            });
+        let data_providers = self
+            .data_providers
+            .iter()
+            .map(|provider| (provider, true))
+            .chain(
+                self.out_of_scope_providers
+                    .iter()
+                    .map(|provider| (provider, false)),
+            );
+        for (provider, in_scope) in data_providers {
+            if self.is_cancelled() {
+                return;
+            }
             for tag in provider.provide_tags() {
-                let documentation = data_provider::generate_documentation(
-                    GenerateDocumentationItem {
-                        description: tag.description.clone(),
-                        references: tag.references.clone(),
-                    },
-                    GenerateDocumentationSetting {
-                        documentation: true,
-                        references: true,
-                        does_support_markdown: true,
-                    },
+                if self.is_hidden(tag.deprecated) {
+                    continue;
+                }
+                // Outside of its scope, only the element of the scope is proposed, such as `<svg>`
+                if !in_scope && !self.is_element_scope_tag(provider.get_id(), &tag.name) {
+                    continue;
+                }
+                let (documentation, data) = self.get_documentation(
+                    tag,
+                    provider.get_id(),
+                    &[&tag.name],
+                    self.documentation_setting(true),
                 );
-                let documentation = if let Some(documentation) = documentation {
-                    Some(Documentation::MarkupContent(documentation))
-                } else {
-                    None
-                };
+                let label_details = self.get_label_details(provider.get_id());
                 self.result.items.push(CompletionItem {
                     label: tag.name.clone(),
+                    tags: deprecated_tags(tag.deprecated),
+                    label_details,
                     kind: Some(CompletionItemKind::PROPERTY),
                     documentation,
+                    data,
                     text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
                         range,
-                        tag.name.clone(),
+                        if element_snippets {
+                            self.get_element_snippet(&tag.name)
+                        } else {
+                            tag.name.clone()
+                        },
                     ))),
-                    insert_text_format: Some(InsertTextFormat::PLAIN_TEXT),
+                    insert_text_format: Some(if element_snippets {
+                        InsertTextFormat::SNIPPET
+                    } else {
+                        InsertTextFormat::PLAIN_TEXT
+                    }),
                     ..Default::default()
                 });
             }
         }
     }
 
+    /// The snippet of the element without the open bracket, such as `a href="$1">$2</a>`
+    fn get_element_snippet(&self, tag: &str) -> String {
+        let quote = match get_attribute_quotes(self.settings, self.html_document) {
+            Quotes::None => "",
+            Quotes::Single => "'",
+            Quotes::Double | Quotes::Auto => "\"",
+        };
+        let mut snippet = tag.to_string();
+        let mut tab_stop = 0;
+        if let Some((_, attributes)) = ELEMENT_SNIPPET_ATTRIBUTES
+            .iter()
+            .find(|(t, _)| t.eq_ignore_ascii_case(tag))
+        {
+            for attribute in *attributes {
+                tab_stop += 1;
+                snippet += &format!(" {}={}${}{}", attribute, quote, tab_stop, quote);
+            }
+        }
+        snippet.push('>');
+        if !self.data_manager.is_void_element(tag, &self.void_elements) {
+            snippet += &format!("${}</{}>", tab_stop + 1, tag);
+        }
+        snippet
+    }
+
     fn collect_attribute_name_suggestions(&mut self, name_start: usize, name_end: usize) {
         let mut replace_end = self.offset;
         let text = self.document.get_content(None);
         while replace_end < name_end
-            && text
-                .get(replace_end..replace_end + 1)
-                .is_some_and(|c| c != "<")
+            && strings::byte_at(text, replace_end).is_some_and(|c| c != b'<')
         {
             replace_end += 1;
         }
@@ -532,23 +1043,76 @@ impl CompletionContext<'_> {
             ScannerState::AfterAttributeName,
             TokenType::DelimiterAssign,
         ) {
-            let quotes = if let Some(settings) = self.settings {
-                settings.attribute_default_value
-            } else {
-                Quotes::Double
-            };
-            match quotes {
+            match get_attribute_quotes(self.settings, self.html_document) {
                 Quotes::None => value = "=$1",
                 Quotes::Single => value = "='$1'",
-                Quotes::Double => value = r#"="$1""#,
+                Quotes::Double | Quotes::Auto => value = r#"="$1""#,
             }
         }
 
         let mut existing_attributes = self.get_existing_attributes();
         existing_attributes.insert(current_attribute.to_string(), false);
 
+        // The `aria-*` attributes that aren't supported by the role of the element are sorted to the end
+        let node = self.node;
+        let role = aria::get_role(self.current_tag.as_ref().unwrap(), |name| {
+            node.attributes.get(name).map(|attr| {
+                attr.value
+                    .as_deref()
+                    .map_or("", |v| v.trim_matches(|c| c == '"' || c == '\''))
+            })
+        });
+
+        // The attributes of `<input>` that apply to its type are sorted to the front, and the others to the end
+        let input_type = self
+            .current_tag
+            .as_ref()
+            .filter(|tag| tag.eq_ignore_ascii_case("input"))
+            .and_then(|_| {
+                node.attributes
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case("type"))
+            })
+            .and_then(|(_, attr)| attr.value.as_deref())
+            .map(|value| value.trim_matches(|c| c == '"' || c == '\'').to_string());
+
+        // The attributes that the values of the other attributes depend on are sorted to the front
+        let dependent_attributes: Vec<(&str, String)> = DEPENDENT_ATTRIBUTES
+            .iter()
+            .filter(|(tag, attribute, value, _, _)| {
+                self.current_tag
+                    .as_ref()
+                    .is_some_and(|t| t.eq_ignore_ascii_case(tag))
+                    && node
+                        .attributes
+                        .iter()
+                        .find(|(name, _)| name.eq_ignore_ascii_case(attribute))
+                        .and_then(|(_, attr)| attr.value.as_deref())
+                        .is_some_and(|v| {
+                            v.trim_matches(|c| c == '"' || c == '\'')
+                                .split_ascii_whitespace()
+                                .any(|v| v.eq_ignore_ascii_case(value))
+                        })
+            })
+            .flat_map(|(_, attribute, value, required, dependents)| {
+                let detail = format!(
+                    "{} by {}=\"{}\"",
+                    if *required { "Required" } else { "Recommended" },
+                    attribute,
+                    value
+                );
+                dependents.iter().map(move |d| (*d, detail.clone()))
+            })
+            .collect();
+
         for provider in &self.data_providers {
+            if self.is_cancelled() {
+                return;
+            }
             for attr in provider.provide_attributes(&self.current_tag.as_ref().unwrap()) {
+                if self.is_hidden(attr.deprecated) {
+                    continue;
+                }
                 if existing_attributes.get(&attr.name).is_some_and(|v| *v) {
                     continue;
                 }
@@ -573,26 +1137,44 @@ impl CompletionContext<'_> {
                 } else {
                     CompletionItemKind::VALUE
                 });
-                let documentation = data_provider::generate_documentation(
-                    GenerateDocumentationItem {
-                        description: attr.description.clone(),
-                        references: attr.references.clone(),
-                    },
-                    GenerateDocumentationSetting {
-                        documentation: true,
-                        references: true,
-                        does_support_markdown: self.does_support_markdown,
-                    },
+                let (documentation, data) = self.get_documentation(
+                    attr,
+                    provider.get_id(),
+                    &[self.current_tag.as_deref().unwrap_or_default(), &attr.name],
+                    self.documentation_setting(self.does_support_markdown),
                 );
-                let documentation = if let Some(documentation) = documentation {
-                    Some(Documentation::MarkupContent(documentation))
+                let mut detail = dependent_attributes
+                    .iter()
+                    .find(|(name, _)| *name == attr.name)
+                    .map(|(_, detail)| detail.clone());
+                let applicable = input_type
+                    .as_ref()
+                    .and_then(|input_type| input_types::is_applicable(input_type, &attr.name));
+                let sort_text = if detail.is_some() || applicable == Some(true) {
+                    Some(format!("!{}", attr.name))
+                } else if applicable == Some(false) {
+                    detail = Some(format!(
+                        "Not applicable to type=\"{}\"",
+                        input_type.as_deref().unwrap_or_default()
+                    ));
+                    Some(format!("~{}", attr.name))
                 } else {
-                    None
+                    role.as_ref()
+                        .filter(|_| attr.name.starts_with("aria-"))
+                        .and_then(|role| aria::is_supported_by_role(role, &attr.name))
+                        .is_some_and(|supported| !supported)
+                        .then(|| format!("~{}", attr.name))
                 };
+                let label_details = self.get_label_details(provider.get_id());
                 self.result.items.push(CompletionItem {
                     label: attr.name.clone(),
+                    tags: deprecated_tags(attr.deprecated),
+                    label_details,
                     kind,
+                    detail,
                     documentation,
+                    data,
+                    sort_text,
                     text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(range, code_snippet))),
                     insert_text_format: Some(InsertTextFormat::SNIPPET),
                     command,
@@ -608,31 +1190,18 @@ impl CompletionContext<'_> {
         range: Range,
         existing_attributes: &HashMap<String, bool>,
     ) {
+        if self.settings.is_some_and(|s| s.hide_data_attributes) {
+            return;
+        }
         let data_attr = "data-";
         let mut data_attributes: HashMap<String, String> = HashMap::new();
         data_attributes.insert(data_attr.to_string(), format!(r#"{data_attr}$1="$2""#));
 
-        fn add_node_data_attributes(
-            data_attributes: &mut HashMap<String, String>,
-            node: &Node,
-            existing_attributes: &HashMap<String, bool>,
-            data_attr: &str,
-        ) {
-            for attr in node.attribute_names() {
-                if attr.starts_with(data_attr)
-                    && !data_attributes.contains_key(&attr[..])
-                    && !existing_attributes.contains_key(attr)
-                {
-                    data_attributes.insert(attr.to_string(), format!(r#"{attr}="$1""#));
-                }
+        for attr in self.html_document.get_data_attributes().into_keys() {
+            if !data_attributes.contains_key(&attr) && !existing_attributes.contains_key(&attr) {
+                let value = format!(r#"{attr}="$1""#);
+                data_attributes.insert(attr, value);
             }
-            for child in &node.children {
-                add_node_data_attributes(data_attributes, child, existing_attributes, data_attr);
-            }
-        }
-
-        for root in &self.html_document.roots {
-            add_node_data_attributes(&mut data_attributes, root, existing_attributes, data_attr);
         }
 
         for (attr, value) in data_attributes {
@@ -653,17 +1222,18 @@ impl CompletionContext<'_> {
         let range: Range;
         let add_quotes: bool;
         let value_prefix;
+        let word_start;
         if self.offset > value_start
             && self.offset <= value_end
-            && is_quote(&self.text[value_start..value_start + 1])
+            && strings::byte_at(self.text, value_start).is_some_and(is_quote)
         {
             // inside quoted attribute
             let value_content_start = value_start + 1;
             let mut value_content_end = value_end;
             // valueEnd points to he char after quote, which encloses the replace range
             if value_end > value_start
-                && self.text.get(value_end - 1..value_end)
-                    == self.text.get(value_start..value_start + 1)
+                && strings::byte_at(self.text, value_end - 1)
+                    == strings::byte_at(self.text, value_start)
             {
                 value_content_end -= 1;
             }
@@ -671,6 +1241,7 @@ impl CompletionContext<'_> {
             let ws_before = get_word_start(self.text, self.offset, value_content_start);
             let ws_after = get_word_end(self.text, self.offset, value_content_end);
             range = self.get_replace_range(ws_before, ws_after);
+            word_start = ws_before;
             value_prefix = if self.offset >= value_content_start && self.offset < value_content_end
             {
                 &self.text[value_content_start..self.offset]
@@ -680,10 +1251,15 @@ impl CompletionContext<'_> {
             add_quotes = false;
         } else {
             range = self.get_replace_range(value_start, value_end);
-            value_prefix = &self.text[value_start..self.offset];
+            // the offset may be in the whitespace after `=` that is before `value_start`
+            value_prefix = self.text.get(value_start..self.offset).unwrap_or_default();
+            word_start = value_start;
             add_quotes = true;
         }
 
+        let start = self.result.items.len();
+        let mut hide_builtins = false;
+        let mut after_builtins = vec![];
         if self.completion_participants.len() > 0 {
             let tag = self
                 .current_tag
@@ -693,58 +1269,141 @@ impl CompletionContext<'_> {
             let attribute = self.current_attribute_name.to_lowercase();
             let full_range = self.get_replace_range(value_start, value_end);
             for participant in self.completion_participants {
-                self.result.items.append(
-                    &mut participant
-                        .on_html_attribute_value(HtmlAttributeValueContext {
-                            document: FullTextDocument::new(
-                                self.document.language_id().to_string(),
-                                self.document.version(),
-                                self.document.get_content(None).to_string(),
-                            ),
-                            html_document: self.html_document.clone(),
-                            position: *self.position,
-                            tag: tag.clone(),
-                            attribute: attribute.clone(),
-                            value: value_prefix.to_string(),
-                            range: full_range,
-                        })
-                        .await,
+                if self.is_cancelled() {
+                    return;
+                }
+                let context = HtmlAttributeValueContext {
+                    document: FullTextDocument::new(
+                        self.document.language_id().to_string(),
+                        self.document.version(),
+                        self.document.get_content(None).to_string(),
+                    ),
+                    html_document: self.html_document.clone(),
+                    position: *self.position,
+                    tag: tag.clone(),
+                    attribute: attribute.clone(),
+                    value: value_prefix.to_string(),
+                    range: full_range,
+                };
+                hide_builtins |= participant.hide_builtin_attribute_values(&context);
+                let mut items =
+                    self.participant_items(participant.on_html_attribute_value(context).await);
+                if participant.priority() < 0 {
+                    after_builtins.append(&mut items);
+                } else {
+                    self.result.items.append(&mut items);
+                }
+            }
+        }
+
+        if !hide_builtins {
+            self.collect_builtin_attribute_value_suggestions(
+                range,
+                add_quotes,
+                value_start,
+                value_end,
+                word_start,
+            );
+            self.collect_path_suggestions(value_start, value_end).await;
+        }
+        if !self.completion_participants.is_empty() {
+            self.result.items.append(&mut after_builtins);
+            dedup_by_label(&mut self.result.items, start);
+        }
+    }
+
+    /// The entries of the directory of the path at the cursor, for the path attributes such as `src`
+    /// and the image candidates of `srcset`, see `do_complete2`
+    async fn collect_path_suggestions(&mut self, value_start: usize, value_end: usize) {
+        let Some(path_completion) = self.path_completion else {
+            return;
+        };
+        let mut content_start = value_start;
+        let mut content_end = value_end;
+        if let Some(quote) = strings::byte_at(self.text, value_start).filter(|c| is_quote(*c)) {
+            content_start += 1;
+            if value_end > content_start
+                && strings::byte_at(self.text, value_end - 1) == Some(quote)
+            {
+                content_end -= 1;
+            }
+        }
+        if self.offset < content_start || self.offset > content_end || self.is_cancelled() {
+            return;
+        }
+        let tag = self
+            .current_tag
+            .as_deref()
+            .unwrap_or_default()
+            .to_lowercase();
+        let attribute = self.current_attribute_name.to_lowercase();
+        match get_path_completion_target(
+            self.data_manager,
+            &tag,
+            &attribute,
+            &self.text[content_start..content_end],
+            self.offset - content_start,
+        ) {
+            Ok(target) => {
+                let range = Range::new(
+                    self.position_at(content_start + target.replace_range.start),
+                    self.position_at(content_start + target.replace_range.end),
                 );
+                let mut items =
+                    provide_path_suggestions(path_completion, &target.directory, range).await;
+                self.result.items.append(&mut items);
             }
+            Err(incomplete) => self.result.is_incomplete |= incomplete,
         }
+    }
 
+    /// The values of the data providers, and the class names, the ids, the fragments,
+    /// the templates of `<meta>` and the language tags in the document
+    fn collect_builtin_attribute_value_suggestions(
+        &mut self,
+        range: Range,
+        add_quotes: bool,
+        value_start: usize,
+        value_end: usize,
+        word_start: usize,
+    ) {
         for provider in &self.data_providers {
-            for value in provider.provide_values(
+            if self.is_cancelled() {
+                return;
+            }
+            for value in self.data_manager.provide_values(
+                provider.as_ref(),
                 &self.current_tag.clone().unwrap_or_default(),
                 &self.current_attribute_name,
             ) {
+                if self.is_hidden(value.deprecated) {
+                    continue;
+                }
                 let insert_text = if add_quotes {
                     format!(r#""{}""#, value.name)
                 } else {
                     value.name.clone()
                 };
 
-                let documentation = data_provider::generate_documentation(
-                    GenerateDocumentationItem {
-                        description: value.description.clone(),
-                        references: value.references.clone(),
-                    },
-                    GenerateDocumentationSetting {
-                        documentation: true,
-                        references: true,
-                        does_support_markdown: self.does_support_markdown,
-                    },
+                let (documentation, data) = self.get_documentation(
+                    value,
+                    provider.get_id(),
+                    &[
+                        self.current_tag.as_deref().unwrap_or_default(),
+                        &self.current_attribute_name,
+                        &value.name,
+                    ],
+                    self.documentation_setting(self.does_support_markdown),
                 );
-                let documentation = if let Some(documentation) = documentation {
-                    Some(Documentation::MarkupContent(documentation))
-                } else {
-                    None
-                };
+                let label_details = self.get_label_details(provider.get_id());
                 self.result.items.push(CompletionItem {
                     label: value.name.clone(),
+                    tags: deprecated_tags(value.deprecated),
+                    label_details,
                     filter_text: Some(insert_text.clone()),
                     kind: Some(CompletionItemKind::UNIT),
                     documentation,
+                    data,
                     text_edit: Some(CompletionTextEdit::Edit(TextEdit {
                         range,
                         new_text: insert_text.clone(),
@@ -754,6 +1413,200 @@ impl CompletionContext<'_> {
                 });
             }
         }
+
+        let attribute = self.current_attribute_name.to_lowercase();
+        if attribute == "class" {
+            self.collect_class_name_suggestions(range, add_quotes);
+        } else if ID_REFERENCE_ATTRIBUTES.contains(&attribute.as_str()) {
+            self.collect_id_suggestions(range, add_quotes);
+        } else if attribute == "href" {
+            self.collect_fragment_suggestions(value_start, value_end);
+        } else if attribute == "content"
+            && self
+                .current_tag
+                .as_ref()
+                .is_some_and(|tag| tag.eq_ignore_ascii_case("meta"))
+        {
+            self.collect_meta_content_suggestions(value_start, value_end);
+        } else if attribute == "lang" || attribute == "hreflang" {
+            let prefix = &self.text[word_start.min(self.offset)..self.offset];
+            self.collect_language_tag_suggestions(range, add_quotes, prefix);
+        }
+    }
+
+    /// Propose the templates of the `content` according to the `name` or `http-equiv` of `<meta>`,
+    /// the whole value is replaced
+    fn collect_meta_content_suggestions(&mut self, value_start: usize, value_end: usize) {
+        let mut start = value_start;
+        let mut end = value_end;
+        let mut add_quotes = true;
+        if let Some(quote) = strings::byte_at(self.text, start).filter(|c| is_quote(*c)) {
+            start += 1;
+            if end > start && strings::byte_at(self.text, end - 1) == Some(quote) {
+                end -= 1;
+            }
+            add_quotes = false;
+        }
+        let range = self.get_replace_range(start, end);
+        let metadata: Vec<String> = self
+            .node
+            .attributes
+            .iter()
+            .filter(|(name, _)| {
+                name.eq_ignore_ascii_case("name") || name.eq_ignore_ascii_case("http-equiv")
+            })
+            .filter_map(|(_, attr)| attr.value.as_deref())
+            .map(|value| value.trim_matches(|c| c == '"' || c == '\'').to_lowercase())
+            .collect();
+        for (name, label, snippet) in META_CONTENT_TEMPLATES {
+            if !metadata.iter().any(|m| m == name) {
+                continue;
+            }
+            let insert_text = if add_quotes {
+                format!(r#""{}""#, snippet)
+            } else {
+                snippet.to_string()
+            };
+            self.result.items.push(CompletionItem {
+                label: label.to_string(),
+                kind: Some(CompletionItemKind::SNIPPET),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range,
+                    new_text: insert_text,
+                })),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Propose the language subtags, and the region variants after the language subtag and `-`
+    fn collect_language_tag_suggestions(&mut self, range: Range, add_quotes: bool, prefix: &str) {
+        let language_tags: Vec<(String, String)> =
+            if let Some((language, _)) = prefix.split_once('-') {
+                language_tags::get_region_variants(language)
+            } else {
+                language_tags::LANGUAGES
+                    .iter()
+                    .map(|(subtag, name, _)| (subtag.to_string(), name.to_string()))
+                    .collect()
+            };
+        for (language_tag, name) in language_tags {
+            let insert_text = if add_quotes {
+                format!(r#""{}""#, language_tag)
+            } else {
+                language_tag.clone()
+            };
+            self.result.items.push(CompletionItem {
+                label: language_tag,
+                filter_text: Some(insert_text.clone()),
+                kind: Some(CompletionItemKind::UNIT),
+                documentation: Some(Documentation::String(name)),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range,
+                    new_text: insert_text,
+                })),
+                insert_text_format: Some(InsertTextFormat::PLAIN_TEXT),
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Propose the ids and the names of `<a>` as the fragment of `href="#..."`,
+    /// only the fragment after `#` is replaced
+    fn collect_fragment_suggestions(&mut self, value_start: usize, value_end: usize) {
+        let mut start = value_start;
+        let mut end = value_end;
+        if let Some(quote) = strings::byte_at(self.text, start).filter(|c| is_quote(*c)) {
+            start += 1;
+            if end > start && strings::byte_at(self.text, end - 1) == Some(quote) {
+                end -= 1;
+            }
+        }
+        if strings::byte_at(self.text, start) != Some(b'#') || self.offset <= start {
+            return;
+        }
+        let range = self.get_replace_range(start + 1, end);
+        let html_document = self.html_document;
+        for fragment in html_document.index().fragments() {
+            if fragment.is_empty() {
+                continue;
+            }
+            self.result.items.push(CompletionItem {
+                label: format!("#{}", fragment),
+                filter_text: Some(fragment.to_string()),
+                kind: Some(CompletionItemKind::REFERENCE),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range,
+                    new_text: fragment.to_string(),
+                })),
+                insert_text_format: Some(InsertTextFormat::PLAIN_TEXT),
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Propose the ids declared in the document, except the ones already in the value
+    fn collect_id_suggestions(&mut self, range: Range, add_quotes: bool) {
+        let existing_ids: Vec<&str> = self
+            .node
+            .attributes
+            .get(self.current_attribute_name.as_str())
+            .and_then(|attr| attr.value.as_deref())
+            .map_or(vec![], |value| {
+                value
+                    .trim_matches(|c| c == '"' || c == '\'')
+                    .split_ascii_whitespace()
+                    .collect()
+            });
+        let html_document = self.html_document;
+        for id in html_document.index().ids() {
+            if id.is_empty() || existing_ids.contains(&id) {
+                continue;
+            }
+            let insert_text = if add_quotes {
+                format!(r#""{}""#, id)
+            } else {
+                id.to_string()
+            };
+            self.result.items.push(CompletionItem {
+                label: id.to_string(),
+                filter_text: Some(insert_text.clone()),
+                kind: Some(CompletionItemKind::REFERENCE),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range,
+                    new_text: insert_text,
+                })),
+                insert_text_format: Some(InsertTextFormat::PLAIN_TEXT),
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Propose the class names used elsewhere in the document
+    fn collect_class_name_suggestions(&mut self, range: Range, add_quotes: bool) {
+        let existing_class_names = self.node.get_class_names();
+        for class_name in self.html_document.get_class_names() {
+            if existing_class_names.contains(&class_name.as_str()) {
+                continue;
+            }
+            let insert_text = if add_quotes {
+                format!(r#""{}""#, class_name)
+            } else {
+                class_name.clone()
+            };
+            self.result.items.push(CompletionItem {
+                label: class_name,
+                filter_text: Some(insert_text.clone()),
+                kind: Some(CompletionItemKind::VALUE),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range,
+                    new_text: insert_text,
+                })),
+                insert_text_format: Some(InsertTextFormat::PLAIN_TEXT),
+                ..Default::default()
+            });
+        }
     }
 
     fn collect_close_tag_suggestions(
@@ -762,6 +1615,9 @@ impl CompletionContext<'_> {
         in_open_tag: bool,
         tag_name_end: usize,
     ) {
+        if self.settings.is_some_and(|s| s.hide_close_tags) {
+            return;
+        }
         let range = self.get_replace_range(after_open_bracket, tag_name_end);
         let close_tag = if is_followed_by(
             self.text,
@@ -824,39 +1680,75 @@ impl CompletionContext<'_> {
         }
 
         for provider in &self.data_providers {
+            if self.is_cancelled() {
+                return;
+            }
             for tag in provider.provide_tags() {
-                let documentation = data_provider::generate_documentation(
-                    GenerateDocumentationItem {
-                        description: tag.description.clone(),
-                        references: tag.references.clone(),
-                    },
-                    GenerateDocumentationSetting {
-                        documentation: true,
-                        references: true,
-                        does_support_markdown: self.does_support_markdown,
-                    },
+                if self.is_hidden(tag.deprecated) {
+                    continue;
+                }
+                let (documentation, data) = self.get_documentation(
+                    tag,
+                    provider.get_id(),
+                    &[&tag.name],
+                    self.documentation_setting(self.does_support_markdown),
                 );
-                let documentation = if let Some(documentation) = documentation {
-                    Some(Documentation::MarkupContent(documentation))
-                } else {
-                    None
-                };
+                let label_details = self.get_label_details(provider.get_id());
                 self.result.items.push(CompletionItem {
                     label: format!("/{}", tag.name),
+                    tags: deprecated_tags(tag.deprecated),
+                    label_details,
                     kind: Some(CompletionItemKind::PROPERTY),
                     documentation,
+                    data,
                     ..Default::default()
                 });
             }
         }
     }
 
+    /// Whether the `tag` of the data provider out of its scope is the element of the scope,
+    /// and the data providers in scope don't propose it, such as `<math>` of the HTML data
+    fn is_element_scope_tag(&self, provider_id: &str, tag: &str) -> bool {
+        self.data_manager
+            .get_element_scope(provider_id)
+            .is_some_and(|scope| scope.eq_ignore_ascii_case(tag))
+            && !self.data_providers.iter().any(|provider| {
+                provider
+                    .provide_tags()
+                    .iter()
+                    .any(|data| data.name.eq_ignore_ascii_case(tag))
+            })
+    }
+
+    /// Show the data provider that contributes the item next to the label
+    fn get_label_details(&self, provider_id: &str) -> Option<CompletionItemLabelDetails> {
+        if self.does_support_label_details {
+            Some(CompletionItemLabelDetails {
+                detail: None,
+                description: Some(provider_id.to_string()),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Whether `CompletionConfiguration::cancellation_token` is cancelled
+    fn is_cancelled(&self) -> bool {
+        is_cancelled(self.settings.and_then(|s| s.cancellation_token.as_ref()))
+    }
+
+    /// Whether the deprecated item is hidden by `CompletionConfiguration`
+    fn is_hidden(&self, deprecated: Option<bool>) -> bool {
+        deprecated.unwrap_or_default() && self.settings.is_some_and(|s| s.hide_deprecated)
+    }
+
     fn collect_auto_close_tag_suggestion(&mut self, tag_close_end: usize, tag: &str) {
         if self.settings.is_some() && self.settings.unwrap().hide_auto_complete_proposals {
             return;
         }
         if !self.data_manager.is_void_element(tag, &self.void_elements) {
-            let pos = self.document.position_at(tag_close_end as u32);
+            let pos = self.position_at(tag_close_end);
             let text_edit = Some(CompletionTextEdit::Edit(TextEdit {
                 range: Range {
                     start: pos,
@@ -875,64 +1767,115 @@ impl CompletionContext<'_> {
         }
     }
 
+    /// The markers of the folding regions in the comment, such as `<!-- #region -->`
+    fn collect_region_suggestions(&mut self, comment_start: usize) {
+        let range = self.get_replace_range(
+            get_word_start(self.text, self.offset, comment_start),
+            self.offset,
+        );
+        for (label, snippet, detail) in [
+            ("#region", "#region $1", "Folding Region Start"),
+            ("#endregion", "#endregion", "Folding Region End"),
+        ] {
+            self.result.items.push(CompletionItem {
+                label: label.to_string(),
+                kind: Some(CompletionItemKind::SNIPPET),
+                detail: Some(detail.to_string()),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+                    range,
+                    snippet.to_string(),
+                ))),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                ..Default::default()
+            });
+        }
+    }
+
     async fn collect_inside_content(&mut self) {
+        let start = self.result.items.len();
+        let mut hide_builtins = false;
+        let mut after_builtins = vec![];
         for participant in self.completion_participants {
-            self.result.items.append(
-                &mut participant
-                    .on_html_content(HtmlContentContext {
-                        document: FullTextDocument::new(
-                            self.document.language_id().to_string(),
-                            self.document.version(),
-                            self.document.get_content(None).to_string(),
-                        ),
-                        html_document: self.html_document.clone(),
-                        position: *self.position,
-                    })
-                    .await,
-            );
+            if self.is_cancelled() {
+                return;
+            }
+            let context = HtmlContentContext {
+                document: FullTextDocument::new(
+                    self.document.language_id().to_string(),
+                    self.document.version(),
+                    self.document.get_content(None).to_string(),
+                ),
+                html_document: self.html_document.clone(),
+                position: *self.position,
+            };
+            hide_builtins |= participant.hide_builtin_content(&context);
+            let mut items = self.participant_items(participant.on_html_content(context).await);
+            if participant.priority() < 0 {
+                after_builtins.append(&mut items);
+            } else {
+                self.result.items.append(&mut items);
+            }
+        }
+        if !hide_builtins {
+            self.collect_character_entity_proposals();
+        }
+        if !self.completion_participants.is_empty() {
+            self.result.items.append(&mut after_builtins);
+            dedup_by_label(&mut self.result.items, start);
         }
-        self.collect_character_entity_proposals();
     }
 
     fn collect_character_entity_proposals(&mut self) {
+        if self.settings.is_some_and(|s| s.hide_character_entities) {
+            return;
+        }
         let mut k: i128 = self.offset as i128 - 1;
-        let mut character_start = self.position.character;
         while k >= 0 && strings::is_letter_or_digit(self.text, k as usize) {
             k -= 1;
-            character_start -= 1;
         }
-        if k >= 0 && self.text.get(k as usize..(k + 1) as usize) == Some("&") {
-            let range = Range::new(
-                Position {
-                    line: self.position.line,
-                    character: character_start - 1,
-                },
-                *self.position,
-            );
+        if k >= 1
+            && strings::byte_at(self.text, k as usize) == Some(b'#')
+            && strings::byte_at(self.text, k as usize - 1) == Some(b'&')
+        {
+            let reference = &self.text[k as usize..self.offset];
+            if let Some(value) = html_entities::decode_numeric_reference(reference) {
+                let range = Range::new(self.position_at(k as usize - 1), *self.position);
+                let label = format!("&{};", reference);
+                self.push_character_entity_proposal(label, &value.to_string(), range);
+            }
+        } else if k >= 0 && strings::byte_at(self.text, k as usize) == Some(b'&') {
+            let range = Range::new(self.position_at(k as usize), *self.position);
             let entities: &HashMap<_, _> = &html_entities::ENTITIES;
             for (entity, value) in entities {
                 if entity.ends_with(";") {
                     let label = format!("&{}", entity);
-                    self.result.items.push(CompletionItem {
-                        label: label.clone(),
-                        kind: Some(CompletionItemKind::KEYWORD),
-                        documentation: Some(Documentation::String(format!(
-                            "Character entity representing '{}",
-                            value
-                        ))),
-                        text_edit: Some(CompletionTextEdit::Edit(TextEdit {
-                            range,
-                            new_text: label,
-                        })),
-                        insert_text_format: Some(InsertTextFormat::PLAIN_TEXT),
-                        ..Default::default()
-                    });
+                    self.push_character_entity_proposal(label, value, range);
                 }
             }
         }
     }
 
+    fn push_character_entity_proposal(&mut self, label: String, value: &str, range: Range) {
+        self.result.items.push(CompletionItem {
+            label: label.clone(),
+            kind: Some(CompletionItemKind::KEYWORD),
+            documentation: Some(Documentation::String(format!(
+                "Character entity representing '{}'",
+                value
+            ))),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range,
+                new_text: label,
+            })),
+            insert_text_format: Some(InsertTextFormat::PLAIN_TEXT),
+            ..Default::default()
+        });
+    }
+
     fn suggest_doctype(&mut self, replace_start: usize, replace_end: usize) {
+        if self.settings.is_some_and(|s| s.hide_doctype) {
+            return;
+        }
         let range = self.get_replace_range(replace_start, replace_end);
         self.result.items.push(CompletionItem {
             label: "!DOCTYPE".to_string(),
@@ -960,12 +1903,12 @@ impl CompletionContext<'_> {
     fn get_line_indent(&self, offset: usize) -> Option<String> {
         let mut start = offset;
         while start > 0 {
-            let ch = self.text.get(start - 1..start);
-            if ch == Some("\n") {
+            let ch = strings::byte_at(self.text, start - 1);
+            if ch == Some(b'\n') {
                 return Some(self.text[start..offset].to_string());
             }
             if let Some(ch) = ch {
-                if !is_white_space(&ch.to_string()) {
+                if !is_white_space(ch) {
                     return None;
                 }
             }
@@ -975,12 +1918,12 @@ impl CompletionContext<'_> {
     }
 }
 
-fn is_white_space(text: &str) -> bool {
-    REG_WHITE_SPACE.is_match(text)
+fn is_white_space(ch: u8) -> bool {
+    matches!(ch, b' ' | b'\t' | b'\n' | 0x0B | 0x0C | b'\r')
 }
 
-fn is_quote(text: &str) -> bool {
-    REG_QUOTE.is_match(text)
+fn is_quote(ch: u8) -> bool {
+    ch == b'"' || ch == b'\''
 }
 
 fn is_followed_by(
@@ -999,7 +1942,7 @@ fn is_followed_by(
 
 fn get_word_start(s: &str, offset: usize, limit: usize) -> usize {
     let mut offset = offset;
-    while offset > limit && !is_white_space(&s[offset - 1..offset]) {
+    while offset > limit && !strings::byte_at(s, offset - 1).is_some_and(is_white_space) {
         offset -= 1;
     }
     offset
@@ -1007,16 +1950,143 @@ fn get_word_start(s: &str, offset: usize, limit: usize) -> usize {
 
 fn get_word_end(s: &str, offset: usize, limit: usize) -> usize {
     let mut offset = offset;
-    while offset < limit && !is_white_space(&s[offset..offset + 1]) {
+    while offset < limit && !strings::byte_at(s, offset).is_some_and(is_white_space) {
         offset += 1;
     }
     offset
 }
 
+fn does_support_label_details(ls_options: &HTMLLanguageServiceOptions) -> bool {
+    if let Some(client_capabilities) = &ls_options.client_capabilities {
+        client_capabilities
+            .text_document
+            .as_ref()
+            .and_then(|t| t.completion.as_ref())
+            .and_then(|c| c.completion_item.as_ref())
+            .and_then(|c| c.label_details_support)
+            .unwrap_or_default()
+    } else {
+        true
+    }
+}
+
+/// Generate the documentation by the `doc_renderer` if it's provided, otherwise by the data manager
+fn generate_documentation(
+    doc_renderer: Option<&DocumentationRenderer>,
+    data_manager: &HTMLDataManager,
+    provider_id: &str,
+    names: &[&str],
+    item: impl Into<GenerateDocumentationItem>,
+    setting: GenerateDocumentationSetting,
+) -> Option<MarkupContent> {
+    if let Some(doc_renderer) = doc_renderer {
+        Some(doc_renderer(item.into())).filter(|documentation| !documentation.value.is_empty())
+    } else {
+        data_manager.generate_documentation(provider_id, names, item, setting)
+    }
+}
+
+/// The quotes of the attribute values that are inserted,
+/// `Quotes::Auto` is resolved to the quote that the document mostly uses
+fn get_attribute_quotes(
+    settings: Option<&CompletionConfiguration>,
+    html_document: &HTMLDocument,
+) -> Quotes {
+    match settings.map_or(Quotes::Double, |s| s.attribute_default_value) {
+        Quotes::Auto => match html_document.get_dominant_quote() {
+            Some('\'') => Quotes::Single,
+            _ => Quotes::Double,
+        },
+        quotes => quotes,
+    }
+}
+
+/// Whether the node is in the content of the elements that only contain text,
+/// in which the tags are not parsed as elements
+fn is_in_raw_text(parent_list: &[&Node]) -> bool {
+    parent_list.iter().any(|parent| {
+        parent.tag.as_ref().is_some_and(|tag| {
+            RAW_TEXT_ELEMENTS
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(tag))
+        })
+    })
+}
+
+fn deprecated_tags(deprecated: Option<bool>) -> Option<Vec<CompletionItemTag>> {
+    if deprecated.unwrap_or_default() {
+        Some(vec![CompletionItemTag::DEPRECATED])
+    } else {
+        None
+    }
+}
+
+/// Remove the items after `start` whose labels appear before them, the first one is kept
+fn dedup_by_label(items: &mut Vec<CompletionItem>, start: usize) {
+    let merged = items.split_off(start);
+    let mut labels = HashSet::new();
+    items.extend(
+        merged
+            .into_iter()
+            .filter(|item| labels.insert(item.label.clone())),
+    );
+}
+
 pub struct CompletionConfiguration {
     pub hide_auto_complete_proposals: bool,
+    /// Don't propose the tags, attributes and values that are marked as deprecated
+    pub hide_deprecated: bool,
+    /// Don't generate the documentation of the tags, attributes and values,
+    /// attach the `data` instead and generate it by `resolve_completion_item` when the client asks
+    pub lazy_documentation: bool,
+    /// The maximum number of the completion items, the rest are truncated
+    /// and the completion list is marked as incomplete, so that the clients re-query as the user types
+    pub max_items: Option<usize>,
+    /// Insert the whole element when a tag is selected, with the required attributes and the end tag,
+    /// such as `<a href="$1">$2</a>`
+    pub element_snippets: bool,
+    /// Don't propose the character entities, such as `&amp;`
+    pub hide_character_entities: bool,
+    /// Don't propose the `data-*` attributes that are used in the document
+    pub hide_data_attributes: bool,
+    /// Don't propose `<!DOCTYPE html>`
+    pub hide_doctype: bool,
+    /// Don't propose the end tags of the open elements, such as `</div>`
+    pub hide_close_tags: bool,
+    /// The tags that `do_tag_complete` doesn't close automatically,
+    /// such as the pseudo-tags of the template languages
+    pub auto_close_exclusions: Vec<String>,
     pub attribute_default_value: Quotes,
     pub provider: HashMap<String, bool>,
+    /// The completion list is empty and incomplete once it's cancelled,
+    /// the data providers and the participants are checked one by one
+    pub cancellation_token: Option<CancellationToken>,
+    /// Show the references, such as MDN, in the documentation of the items
+    pub references: bool,
+    /// Show the Baseline status or the browser support beneath the references
+    pub baseline: bool,
+}
+
+impl Default for CompletionConfiguration {
+    fn default() -> Self {
+        CompletionConfiguration {
+            hide_auto_complete_proposals: false,
+            hide_deprecated: false,
+            lazy_documentation: false,
+            max_items: None,
+            element_snippets: false,
+            hide_character_entities: false,
+            hide_data_attributes: false,
+            hide_doctype: false,
+            hide_close_tags: false,
+            auto_close_exclusions: vec![],
+            attribute_default_value: Quotes::Double,
+            provider: HashMap::new(),
+            cancellation_token: None,
+            references: true,
+            baseline: true,
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -1024,4 +2094,7 @@ pub enum Quotes {
     None,
     Single,
     Double,
+    /// The quote that most of the attribute values in the document use,
+    /// or double quotes if they are used equally
+    Auto,
 }
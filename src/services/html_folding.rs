@@ -1,36 +1,159 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::HashSet};
 
 use lazy_static::lazy_static;
 use lsp_textdocument::FullTextDocument;
-use lsp_types::{FoldingRange, FoldingRangeKind};
+use lsp_types::{FoldingRange, FoldingRangeKind, Position, PositionEncodingKind};
 use regex::Regex;
 
-use crate::{parser::html_scanner::TokenType, HTMLDataManager, HTMLLanguageService};
+use crate::{
+    cancellation::is_cancelled,
+    parser::{html_embedded::get_script_language, html_scanner::TokenType},
+    participant::{IFoldingRangeParticipant, ParticipantError},
+    utils::{chunks::Chunks, position},
+    CancellationToken, HTMLDataManager, HTMLLanguageService,
+};
 
 lazy_static! {
     static ref REG_REGION: Regex = Regex::new(r"^\s*#(region\b)|(endregion\b)").unwrap();
 }
 
 pub fn get_folding_ranges(
-    document: FullTextDocument,
+    document: &FullTextDocument,
     context: FoldingRangeContext,
     data_manager: &HTMLDataManager,
+    participants: &[Box<dyn IFoldingRangeParticipant>],
+    on_error: &dyn Fn(&ParticipantError),
+    encoding: Option<&PositionEncodingKind>,
+) -> Vec<FoldingRange> {
+    let ranges = collect_folding_ranges(
+        document,
+        &context,
+        data_manager,
+        participants,
+        on_error,
+        encoding,
+        |_| {},
+    );
+
+    let range_limit = context.range_limit.unwrap_or(usize::MAX);
+    if ranges.len() > range_limit {
+        limit_ranges(ranges, range_limit)
+    } else {
+        ranges
+    }
+}
+
+/// Like `get_folding_ranges`, and the ranges are passed to `on_chunk` in chunks of `chunk_size`
+/// once they are settled while the document is scanned
+///
+/// The ranges are passed after the whole document is scanned if `range_limit` is set,
+/// since the ranges to drop are unknown until then.
+#[allow(clippy::too_many_arguments)]
+pub fn get_folding_ranges_streaming(
+    document: &FullTextDocument,
+    context: FoldingRangeContext,
+    data_manager: &HTMLDataManager,
+    participants: &[Box<dyn IFoldingRangeParticipant>],
+    on_error: &dyn Fn(&ParticipantError),
+    encoding: Option<&PositionEncodingKind>,
+    chunk_size: usize,
+    mut on_chunk: impl FnMut(Vec<FoldingRange>),
+) {
+    let mut chunks = Chunks::new(chunk_size, &mut on_chunk);
+    if context.range_limit.is_some() {
+        for range in get_folding_ranges(
+            document,
+            context,
+            data_manager,
+            participants,
+            on_error,
+            encoding,
+        ) {
+            chunks.push(range);
+        }
+    } else {
+        let mut settled = 0;
+        let ranges = collect_folding_ranges(
+            document,
+            &context,
+            data_manager,
+            participants,
+            on_error,
+            encoding,
+            |ranges| {
+                settled += ranges.len();
+                for range in ranges {
+                    chunks.push(range.clone());
+                }
+            },
+        );
+        for range in ranges.into_iter().skip(settled) {
+            chunks.push(range);
+        }
+    }
+    chunks.flush();
+}
+
+/// Collect all the ranges without `range_limit`, the ranges are passed to `on_settled`
+/// in order once they won't be changed
+fn collect_folding_ranges(
+    document: &FullTextDocument,
+    context: &FoldingRangeContext,
+    data_manager: &HTMLDataManager,
+    participants: &[Box<dyn IFoldingRangeParticipant>],
+    on_error: &dyn Fn(&ParticipantError),
+    encoding: Option<&PositionEncodingKind>,
+    mut on_settled: impl FnMut(&[FoldingRange]),
 ) -> Vec<FoldingRange> {
     let void_elements = data_manager.get_void_elements(document.language_id());
     let mut scanner = HTMLLanguageService::create_scanner(document.get_content(None), 0);
+    scanner.set_template_syntax(data_manager.is_template_language(document.language_id()));
     let mut token = scanner.scan();
     let mut ranges = vec![];
-    let mut stack = vec![]; // Vec<(startLine: usize, tag_name: String)>
+    let mut embedded_ranges = vec![];
+    // Vec<(start_line: u32, tag_name: String, attributes_range: Option<usize>)>
+    let mut stack: Vec<(u32, String, Option<usize>)> = vec![];
     let mut last_tag_name: Option<String> = None;
+    let mut last_attribute_name: Option<String> = None;
+    // the `type` of the last `<script>`, the language of its content passed to the participants
+    let mut script_type: Option<String> = None;
     let mut prev_start = u32::MAX;
+    let mut settled = 0;
+    let region_markers: Vec<(Regex, Regex)> = context
+        .region_markers
+        .iter()
+        .filter_map(|marker| {
+            Some((
+                Regex::new(&marker.start).ok()?,
+                Regex::new(&marker.end).ok()?,
+            ))
+        })
+        .collect();
 
     while token != TokenType::EOS {
+        if is_cancelled(context.cancellation_token.as_ref()) {
+            return vec![];
+        }
         match token {
             TokenType::StartTag => {
                 let tag_name = scanner.get_token_text();
-                let start_line = document.position_at(scanner.get_token_offset() as u32).line;
-                stack.push((start_line, tag_name.to_string()));
+                let start_line =
+                    position::position_at(document, scanner.get_token_offset(), encoding).line;
+                stack.push((start_line, tag_name.to_string(), None));
                 last_tag_name = Some(tag_name.to_string());
+                script_type = None;
+            }
+            TokenType::AttributeName => {
+                last_attribute_name = Some(scanner.get_token_text().to_lowercase());
+            }
+            TokenType::AttributeValue
+                if last_attribute_name.as_deref() == Some("type")
+                    && last_tag_name
+                        .as_ref()
+                        .is_some_and(|tag| tag.eq_ignore_ascii_case("script")) =>
+            {
+                let value = scanner.get_token_text();
+                script_type = Some(value.trim_matches(|c| c == '"' || c == '\'').to_string());
             }
             TokenType::EndTag => {
                 last_tag_name = Some(scanner.get_token_text().to_string());
@@ -53,26 +176,62 @@ pub fn get_folding_ranges(
                         }
                     }
                     if is_find {
-                        let start_line = stack[i].0;
+                        let (start_line, _, attributes_range) = stack[i];
                         stack.truncate(i);
-                        let line = document.position_at(scanner.get_token_end() as u32).line;
-                        if line > start_line + 1 && prev_start != start_line {
-                            ranges.push(FoldingRange {
+                        let line =
+                            position::position_at(document, scanner.get_token_end(), encoding).line;
+                        if line > start_line + 1 {
+                            let range = FoldingRange {
                                 start_line,
                                 end_line: line - 1,
                                 ..Default::default()
-                            });
-                            prev_start = start_line;
+                            };
+                            // the element range takes the place of its start tag range
+                            if let Some(index) = attributes_range {
+                                ranges[index] = range;
+                            } else if prev_start != start_line {
+                                ranges.push(range);
+                                prev_start = start_line;
+                            }
+                        }
+                    }
+                } else if token == TokenType::StartTagClose {
+                    if let Some((start_line, tag_name, attributes_range)) = stack.last_mut() {
+                        if Some(&*tag_name) == last_tag_name.as_ref() && prev_start != *start_line {
+                            let offset = scanner.get_token_offset();
+                            let position = position::position_at(document, offset, encoding);
+                            let line_start = position::offset_at(
+                                document,
+                                Position::new(position.line, 0),
+                                encoding,
+                            );
+                            let content = document.get_content(None);
+                            // keep the line of `>` visible if nothing precedes it
+                            let end_line = if content[line_start..offset].trim().is_empty() {
+                                position.line.saturating_sub(1)
+                            } else {
+                                position.line
+                            };
+                            if end_line > *start_line {
+                                *attributes_range = Some(ranges.len());
+                                ranges.push(FoldingRange {
+                                    start_line: *start_line,
+                                    end_line,
+                                    ..Default::default()
+                                });
+                                prev_start = *start_line;
+                            }
                         }
                     }
                 }
             }
             TokenType::Comment => {
-                let mut start_line = document.position_at(scanner.get_token_offset() as u32).line;
+                let mut start_line =
+                    position::position_at(document, scanner.get_token_offset(), encoding).line;
                 let text = scanner.get_token_text();
-                if let Some(caps) = REG_REGION.captures(&text) {
-                    if caps.get(1).is_some() {
-                        stack.push((start_line, String::new()));
+                if let Some(is_start) = get_region_marker(text, &region_markers) {
+                    if is_start {
+                        stack.push((start_line, String::new(), None));
                     } else if stack.len() > 0 {
                         let mut i = stack.len() - 1;
                         let mut is_find = true;
@@ -100,9 +259,8 @@ pub fn get_folding_ranges(
                         }
                     }
                 } else {
-                    let end_line = document
-                        .position_at(scanner.get_token_end() as u32 + 3)
-                        .line;
+                    let end_line =
+                        position::position_at(document, scanner.get_token_end() + 3, encoding).line;
                     if start_line < end_line {
                         ranges.push(FoldingRange {
                             start_line,
@@ -114,17 +272,76 @@ pub fn get_folding_ranges(
                     }
                 }
             }
+            TokenType::Script | TokenType::Styles if !participants.is_empty() => {
+                let language = if token == TokenType::Script {
+                    get_script_language(script_type.as_deref())
+                } else {
+                    "css".to_string()
+                };
+                let start = position::position_at(document, scanner.get_token_offset(), encoding);
+                let content = scanner.get_token_text();
+                for participant in participants {
+                    let ranges = participant
+                        .get_folding_ranges(&language, content)
+                        .unwrap_or_else(|error| {
+                            on_error(&error);
+                            vec![]
+                        });
+                    for mut range in ranges {
+                        if range.start_line == 0 {
+                            range.start_character =
+                                range.start_character.map(|c| c + start.character);
+                        }
+                        if range.end_line == 0 {
+                            range.end_character = range.end_character.map(|c| c + start.character);
+                        }
+                        range.start_line += start.line;
+                        range.end_line += start.line;
+                        embedded_ranges.push(range);
+                    }
+                }
+            }
             _ => {}
         }
+        // the start tag ranges of the open elements may be replaced by the element ranges
+        if ranges.len() > settled {
+            let end = stack
+                .iter()
+                .filter_map(|(_, _, attributes_range)| *attributes_range)
+                .min()
+                .unwrap_or(ranges.len());
+            if end > settled {
+                on_settled(&ranges[settled..end]);
+                settled = end;
+            }
+        }
         token = scanner.scan();
     }
 
-    let range_limit = context.range_limit.unwrap_or(usize::MAX);
-    if ranges.len() > range_limit {
-        limit_ranges(ranges, range_limit)
-    } else {
-        ranges
+    // only one range can start on a line, the HTML ranges take precedence
+    let mut start_lines: HashSet<u32> = ranges.iter().map(|r| r.start_line).collect();
+    for range in embedded_ranges {
+        if start_lines.insert(range.start_line) {
+            ranges.push(range);
+        }
+    }
+
+    ranges
+}
+
+/// Whether the comment text starts a region (`Some(true)`) or ends a region (`Some(false)`)
+fn get_region_marker(text: &str, region_markers: &[(Regex, Regex)]) -> Option<bool> {
+    if let Some(caps) = REG_REGION.captures(text) {
+        return Some(caps.get(1).is_some());
     }
+    for (start, end) in region_markers {
+        if start.is_match(text) {
+            return Some(true);
+        } else if end.is_match(text) {
+            return Some(false);
+        }
+    }
+    None
 }
 
 fn limit_ranges(mut ranges: Vec<FoldingRange>, range_limit: usize) -> Vec<FoldingRange> {
@@ -230,7 +447,21 @@ fn set_nesting_level(
     }
 }
 
-#[derive(Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct FoldingRangeContext {
+    /// The maximum number of ranges to return, the innermost ranges are dropped first
     pub range_limit: Option<usize>,
+    /// The comments that start and end a region, in addition to `#region` and `#endregion`
+    pub region_markers: Vec<FoldingRegionMarkers>,
+    /// No range is returned once it's cancelled
+    pub cancellation_token: Option<CancellationToken>,
+}
+
+/// The regular expressions that match the text of the comments that start and end a region,
+/// such as `^\s*BEGIN\b` and `^\s*END\b` for `<!-- BEGIN section -->` and `<!-- END -->`,
+/// the markers with an invalid expression are ignored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoldingRegionMarkers {
+    pub start: String,
+    pub end: String,
 }
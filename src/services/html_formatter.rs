@@ -1,19 +1,34 @@
 use lsp_textdocument::FullTextDocument;
-use lsp_types::{Position, Range, TextEdit};
+use lsp_types::{Position, PositionEncodingKind, Range, TextEdit};
 use regex::Regex;
 
-use crate::beautify::beautify_html::html_beautify;
+use crate::{
+    beautify::beautify_html::html_beautify,
+    cancellation::is_cancelled,
+    parse_html_document,
+    parser::html_document::Node,
+    participant::{IFormatterParticipant, ParticipantError},
+    utils::{position, strings::byte_at},
+    CancellationToken, HTMLDataManager,
+};
 
 pub fn format(
     document: &FullTextDocument,
     range: &Option<Range>,
     options: &HTMLFormatConfiguration,
+    data_manager: &HTMLDataManager,
+    participants: &[Box<dyn IFormatterParticipant>],
+    on_error: &dyn Fn(&ParticipantError),
+    encoding: Option<&PositionEncodingKind>,
 ) -> Vec<TextEdit> {
     let mut value = document.get_content(None);
     let mut initial_indent_level = 0;
+    let mut includes_end = true;
+    let mut starts_line = true;
+    let mut ends_line = false;
     let tab_size = options.tab_size;
     let range = if let Some(range) = range {
-        let mut start_offset = document.offset_at(range.start) as usize;
+        let mut start_offset = position::offset_at(document, range.start, encoding);
 
         // include all leading whitespace if at the beginning of the line
         let mut extended_start = start_offset;
@@ -30,7 +45,7 @@ pub fn format(
         }
 
         // include all following whitespace until the end of the line
-        let mut end_offset = document.offset_at(range.end) as usize;
+        let mut end_offset = position::offset_at(document, range.end, encoding);
         let mut extended_end = end_offset;
         while extended_end < value.len() && is_whitespace(value, extended_end) {
             extended_end += 1;
@@ -38,52 +53,82 @@ pub fn format(
         if extended_end == value.len() || is_eol(value, extended_end) {
             end_offset = extended_end;
         }
-        let range = if document
-            .get_content(None)
-            .get(start_offset - 1..start_offset)
-            .is_some_and(|v| v == "\n")
-        {
-            let start = document.position_at(start_offset as u32);
-            Range::new(
-                Position {
-                    line: start.line + 1,
-                    character: 0,
-                },
-                document.position_at(end_offset as u32),
-            )
-        } else {
-            Range::new(
-                document.position_at(start_offset as u32),
-                document.position_at(end_offset as u32),
-            )
-        };
+        let range = Range::new(
+            position::position_at(document, start_offset, encoding),
+            position::position_at(document, end_offset, encoding),
+        );
 
         // Do not modify if substring starts in inside an element
         // Ending inside an element is fine as it doesn't cause formatting errors
         let first_half = &value[0..start_offset];
         if Regex::new(".*[<][^>]*$").unwrap().is_match(first_half) {
-            // return without modification
-            let value = &value[start_offset..end_offset];
-            return vec![TextEdit::new(range, value.to_string())];
+            return vec![];
         }
 
-        value = &value[start_offset..end_offset];
+        // The range is indented in the node that contains it
+        let html_document = parse_html_document(value, document.language_id(), data_manager);
+        if let Some(node) = find_enclosing_node(&html_document.roots, start_offset, end_offset) {
+            let start_of_line_offset = value[..node.start].rfind('\n').map_or(0, |i| i + 1);
+            initial_indent_level = compute_indent_level(value, start_of_line_offset, options);
+            if node.tag.as_deref() != Some("html") || options.indent_inner_html {
+                initial_indent_level += 1;
+            }
+        }
 
-        if start_offset != 0 {
-            let start_of_line_offset =
-                document.offset_at(Position::new(range.start.line, 0)) as usize;
-            initial_indent_level =
-                compute_indent_level(document.get_content(None), start_of_line_offset, &options);
+        // The range is reversed, such as by the positions in the middle of the characters
+        if start_offset > end_offset {
+            return vec![];
         }
+        includes_end = end_offset == value.len();
+        starts_line = start_offset == 0 || is_eol(value, start_offset - 1);
+        value = &value[start_offset..end_offset];
+        // The line break at the end of the range is kept, the next line isn't formatted
+        ends_line = !includes_end && value.ends_with('\n');
         range
     } else {
         Range::new(
             Position::new(0, 0),
-            document.position_at(value.len() as u32),
+            position::position_at(document, value.len(), encoding),
         )
     };
 
-    let mut result = html_beautify(&trim_left(value), &options);
+    let language_id = document.language_id();
+    let mut result = html_beautify(
+        &trim_left(value),
+        language_id,
+        options,
+        data_manager,
+        participants,
+        on_error,
+    );
+    // The partial result would break the document
+    if is_cancelled(options.cancellation_token.as_ref()) {
+        return vec![];
+    }
+    // The final newline is only for the end of the document
+    if !includes_end && (options.end_with_newline || options.insert_final_newline) {
+        if let Some(stripped) = result.strip_suffix('\n') {
+            result = stripped.to_string();
+        }
+    }
+    if options.check_idempotence {
+        let again = html_beautify(
+            &result,
+            language_id,
+            options,
+            data_manager,
+            participants,
+            &|_| {},
+        );
+        // The formatting isn't stable, so it isn't applied
+        if again != result {
+            return vec![];
+        }
+    }
+
+    if ends_line && !result.ends_with('\n') {
+        result.push('\n');
+    }
 
     if initial_indent_level > 0 {
         let indent = if options.insert_spaces {
@@ -103,26 +148,67 @@ pub fn format(
                 .collect::<Vec<_>>()
                 .join(&format!("\n{}", &indent));
         }
-        if range.start.character == 0 {
+        // The first line follows the text before the range if it doesn't start the line
+        if starts_line {
             result = indent + &result;
         }
     }
 
+    if options.trim_trailing_whitespace {
+        result = result
+            .split('\n')
+            .map(|line| line.trim_end_matches([' ', '\t', '\r']))
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+    let eol = match options.end_of_line {
+        HtmlEndOfLine::Lf => "\n",
+        HtmlEndOfLine::CrLf => "\r\n",
+        HtmlEndOfLine::Auto => get_end_of_line(document.get_content(None)),
+    };
+    result = result.replace("\r\n", "\n");
+    if eol != "\n" {
+        result = result.replace('\n', eol);
+    }
+
+    if result == value {
+        return vec![];
+    }
     vec![TextEdit::new(range, result)]
 }
 
+/// The end of line of the first line in the content, it's `\n` if there is only one line
+fn get_end_of_line(content: &str) -> &'static str {
+    if let Some(index) = content.find('\n') {
+        if content[..index].ends_with('\r') {
+            return "\r\n";
+        }
+    }
+    "\n"
+}
+
 fn trim_left(value: &str) -> String {
     Regex::new("^\\s+").unwrap().replace(value, "").to_string()
 }
 
+/// Find the innermost node whose content contains the range
+fn find_enclosing_node(nodes: &[Node], start: usize, end: usize) -> Option<&Node> {
+    let encloses = |node: &&Node| {
+        node.start_tag_end
+            .is_some_and(|start_tag_end| start_tag_end <= start)
+            && node.end_tag_start.unwrap_or(node.end) >= end
+    };
+    let mut node = nodes.iter().find(encloses)?;
+    while let Some(child) = node.children.iter().find(encloses) {
+        node = child;
+    }
+    Some(node)
+}
+
 fn compute_indent_level(content: &str, offset: usize, options: &HTMLFormatConfiguration) -> usize {
-    let mut i = offset;
     let mut n_chars = 0;
     let tab_size = options.tab_size as usize;
-    let length = content.len();
-    let mut bytes = content.bytes().skip(i - 1);
-    while i < length {
-        let ch = bytes.next().unwrap();
+    for ch in content[offset..].bytes() {
         if ch == b' ' {
             n_chars += 1;
         } else if ch == b'\t' {
@@ -130,18 +216,16 @@ fn compute_indent_level(content: &str, offset: usize, options: &HTMLFormatConfig
         } else {
             break;
         }
-        i += 1;
     }
     n_chars / tab_size
 }
 
 fn is_eol(text: &str, offset: usize) -> bool {
-    text.get(offset..offset + 1).is_some_and(|c| c == "\n")
+    byte_at(text, offset) == Some(b'\n')
 }
 
 fn is_whitespace(text: &str, offset: usize) -> bool {
-    text.get(offset..offset + 1)
-        .is_some_and(|c| vec![" ", "\t"].contains(&c))
+    byte_at(text, offset).is_some_and(|c| c == b' ' || c == b'\t')
 }
 
 pub struct HTMLFormatConfiguration {
@@ -149,22 +233,53 @@ pub struct HTMLFormatConfiguration {
     pub insert_spaces: bool,
     pub indent_empty_lines: bool,
     pub wrap_line_length: Option<usize>,
-    // pub unformatted: Option<Vec<String>>,
-    // pub content_unformatted: Option<Vec<String>>,
-    // pub indent_inner_html: bool,
-    // pub wrap_attributes: HtmlWrapAttributes,
+    /// The tags that are kept as they are, including their start tags
+    pub unformatted: Vec<String>,
+    /// The tags whose content is kept as it is
+    pub content_unformatted: Vec<String>,
+    /// The inline tags, which stay on the same line as the text around them
+    pub inline: Vec<String>,
+    /// Whether to indent `<head>` and `<body>` under `<html>`
+    pub indent_inner_html: bool,
+    /// How to wrap the attributes of the tags
+    pub wrap_attributes: HtmlWrapAttributes,
+    /// How to quote the values of the attributes
+    pub attribute_quotes: HtmlAttributeQuotes,
     /// default same of tab_size if None
     pub wrap_attributes_indent_size: Option<u8>,
     pub preserve_new_lines: bool,
     pub max_preserve_new_lines: Option<usize>,
-    // pub indent_handlebars: bool,
+    /// Whether to indent the content of handlebars blocks such as `{{#if}}` and `{{/if}}`
+    pub indent_handlebars: bool,
     pub end_with_newline: bool,
-    // pub extra_liners: Option<Vec<String>>,
+    /// The same as `end_with_newline`, the formatted content ends with a newline if either is true
+    pub insert_final_newline: bool,
+    /// Whether to remove the whitespace at the end of the lines
+    pub trim_trailing_whitespace: bool,
+    /// The end of line of the formatted content
+    pub end_of_line: HtmlEndOfLine,
+    /// Format the formatted content again and return no edits if it changes, it's used for debugging
+    pub check_idempotence: bool,
+    /// How to write the end of the void elements such as `<br>`
+    pub void_element_style: HtmlVoidElementStyle,
+    /// The tags that have an empty line before them, `/html` means the end tag of `<html>`
+    pub extra_liners: Vec<String>,
+    /// No edit is returned once it's cancelled
+    pub cancellation_token: Option<CancellationToken>,
     // pub indent_scripts: HtmlIndentScripts,
     // pub templating: Vec<HtmlTemplating>,
     // pub unformatted_content_delimiter: String,
 }
 
+/// The default `inline` tags, the same as js-beautify
+const INLINE_ELEMENTS: [&str; 57] = [
+    "a", "abbr", "area", "audio", "b", "bdi", "bdo", "br", "button", "canvas", "cite", "code",
+    "data", "datalist", "del", "dfn", "em", "embed", "i", "iframe", "img", "input", "ins", "kbd",
+    "keygen", "label", "map", "mark", "math", "meter", "noscript", "object", "output", "progress",
+    "q", "ruby", "s", "samp", "select", "small", "span", "strong", "sub", "sup", "svg", "template",
+    "textarea", "time", "u", "var", "video", "wbr", "text", "acronym", "big", "strike", "tt",
+];
+
 impl Default for HTMLFormatConfiguration {
     fn default() -> Self {
         Self {
@@ -172,16 +287,28 @@ impl Default for HTMLFormatConfiguration {
             insert_spaces: true,
             indent_empty_lines: false,
             wrap_line_length: Some(120),
-            // unformatted: None,
-            // content_unformatted: None,
-            // indent_inner_html: false,
-            // wrap_attributes: HtmlWrapAttributes::default(),
+            unformatted: vec![],
+            content_unformatted: ["pre", "textarea", "script", "style"]
+                .map(|tag| tag.to_string())
+                .to_vec(),
+            inline: INLINE_ELEMENTS.map(|tag| tag.to_string()).to_vec(),
+            indent_inner_html: false,
+            wrap_attributes: HtmlWrapAttributes::default(),
+            attribute_quotes: HtmlAttributeQuotes::default(),
             wrap_attributes_indent_size: None,
             preserve_new_lines: true,
             max_preserve_new_lines: Some(32786),
-            // indent_handlebars: false,
+            indent_handlebars: false,
             end_with_newline: false,
-            // extra_liners: None,
+            insert_final_newline: false,
+            trim_trailing_whitespace: false,
+            end_of_line: HtmlEndOfLine::default(),
+            check_idempotence: false,
+            void_element_style: HtmlVoidElementStyle::default(),
+            extra_liners: ["head", "body", "/html"]
+                .map(|tag| tag.to_string())
+                .to_vec(),
+            cancellation_token: None,
             // indent_scripts: HtmlIndentScripts::default(),
             // templating: vec![HtmlTemplating::default()],
             // unformatted_content_delimiter: "".to_string(),
@@ -201,21 +328,25 @@ impl Default for HTMLFormatConfiguration {
 //     }
 // }
 
-// pub enum HtmlWrapAttributes {
-//     Auto,
-//     Force,
-//     ForceAligned,
-//     ForceExpandMultiline,
-//     AlignedMultiple,
-//     Preserve,
-//     PreserveAligned,
-// }
-
-// impl Default for HtmlWrapAttributes {
-//     fn default() -> Self {
-//         HtmlWrapAttributes::Auto
-//     }
-// }
+/// The modes of wrapping the attributes, the same as `wrap_attributes` of js-beautify
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HtmlWrapAttributes {
+    /// Put every attribute on its own line only when the tag exceeds `wrap_line_length`
+    #[default]
+    Auto,
+    /// Put every attribute except the first on its own line if there are multiple attributes
+    Force,
+    /// Like `Force`, and align the attributes with the first one
+    ForceAligned,
+    /// Put every attribute and the closing bracket on its own line if there are multiple attributes
+    ForceExpandMultiline,
+    /// Wrap the attributes aligned with the first one when the line exceeds `wrap_line_length`
+    AlignedMultiple,
+    /// Keep the attributes on the lines where they are
+    Preserve,
+    /// Like `Preserve`, and align the wrapped attributes with the first one
+    PreserveAligned,
+}
 
 // pub enum HtmlTemplating {
 //     Auto,
@@ -233,3 +364,39 @@ impl Default for HTMLFormatConfiguration {
 //         HtmlTemplating::Auto
 //     }
 // }
+
+/// The styles of writing the void elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HtmlVoidElementStyle {
+    /// Write as `<br />`
+    #[default]
+    SelfClose,
+    /// Write as `<br>`
+    NoSlash,
+    /// Keep the style of the document
+    Preserve,
+}
+
+/// The quotes of the attribute values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HtmlAttributeQuotes {
+    /// Keep the quotes of the document
+    #[default]
+    Preserve,
+    /// Quote with `"`
+    Double,
+    /// Quote with `'`
+    Single,
+}
+
+/// The end of line of the formatted content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HtmlEndOfLine {
+    /// `\n`
+    Lf,
+    /// `\r\n`
+    CrLf,
+    /// The same as the first line of the document
+    #[default]
+    Auto,
+}
@@ -1,28 +1,38 @@
 use lsp_textdocument::FullTextDocument;
-use lsp_types::{DocumentHighlight, DocumentHighlightKind, Position, Range};
+use lsp_types::{DocumentHighlight, DocumentHighlightKind, Position, PositionEncodingKind, Range};
 
-use crate::parser::{
-    html_document::HTMLDocument,
-    html_scanner::{Scanner, ScannerState, TokenType},
+use crate::{
+    parser::{
+        html_document::{HTMLDocument, Node},
+        html_scanner::{Scanner, ScannerState, TokenType},
+    },
+    utils::position,
 };
 
 pub fn find_document_highlights(
     document: &FullTextDocument,
     position: &Position,
     html_document: &HTMLDocument,
+    encoding: Option<&PositionEncodingKind>,
 ) -> Vec<DocumentHighlight> {
-    let offset = document.offset_at(*position);
-    if let Some(node) = html_document.find_node_at(offset as usize, &mut vec![]) {
+    let offset = position::offset_at(document, *position, encoding);
+    if let Some(node) = html_document.find_node_at(offset, &mut vec![]) {
         if node.tag.is_none() {
             return vec![];
         }
 
         let mut result = vec![];
-        let start_tag_range = get_tag_name_range(TokenType::StartTag, document, node.start);
+        let start_tag_range =
+            get_tag_name_range(TokenType::StartTag, document, node.start, encoding);
         let end_tag_range = if node.is_self_closing() {
             None
         } else {
-            get_tag_name_range(TokenType::EndTag, document, node.end_tag_start.unwrap())
+            get_tag_name_range(
+                TokenType::EndTag,
+                document,
+                node.end_tag_start.unwrap(),
+                encoding,
+            )
         };
 
         if start_tag_range.is_some_and(|range| covers(&range, position))
@@ -40,6 +50,22 @@ pub fn find_document_highlights(
                     kind: Some(DocumentHighlightKind::READ),
                 });
             }
+        } else if let Some(name) = get_attribute_name_at(node, offset) {
+            // `tabindex` of all elements
+            let mut offsets = vec![];
+            for root in html_document.roots.iter() {
+                collect_attribute_offsets(root, name, &mut offsets);
+            }
+            offsets.sort();
+            for offset in offsets {
+                result.push(DocumentHighlight {
+                    range: Range::new(
+                        position::position_at(document, offset, encoding),
+                        position::position_at(document, offset + name.len(), encoding),
+                    ),
+                    kind: Some(DocumentHighlightKind::READ),
+                });
+            }
         }
 
         result
@@ -48,6 +74,32 @@ pub fn find_document_highlights(
     }
 }
 
+/// The name of the attribute of `node` that `offset` is on
+fn get_attribute_name_at(node: &Node, offset: usize) -> Option<&str> {
+    if node
+        .start_tag_end
+        .is_some_and(|start_tag_end| offset >= start_tag_end)
+    {
+        return None;
+    }
+    node.attributes
+        .iter()
+        .find(|(name, attr)| attr.offset <= offset && offset <= attr.offset + name.len())
+        .map(|(name, _)| name.as_str())
+}
+
+fn collect_attribute_offsets(node: &Node, name: &str, offsets: &mut Vec<usize>) {
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        for (attr_name, attr) in &node.attributes {
+            if attr_name.eq_ignore_ascii_case(name) {
+                offsets.push(attr.offset);
+            }
+        }
+        stack.extend(node.children.iter().rev());
+    }
+}
+
 fn is_before_or_equal(pos1: &Position, pos2: &Position) -> bool {
     pos1.line < pos2.line || (pos1.line == pos2.line && pos1.character <= pos2.character)
 }
@@ -60,6 +112,7 @@ fn get_tag_name_range(
     token_type: TokenType,
     document: &FullTextDocument,
     start_offset: usize,
+    encoding: Option<&PositionEncodingKind>,
 ) -> Option<Range> {
     let mut scanner = Scanner::new(
         document.get_content(None),
@@ -73,8 +126,8 @@ fn get_tag_name_range(
     }
     if token != TokenType::EOS {
         Some(Range {
-            start: document.position_at(scanner.get_token_offset() as u32),
-            end: document.position_at(scanner.get_token_end() as u32),
+            start: position::position_at(document, scanner.get_token_offset(), encoding),
+            end: position::position_at(document, scanner.get_token_end(), encoding),
         })
     } else {
         None
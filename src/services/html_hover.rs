@@ -1,24 +1,31 @@
-use std::collections::HashMap;
-
 use lazy_static::lazy_static;
 use lsp_textdocument::FullTextDocument;
-use lsp_types::{Hover, HoverContents, MarkedString, MarkupContent, MarkupKind, Position, Range};
+use lsp_types::{
+    Hover, HoverContents, MarkedString, MarkupContent, MarkupKind, Position, PositionEncodingKind,
+    Range,
+};
 use regex::Regex;
 
 use crate::{
     language_facts::{
+        content_model,
         data_manager::HTMLDataManager,
         data_provider::{
-            self, GenerateDocumentationItem, GenerateDocumentationSetting, IHTMLDataProvider,
+            DocumentationRenderer, GenerateDocumentationItem, GenerateDocumentationSetting,
+            IHTMLDataProvider,
         },
+        doctype::{Doctype, DocumentMode},
     },
     parser::{
         html_document::HTMLDocument,
         html_entities,
         html_scanner::{Scanner, ScannerState, TokenType},
     },
-    participant::{HtmlAttributeValueContext, HtmlContentContext, IHoverParticipant},
-    utils::{markdown, strings},
+    participant::{
+        HtmlAttributeNameContext, HtmlAttributeValueContext, HtmlContentContext, HtmlTagContext,
+        IHoverParticipant, ParticipantError, ParticipantErrorHandler,
+    },
+    utils::{markdown, position, strings},
     HTMLLanguageServiceOptions,
 };
 
@@ -28,14 +35,20 @@ lazy_static! {
 
 pub struct HTMLHover {
     supports_markdown: bool,
+    position_encoding: Option<PositionEncodingKind>,
+    doc_renderer: Option<DocumentationRenderer>,
     hover_participants: Vec<Box<dyn IHoverParticipant>>,
+    participant_error_handler: Option<ParticipantErrorHandler>,
 }
 
 impl HTMLHover {
     pub fn new(ls_options: &HTMLLanguageServiceOptions) -> HTMLHover {
         HTMLHover {
             supports_markdown: markdown::does_support_markdown(&ls_options),
+            position_encoding: ls_options.position_encoding.clone(),
+            doc_renderer: ls_options.doc_renderer.clone(),
             hover_participants: vec![],
+            participant_error_handler: ls_options.participant_error_handler.clone(),
         }
     }
 
@@ -43,6 +56,16 @@ impl HTMLHover {
         self.hover_participants = hover_participants;
     }
 
+    /// The hover of the participant, or `None` if it failed and the error is reported
+    fn participant_hover(&self, hover: Result<Option<Hover>, ParticipantError>) -> Option<Hover> {
+        hover.unwrap_or_else(|error| {
+            if let Some(handler) = &self.participant_error_handler {
+                handler(&error);
+            }
+            None
+        })
+    }
+
     pub async fn do_hover(
         &self,
         document: &FullTextDocument,
@@ -51,10 +74,15 @@ impl HTMLHover {
         options: Option<HoverSettings>,
         data_manager: &HTMLDataManager,
     ) -> Option<Hover> {
-        let offset = document.offset_at(*position) as usize;
-        let node = html_document.find_node_at(offset, &mut vec![]);
+        let offset = position::offset_at(document, *position, self.position_encoding.as_ref());
+        let mut parent_list = vec![];
+        let node = html_document.find_node_at(offset, &mut parent_list);
         let text = document.get_content(None);
 
+        if let Some(hover) = self.get_doctype_hover(document, offset) {
+            return Some(hover);
+        }
+
         if node.is_none() {
             return None;
         }
@@ -66,26 +94,31 @@ impl HTMLHover {
 
         let node = node.unwrap();
 
-        let mut data_providers = vec![];
-        for provider in data_manager.get_data_providers() {
-            if provider.is_applicable(document.language_id()) {
-                data_providers.push(provider);
-            }
-        }
+        // The data providers scoped to an element such as `<svg>` apply in its subtree
+        let tags: Vec<&str> = parent_list
+            .iter()
+            .chain([&node])
+            .filter_map(|node| node.tag.as_deref())
+            .collect();
+        let data_providers = data_manager
+            .get_applicable_data_providers(document.language_id())
+            .into_iter()
+            .filter(|provider| data_manager.is_in_element_scope(provider.as_ref(), &tags))
+            .collect();
 
         let options = if options.is_some() {
             options.unwrap()
         } else {
-            HoverSettings {
-                documentation: true,
-                references: true,
-            }
+            HoverSettings::default()
         };
         let mut context = HoverContext {
             options,
             data_providers,
+            data_manager,
             offset,
             position,
+            position_encoding: self.position_encoding.as_ref(),
+            doc_renderer: self.doc_renderer.as_ref(),
             document,
             html_document,
         };
@@ -99,33 +132,30 @@ impl HTMLHover {
                 node.end_tag_start.unwrap(),
                 &mut context,
             );
-            if tag_range.is_some() {
-                return self.get_tag_hover(
-                    &node.tag.clone().unwrap(),
-                    tag_range.unwrap(),
-                    false,
-                    &mut context,
-                );
+            if let Some(tag_range) = tag_range {
+                return self
+                    .get_tag_hover(&node.tag.clone().unwrap(), tag_range, false, &mut context)
+                    .await;
             }
             return None;
         }
 
         let tag_range = self.get_tag_name_range(TokenType::StartTag, node.start, &mut context);
-        if tag_range.is_some() {
-            return self.get_tag_hover(
-                &node.tag.clone().unwrap(),
-                tag_range.unwrap(),
-                true,
-                &mut context,
-            );
+        if let Some(tag_range) = tag_range {
+            return self
+                .get_tag_hover(&node.tag.clone().unwrap(), tag_range, true, &mut context)
+                .await;
         }
 
         let attr_range =
             self.get_tag_name_range(TokenType::AttributeName, node.start, &mut context);
         if attr_range.is_some() {
             let tag = node.tag.clone().unwrap();
-            let attr = document.get_content(attr_range);
-            return self.get_attr_hover(&tag, attr, attr_range.unwrap(), &mut context);
+            let attr_range = attr_range.unwrap();
+            let attr = context.get_content(attr_range);
+            return self
+                .get_attr_hover(&tag, attr, attr_range, &mut context)
+                .await;
         }
 
         let entity_range = self.get_entity_range(&mut context);
@@ -138,10 +168,10 @@ impl HTMLHover {
         if attr_value_range.is_some() {
             let attr_value_range = attr_value_range.unwrap();
             let tag = node.tag.clone().unwrap();
-            let attr_value = &HTMLHover::trim_quotes(document.get_content(Some(attr_value_range)));
+            let attr_value = &HTMLHover::trim_quotes(context.get_content(attr_value_range));
             let match_attr = self.scan_attr_and_attr_value(
                 node.start,
-                document.offset_at(attr_value_range.start) as usize,
+                context.offset_at(attr_value_range.start),
                 &mut context,
             );
             if match_attr.is_some() {
@@ -169,7 +199,7 @@ impl HTMLHover {
                     position: *position,
                 })
                 .await;
-            if let Some(hover) = hover {
+            if let Some(hover) = self.participant_hover(hover) {
                 return Some(hover);
             }
         }
@@ -177,37 +207,70 @@ impl HTMLHover {
         None
     }
 
-    fn get_tag_hover<'a>(
+    async fn get_tag_hover<'a>(
         &self,
         cur_tag: &str,
         range: Range,
-        _open: bool,
+        open: bool,
         context: &mut HoverContext<'a>,
     ) -> Option<Hover> {
+        for hover_participant in &self.hover_participants {
+            if let Some(hover) = self.participant_hover(
+                hover_participant
+                    .on_html_tag(HtmlTagContext {
+                        document: FullTextDocument::new(
+                            context.document.language_id().to_string(),
+                            context.document.version(),
+                            context.document.get_content(None).to_string(),
+                        ),
+                        html_document: context.html_document.clone(),
+                        position: *context.position,
+                        tag: cur_tag.to_string(),
+                        open,
+                        range,
+                    })
+                    .await,
+            ) {
+                return Some(hover);
+            }
+        }
+        let case_sensitive = context
+            .data_manager
+            .is_case_sensitive(context.document.language_id());
         for provider in &context.data_providers {
             let mut hover = None;
 
             for tag in provider.provide_tags() {
-                if tag.name.to_lowercase() == cur_tag.to_lowercase() {
-                    let markup_content = data_provider::generate_documentation(
-                        GenerateDocumentationItem {
-                            description: tag.description.clone(),
-                            references: tag.references.clone(),
-                        },
-                        GenerateDocumentationSetting {
-                            documentation: context.options.documentation,
-                            references: context.options.references,
-                            does_support_markdown: self.supports_markdown,
-                        },
-                    )
-                    .unwrap_or(MarkupContent {
-                        kind: if self.supports_markdown {
-                            MarkupKind::Markdown
-                        } else {
-                            MarkupKind::PlainText
-                        },
-                        value: "".to_string(),
-                    });
+                let is_same_tag = if case_sensitive {
+                    tag.name == cur_tag
+                } else {
+                    tag.name.to_lowercase() == cur_tag.to_lowercase()
+                };
+                if is_same_tag {
+                    let markup_content = context
+                        .generate_documentation(
+                            provider.get_id(),
+                            &[&tag.name],
+                            tag,
+                            GenerateDocumentationSetting {
+                                documentation: context.options.documentation,
+                                references: context.options.references,
+                                baseline: context.options.baseline,
+                                does_support_markdown: self.supports_markdown,
+                            },
+                        )
+                        .unwrap_or(MarkupContent {
+                            kind: if self.supports_markdown {
+                                MarkupKind::Markdown
+                            } else {
+                                MarkupKind::PlainText
+                            },
+                            value: "".to_string(),
+                        });
+                    let mut markup_content = markup_content;
+                    if context.options.content_model {
+                        self.append_content_model(&mut markup_content.value, cur_tag);
+                    }
                     hover = Some(Hover {
                         contents: self.convert_contents(HoverContents::Markup(markup_content)),
                         range: Some(range),
@@ -221,26 +284,66 @@ impl HTMLHover {
         None
     }
 
-    fn get_attr_hover<'a>(
+    /// Append the content categories, permitted content and permitted parents of the element
+    fn append_content_model(&self, value: &mut String, tag: &str) {
+        if let Some(model) = content_model::get_content_model(tag) {
+            for (title, text) in [
+                ("Content categories", model.categories),
+                ("Permitted content", model.content),
+                ("Permitted parents", model.parents),
+            ] {
+                if !value.is_empty() {
+                    *value += "\n\n";
+                }
+                if self.supports_markdown {
+                    *value += &format!("**{}**: {}", title, text);
+                } else {
+                    *value += &format!("{}: {}", title, text.replace('`', ""));
+                }
+            }
+        }
+    }
+
+    async fn get_attr_hover<'a>(
         &self,
         cur_tag: &str,
         cur_attr: &str,
         range: Range,
         context: &mut HoverContext<'a>,
     ) -> Option<Hover> {
+        for hover_participant in &self.hover_participants {
+            if let Some(hover) = self.participant_hover(
+                hover_participant
+                    .on_html_attribute_name(HtmlAttributeNameContext {
+                        document: FullTextDocument::new(
+                            context.document.language_id().to_string(),
+                            context.document.version(),
+                            context.document.get_content(None).to_string(),
+                        ),
+                        html_document: context.html_document.clone(),
+                        position: *context.position,
+                        tag: cur_tag.to_string(),
+                        attribute: cur_attr.to_string(),
+                        range,
+                    })
+                    .await,
+            ) {
+                return Some(hover);
+            }
+        }
         for provider in &context.data_providers {
             let mut hover = None;
 
             for attr in provider.provide_attributes(cur_tag) {
-                if cur_attr == attr.name && attr.description.is_some() {
-                    let contents = data_provider::generate_documentation(
-                        GenerateDocumentationItem {
-                            description: attr.description.clone(),
-                            references: attr.references.clone(),
-                        },
+                if attr.name.eq_ignore_ascii_case(cur_attr) && attr.description.is_some() {
+                    let contents = context.generate_documentation(
+                        provider.get_id(),
+                        &[cur_tag, &attr.name],
+                        attr,
                         GenerateDocumentationSetting {
                             documentation: context.options.documentation,
                             references: context.options.references,
+                            baseline: context.options.baseline,
                             does_support_markdown: self.supports_markdown,
                         },
                     );
@@ -259,9 +362,49 @@ impl HTMLHover {
                 return hover;
             }
         }
+        if cur_attr.starts_with("data-") {
+            return self.get_data_attr_hover(cur_attr, range, context);
+        }
         None
     }
 
+    /// The `data-*` attributes have no documentation,
+    /// so show how many times it's used in the document and where is the first occurrence
+    fn get_data_attr_hover<'a>(
+        &self,
+        cur_attr: &str,
+        range: Range,
+        context: &HoverContext<'a>,
+    ) -> Option<Hover> {
+        let data_attributes = context.html_document.get_data_attributes();
+        let offsets = data_attributes.get(cur_attr)?;
+        let first = context.position_at(offsets[0]);
+        let times = if offsets.len() == 1 { "time" } else { "times" };
+        let name = if self.supports_markdown {
+            format!("`{}`", cur_attr)
+        } else {
+            cur_attr.to_string()
+        };
+        let value = format!(
+            "{} is used {} {} in the document, first at line {}",
+            name,
+            offsets.len(),
+            times,
+            first.line + 1
+        );
+        Some(Hover {
+            contents: self.convert_contents(HoverContents::Markup(MarkupContent {
+                kind: if self.supports_markdown {
+                    MarkupKind::Markdown
+                } else {
+                    MarkupKind::PlainText
+                },
+                value,
+            })),
+            range: Some(range),
+        })
+    }
+
     async fn get_attr_value_hover<'a>(
         &self,
         cur_tag: &str,
@@ -271,36 +414,41 @@ impl HTMLHover {
         context: &mut HoverContext<'a>,
     ) -> Option<Hover> {
         for hover_participant in &self.hover_participants {
-            if let Some(hover) = hover_participant
-                .on_html_attribute_value(HtmlAttributeValueContext {
-                    document: FullTextDocument::new(
-                        context.document.language_id().to_string(),
-                        context.document.version(),
-                        context.document.get_content(None).to_string(),
-                    ),
-                    html_document: context.html_document.clone(),
-                    position: *context.position,
-                    tag: cur_tag.to_string(),
-                    attribute: cur_attr.to_string(),
-                    value: cur_attr_value.to_string(),
-                    range,
-                })
-                .await
-            {
+            if let Some(hover) = self.participant_hover(
+                hover_participant
+                    .on_html_attribute_value(HtmlAttributeValueContext {
+                        document: FullTextDocument::new(
+                            context.document.language_id().to_string(),
+                            context.document.version(),
+                            context.document.get_content(None).to_string(),
+                        ),
+                        html_document: context.html_document.clone(),
+                        position: *context.position,
+                        tag: cur_tag.to_string(),
+                        attribute: cur_attr.to_string(),
+                        value: cur_attr_value.to_string(),
+                        range,
+                    })
+                    .await,
+            ) {
                 return Some(hover);
             }
         }
         for provider in &context.data_providers {
-            for attr_value in provider.provide_values(cur_tag, cur_attr) {
+            for attr_value in
+                context
+                    .data_manager
+                    .provide_values(provider.as_ref(), cur_tag, cur_attr)
+            {
                 if cur_attr_value == attr_value.name && attr_value.description.is_some() {
-                    let contents = data_provider::generate_documentation(
-                        GenerateDocumentationItem {
-                            description: attr_value.description.clone(),
-                            references: attr_value.references.clone(),
-                        },
+                    let contents = context.generate_documentation(
+                        provider.get_id(),
+                        &[cur_tag, cur_attr, &attr_value.name],
+                        attr_value,
                         GenerateDocumentationSetting {
                             documentation: context.options.documentation,
                             references: context.options.references,
+                            baseline: context.options.baseline,
                             does_support_markdown: self.supports_markdown,
                         },
                     );
@@ -317,6 +465,73 @@ impl HTMLHover {
         None
     }
 
+    /// Hover on the DOCTYPE declaration, the range covers the whole declaration
+    fn get_doctype_hover(&self, document: &FullTextDocument, offset: usize) -> Option<Hover> {
+        let text = document.get_content(None);
+        let mut end = (offset + 2).min(text.len());
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        let start = text[..end].rfind("<!")?;
+
+        let mut scanner = Scanner::new(text, start, ScannerState::WithinContent, false);
+        if scanner.scan() != TokenType::StartDoctypeTag {
+            return None;
+        }
+        let mut content = "";
+        let mut end = scanner.get_token_end();
+        loop {
+            match scanner.scan() {
+                TokenType::Doctype => {
+                    end = scanner.get_token_end();
+                    content = &text[scanner.get_token_offset()..end];
+                }
+                TokenType::EndDoctypeTag => {
+                    end = scanner.get_token_end();
+                    break;
+                }
+                _ => break,
+            }
+        }
+        if offset > end {
+            return None;
+        }
+
+        let doctype = Doctype::parse(content);
+        let value = if doctype.is_html5() {
+            "The HTML5 doctype, the browsers render the document in standards mode.".to_string()
+        } else {
+            let mode = match doctype.get_document_mode() {
+                DocumentMode::NoQuirks => "standards mode",
+                DocumentMode::LimitedQuirks => "almost standards mode",
+                DocumentMode::Quirks => "quirks mode",
+            };
+            let html5 = if self.supports_markdown {
+                "`<!DOCTYPE html>`"
+            } else {
+                "<!DOCTYPE html>"
+            };
+            format!(
+                "Legacy doctype, the browsers render the document in {}. Use {} instead.",
+                mode, html5
+            )
+        };
+        Some(Hover {
+            contents: self.convert_contents(HoverContents::Markup(MarkupContent {
+                kind: if self.supports_markdown {
+                    MarkupKind::Markdown
+                } else {
+                    MarkupKind::PlainText
+                },
+                value,
+            })),
+            range: Some(Range::new(
+                position::position_at(document, start, self.position_encoding.as_ref()),
+                position::position_at(document, end, self.position_encoding.as_ref()),
+            )),
+        })
+    }
+
     fn get_entity_hover(
         &self,
         text: &str,
@@ -325,44 +540,42 @@ impl HTMLHover {
     ) -> Option<Hover> {
         let cur_entity = self.filter_entity(text, context);
 
-        let entities: &HashMap<_, _> = &html_entities::ENTITIES;
-        for (entity, value) in entities {
-            let label = format!("&{}", entity);
-
-            if cur_entity == label {
-                let code = value
-                    .chars()
-                    .map(|b| format!("{:02X}", b as u32))
-                    .collect::<Vec<String>>()
-                    .join("");
-                let mut hex = String::from("U+");
-
-                if code.len() < 4 {
-                    let zeroes = 4 - code.len();
-                    let mut k = 0;
-                    while k < zeroes {
-                        hex = hex + "0";
-                        k += 1;
-                    }
-                }
+        let value = if cur_entity.starts_with("&#") {
+            html_entities::decode_numeric_reference(&cur_entity[1..cur_entity.len() - 1])?
+                .to_string()
+        } else {
+            html_entities::ENTITIES.get(&cur_entity[1..])?.to_string()
+        };
 
-                hex += &code;
-
-                let content = format!(
-                    "Character entity representing '{0}', unicode equivalent '{1}'",
-                    value, hex
-                );
-                return Some(Hover {
-                    contents: self.convert_contents(HoverContents::Markup(MarkupContent {
-                        kind: MarkupKind::PlainText,
-                        value: content,
-                    })),
-                    range: Some(range),
-                });
+        let code = value
+            .chars()
+            .map(|b| format!("{:02X}", b as u32))
+            .collect::<Vec<String>>()
+            .join("");
+        let mut hex = String::from("U+");
+
+        if code.len() < 4 {
+            let zeroes = 4 - code.len();
+            let mut k = 0;
+            while k < zeroes {
+                hex = hex + "0";
+                k += 1;
             }
         }
 
-        None
+        hex += &code;
+
+        let content = format!(
+            "Character entity representing '{0}', unicode equivalent '{1}'",
+            value, hex
+        );
+        Some(Hover {
+            contents: self.convert_contents(HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::PlainText,
+                value: content,
+            })),
+            range: Some(range),
+        })
     }
 
     fn get_tag_name_range(
@@ -386,82 +599,60 @@ impl HTMLHover {
         }
         if token == token_type && context.offset <= scanner.get_token_end() {
             return Some(Range {
-                start: context
-                    .document
-                    .position_at(scanner.get_token_offset() as u32),
-                end: context.document.position_at(scanner.get_token_end() as u32),
+                start: context.position_at(scanner.get_token_offset()),
+                end: context.position_at(scanner.get_token_end()),
             });
         }
         None
     }
 
     fn get_entity_range(&self, context: &mut HoverContext) -> Option<Range> {
-        let mut k = context.offset;
-        let mut character_start = context.position.character;
-
         let text = context.document.get_content(None);
+        let (k, n) = HTMLHover::get_entity_bounds(text, context.offset)?;
 
-        while k > 0 && strings::is_letter_or_digit(text, k - 1) {
-            k -= 1;
-            character_start -= 1;
-        }
-
-        let mut n = k;
-        let mut character_end = character_start;
-
-        while strings::is_letter_or_digit(text, n) {
-            n += 1;
-            character_end += 1;
-        }
-
-        if k > 0 && text.get(k - 1..k) == Some("&") {
-            return if text.get(n..n + 1) == Some(";") {
-                Some(Range {
-                    start: Position {
-                        line: context.position.line,
-                        character: character_start,
-                    },
-                    end: Position {
-                        line: context.position.line,
-                        character: character_end + 1,
-                    },
-                })
-            } else {
-                Some(Range {
-                    start: Position {
-                        line: context.position.line,
-                        character: character_start,
-                    },
-                    end: Position {
-                        line: context.position.line,
-                        character: character_end,
-                    },
-                })
-            };
+        if strings::byte_at(text, n) == Some(b';') {
+            Some(Range {
+                start: context.position_at(k),
+                end: context.position_at(n + 1),
+            })
+        } else {
+            Some(Range {
+                start: context.position_at(k),
+                end: context.position_at(n),
+            })
         }
-        None
     }
 
     fn filter_entity(&self, text: &str, context: &mut HoverContext) -> String {
-        let mut k: isize = context.offset as isize - 1;
-        let mut new_text = String::from("&");
+        let (k, n) = HTMLHover::get_entity_bounds(text, context.offset).unwrap_or((0, 0));
+        format!("&{};", &text[k..n])
+    }
 
-        while k >= 0 && strings::is_letter_or_digit(text, k as usize) {
+    /// Get the bounds of the entity name around `offset`, excluding `&` and `;`
+    ///
+    /// The name of numeric character references contains the leading `#`.
+    fn get_entity_bounds(text: &str, offset: usize) -> Option<(usize, usize)> {
+        let mut k = offset;
+        while k > 0 && strings::is_letter_or_digit(text, k - 1) {
             k -= 1;
         }
-
-        let mut k = k as usize;
-
-        k += 1;
-
-        while strings::is_letter_or_digit(text, k) {
-            new_text += &text[k..k + 1];
-            k += 1;
+        if k > 0 && strings::byte_at(text, k - 1) == Some(b'#') {
+            k -= 1;
         }
 
-        new_text += ";";
+        let mut n = k;
+        if strings::byte_at(text, n) == Some(b'#') {
+            n += 1;
+        }
+        while strings::is_letter_or_digit(text, n) {
+            n += 1;
+        }
 
-        new_text
+        if k > 0 && strings::byte_at(text, k - 1) == Some(b'&') {
+            Some((k, n))
+        } else {
+            None
+        }
     }
 
     fn scan_attr_and_attr_value(
@@ -538,13 +729,84 @@ impl HTMLHover {
 pub struct HoverSettings {
     pub documentation: bool,
     pub references: bool,
+    /// Show the Baseline status or the browser support beneath the references
+    pub baseline: bool,
+    /// Show the content categories, permitted content and permitted parents of the element
+    pub content_model: bool,
+}
+
+impl HoverSettings {
+    /// The settings with the Baseline status and without the content model
+    pub fn new(documentation: bool, references: bool) -> HoverSettings {
+        HoverSettings {
+            documentation,
+            references,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_baseline(mut self, baseline: bool) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    pub fn with_content_model(mut self, content_model: bool) -> Self {
+        self.content_model = content_model;
+        self
+    }
+}
+
+impl Default for HoverSettings {
+    fn default() -> Self {
+        HoverSettings {
+            documentation: true,
+            references: true,
+            baseline: true,
+            content_model: false,
+        }
+    }
 }
 
 struct HoverContext<'a> {
     options: HoverSettings,
     data_providers: Vec<&'a Box<dyn IHTMLDataProvider>>,
+    data_manager: &'a HTMLDataManager,
     offset: usize,
     position: &'a Position,
+    position_encoding: Option<&'a PositionEncodingKind>,
+    doc_renderer: Option<&'a DocumentationRenderer>,
     document: &'a FullTextDocument,
     html_document: &'a HTMLDocument,
 }
+
+impl<'a> HoverContext<'a> {
+    /// Generate the documentation by the `doc_renderer` of the options if it's provided,
+    /// otherwise by the data manager
+    fn generate_documentation(
+        &self,
+        provider_id: &str,
+        names: &[&str],
+        item: impl Into<GenerateDocumentationItem>,
+        setting: GenerateDocumentationSetting,
+    ) -> Option<MarkupContent> {
+        if let Some(doc_renderer) = self.doc_renderer {
+            Some(doc_renderer(item.into())).filter(|documentation| !documentation.value.is_empty())
+        } else {
+            self.data_manager
+                .generate_documentation(provider_id, names, item, setting)
+        }
+    }
+
+    fn position_at(&self, offset: usize) -> Position {
+        position::position_at(self.document, offset, self.position_encoding)
+    }
+
+    fn offset_at(&self, position: Position) -> usize {
+        position::offset_at(self.document, position, self.position_encoding)
+    }
+
+    fn get_content(&self, range: Range) -> &'a str {
+        let text = self.document.get_content(None);
+        &text[self.offset_at(range.start)..self.offset_at(range.end)]
+    }
+}
@@ -1,38 +1,73 @@
 use lsp_textdocument::FullTextDocument;
-use lsp_types::{Position, Range};
+use lsp_types::{Position, PositionEncodingKind, Range};
+use regex::Regex;
 
-use crate::parser::html_document::HTMLDocument;
+use crate::{parser::html_document::HTMLDocument, utils::position};
 
 pub fn find_linked_editing_ranges(
     document: &FullTextDocument,
     position: Position,
     html_document: &HTMLDocument,
+    settings: Option<&LinkedEditingConfiguration>,
+    encoding: Option<&PositionEncodingKind>,
 ) -> Option<Vec<Range>> {
-    let offset = document.offset_at(position) as usize;
+    let offset = position::offset_at(document, position, encoding);
     let node = html_document.find_node_at(offset, &mut vec![])?;
 
-    let tag_len = if let Some(tag) = &node.tag {
-        tag.len()
+    let content = document.get_content(None);
+    let tag_name_reg = settings
+        .and_then(|settings| settings.tag_name_pattern.as_ref())
+        .and_then(|pattern| Regex::new(&format!("^(?:{})", pattern)).ok());
+
+    let (end_tag_start, start_tag_len, end_tag_len) = if let Some(reg) = tag_name_reg {
+        // The parser doesn't match the end tag if the name has the characters such as `@`
+        let end_tag_start = node.end_tag_start.or_else(|| {
+            content[node.start..node.end]
+                .rfind("</")
+                .map(|index| node.start + index)
+        })?;
+        let start_tag = reg.find(&content[node.start + "<".len()..])?.as_str();
+        let end_tag = reg.find(&content[end_tag_start + "</".len()..])?.as_str();
+        if !start_tag.eq_ignore_ascii_case(end_tag) {
+            return None;
+        }
+        (end_tag_start, start_tag.len(), end_tag.len())
     } else {
-        0
+        let tag_len = if let Some(tag) = &node.tag {
+            tag.len()
+        } else {
+            0
+        };
+        (node.end_tag_start?, tag_len, tag_len)
     };
 
-    let end_tag_start = node.end_tag_start?;
+    let include_open_bracket = settings.is_some_and(|settings| settings.include_open_bracket);
 
-    if (node.start + "<".len() <= offset && offset <= node.start + "<".len() + tag_len)
-        || (end_tag_start + "</".len() <= offset && offset <= end_tag_start + "</".len() + tag_len)
+    if (node.start + "<".len() <= offset && offset <= node.start + "<".len() + start_tag_len)
+        || (end_tag_start + "</".len() <= offset
+            && offset <= end_tag_start + "</".len() + end_tag_len)
+        || (include_open_bracket && (offset == node.start || offset == end_tag_start))
     {
         Some(vec![
             Range::new(
-                document.position_at((node.start + "<".len()) as u32),
-                document.position_at((node.start + "<".len() + tag_len) as u32),
+                position::position_at(document, node.start + "<".len(), encoding),
+                position::position_at(document, node.start + "<".len() + start_tag_len, encoding),
             ),
             Range::new(
-                document.position_at((end_tag_start + "</".len()) as u32),
-                document.position_at((end_tag_start + "</".len() + tag_len) as u32),
+                position::position_at(document, end_tag_start + "</".len(), encoding),
+                position::position_at(document, end_tag_start + "</".len() + end_tag_len, encoding),
             ),
         ])
     } else {
         None
     }
 }
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinkedEditingConfiguration {
+    /// The regular expression that matches the tag names, such as `[\w.@:-]+`
+    /// for the component tags of Vue and Lit, the tag names of the parser are used if it's `None`
+    pub tag_name_pattern: Option<String>,
+    /// Link the tag names when the cursor is just before the `<` of the tags
+    pub include_open_bracket: bool,
+}
@@ -1,12 +1,15 @@
-use std::collections::HashMap;
-
 use lazy_static::lazy_static;
 use lsp_textdocument::FullTextDocument;
-use lsp_types::{DocumentLink, Range, Url};
+use lsp_types::{DocumentLink, PositionEncodingKind, Range, Url};
 use regex::Regex;
 
 use crate::{
-    parser::html_scanner::{Scanner, ScannerState, TokenType},
+    parser::{
+        html_document::HTMLDocument,
+        html_index::HTMLDocumentIndex,
+        html_scanner::{contains_template, Scanner, ScannerState, TokenType},
+    },
+    utils::{chunks::Chunks, position, strings},
     DocumentContext, HTMLDataManager,
 };
 
@@ -21,36 +24,123 @@ lazy_static! {
 pub fn find_document_links(
     uri: &Url,
     document: &FullTextDocument,
+    html_document: &HTMLDocument,
     document_context: &impl DocumentContext,
     data_manager: &HTMLDataManager,
+    encoding: Option<&PositionEncodingKind>,
 ) -> Vec<DocumentLink> {
     let mut links = vec![];
+    scan_document_links(
+        uri,
+        document,
+        html_document,
+        document_context,
+        data_manager,
+        encoding,
+        |link| links.push(link),
+    );
+    links
+}
+
+/// Like `find_document_links`, and the links are passed to `on_chunk` in chunks of `chunk_size`
+/// in document order while the document is scanned
+#[allow(clippy::too_many_arguments)]
+pub fn find_document_links_streaming(
+    uri: &Url,
+    document: &FullTextDocument,
+    html_document: &HTMLDocument,
+    document_context: &impl DocumentContext,
+    data_manager: &HTMLDataManager,
+    encoding: Option<&PositionEncodingKind>,
+    chunk_size: usize,
+    mut on_chunk: impl FnMut(Vec<DocumentLink>),
+) {
+    let mut chunks = Chunks::new(chunk_size, &mut on_chunk);
+    scan_document_links(
+        uri,
+        document,
+        html_document,
+        document_context,
+        data_manager,
+        encoding,
+        |link| chunks.push(link),
+    );
+    chunks.flush();
+}
+
+fn scan_document_links(
+    uri: &Url,
+    document: &FullTextDocument,
+    html_document: &HTMLDocument,
+    document_context: &impl DocumentContext,
+    data_manager: &HTMLDataManager,
+    encoding: Option<&PositionEncodingKind>,
+    mut on_link: impl FnMut(DocumentLink),
+) {
+    let index = html_document.index();
+    let mut push = |mut link: DocumentLink| {
+        resolve_local_target(uri, document, encoding, index, &mut link);
+        on_link(link);
+    };
     let mut scanner = Scanner::new(
         document.get_content(None),
         0,
         ScannerState::WithinContent,
         false,
     );
+    let template_syntax = data_manager.is_template_language(document.language_id());
+    let case_sensitive = data_manager.is_case_sensitive(document.language_id());
+    scanner.set_template_syntax(template_syntax);
     let mut last_attribute_name = None;
     let mut last_tag_name = None;
     let mut in_base_tag = false;
     let mut base = None;
-    let mut id_locations = HashMap::new();
+    // `http-equiv="refresh"` and `content` of `<meta>`, they may be in any order
+    let mut is_refresh = false;
+    let mut refresh_content: Option<(String, usize)> = None;
 
     let mut token = scanner.scan();
     while token != TokenType::EOS {
         match token {
             TokenType::StartTag => {
-                last_tag_name = Some(scanner.get_token_text().to_lowercase());
+                let tag_name = scanner.get_token_text();
+                last_tag_name = Some(if case_sensitive {
+                    tag_name.to_string()
+                } else {
+                    tag_name.to_lowercase()
+                });
                 if !in_base_tag {
                     in_base_tag = last_tag_name.as_ref().unwrap() == "base";
                 }
+                is_refresh = false;
+                refresh_content = None;
+            }
+            TokenType::StartTagClose | TokenType::StartTagSelfClose => {
+                if let Some((content, offset)) = refresh_content.take().filter(|_| is_refresh) {
+                    if let Some(url) = get_refresh_url(&content) {
+                        if let Some(link) = create_link(
+                            uri,
+                            document,
+                            encoding,
+                            document_context,
+                            &content[url.clone()],
+                            offset + url.start,
+                            offset + url.end,
+                            &base,
+                        ) {
+                            push(link);
+                        }
+                    }
+                }
             }
             TokenType::AttributeName => {
                 last_attribute_name = Some(scanner.get_token_text().to_lowercase());
             }
             TokenType::AttributeValue => {
-                if last_tag_name.is_some() && last_attribute_name.is_some() {
+                // The values made by the templates are unknown until they are rendered
+                if template_syntax && contains_template(scanner.get_token_text()) {
+                    last_attribute_name = None;
+                } else if last_tag_name.is_some() && last_attribute_name.is_some() {
                     let tag_name = last_tag_name.as_ref().unwrap();
                     let attribute_name = last_attribute_name.as_ref().unwrap();
                     if data_manager.is_path_attribute(&tag_name, &attribute_name) {
@@ -60,13 +150,14 @@ pub fn find_document_links(
                             if let Some(link) = create_link(
                                 uri,
                                 document,
+                                encoding,
                                 document_context,
                                 &attribute_value,
                                 scanner.get_token_offset(),
                                 scanner.get_token_end(),
                                 &base,
                             ) {
-                                links.push(link);
+                                push(link);
                             }
                         }
                         if in_base_tag && base.is_none() {
@@ -81,10 +172,81 @@ pub fn find_document_links(
                         }
                         in_base_tag = false;
                         last_attribute_name = None;
-                    } else if attribute_name == "id" {
-                        let text = scanner.get_token_text();
-                        let id = normalize_ref(&text);
-                        id_locations.insert(id.to_string(), scanner.get_token_offset());
+                    } else if tag_name == "meta" && attribute_name == "http-equiv" {
+                        is_refresh = normalize_ref(scanner.get_token_text())
+                            .trim()
+                            .eq_ignore_ascii_case("refresh");
+                    } else if tag_name == "meta" && attribute_name == "content" {
+                        let (value, value_offset) = get_attribute_value(&scanner);
+                        refresh_content = Some((value.to_string(), value_offset));
+                    } else if attribute_name == "itemtype" {
+                        let (value, value_offset) = get_attribute_value(&scanner);
+                        let mut offset = 0;
+                        for url in value.split_ascii_whitespace() {
+                            let start = offset + value[offset..].find(url).unwrap();
+                            offset = start + url.len();
+                            if let Some(link) = create_link(
+                                uri,
+                                document,
+                                encoding,
+                                document_context,
+                                url,
+                                value_offset + start,
+                                value_offset + offset,
+                                &base,
+                            ) {
+                                push(link);
+                            }
+                        }
+                    } else if attribute_name == "style" {
+                        let (value, value_offset) = get_attribute_value(&scanner);
+                        for url in get_css_urls(value) {
+                            if let Some(link) = create_link(
+                                uri,
+                                document,
+                                encoding,
+                                document_context,
+                                &value[url.clone()],
+                                value_offset + url.start,
+                                value_offset + url.end,
+                                &base,
+                            ) {
+                                push(link);
+                            }
+                        }
+                    } else if data_manager.is_srcset_attribute(tag_name, attribute_name) {
+                        let (value, value_offset) = get_attribute_value(&scanner);
+                        for url in strings::get_srcset_urls(value) {
+                            if let Some(link) = create_link(
+                                uri,
+                                document,
+                                encoding,
+                                document_context,
+                                &value[url.clone()],
+                                value_offset + url.start,
+                                value_offset + url.end,
+                                &base,
+                            ) {
+                                push(link);
+                            }
+                        }
+                    }
+                }
+            }
+            TokenType::Styles => {
+                let styles = scanner.get_token_text();
+                for url in get_css_urls(styles) {
+                    if let Some(link) = create_link(
+                        uri,
+                        document,
+                        encoding,
+                        document_context,
+                        &styles[url.clone()],
+                        scanner.get_token_offset() + url.start,
+                        scanner.get_token_offset() + url.end,
+                        &base,
+                    ) {
+                        push(link);
                     }
                 }
             }
@@ -92,37 +254,44 @@ pub fn find_document_links(
         }
         token = scanner.scan();
     }
+}
 
-    for link in &mut links {
-        let local_with_hash = format!("{}#", uri);
-        if let Some(target) = &mut link.target {
-            let target = target.to_string();
-            if target.starts_with(&local_with_hash) {
-                let hash = &target[local_with_hash.len()..];
-                if let Some(offset) = id_locations.get(hash) {
-                    let pos = document.position_at(*offset as u32);
-                    link.target = Some(
-                        Url::parse(&format!(
-                            "{}{},{}",
-                            local_with_hash,
-                            pos.line + 1,
-                            pos.character + 1
-                        ))
-                        .unwrap(),
-                    )
-                } else {
-                    link.target = Some(uri.clone());
-                }
+/// Point the link to the fragment of the document at the line and the column of the element with the id
+fn resolve_local_target(
+    uri: &Url,
+    document: &FullTextDocument,
+    encoding: Option<&PositionEncodingKind>,
+    index: &HTMLDocumentIndex,
+    link: &mut DocumentLink,
+) {
+    let local_with_hash = format!("{}#", uri);
+    if let Some(target) = &mut link.target {
+        let target = target.to_string();
+        if target.starts_with(&local_with_hash) {
+            let hash = &target[local_with_hash.len()..];
+            if let Some(node) = index.find_id(hash) {
+                let pos = position::position_at(document, node.offset, encoding);
+                link.target = Some(
+                    Url::parse(&format!(
+                        "{}{},{}",
+                        local_with_hash,
+                        pos.line + 1,
+                        pos.character + 1
+                    ))
+                    .unwrap(),
+                )
+            } else {
+                link.target = Some(uri.clone());
             }
         }
     }
-
-    links
 }
 
+#[allow(clippy::too_many_arguments)]
 fn create_link(
     uri: &Url,
     document: &FullTextDocument,
+    encoding: Option<&PositionEncodingKind>,
     document_context: &impl DocumentContext,
     attribute_value: &str,
     mut start_offset: usize,
@@ -142,8 +311,8 @@ fn create_link(
 
     Some(DocumentLink {
         range: Range::new(
-            document.position_at(start_offset as u32),
-            document.position_at(end_offset as u32),
+            position::position_at(document, start_offset, encoding),
+            position::position_at(document, end_offset, encoding),
         ),
         target,
         tooltip: None,
@@ -151,8 +320,107 @@ fn create_link(
     })
 }
 
+/// Get the range of the URL in the `content` of `<meta http-equiv="refresh">`,
+/// such as `/next.html` of `5; url=/next.html`
+///
+/// <https://html.spec.whatwg.org/multipage/semantics.html#shared-declarative-refresh-steps>
+fn get_refresh_url(content: &str) -> Option<std::ops::Range<usize>> {
+    let bytes = content.as_bytes();
+    let mut offset = 0;
+    let skip_whitespace = |mut offset: usize| {
+        while offset < bytes.len() && bytes[offset].is_ascii_whitespace() {
+            offset += 1;
+        }
+        offset
+    };
+    offset = skip_whitespace(offset);
+    while offset < bytes.len() && (bytes[offset].is_ascii_digit() || bytes[offset] == b'.') {
+        offset += 1;
+    }
+    offset = skip_whitespace(offset);
+    if offset < bytes.len() && (bytes[offset] == b';' || bytes[offset] == b',') {
+        offset += 1;
+    }
+    offset = skip_whitespace(offset);
+    if bytes.len() >= offset + 3 && bytes[offset..offset + 3].eq_ignore_ascii_case(b"url") {
+        let equal = skip_whitespace(offset + 3);
+        if equal < bytes.len() && bytes[equal] == b'=' {
+            offset = skip_whitespace(equal + 1);
+        }
+    }
+    let mut end = content.trim_end().len();
+    if offset < end && (bytes[offset] == b'"' || bytes[offset] == b'\'') {
+        let quote = bytes[offset];
+        offset += 1;
+        end = content[offset..]
+            .find(quote as char)
+            .map_or(end, |i| offset + i);
+    }
+    if offset < end {
+        Some(offset..end)
+    } else {
+        None
+    }
+}
+
+/// Get the ranges of the URLs in the `url()` functions of the CSS, including the quotes
+fn get_css_urls(css: &str) -> Vec<std::ops::Range<usize>> {
+    let bytes = css.as_bytes();
+    let mut urls = vec![];
+    let mut offset = 0;
+    while offset + 4 <= bytes.len() {
+        if !bytes[offset..offset + 4].eq_ignore_ascii_case(b"url(")
+            || offset > 0
+                && (bytes[offset - 1].is_ascii_alphanumeric() || bytes[offset - 1] == b'-')
+        {
+            offset += 1;
+            continue;
+        }
+        offset += 4;
+        while offset < bytes.len() && bytes[offset].is_ascii_whitespace() {
+            offset += 1;
+        }
+        let start = offset;
+        if offset < bytes.len() && (bytes[offset] == b'"' || bytes[offset] == b'\'') {
+            let quote = bytes[offset];
+            offset += 1;
+            while offset < bytes.len() && bytes[offset] != quote && bytes[offset] != b'\n' {
+                offset += 1;
+            }
+            if offset < bytes.len() && bytes[offset] == quote {
+                offset += 1;
+                urls.push(start..offset);
+            }
+        } else {
+            while offset < bytes.len()
+                && bytes[offset] != b')'
+                && !bytes[offset].is_ascii_whitespace()
+            {
+                offset += 1;
+            }
+            if offset > start {
+                urls.push(start..offset);
+            }
+        }
+    }
+    urls
+}
+
+/// The value of the attribute value token without the quotes, and the offset of the value
+fn get_attribute_value<'s>(scanner: &'s Scanner) -> (&'s str, usize) {
+    let attribute_value = scanner.get_token_text();
+    let value = normalize_ref(attribute_value);
+    let offset = scanner.get_token_offset()
+        + if value.len() < attribute_value.len() {
+            1
+        } else {
+            0
+        };
+    (value, offset)
+}
+
 fn normalize_ref(url: &str) -> &str {
-    if url.len() > 0 {
+    if url.len() > 1 {
         let first = url.get(0..1);
         let last = url.get(url.len() - 1..url.len());
         if first == last && (first == Some("'") || first == Some(r#"""#)) {
@@ -190,7 +458,7 @@ fn get_workspace_url(
         return None;
     }
     if token_content.starts_with("#") {
-        return Some(format!("{}{}", document_uri.to_string(), token_content));
+        return Some(format!("{}{}", document_uri, token_content));
     }
 
     if token_content.starts_with("//") {
@@ -1,14 +1,18 @@
 use lsp_textdocument::FullTextDocument;
-use lsp_types::Position;
+use lsp_types::{Position, PositionEncodingKind, Range};
 
-use crate::parser::html_document::HTMLDocument;
+use crate::{
+    parser::html_document::{HTMLDocument, Node},
+    utils::position,
+};
 
 pub fn find_matching_tag_position(
     document: &FullTextDocument,
     position: Position,
     html_document: &HTMLDocument,
+    encoding: Option<&PositionEncodingKind>,
 ) -> Option<Position> {
-    let offset = document.offset_at(position) as usize;
+    let offset = position::offset_at(document, position, encoding);
     let node = html_document.find_node_at(offset, &mut vec![])?;
 
     let tag = node.tag.as_ref()?;
@@ -18,14 +22,83 @@ pub fn find_matching_tag_position(
     // Within open tag, compute close tag
     if node.start + "<".len() <= offset && offset <= node.start + "<".len() + tag.len() {
         let mirror_offset = (offset - "<".len() - node.start) + end_tag_start + "</".len();
-        return Some(document.position_at(mirror_offset as u32));
+        return Some(position::position_at(document, mirror_offset, encoding));
     }
 
     // Within closing tag, compute open tag
     if end_tag_start + "</".len() <= offset && offset <= end_tag_start + "</".len() + tag.len() {
         let mirror_offset = (offset - "</".len() - end_tag_start) + node.start + "<".len();
-        return Some(document.position_at(mirror_offset as u32));
+        return Some(position::position_at(document, mirror_offset, encoding));
     }
 
     None
 }
+
+/// The ranges of the start tag name and the end tag name when `position` is in one of them
+pub fn find_matching_tag_ranges(
+    document: &FullTextDocument,
+    position: Position,
+    html_document: &HTMLDocument,
+    encoding: Option<&PositionEncodingKind>,
+) -> Option<(Range, Range)> {
+    let offset = position::offset_at(document, position, encoding);
+    let node = html_document.find_node_at(offset, &mut vec![])?;
+
+    let (start_tag_name, end_tag_name) = get_tag_name_offsets(node)?;
+    if (start_tag_name.0 <= offset && offset <= start_tag_name.1)
+        || (end_tag_name.0 <= offset && offset <= end_tag_name.1)
+    {
+        Some(to_ranges(document, start_tag_name, end_tag_name, encoding))
+    } else {
+        None
+    }
+}
+
+/// The ranges of the start tag name and the end tag name of all the elements that have an end tag,
+/// in the order of the start tags
+pub fn get_all_tag_pairs(
+    document: &FullTextDocument,
+    html_document: &HTMLDocument,
+    encoding: Option<&PositionEncodingKind>,
+) -> Vec<(Range, Range)> {
+    let mut result = vec![];
+    let mut stack: Vec<&Node> = html_document.roots.iter().rev().collect();
+    while let Some(node) = stack.pop() {
+        if let Some((start_tag_name, end_tag_name)) = get_tag_name_offsets(node) {
+            result.push(to_ranges(document, start_tag_name, end_tag_name, encoding));
+        }
+        stack.extend(node.children.iter().rev());
+    }
+    result
+}
+
+/// The offsets of the start tag name and the end tag name of the node
+fn get_tag_name_offsets(node: &Node) -> Option<((usize, usize), (usize, usize))> {
+    let tag = node.tag.as_ref()?;
+    let end_tag_start = node.end_tag_start?;
+    Some((
+        (node.start + "<".len(), node.start + "<".len() + tag.len()),
+        (
+            end_tag_start + "</".len(),
+            end_tag_start + "</".len() + tag.len(),
+        ),
+    ))
+}
+
+fn to_ranges(
+    document: &FullTextDocument,
+    start_tag_name: (usize, usize),
+    end_tag_name: (usize, usize),
+    encoding: Option<&PositionEncodingKind>,
+) -> (Range, Range) {
+    (
+        Range::new(
+            position::position_at(document, start_tag_name.0, encoding),
+            position::position_at(document, start_tag_name.1, encoding),
+        ),
+        Range::new(
+            position::position_at(document, end_tag_name.0, encoding),
+            position::position_at(document, end_tag_name.1, encoding),
+        ),
+    )
+}
@@ -0,0 +1,136 @@
+use std::ops;
+
+use lsp_types::{Command, CompletionItem, CompletionItemKind, CompletionTextEdit, Range, TextEdit};
+
+use crate::{
+    language_facts::data_manager::HTMLDataManager, utils::strings, DocumentContext,
+    FileSystemProvider, FileType,
+};
+
+/// The file system and the document to complete the paths of the attribute values,
+/// the relative paths are resolved against `uri` by `document_context`
+pub struct PathCompletion<'a> {
+    pub uri: &'a str,
+    pub document_context: &'a dyn DocumentContext,
+    pub file_system_provider: &'a dyn FileSystemProvider,
+}
+
+/// The path at the cursor of the attribute value to complete
+pub struct PathCompletionTarget {
+    /// The directory to list, it's the path before the last slash of the value before the cursor
+    pub directory: String,
+    /// The range of the value to replace by the entries of the directory, relative to the value
+    pub replace_range: ops::Range<usize>,
+}
+
+/// Get the path to complete at `cursor` of the `value` of the `attribute` of `tag`,
+/// `value` is without the quotes and `cursor` is relative to it
+///
+/// The value of `srcset` is a list of image candidates, the URL of the candidate at the cursor is completed,
+/// the paths that aren't local such as `http://` are not completed.
+/// It's `Err` with whether more input is needed, such as for `.` and `..`.
+pub fn get_path_completion_target(
+    data_manager: &HTMLDataManager,
+    tag: &str,
+    attribute: &str,
+    value: &str,
+    cursor: usize,
+) -> Result<PathCompletionTarget, bool> {
+    let url = if data_manager.is_srcset_attribute(tag, attribute) {
+        get_srcset_url_at(value, cursor).ok_or(false)?
+    } else if data_manager.is_path_attribute(tag, attribute) {
+        0..value.len()
+    } else {
+        return Err(false);
+    };
+    let full_value = &value[url.clone()];
+    if full_value.starts_with("http") || full_value.starts_with("//") {
+        return Err(false);
+    }
+    if full_value == "." || full_value == ".." {
+        return Err(true);
+    }
+
+    let value_before_cursor = &value[url.start..cursor];
+    let Some(last_slash) = value_before_cursor.rfind('/') else {
+        return Ok(PathCompletionTarget {
+            directory: ".".to_string(),
+            replace_range: url,
+        });
+    };
+    // Replace the segment of the cursor, such as `src` of `./s|rc/test.js`
+    let start = url.start + last_slash + 1;
+    let end = value[start..url.end]
+        .find(' ')
+        .map_or(url.end, |index| start + index);
+    Ok(PathCompletionTarget {
+        directory: value_before_cursor[..=last_slash].to_string(),
+        replace_range: start..end,
+    })
+}
+
+/// The range of the URL of the image candidate at `cursor` of the `srcset`,
+/// it's empty at the cursor when a new candidate is started, and `None` in the descriptors
+fn get_srcset_url_at(value: &str, cursor: usize) -> Option<ops::Range<usize>> {
+    if let Some(url) = strings::get_srcset_urls(value)
+        .into_iter()
+        .find(|url| url.start <= cursor && cursor <= url.end)
+    {
+        return Some(url);
+    }
+    let before = value[..cursor].trim_end();
+    if before.is_empty() || before.ends_with(',') {
+        Some(cursor..cursor)
+    } else {
+        None
+    }
+}
+
+/// The entries of the `directory` except the hidden ones to replace `range`,
+/// the directories end with `/` and trigger the completion again to continue the path
+pub async fn provide_path_suggestions(
+    path_completion: &PathCompletion<'_>,
+    directory: &str,
+    range: Range,
+) -> Vec<CompletionItem> {
+    let Some(directory) = path_completion
+        .document_context
+        .resolve_reference(directory, path_completion.uri)
+    else {
+        return vec![];
+    };
+    let mut result = vec![];
+    for (name, file_type) in path_completion
+        .file_system_provider
+        .read_directory(directory)
+        .await
+    {
+        if name.starts_with('.') {
+            continue;
+        }
+        let (label, kind, command) = if file_type == FileType::Directory {
+            (
+                format!("{}/", name),
+                CompletionItemKind::FOLDER,
+                Some(Command {
+                    title: "Suggest".to_string(),
+                    command: "editor.action.triggerSuggest".to_string(),
+                    arguments: None,
+                }),
+            )
+        } else {
+            (name, CompletionItemKind::FILE, None)
+        };
+        result.push(CompletionItem {
+            label: label.clone(),
+            kind: Some(kind),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range,
+                new_text: label,
+            })),
+            command,
+            ..Default::default()
+        });
+    }
+    result
+}
@@ -1,9 +1,12 @@
 use std::collections::HashMap;
 
 use lsp_textdocument::FullTextDocument;
-use lsp_types::{Position, Range, TextEdit, Url, WorkspaceEdit};
+use lsp_types::{Position, PositionEncodingKind, Range, TextEdit, Url, WorkspaceEdit};
 
-use crate::parser::html_document::{HTMLDocument, Node};
+use crate::{
+    parser::html_document::{HTMLDocument, Node},
+    utils::position,
+};
 
 pub fn do_rename(
     uri: Url,
@@ -11,8 +14,9 @@ pub fn do_rename(
     position: Position,
     new_name: &str,
     html_document: &HTMLDocument,
+    encoding: Option<&PositionEncodingKind>,
 ) -> Option<WorkspaceEdit> {
-    let offset = document.offset_at(position) as usize;
+    let offset = position::offset_at(document, position, encoding);
     let node = html_document.find_node_at(offset, &mut vec![])?;
 
     let tag = node.tag.as_ref()?;
@@ -24,16 +28,16 @@ pub fn do_rename(
     let mut edits = vec![];
 
     let start_tag_range = Range::new(
-        document.position_at((node.start + "<".len()) as u32),
-        document.position_at((node.start + "<".len() + tag.len()) as u32),
+        position::position_at(document, node.start + "<".len(), encoding),
+        position::position_at(document, node.start + "<".len() + tag.len(), encoding),
     );
 
     edits.push(TextEdit::new(start_tag_range, new_name.to_string()));
 
     if let Some(end_tag_start) = node.end_tag_start {
         let end_tag_range = Range::new(
-            document.position_at((end_tag_start + "</".len()) as u32),
-            document.position_at((end_tag_start + "</".len() + tag.len()) as u32),
+            position::position_at(document, end_tag_start + "</".len(), encoding),
+            position::position_at(document, end_tag_start + "</".len() + tag.len(), encoding),
         );
         edits.push(TextEdit::new(end_tag_range, new_name.to_string()));
     }
@@ -1,13 +1,14 @@
-use std::vec;
+use std::collections::HashMap;
 
 use lsp_textdocument::FullTextDocument;
-use lsp_types::{Position, Range, SelectionRange};
+use lsp_types::{Position, PositionEncodingKind, Range, SelectionRange};
 
 use crate::{
     parser::{
         html_document::{HTMLDocument, Node},
         html_scanner::TokenType,
     },
+    utils::position,
     HTMLLanguageService,
 };
 
@@ -15,19 +16,58 @@ pub fn get_selection_ranges(
     document: &FullTextDocument,
     positions: &Vec<Position>,
     html_document: &HTMLDocument,
+    encoding: Option<&PositionEncodingKind>,
 ) -> Vec<SelectionRange> {
+    let mut token_cache = TokenCache::default();
     positions
         .iter()
-        .map(|position| get_selection_range(position, document, html_document))
+        .map(|position| {
+            get_selection_range(
+                position,
+                document,
+                html_document,
+                encoding,
+                &mut token_cache,
+            )
+        })
         .collect()
 }
 
+/// The type, start and end of a token
+type Token = (TokenType, usize, usize);
+
+/// The tokens of the scanned ranges of the document, so that the positions in the same node
+/// don't scan it again
+#[derive(Default)]
+struct TokenCache {
+    tokens: HashMap<(usize, usize), Vec<Token>>,
+}
+
+impl TokenCache {
+    /// The tokens in `content[start..end]`
+    fn get_tokens(&mut self, content: &str, start: usize, end: usize) -> &[Token] {
+        self.tokens.entry((start, end)).or_insert_with(|| {
+            let mut scanner = HTMLLanguageService::create_scanner(&content[..end], start);
+            let mut token = scanner.scan();
+            let mut tokens = vec![];
+            while token != TokenType::EOS {
+                tokens.push((token, scanner.get_token_offset(), scanner.get_token_end()));
+                token = scanner.scan();
+            }
+            tokens
+        })
+    }
+}
+
 fn get_selection_range(
     position: &Position,
     document: &FullTextDocument,
     html_document: &HTMLDocument,
+    encoding: Option<&PositionEncodingKind>,
+    token_cache: &mut TokenCache,
 ) -> SelectionRange {
-    let applicable_ranges = get_applicable_ranges(position, document, html_document);
+    let applicable_ranges =
+        get_applicable_ranges(position, document, html_document, encoding, token_cache);
     let mut prev: Option<(usize, usize)> = None;
     let mut current: Option<Box<SelectionRange>> = None;
     if applicable_ranges.len() > 0 {
@@ -37,8 +77,8 @@ fn get_selection_range(
             if !prev.is_some_and(|v| range == v) {
                 current = Some(Box::new(SelectionRange {
                     range: Range::new(
-                        document.position_at(range.0 as u32),
-                        document.position_at(range.1 as u32),
+                        position::position_at(document, range.0, encoding),
+                        position::position_at(document, range.1, encoding),
                     ),
                     parent: current,
                 }));
@@ -65,12 +105,28 @@ fn get_applicable_ranges(
     position: &Position,
     document: &FullTextDocument,
     html_document: &HTMLDocument,
+    encoding: Option<&PositionEncodingKind>,
+    token_cache: &mut TokenCache,
 ) -> Vec<(usize, usize)> {
-    let curr_offset = document.offset_at(*position) as usize;
+    let content = document.get_content(None);
+    let curr_offset = position::offset_at(document, *position, encoding);
     let mut parent_list = vec![];
     let curr_node = html_document.find_node_at(curr_offset, &mut parent_list);
 
     let mut result = get_all_parent_tag_ranges(parent_list, html_document);
+    if curr_node.is_none() {
+        // Cursor inside a comment or the doctype outside of the elements
+        let mut content_level_ranges = get_content_level_ranges(
+            content,
+            token_cache.get_tokens(content, 0, content.len()),
+            curr_offset,
+        );
+        if !content_level_ranges.is_empty() {
+            result.retain(|range| range.0 <= curr_offset && curr_offset <= range.1);
+            content_level_ranges.append(&mut result);
+            result = content_level_ranges;
+        }
+    }
     if let Some(curr_node) = curr_node {
         // Self-closing or void elements
         if curr_node.start_tag_end.is_some() && curr_node.end_tag_start.is_none() {
@@ -80,13 +136,9 @@ fn get_applicable_ranges(
                 return vec![(curr_node.start, curr_node.end)];
             }
 
-            let close_range = Range::new(
-                document.position_at(start_tag_end - 2),
-                document.position_at(start_tag_end),
-            );
-            let close_text = document.get_content(Some(close_range));
+            let close_text = content.get(start_tag_end as usize - 2..start_tag_end as usize);
 
-            if close_text == "/>" {
+            if close_text == Some("/>") {
                 // Self-closing element
                 result.insert(0, (curr_node.start + 1, start_tag_end as usize - 2));
             } else {
@@ -95,7 +147,7 @@ fn get_applicable_ranges(
             }
 
             let mut attribute_level_ranges =
-                get_attribute_level_ranges(document, curr_node, curr_offset);
+                get_attribute_level_ranges(content, curr_node, curr_offset, token_cache);
             attribute_level_ranges.append(&mut result);
             result = attribute_level_ranges;
             return result;
@@ -116,7 +168,7 @@ fn get_applicable_ranges(
         if curr_node.start < curr_offset && curr_offset < start_tag_end {
             result.insert(0, (curr_node.start + 1, start_tag_end - 1));
             let mut attribute_level_ranges =
-                get_attribute_level_ranges(document, curr_node, curr_offset);
+                get_attribute_level_ranges(content, curr_node, curr_offset, token_cache);
             attribute_level_ranges.append(&mut result);
             result = attribute_level_ranges;
             return result;
@@ -125,7 +177,13 @@ fn get_applicable_ranges(
         // Cursor inside `bar`
         if start_tag_end <= curr_offset && curr_offset <= end_tag_start {
             result.insert(0, (start_tag_end, end_tag_start));
-            return result;
+            let mut content_level_ranges = get_content_level_ranges(
+                content,
+                token_cache.get_tokens(content, start_tag_end, end_tag_start),
+                curr_offset,
+            );
+            content_level_ranges.append(&mut result);
+            return content_level_ranges;
         }
 
         // Cursor inside `</div>`
@@ -170,58 +228,117 @@ fn get_node_ranges(node: &Node) -> Vec<(usize, usize)> {
     vec![(node.start, node.end)]
 }
 
+/// The ranges of the word, comment or doctype at `curr_offset` in the `tokens` of the content
+fn get_content_level_ranges(
+    content: &str,
+    tokens: &[Token],
+    curr_offset: usize,
+) -> Vec<(usize, usize)> {
+    let index = tokens.partition_point(|(_, _, end)| *end < curr_offset);
+    let Some(&(token, token_start, token_end)) = tokens.get(index) else {
+        return vec![];
+    };
+    if token_start > curr_offset {
+        return vec![];
+    }
+    match token {
+        TokenType::Content => {
+            let is_word = |c: char| c.is_alphanumeric() || c == '_';
+            let text = &content[token_start..token_end];
+            let relative_offset = curr_offset - token_start;
+            let word_start = text[..relative_offset]
+                .rfind(|c| !is_word(c))
+                .map_or(0, |i| i + text[i..].chars().next().unwrap().len_utf8());
+            let word_end = text[relative_offset..]
+                .find(|c| !is_word(c))
+                .map_or(text.len(), |i| relative_offset + i);
+            if word_start < word_end {
+                vec![(token_start + word_start, token_start + word_end)]
+            } else {
+                vec![]
+            }
+        }
+        TokenType::StartCommentTag
+        | TokenType::Comment
+        | TokenType::EndCommentTag
+        | TokenType::StartDoctypeTag
+        | TokenType::Doctype
+        | TokenType::EndDoctypeTag => {
+            let Some(start_index) = tokens[..=index].iter().rposition(|(token, _, _)| {
+                *token == TokenType::StartCommentTag || *token == TokenType::StartDoctypeTag
+            }) else {
+                return vec![];
+            };
+            let Some(end_index) = tokens[index..].iter().position(|(token, _, _)| {
+                *token == TokenType::EndCommentTag || *token == TokenType::EndDoctypeTag
+            }) else {
+                return vec![];
+            };
+            let (_, start, _) = tokens[start_index];
+            let (_, _, end) = tokens[index + end_index];
+            let mut result = vec![];
+            // `foo` of `<!-- foo -->` and `html` of `<!DOCTYPE html>`
+            if let Some((_, inner_start, inner_end)) = tokens[start_index..index + end_index]
+                .iter()
+                .find(|(token, _, _)| *token == TokenType::Comment || *token == TokenType::Doctype)
+            {
+                let inner = &content[*inner_start..*inner_end];
+                let inner_start = inner_start + inner.len() - inner.trim_start().len();
+                let inner_end = inner_end - (inner.len() - inner.trim_end().len());
+                if inner_start <= curr_offset && curr_offset <= inner_end {
+                    result.push((inner_start, inner_end));
+                }
+            }
+            // `<!-- foo -->` and `<!DOCTYPE html>`
+            result.push((start, end));
+            result
+        }
+        _ => vec![],
+    }
+}
+
 fn get_attribute_level_ranges(
-    document: &FullTextDocument,
+    content: &str,
     curr_node: &Node,
     curr_offset: usize,
+    token_cache: &mut TokenCache,
 ) -> Vec<(usize, usize)> {
-    let curr_node_range = Range::new(
-        document.position_at(curr_node.start as u32),
-        document.position_at(curr_node.end as u32),
-    );
-    let curr_node_text = document.get_content(Some(curr_node_range));
-    let relative_offset = curr_offset - curr_node.start;
-
     // Tag level semantic selection
 
-    let mut scanner = HTMLLanguageService::create_scanner(curr_node_text, 0);
-    let mut token = scanner.scan();
-
     // For text like
     // <div class="foo">bar</div>
 
-    let position_offset = curr_node.start;
+    let start_tag_end = curr_node.start_tag_end.unwrap_or(curr_node.end);
+    let tokens = token_cache.get_tokens(content, curr_node.start, start_tag_end);
 
     let mut result = vec![];
 
     let mut is_inside_attribute = false;
     let mut attr_start = 0;
 
-    while token != TokenType::EOS {
+    for &(token, token_offset, token_end) in tokens {
         match token {
             TokenType::AttributeName => {
-                if relative_offset < scanner.get_token_offset() {
+                if curr_offset < token_offset {
                     is_inside_attribute = false;
                 } else {
-                    if relative_offset <= scanner.get_token_end() {
+                    if curr_offset <= token_end {
                         // `class`
-                        result.insert(0, (scanner.get_token_offset(), scanner.get_token_end()));
+                        result.insert(0, (token_offset, token_end));
                     }
                     is_inside_attribute = true;
-                    attr_start = scanner.get_token_offset();
+                    attr_start = token_offset;
                 }
             }
             TokenType::AttributeValue => {
                 if is_inside_attribute {
-                    let value_text = scanner.get_token_text();
-                    if relative_offset < scanner.get_token_offset() {
+                    let value_text = &content[token_offset..token_end];
+                    if curr_offset < token_offset {
                         // `class="foo"`
-                        result.push((attr_start, scanner.get_token_end()));
-                    } else if relative_offset >= scanner.get_token_offset()
-                        && relative_offset <= scanner.get_token_end()
-                    {
+                        result.push((attr_start, token_end));
+                    } else if curr_offset >= token_offset && curr_offset <= token_end {
                         // `"foo"`
-                        result.insert(0, (scanner.get_token_offset(), scanner.get_token_end()));
+                        result.insert(0, (token_offset, token_end));
                         // `foo`
                         let first_ch = value_text.get(0..1);
                         let end_ch = value_text.get((value_text.len() - 1)..);
@@ -230,26 +347,17 @@ fn get_attribute_level_ranges(
                             || (first_ch.is_some_and(|ch| ch == "'")
                                 && end_ch.is_some_and(|ch| ch == "'"))
                         {
-                            if relative_offset >= scanner.get_token_offset() + 1
-                                && relative_offset <= scanner.get_token_end() - 1
-                            {
-                                result.insert(
-                                    0,
-                                    (scanner.get_token_offset() + 1, scanner.get_token_end() - 1),
-                                );
+                            if curr_offset > token_offset && curr_offset < token_end {
+                                result.insert(0, (token_offset + 1, token_end - 1));
                             }
                         }
                         // `class="foo"`
-                        result.push((attr_start, scanner.get_token_end()));
+                        result.push((attr_start, token_end));
                     }
                 }
             }
             _ => {}
         }
-        token = scanner.scan();
     }
     result
-        .iter()
-        .map(|pair| (pair.0 + position_offset, pair.1 + position_offset))
-        .collect()
 }
@@ -1,66 +1,275 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use lsp_textdocument::FullTextDocument;
-use lsp_types::{DocumentSymbol, Location, Range, SymbolInformation, SymbolKind, Url};
+use lsp_types::{
+    DocumentSymbol, Location, PositionEncodingKind, Range, SymbolInformation, SymbolKind,
+    SymbolTag, Url,
+};
 
-use crate::parser::html_document::{HTMLDocument, Node};
+use crate::{
+    parser::{
+        html_document::{HTMLDocument, Node},
+        html_parse::HTMLParser,
+    },
+    utils::{chunks::Chunks, position},
+    HTMLDataManager,
+};
 
 pub fn find_document_symbols(
     uri: &Url,
     document: &FullTextDocument,
     html_document: &HTMLDocument,
-) -> Vec<SymbolInformation> {
+    data_manager: &HTMLDataManager,
+    settings: Option<&SymbolsConfiguration>,
+    encoding: Option<&PositionEncodingKind>,
+) -> (Vec<SymbolInformation>, bool) {
     let mut symbols = vec![];
-    let symbols2 = find_document_symbols2(document, html_document);
+    let exceeded = provide_root_symbols(
+        document,
+        html_document,
+        data_manager,
+        settings,
+        encoding,
+        |root| walk(uri, &root, None, &mut symbols),
+    );
 
-    for symbol in &symbols2 {
-        walk(uri, symbol, None, &mut symbols);
-    }
+    (symbols, exceeded)
+}
+
+/// Like `find_document_symbols`, and the symbols are passed to `on_chunk` in chunks of `chunk_size`
+/// in document order, it returns whether the symbols are truncated
+#[allow(clippy::too_many_arguments)]
+pub fn find_document_symbols_streaming(
+    uri: &Url,
+    document: &FullTextDocument,
+    html_document: &HTMLDocument,
+    data_manager: &HTMLDataManager,
+    settings: Option<&SymbolsConfiguration>,
+    encoding: Option<&PositionEncodingKind>,
+    chunk_size: usize,
+    mut on_chunk: impl FnMut(Vec<SymbolInformation>),
+) -> bool {
+    let mut chunks = Chunks::new(chunk_size, &mut on_chunk);
+    let exceeded = provide_root_symbols(
+        document,
+        html_document,
+        data_manager,
+        settings,
+        encoding,
+        |root| {
+            let mut symbols = vec![];
+            walk(uri, &root, None, &mut symbols);
+            for symbol in symbols {
+                chunks.push(symbol);
+            }
+        },
+    );
+    chunks.flush();
 
-    symbols
+    exceeded
 }
 
 pub fn find_document_symbols2(
     document: &FullTextDocument,
     html_document: &HTMLDocument,
-) -> Vec<DocumentSymbol> {
+    data_manager: &HTMLDataManager,
+    settings: Option<&SymbolsConfiguration>,
+    encoding: Option<&PositionEncodingKind>,
+) -> (Vec<DocumentSymbol>, bool) {
     let mut symbols = vec![];
+    let exceeded = provide_root_symbols(
+        document,
+        html_document,
+        data_manager,
+        settings,
+        encoding,
+        |root| symbols.push(root),
+    );
+
+    (symbols, exceeded)
+}
+
+/// Like `find_document_symbols2`, and the symbols of the root elements with their children
+/// are passed to `on_chunk` in chunks of `chunk_size`, it returns whether the symbols are truncated
+pub fn find_document_symbols2_streaming(
+    document: &FullTextDocument,
+    html_document: &HTMLDocument,
+    data_manager: &HTMLDataManager,
+    settings: Option<&SymbolsConfiguration>,
+    encoding: Option<&PositionEncodingKind>,
+    chunk_size: usize,
+    mut on_chunk: impl FnMut(Vec<DocumentSymbol>),
+) -> bool {
+    let mut chunks = Chunks::new(chunk_size, &mut on_chunk);
+    let exceeded = provide_root_symbols(
+        document,
+        html_document,
+        data_manager,
+        settings,
+        encoding,
+        |root| chunks.push(root),
+    );
+    chunks.flush();
+
+    exceeded
+}
+
+/// Provide the symbols of the root elements to `on_root` one by one,
+/// it returns whether the symbols are truncated by `SymbolsConfiguration::max_symbols`
+fn provide_root_symbols(
+    document: &FullTextDocument,
+    html_document: &HTMLDocument,
+    data_manager: &HTMLDataManager,
+    settings: Option<&SymbolsConfiguration>,
+    encoding: Option<&PositionEncodingKind>,
+    mut on_root: impl FnMut(DocumentSymbol),
+) -> bool {
+    let deprecated_tags = get_deprecated_tags(document.language_id(), data_manager);
+    let mut remaining = settings
+        .and_then(|settings| settings.max_symbols)
+        .unwrap_or(usize::MAX);
 
-    for root in &html_document.roots {
-        provide_file_symbols_internal(document, root, &mut symbols);
+    for root in html_document.roots.iter() {
+        let mut symbols = vec![];
+        let completed = provide_file_symbols_internal(
+            document,
+            root,
+            &deprecated_tags,
+            settings,
+            encoding,
+            &mut remaining,
+            &mut symbols,
+        );
+        // the root has the truncated children, or it's missing if the limit is reached before it
+        symbols.into_iter().for_each(&mut on_root);
+        if !completed {
+            return true;
+        }
     }
 
-    symbols
+    false
+}
+
+/// The lowercase names of the tags that are marked as deprecated by the data providers
+fn get_deprecated_tags(language_id: &str, data_manager: &HTMLDataManager) -> HashSet<String> {
+    data_manager
+        .get_applicable_data_providers(language_id)
+        .iter()
+        .flat_map(|provider| provider.provide_tags())
+        .filter(|tag| tag.deprecated.unwrap_or_default())
+        .map(|tag| tag.name.to_lowercase())
+        .collect()
 }
 
+/// Provide the symbol of the node and its descendants in document order,
+/// it returns `false` if the limit of `remaining` is reached before all are provided
 fn provide_file_symbols_internal(
     document: &FullTextDocument,
     node: &Node,
+    deprecated_tags: &HashSet<String>,
+    settings: Option<&SymbolsConfiguration>,
+    encoding: Option<&PositionEncodingKind>,
+    remaining: &mut usize,
     symbols: &mut Vec<DocumentSymbol>,
-) {
+) -> bool {
+    if *remaining == 0 {
+        return false;
+    }
+    *remaining -= 1;
+
+    // The nodes being visited, with the index of their next child and the symbols of their children
+    let mut stack: Vec<(&Node, usize, Vec<DocumentSymbol>)> = vec![(node, 0, vec![])];
+    let mut completed = true;
+    while let Some((node, next, _)) = stack.last_mut() {
+        if completed {
+            if let Some(child) = node.children.get(*next) {
+                *next += 1;
+                if *remaining == 0 {
+                    completed = false;
+                } else {
+                    *remaining -= 1;
+                    stack.push((child, 0, vec![]));
+                }
+                continue;
+            }
+        }
+        let (node, _, children) = stack.pop().unwrap();
+        let symbol = node_to_symbol(
+            document,
+            node,
+            children,
+            deprecated_tags,
+            settings,
+            encoding,
+        );
+        match stack.last_mut() {
+            Some((_, _, siblings)) => siblings.push(symbol),
+            None => symbols.push(symbol),
+        }
+    }
+    completed
+}
+
+fn node_to_symbol(
+    document: &FullTextDocument,
+    node: &Node,
+    children: Vec<DocumentSymbol>,
+    deprecated_tags: &HashSet<String>,
+    settings: Option<&SymbolsConfiguration>,
+    encoding: Option<&PositionEncodingKind>,
+) -> DocumentSymbol {
     let name = node_to_name(node);
     let range = Range::new(
-        document.position_at(node.start as u32),
-        document.position_at(node.end as u32),
+        position::position_at(document, node.start, encoding),
+        position::position_at(document, node.end, encoding),
     );
 
-    let mut children = vec![];
-
-    for child in &node.children {
-        provide_file_symbols_internal(document, &child, &mut children);
-    }
+    let is_deprecated = node
+        .tag
+        .as_ref()
+        .is_some_and(|tag| deprecated_tags.contains(&tag.to_lowercase()));
 
     #[allow(deprecated)]
-    let symbol = DocumentSymbol {
+    DocumentSymbol {
         name,
-        detail: None,
-        kind: SymbolKind::FIELD,
-        range: range.clone(),
+        detail: if settings.is_some_and(|settings| settings.attributes_in_detail) {
+            node_to_detail(node)
+        } else {
+            None
+        },
+        kind: get_symbol_kind(node.tag.as_deref(), settings),
+        range,
         selection_range: range,
-        tags: None,
+        tags: if is_deprecated {
+            Some(vec![SymbolTag::DEPRECATED])
+        } else {
+            None
+        },
         children: Some(children),
         deprecated: None,
-    };
+    }
+}
 
-    symbols.push(symbol);
+/// The kind of the symbol of the element, by the category of the element
+fn get_symbol_kind(tag: Option<&str>, settings: Option<&SymbolsConfiguration>) -> SymbolKind {
+    let Some(tag) = tag else {
+        return SymbolKind::FIELD;
+    };
+    let tag = tag.to_lowercase();
+    if let Some(kind) = settings.and_then(|settings| settings.symbol_kinds.get(&tag)) {
+        return *kind;
+    }
+    match tag.as_str() {
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "hgroup" | "title" => SymbolKind::STRING,
+        "form" | "fieldset" => SymbolKind::OBJECT,
+        "input" | "select" | "textarea" | "button" | "output" | "datalist" => SymbolKind::PROPERTY,
+        "article" | "aside" | "nav" | "section" | "header" | "footer" | "main" => {
+            SymbolKind::NAMESPACE
+        }
+        "script" => SymbolKind::FUNCTION,
+        "template" => SymbolKind::CLASS,
+        _ => SymbolKind::FIELD,
+    }
 }
 
 fn walk(
@@ -69,27 +278,58 @@ fn walk(
     parent: Option<&DocumentSymbol>,
     symbols: &mut Vec<SymbolInformation>,
 ) {
-    #[allow(deprecated)]
-    let symbol = SymbolInformation {
-        name: node.name.clone(),
-        kind: node.kind.clone(),
-        tags: None,
-        location: Location::new(uri.clone(), node.range),
-        deprecated: None,
-        container_name: parent.map(|v| v.name.clone()),
-    };
+    let mut stack = vec![(node, parent)];
+    while let Some((node, parent)) = stack.pop() {
+        #[allow(deprecated)]
+        let symbol = SymbolInformation {
+            name: node.name.clone(),
+            kind: node.kind,
+            tags: node.tags.clone(),
+            location: Location::new(uri.clone(), node.range),
+            deprecated: None,
+            container_name: parent.map(|v| v.name.clone()),
+        };
 
-    symbols.push(symbol);
+        symbols.push(symbol);
 
-    if let Some(children) = &node.children {
-        for child in children {
-            walk(uri, child, Some(&node), symbols)
+        if let Some(children) = &node.children {
+            stack.extend(children.iter().rev().map(|child| (child, Some(node))));
         }
     }
 }
 
+/// The summary of the attributes except `id` and `class` in source order, such as `type=submit name=save`
+fn node_to_detail(node: &Node) -> Option<String> {
+    let mut attributes: Vec<_> = node
+        .attributes
+        .iter()
+        .filter(|(name, _)| !name.eq_ignore_ascii_case("id") && !name.eq_ignore_ascii_case("class"))
+        .collect();
+    if attributes.is_empty() {
+        return None;
+    }
+    attributes.sort_by_key(|(_, attribute)| attribute.offset);
+
+    let detail = attributes
+        .into_iter()
+        .map(|(name, attribute)| match &attribute.value {
+            Some(value) => {
+                let unquoted = value.trim_matches(|c| c == '"' || c == '\'');
+                if unquoted.contains(char::is_whitespace) {
+                    format!("{}={}", name, value)
+                } else {
+                    format!("{}={}", name, unquoted)
+                }
+            }
+            None => name.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some(detail)
+}
+
 fn node_to_name(node: &Node) -> String {
-    if let Some(mut name) = node.tag.clone() {
+    if let Some(mut name) = node.tag.as_ref().map(|tag| tag.to_string()) {
         if !node.attributes.is_empty() {
             let id = node.attributes.get("id").map(|v| v.value.clone()).flatten();
             let class = node
@@ -115,3 +355,111 @@ fn node_to_name(node: &Node) -> String {
         "?".to_string()
     }
 }
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SymbolsConfiguration {
+    /// The kinds of the symbols of the elements keyed by the lowercase tag names,
+    /// they take precedence over the kinds by the categories of the elements,
+    /// such as `Namespace` for the sections and `Property` for the form controls
+    pub symbol_kinds: HashMap<String, SymbolKind>,
+    /// The maximum number of the symbols, the first ones in document order are returned
+    /// if the document has more symbols
+    pub max_symbols: Option<usize>,
+    /// Summarize the attributes except `id` and `class` in `DocumentSymbol::detail`,
+    /// such as `type=submit name=save`
+    pub attributes_in_detail: bool,
+}
+
+/// The parsed documents of the workspace and their symbols,
+/// it answers `workspace/symbol` of the HTML projects
+#[derive(Default)]
+pub struct WorkspaceDocuments {
+    documents: BTreeMap<Url, WorkspaceDocument>,
+    position_encoding: Option<PositionEncodingKind>,
+}
+
+struct WorkspaceDocument {
+    document: FullTextDocument,
+    html_document: HTMLDocument,
+    symbols: Vec<SymbolInformation>,
+}
+
+impl WorkspaceDocuments {
+    pub fn new() -> WorkspaceDocuments {
+        WorkspaceDocuments::default()
+    }
+
+    /// The locations of the symbols are counted in `position_encoding`,
+    /// such as the encoding negotiated with the client
+    pub fn with_position_encoding(position_encoding: PositionEncodingKind) -> WorkspaceDocuments {
+        WorkspaceDocuments {
+            position_encoding: Some(position_encoding),
+            ..Default::default()
+        }
+    }
+
+    /// Parse the document and collect its symbols, it replaces the document of the `uri`
+    pub fn update(
+        &mut self,
+        uri: Url,
+        document: FullTextDocument,
+        data_manager: &HTMLDataManager,
+        settings: Option<&SymbolsConfiguration>,
+    ) {
+        let html_document = HTMLParser::parse_document(&document, data_manager);
+        let (symbols, _) = find_document_symbols(
+            &uri,
+            &document,
+            &html_document,
+            data_manager,
+            settings,
+            self.position_encoding.as_ref(),
+        );
+        self.documents.insert(
+            uri,
+            WorkspaceDocument {
+                document,
+                html_document,
+                symbols,
+            },
+        );
+    }
+
+    /// Remove the document of the `uri`, return whether it exists
+    pub fn remove(&mut self, uri: &Url) -> bool {
+        self.documents.remove(uri).is_some()
+    }
+
+    pub fn get(&self, uri: &Url) -> Option<(&FullTextDocument, &HTMLDocument)> {
+        self.documents
+            .get(uri)
+            .map(|document| (&document.document, &document.html_document))
+    }
+
+    pub fn uris(&self) -> impl Iterator<Item = &Url> {
+        self.documents.keys()
+    }
+
+    /// Find the symbols whose names fuzzy match the `query` in all documents,
+    /// the names include the ids and the classes of the elements, such as `div#main.nav`
+    pub fn find_workspace_symbols(&self, query: &str) -> Vec<SymbolInformation> {
+        let query: Vec<char> = query.to_lowercase().chars().collect();
+        self.documents
+            .values()
+            .flat_map(|document| &document.symbols)
+            .filter(|symbol| is_fuzzy_match(&query, &symbol.name))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Whether the characters of the `query` appear in the `name` in order, ignoring case
+fn is_fuzzy_match(query: &[char], name: &str) -> bool {
+    let mut query = query.iter().peekable();
+    for c in name.to_lowercase().chars() {
+        if query.peek() == Some(&&c) {
+            query.next();
+        }
+    }
+    query.peek().is_none()
+}
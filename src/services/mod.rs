@@ -14,6 +14,8 @@ pub(crate) mod html_linked_editing;
 pub(crate) mod html_links;
 #[cfg(feature = "matching_tag_position")]
 pub(crate) mod html_matching_tag_position;
+#[cfg(feature = "completion")]
+pub(crate) mod html_path_completion;
 #[cfg(feature = "rename")]
 pub(crate) mod html_rename;
 #[cfg(feature = "selection_range")]
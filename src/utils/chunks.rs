@@ -0,0 +1,31 @@
+/// Pass the items to the callback in chunks of `size`, such as for the partial results of LSP
+pub struct Chunks<'a, T> {
+    size: usize,
+    items: Vec<T>,
+    callback: &'a mut dyn FnMut(Vec<T>),
+}
+
+impl<'a, T> Chunks<'a, T> {
+    /// The `size` is at least 1
+    pub fn new(size: usize, callback: &'a mut dyn FnMut(Vec<T>)) -> Chunks<'a, T> {
+        Chunks {
+            size: size.max(1),
+            items: vec![],
+            callback,
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+        if self.items.len() >= self.size {
+            (self.callback)(std::mem::take(&mut self.items));
+        }
+    }
+
+    /// Pass the rest of the items
+    pub fn flush(&mut self) {
+        if !self.items.is_empty() {
+            (self.callback)(std::mem::take(&mut self.items));
+        }
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(any(feature = "folding", feature = "links", feature = "symbols"))]
+pub mod chunks;
 pub mod markdown;
 pub mod markup;
+pub mod position;
 pub mod strings;
@@ -0,0 +1,92 @@
+use lsp_textdocument::FullTextDocument;
+use lsp_types::{Position, PositionEncodingKind};
+
+/// Convert the byte `offset` to a `Position` whose character is counted in `encoding`
+///
+/// If `encoding` is `None`, the conversion of `FullTextDocument` is used.
+pub fn position_at(
+    document: &FullTextDocument,
+    offset: usize,
+    encoding: Option<&PositionEncodingKind>,
+) -> Position {
+    let encoding = if let Some(encoding) = encoding {
+        encoding
+    } else {
+        return document.position_at(offset as u32);
+    };
+    let text = document.get_content(None);
+    let offset = floor_char_boundary(text, offset);
+    let line = document.position_at(offset as u32).line;
+    let line_start = document.offset_at(Position::new(line, 0)) as usize;
+    let character = text[line_start..offset]
+        .chars()
+        .map(|c| char_len(c, encoding))
+        .sum::<usize>();
+    Position::new(line, character as u32)
+}
+
+/// Convert the `position` whose character is counted in `encoding` to a byte offset
+///
+/// If `encoding` is `None`, the conversion of `FullTextDocument` is used.
+pub fn offset_at(
+    document: &FullTextDocument,
+    position: Position,
+    encoding: Option<&PositionEncodingKind>,
+) -> usize {
+    let encoding = if let Some(encoding) = encoding {
+        encoding
+    } else {
+        return document.offset_at(position) as usize;
+    };
+    let text = document.get_content(None);
+    let line_start = document.offset_at(Position::new(position.line, 0)) as usize;
+    let line_end = document.offset_at(Position::new(position.line + 1, 0)) as usize;
+    let mut offset = line_start;
+    let mut character = 0;
+    for c in text[line_start..line_end].chars() {
+        if character >= position.character as usize || c == '\r' || c == '\n' {
+            break;
+        }
+        character += char_len(c, encoding);
+        offset += c.len_utf8();
+    }
+    offset
+}
+
+fn char_len(c: char, encoding: &PositionEncodingKind) -> usize {
+    if *encoding == PositionEncodingKind::UTF8 {
+        c.len_utf8()
+    } else if *encoding == PositionEncodingKind::UTF32 {
+        1
+    } else {
+        c.len_utf16()
+    }
+}
+
+fn floor_char_boundary(text: &str, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_byte() {
+        let document = FullTextDocument::new("html".to_string(), 0, "<p>\n中文😀</p>".to_string());
+        let offset = "<p>\n中文😀".len();
+        for (encoding, character) in [
+            (PositionEncodingKind::UTF8, 10),
+            (PositionEncodingKind::UTF16, 4),
+            (PositionEncodingKind::UTF32, 3),
+        ] {
+            let position = position_at(&document, offset, Some(&encoding));
+            assert_eq!(position, Position::new(1, character));
+            assert_eq!(offset_at(&document, position, Some(&encoding)), offset);
+        }
+    }
+}
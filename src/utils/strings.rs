@@ -1,7 +1,71 @@
+/// Get the byte at `index` of `text`
+///
+/// It's O(1) and never panics, even if `index` is not on a char boundary.
+pub fn byte_at(text: &str, index: usize) -> Option<u8> {
+    text.as_bytes().get(index).copied()
+}
+
+/// Whether the lowercase of `text` equals `lowercase`, without allocating the lowercase of `text`
+pub fn eq_lowercase(text: &str, lowercase: &str) -> bool {
+    text.chars()
+        .flat_map(char::to_lowercase)
+        .eq(lowercase.chars())
+}
+
+/// Remove the matching quotes around the attribute value `value`, if it's quoted
+pub fn unquote(value: &str) -> &str {
+    if value.len() > 1 {
+        let first = value.as_bytes()[0];
+        if (first == b'"' || first == b'\'') && value.as_bytes()[value.len() - 1] == first {
+            return &value[1..value.len() - 1];
+        }
+    }
+    value
+}
+
 #[cfg(any(feature = "completion", feature = "hover"))]
 pub fn is_letter_or_digit(text: &str, index: usize) -> bool {
-    use regex::Regex;
+    byte_at(text, index).is_some_and(|c| c.is_ascii_alphanumeric())
+}
 
-    let c = text.get(index..index + 1);
-    c.is_some_and(|c| Regex::new("^[A-Za-z0-9]+$").unwrap().is_match(c))
+/// Get the ranges of the URLs in the image candidates of `srcset`,
+/// every candidate is a URL followed by the optional descriptors and separated by commas
+///
+/// <https://html.spec.whatwg.org/multipage/images.html#parsing-a-srcset-attribute>
+#[cfg(any(feature = "completion", feature = "links"))]
+pub fn get_srcset_urls(value: &str) -> Vec<std::ops::Range<usize>> {
+    let bytes = value.as_bytes();
+    let mut urls = vec![];
+    let mut offset = 0;
+    while offset < bytes.len() {
+        while offset < bytes.len() && (bytes[offset].is_ascii_whitespace() || bytes[offset] == b',')
+        {
+            offset += 1;
+        }
+        let start = offset;
+        while offset < bytes.len() && !bytes[offset].is_ascii_whitespace() {
+            offset += 1;
+        }
+        let mut end = offset;
+        if end > start && bytes[end - 1] == b',' {
+            // The URL ending with commas has no descriptors
+            while end > start && bytes[end - 1] == b',' {
+                end -= 1;
+            }
+        } else {
+            let mut in_parens = false;
+            while offset < bytes.len() && (in_parens || bytes[offset] != b',') {
+                match bytes[offset] {
+                    b'(' => in_parens = true,
+                    b')' => in_parens = false,
+                    _ => {}
+                }
+                offset += 1;
+            }
+        }
+        if end > start {
+            urls.push(start..end);
+        }
+    }
+    urls
 }
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 
 #[cfg(feature = "completion")]
 use html_languageservice::{
+    html_data::IValueData, language_facts::data_provider::HTMLDataProvider, CancellationToken,
     CompletionConfiguration, DefaultDocumentContext, HTMLDataManager, HTMLLanguageService,
     HTMLLanguageServiceOptions, Quotes,
 };
@@ -159,6 +160,7 @@ fn test_quote_completion(
     expected: Option<String>,
     options: Option<&CompletionConfiguration>,
 ) {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
     let offset = value.find('|').unwrap();
     let value: &str = &format!("{}{}", &value[..offset], &value[offset + 1..]);
 
@@ -166,13 +168,16 @@ fn test_quote_completion(
     let position = document.position_at(offset as u32);
     let html_document =
         HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::new(true, None));
-    let actual =
-        HTMLLanguageService::do_quote_complete(&document, &position, &html_document, options);
+    let actual = ls.do_quote_complete(&document, &position, &html_document, options);
     assert_eq!(actual, expected);
 }
 
 #[cfg(feature = "completion")]
-fn test_tag_completion(value: &str, expected: Option<String>) {
+fn test_tag_completion(
+    value: &str,
+    expected: Option<String>,
+    options: Option<&CompletionConfiguration>,
+) {
     let offset = value.find('|').unwrap();
     let value: &str = &format!("{}{}", &value[..offset], &value[offset + 1..]);
 
@@ -183,7 +188,7 @@ fn test_tag_completion(value: &str, expected: Option<String>) {
     let position = document.position_at(offset as u32);
     let data_manager = HTMLDataManager::default();
     let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
-    let actual = ls.do_tag_complete(&document, &position, &html_document, &data_manager);
+    let actual = ls.do_tag_complete(&document, &position, &html_document, options, &data_manager);
     assert_eq!(actual, expected);
 }
 
@@ -1156,8 +1161,7 @@ async fn complete() {
         },
         Some(CompletionConfiguration {
             hide_auto_complete_proposals: true,
-            attribute_default_value: Quotes::Double,
-            provider: HashMap::new(),
+            ..Default::default()
         }),
         None,
     )
@@ -1289,20 +1293,242 @@ async fn references() {
 
 #[cfg(feature = "completion")]
 #[tokio::test]
-async fn case_sensitivity() {
+async fn character_entity() {
     test_completion_for(
-        "<LI></|",
+        "<div>&nbs|</div>",
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "&nbsp;",
+                result_text: Some("<div>&nbsp;</div>"),
+                documentation: Some(Documentation::String(
+                    "Character entity representing '\u{00A0}'".to_string(),
+                )),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<div>&#169|</div>",
+        Expected {
+            count: Some(1),
+            items: vec![ItemDescription {
+                label: "&#169;",
+                result_text: Some("<div>&#169;</div>"),
+                documentation: Some(Documentation::String(
+                    "Character entity representing '\u{00A9}'".to_string(),
+                )),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<div>&#x27|</div>",
+        Expected {
+            count: Some(1),
+            items: vec![ItemDescription {
+                label: "&#x27;",
+                result_text: Some("<div>&#x27;</div>"),
+                documentation: Some(Documentation::String(
+                    "Character entity representing '''".to_string(),
+                )),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<div>&#xzz|</div>",
+        Expected {
+            count: Some(0),
+            items: vec![],
+        },
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn custom_data_from_json() {
+    let mut data_manager = HTMLDataManager::new(false, None);
+    data_manager
+        .add_data_provider_from_json(
+            r#"{
+                "version": 1.1,
+                "tags": [
+                    {
+                        "name": "my-button",
+                        "description": { "kind": "markdown", "value": "A **custom** button" }
+                    }
+                ],
+                "globalAttributes": [
+                    { "name": "my-attr", "valueSet": "my-values" }
+                ],
+                "valueSets": [
+                    { "name": "my-values", "values": [{ "name": "on" }, { "name": "off" }] }
+                ]
+            }"#,
+        )
+        .unwrap();
+    assert!(data_manager
+        .add_data_provider_from_json("not/exists/custom-data.json")
+        .is_err());
+
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    for (value, labels) in [
+        ("<|", vec!["my-button"]),
+        ("<my-button |", vec!["my-attr"]),
+        ("<my-button my-attr=|", vec!["on", "off"]),
+    ] {
+        let offset = value.find('|').unwrap();
+        let value = format!("{}{}", &value[..offset], &value[offset + 1..]);
+        let document = FullTextDocument::new("html".to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let list = ls
+            .do_complete(
+                &document,
+                &position,
+                &html_document,
+                DefaultDocumentContext,
+                None,
+                &data_manager,
+            )
+            .await;
+        let actual: Vec<&str> = list.items.iter().map(|i| i.label.as_str()).collect();
+        for label in labels {
+            assert!(actual.contains(&label), "{} not in {:?}", label, actual);
+        }
+    }
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn custom_elements_manifest() {
+    let mut data_manager = HTMLDataManager::new(false, None);
+    data_manager
+        .add_data_provider_from_custom_elements_manifest(
+            r#"{
+                "schemaVersion": "1.0.0",
+                "modules": [
+                    {
+                        "kind": "javascript-module",
+                        "path": "src/my-button.js",
+                        "declarations": [
+                            {
+                                "kind": "class",
+                                "name": "MyButton",
+                                "customElement": true,
+                                "tagName": "my-button",
+                                "description": "A button of the design system.",
+                                "attributes": [
+                                    {
+                                        "name": "size",
+                                        "description": "The size of the button.",
+                                        "type": { "text": "'small' | 'large'" },
+                                        "default": "'small'"
+                                    }
+                                ],
+                                "slots": [
+                                    { "name": "", "description": "The label of the button." },
+                                    { "name": "icon", "description": "The icon before the label." }
+                                ],
+                                "events": [{ "name": "toggle", "description": "Fired when toggled." }]
+                            },
+                            { "kind": "function", "name": "helper" }
+                        ]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    for (value, labels) in [
+        ("<|", vec!["my-button"]),
+        ("<my-button |", vec!["size"]),
+        ("<my-button size=|", vec!["small", "large"]),
+    ] {
+        let offset = value.find('|').unwrap();
+        let value = format!("{}{}", &value[..offset], &value[offset + 1..]);
+        let document = FullTextDocument::new("html".to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let list = ls
+            .do_complete(
+                &document,
+                &position,
+                &html_document,
+                DefaultDocumentContext,
+                None,
+                &data_manager,
+            )
+            .await;
+        let actual: Vec<&str> = list.items.iter().map(|i| i.label.as_str()).collect();
+        for label in labels {
+            assert!(actual.contains(&label), "{} not in {:?}", label, actual);
+        }
+    }
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn scoped_data_provider() {
+    let data =
+        serde_json::from_str(r#"{ "version": 1.1, "tags": [{ "name": "my-vue-tag" }] }"#).unwrap();
+    let mut data_manager = HTMLDataManager::default();
+    data_manager.set_scoped_data_provider(
+        "vue",
+        Box::new(HTMLDataProvider::new("vue".to_string(), data)),
+        vec!["vue".to_string()],
+    );
+
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    for (language_id, expected) in [("vue", true), ("html", false)] {
+        let document = FullTextDocument::new(language_id.to_string(), 0, "<".to_string());
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let list = ls
+            .do_complete(
+                &document,
+                &Position::new(0, 1),
+                &html_document,
+                DefaultDocumentContext,
+                None,
+                &data_manager,
+            )
+            .await;
+        let labels: Vec<&str> = list.items.iter().map(|i| i.label.as_str()).collect();
+        assert_eq!(labels.contains(&"my-vue-tag"), expected);
+        assert!(labels.contains(&"div"));
+    }
+}
+
+#[cfg(all(feature = "completion", feature = "svg_data"))]
+#[tokio::test]
+async fn svg() {
+    test_completion_for(
+        "<svg><|",
         Expected {
             count: None,
             items: vec![
                 ItemDescription {
-                    label: "/LI",
-                    result_text: Some("<LI></LI>"),
+                    label: "linearGradient",
+                    result_text: Some("<svg><linearGradient"),
                     ..Default::default()
                 },
                 ItemDescription {
-                    label: "/li",
-                    not_available: Some(true),
+                    label: "circle",
+                    result_text: Some("<svg><circle"),
                     ..Default::default()
                 },
             ],
@@ -1312,26 +1538,54 @@ async fn case_sensitivity() {
     )
     .await;
     test_completion_for(
-        "<lI></|",
+        "<svg><circle |",
         Expected {
             count: None,
-            items: vec![ItemDescription {
-                label: "/lI",
-                result_text: Some("<lI></lI>"),
-                ..Default::default()
-            }],
+            items: vec![
+                ItemDescription {
+                    label: "cx",
+                    result_text: Some(r#"<svg><circle cx="$1""#),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "stroke-linecap",
+                    result_text: Some(r#"<svg><circle stroke-linecap="$1""#),
+                    ..Default::default()
+                },
+            ],
         },
         None,
         None,
     )
     .await;
     test_completion_for(
-        "<iNpUt |",
+        r#"<svg><circle stroke-linecap="|"#,
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "butt",
+                    result_text: Some(r#"<svg><circle stroke-linecap="butt"#),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "round",
+                    result_text: Some(r#"<svg><circle stroke-linecap="round"#),
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<div |",
         Expected {
             count: None,
             items: vec![ItemDescription {
-                label: "type",
-                result_text: Some(r#"<iNpUt type="$1""#),
+                label: "stroke-linecap",
+                not_available: Some(true),
                 ..Default::default()
             }],
         },
@@ -1339,13 +1593,48 @@ async fn case_sensitivity() {
         None,
     )
     .await;
+
+    // The SVG elements are only proposed in `<svg>`, which is proposed outside of it
+    for (value, result_text) in [
+        ("<|", "<svg"),
+        ("<div><|", "<div><svg"),
+        ("<svg></svg><|", "<svg></svg><svg"),
+        ("<svg/><|", "<svg/><svg"),
+    ] {
+        test_completion_for(
+            value,
+            Expected {
+                count: None,
+                items: vec![
+                    ItemDescription {
+                        label: "svg",
+                        result_text: Some(result_text),
+                        ..Default::default()
+                    },
+                    ItemDescription {
+                        label: "circle",
+                        not_available: Some(true),
+                        ..Default::default()
+                    },
+                    ItemDescription {
+                        label: "feBlend",
+                        not_available: Some(true),
+                        ..Default::default()
+                    },
+                ],
+            },
+            None,
+            None,
+        )
+        .await;
+    }
     test_completion_for(
-        "<INPUT TYPE=|",
+        "<circle |",
         Expected {
             count: None,
             items: vec![ItemDescription {
-                label: "color",
-                result_text: Some(r#"<INPUT TYPE="color""#),
+                label: "cx",
+                not_available: Some(true),
                 ..Default::default()
             }],
         },
@@ -1354,12 +1643,12 @@ async fn case_sensitivity() {
     )
     .await;
     test_completion_for(
-        "<dIv>|",
+        "<svg><g><|</g></svg>",
         Expected {
             count: None,
             items: vec![ItemDescription {
-                label: "</dIv>",
-                result_text: Some("<dIv>$0</dIv>"),
+                label: "feBlend",
+                result_text: Some("<svg><g><feBlend</g></svg>"),
                 ..Default::default()
             }],
         },
@@ -1369,36 +1658,118 @@ async fn case_sensitivity() {
     .await;
 }
 
-#[cfg(feature = "completion")]
+#[cfg(all(feature = "completion", feature = "mathml_data"))]
 #[tokio::test]
-async fn handlebar_completion() {
+async fn mathml() {
     test_completion_for(
-            r#"<script id="entry-template" type="text/x-handlebars-template"> <| </script>"#,
+        "<math><|",
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "mfrac",
+                    result_text: Some("<math><mfrac"),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "annotation-xml",
+                    result_text: Some("<math><annotation-xml"),
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<math display="|"#,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "block",
+                result_text: Some(r#"<math display="block"#),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<math><mo |",
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "stretchy",
+                    result_text: Some(r#"<math><mo stretchy="$1""#),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "mathvariant",
+                    result_text: Some(r#"<math><mo mathvariant="$1""#),
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+
+    // The MathML elements are only proposed in `<math>`, which is proposed once outside of it
+    for value in ["<|", "<div><|", "<math></math><|"] {
+        test_completion_for(
+            value,
             Expected {
                 count: None,
-                items: vec![ItemDescription {
-                    label: "div",
-                    result_text: Some(
-                        r#"<script id="entry-template" type="text/x-handlebars-template"> <div </script>"#,
-                    ),
-                    ..Default::default()
-                }],
+                items: vec![
+                    ItemDescription {
+                        label: "mfrac",
+                        not_available: Some(true),
+                        ..Default::default()
+                    },
+                    ItemDescription {
+                        label: "annotation-xml",
+                        not_available: Some(true),
+                        ..Default::default()
+                    },
+                ],
             },
             None,
             None,
-        ).await;
-}
-
-#[cfg(feature = "completion")]
-#[tokio::test]
-async fn support_script_type() {
+        )
+        .await;
+    }
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    let document = FullTextDocument::new("html".to_string(), 0, "<".to_string());
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let list = ls
+        .do_complete(
+            &document,
+            &Position::new(0, 1),
+            &html_document,
+            DefaultDocumentContext,
+            None,
+            &data_manager,
+        )
+        .await;
+    assert_eq!(
+        list.items
+            .iter()
+            .filter(|item| item.label == "math")
+            .count(),
+        1
+    );
     test_completion_for(
-        r#"<script id="html-template" type="text/html"> <| </script>"#,
+        "<mo |",
         Expected {
             count: None,
             items: vec![ItemDescription {
-                label: "div",
-                result_text: Some(r#"<script id="html-template" type="text/html"> <div </script>"#),
+                label: "stretchy",
+                not_available: Some(true),
                 ..Default::default()
             }],
         },
@@ -1408,13 +1779,1521 @@ async fn support_script_type() {
     .await;
 }
 
-#[cfg(feature = "completion")]
+#[cfg(all(feature = "completion", feature = "vue_data"))]
 #[tokio::test]
-async fn complete_aria() {
-    let expected_aria_attributes = vec![
-        ItemDescription {
-            label: "aria-activedescendant",
-            ..Default::default()
+async fn vue() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    for (language_id, value, labels, expected) in [
+        ("vue", "<|", vec!["transition", "keep-alive", "div"], true),
+        (
+            "vue",
+            "<a |",
+            vec!["v-if", "v-for", ":href", "@click", "href"],
+            true,
+        ),
+        ("vue", "<transition mode=|", vec!["out-in"], true),
+        ("html", "<|", vec!["transition"], false),
+        ("html", "<a |", vec!["v-if", ":href", "@click"], false),
+    ] {
+        let offset = value.find('|').unwrap();
+        let value = format!("{}{}", &value[..offset], &value[offset + 1..]);
+        let document = FullTextDocument::new(language_id.to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let list = ls
+            .do_complete(
+                &document,
+                &position,
+                &html_document,
+                DefaultDocumentContext,
+                None,
+                &data_manager,
+            )
+            .await;
+        let actual: Vec<&str> = list.items.iter().map(|i| i.label.as_str()).collect();
+        for label in labels {
+            assert_eq!(
+                actual.contains(&label),
+                expected,
+                "{} in {:?}",
+                label,
+                actual
+            );
+        }
+    }
+}
+
+#[cfg(all(feature = "completion", feature = "angular_data"))]
+#[tokio::test]
+async fn angular() {
+    test_completion_for(
+        "<div *ng|",
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "*ngIf",
+                    result_text: Some(r#"<div *ngIf="$1""#),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "[(ngModel)]",
+                    result_text: Some(r#"<div [(ngModel)]="$1""#),
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<ng-|",
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "ng-container",
+                result_text: Some("<ng-container"),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(all(feature = "completion", feature = "svelte_data"))]
+#[tokio::test]
+async fn svelte() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    for (language_id, value, labels, expected) in [
+        (
+            "svelte",
+            "<|",
+            vec!["svelte:head", "svelte:window", "div"],
+            true,
+        ),
+        (
+            "svelte",
+            "<input |",
+            vec!["bind:value", "on:click", "class:", "use:"],
+            true,
+        ),
+        (
+            "svelte",
+            "<svelte:window |",
+            vec!["bind:innerWidth", "on:click"],
+            true,
+        ),
+        ("html", "<|", vec!["svelte:head"], false),
+        ("html", "<input |", vec!["bind:value", "on:click"], false),
+    ] {
+        let offset = value.find('|').unwrap();
+        let value = format!("{}{}", &value[..offset], &value[offset + 1..]);
+        let document = FullTextDocument::new(language_id.to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let list = ls
+            .do_complete(
+                &document,
+                &position,
+                &html_document,
+                DefaultDocumentContext,
+                None,
+                &data_manager,
+            )
+            .await;
+        let actual: Vec<&str> = list.items.iter().map(|i| i.label.as_str()).collect();
+        for label in labels {
+            assert_eq!(
+                actual.contains(&label),
+                expected,
+                "{} in {:?}",
+                label,
+                actual
+            );
+        }
+    }
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn aria_role() {
+    test_completion_for(
+        r#"<div role="|"#,
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "button",
+                    result_text: Some(r#"<div role="button"#),
+                    documentation: Some(Documentation::MarkupContent(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value: "An input that allows for user-triggered actions when clicked or pressed.".to_string(),
+                    })),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "navigation",
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    for (value, supported, unsupported) in [
+        (
+            r#"<div role="slider" |"#,
+            vec!["aria-valuenow", "aria-label"],
+            vec!["aria-checked", "aria-level"],
+        ),
+        (
+            r#"<input type="checkbox" |"#,
+            vec!["aria-checked", "aria-required"],
+            vec!["aria-valuenow", "aria-multiline"],
+        ),
+        (
+            "<h1 |",
+            vec!["aria-level", "aria-hidden"],
+            vec!["aria-checked"],
+        ),
+        (
+            "<custom-element |",
+            vec!["aria-checked", "aria-level"],
+            vec![],
+        ),
+    ] {
+        let offset = value.find('|').unwrap();
+        let value = format!("{}{}", &value[..offset], &value[offset + 1..]);
+        let document = FullTextDocument::new("html".to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let list = ls
+            .do_complete(
+                &document,
+                &position,
+                &html_document,
+                DefaultDocumentContext,
+                None,
+                &data_manager,
+            )
+            .await;
+        let sort_text = |label: &str| {
+            list.items
+                .iter()
+                .find(|i| i.label == label)
+                .unwrap()
+                .sort_text
+                .clone()
+        };
+        for label in supported {
+            assert_eq!(sort_text(label), None, "{}", label);
+        }
+        for label in unsupported {
+            assert_eq!(sort_text(label), Some(format!("~{}", label)), "{}", label);
+        }
+    }
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn deprecated() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    for (value, label, hide_deprecated, expected) in [
+        ("<|", "center", false, Some(true)),
+        ("<|", "div", false, Some(false)),
+        ("<table |", "align", false, Some(true)),
+        ("<table |", "border", false, Some(false)),
+        ("<div |", "aria-grabbed", false, Some(true)),
+        ("<|", "center", true, None),
+        ("<table |", "align", true, None),
+        ("<table |", "border", true, Some(false)),
+    ] {
+        let offset = value.find('|').unwrap();
+        let value = format!("{}{}", &value[..offset], &value[offset + 1..]);
+        let document = FullTextDocument::new("html".to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let list = ls
+            .do_complete(
+                &document,
+                &position,
+                &html_document,
+                DefaultDocumentContext,
+                Some(&CompletionConfiguration {
+                    hide_deprecated,
+                    ..Default::default()
+                }),
+                &data_manager,
+            )
+            .await;
+        let item = list.items.iter().find(|i| i.label == label);
+        assert_eq!(
+            item.map(|i| i.tags == Some(vec![CompletionItemTag::DEPRECATED])),
+            expected,
+            "{}",
+            label
+        );
+        if expected == Some(true) {
+            if let Some(Documentation::MarkupContent(documentation)) = &item.unwrap().documentation
+            {
+                assert!(documentation.value.starts_with("**Deprecated**\n\n"));
+            } else {
+                panic!("{} should have documentation", label);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn registered_value_set() {
+    let mut data_manager = HTMLDataManager::default();
+    data_manager
+        .add_data_provider_from_json(
+            r#"{
+                "version": 1.1,
+                "globalAttributes": [{ "name": "data-test-id", "valueSet": "my-targets" }]
+            }"#,
+        )
+        .unwrap();
+    let value = |name: &str| IValueData {
+        name: name.to_string(),
+        description: None,
+        references: None,
+        browsers: None,
+        status: None,
+        deprecated: None,
+    };
+    data_manager.register_value_set("my-targets", vec![value("submit"), value("cancel")]);
+    // the value set of the data provider takes precedence
+    data_manager.register_value_set("b", vec![value("maybe")]);
+
+    async fn complete(value: &str, data_manager: &HTMLDataManager) -> Vec<String> {
+        let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+        let offset = value.find('|').unwrap();
+        let value = format!("{}{}", &value[..offset], &value[offset + 1..]);
+        let document = FullTextDocument::new("html".to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, data_manager);
+        let list = ls
+            .do_complete(
+                &document,
+                &position,
+                &html_document,
+                DefaultDocumentContext,
+                None,
+                data_manager,
+            )
+            .await;
+        list.items.into_iter().map(|i| i.label).collect()
+    }
+
+    let labels = complete(r#"<div data-test-id="|"#, &data_manager).await;
+    assert_eq!(labels, vec!["submit", "cancel"]);
+    let labels = complete(r#"<div aria-busy="|"#, &data_manager).await;
+    assert!(!labels.contains(&"maybe".to_string()));
+
+    assert_eq!(
+        data_manager
+            .unregister_value_set("my-targets")
+            .unwrap()
+            .len(),
+        2
+    );
+    let labels = complete(r#"<div data-test-id="|"#, &data_manager).await;
+    assert!(labels.is_empty());
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn lazy_documentation() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    for (value, label) in [
+        ("<|", "div"),
+        ("<div |", "hidden"),
+        ("<form method=\"|", "get"),
+    ] {
+        let offset = value.find('|').unwrap();
+        let value = format!("{}{}", &value[..offset], &value[offset + 1..]);
+        let document = FullTextDocument::new("html".to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let mut lists = vec![];
+        for lazy_documentation in [false, true] {
+            let list = ls
+                .do_complete(
+                    &document,
+                    &position,
+                    &html_document,
+                    DefaultDocumentContext,
+                    Some(&CompletionConfiguration {
+                        lazy_documentation,
+                        ..Default::default()
+                    }),
+                    &data_manager,
+                )
+                .await;
+            lists.push(list);
+        }
+        let find = |list: &CompletionList| list.items.iter().find(|i| i.label == label).cloned();
+        let eager = find(&lists[0]).unwrap();
+        let lazy = find(&lists[1]).unwrap();
+        assert!(eager.documentation.is_some(), "{}", label);
+        assert_eq!(eager.data, None);
+        assert_eq!(lazy.documentation, None);
+        assert!(lazy.data.is_some());
+
+        let resolved = ls.resolve_completion_item(lazy, &data_manager);
+        assert_eq!(resolved.documentation, eager.documentation, "{}", label);
+    }
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn documentation_settings() {
+    let data = serde_json::from_str(
+        r#"{
+            "version": 1.1,
+            "tags": [{
+                "name": "my-tag",
+                "description": "My tag.",
+                "references": [{ "name": "Ref", "url": "https://example.com/my-tag" }],
+                "status": { "baseline": "high", "baseline_low_date": "2015-07-29" }
+            }]
+        }"#,
+    )
+    .unwrap();
+    let mut data_manager = HTMLDataManager::default();
+    data_manager.set_data_provider(
+        "my",
+        Box::new(HTMLDataProvider::new("my".to_string(), data)),
+    );
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let document = FullTextDocument::new("html".to_string(), 0, "<".to_string());
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    for (references, baseline, expected) in [
+        (
+            true,
+            true,
+            "My tag.\n\n[Ref](https://example.com/my-tag)\n\n_Widely available across major browsers (Baseline since 2015)_",
+        ),
+        (
+            false,
+            true,
+            "My tag.\n\n_Widely available across major browsers (Baseline since 2015)_",
+        ),
+        (true, false, "My tag.\n\n[Ref](https://example.com/my-tag)"),
+        (false, false, "My tag."),
+    ] {
+        for lazy_documentation in [false, true] {
+            let list = ls
+                .do_complete(
+                    &document,
+                    &Position::new(0, 1),
+                    &html_document,
+                    DefaultDocumentContext,
+                    Some(&CompletionConfiguration {
+                        lazy_documentation,
+                        references,
+                        baseline,
+                        ..Default::default()
+                    }),
+                    &data_manager,
+                )
+                .await;
+            let item = list.items.into_iter().find(|i| i.label == "my-tag").unwrap();
+            let item = ls.resolve_completion_item(item, &data_manager);
+            let Some(Documentation::MarkupContent(documentation)) = item.documentation else {
+                panic!("no documentation");
+            };
+            assert_eq!(documentation.value, expected);
+        }
+    }
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn max_items() {
+    async fn complete(max_items: Option<usize>) -> CompletionList {
+        let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+        let data_manager = HTMLDataManager::default();
+        let value = "<div data-a data-b data-c></div><div ";
+        let document = FullTextDocument::new("html".to_string(), 0, value.to_string());
+        let position = document.position_at(value.len() as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        ls.do_complete(
+            &document,
+            &position,
+            &html_document,
+            DefaultDocumentContext,
+            Some(&CompletionConfiguration {
+                max_items,
+                ..Default::default()
+            }),
+            &data_manager,
+        )
+        .await
+    }
+
+    let all = complete(None).await;
+    assert!(!all.is_incomplete);
+
+    let list = complete(Some(5)).await;
+    assert!(list.is_incomplete);
+    let labels: Vec<&str> = list.items.iter().map(|i| i.label.as_str()).collect();
+    assert_eq!(labels.len(), 5);
+    let mut sorted = labels.clone();
+    sorted.sort();
+    assert_eq!(labels, sorted);
+    for _ in 0..3 {
+        let again = complete(Some(5)).await;
+        let again: Vec<&str> = again.items.iter().map(|i| i.label.as_str()).collect();
+        assert_eq!(again, labels);
+    }
+
+    let list = complete(Some(all.items.len())).await;
+    assert!(!list.is_incomplete);
+    assert_eq!(list.items.len(), all.items.len());
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn label_details() {
+    let mut data_manager = HTMLDataManager::default();
+    data_manager.set_data_provider(
+        "MyDesignSystem",
+        Box::new(HTMLDataProvider::new(
+            "MyDesignSystem".to_string(),
+            serde_json::from_str(r#"{ "version": 1.1, "tags": [{ "name": "my-button" }] }"#)
+                .unwrap(),
+        )),
+    );
+    let document = FullTextDocument::new("html".to_string(), 0, "<".to_string());
+    let position = document.position_at(1);
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let description = |list: &CompletionList, label: &str| {
+        list.items
+            .iter()
+            .find(|i| i.label == label)
+            .unwrap()
+            .label_details
+            .as_ref()
+            .and_then(|d| d.description.clone())
+    };
+
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let list = ls
+        .do_complete(
+            &document,
+            &position,
+            &html_document,
+            DefaultDocumentContext,
+            None,
+            &data_manager,
+        )
+        .await;
+    assert_eq!(description(&list, "div").as_deref(), Some("html5"));
+    assert_eq!(
+        description(&list, "my-button").as_deref(),
+        Some("MyDesignSystem")
+    );
+
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions {
+        client_capabilities: Some(ClientCapabilities::default()),
+        ..Default::default()
+    });
+    let list = ls
+        .do_complete(
+            &document,
+            &position,
+            &html_document,
+            DefaultDocumentContext,
+            None,
+            &data_manager,
+        )
+        .await;
+    assert_eq!(description(&list, "div"), None);
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn class_names() {
+    test_completion_for(
+        r#"<div class="box red"></div><p class="box b|"></p>"#,
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "red",
+                    result_text: Some(r#"<div class="box red"></div><p class="box red"></p>"#),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "box",
+                    not_available: Some(true),
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<div class="box red"></div><p class=|"#,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "box",
+                result_text: Some(r#"<div class="box red"></div><p class="box""#),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<div class="box"></div><p class="|red"></p>"#,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "box",
+                result_text: Some(r#"<div class="box"></div><p class="box"></p>"#),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn id_references() {
+    test_completion_for(
+        r#"<input id="name"><datalist id="colors"></datalist><label for="|"></label>"#,
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "name",
+                    result_text: Some(
+                        r#"<input id="name"><datalist id="colors"></datalist><label for="name"></label>"#,
+                    ),
+                    kind: Some(CompletionItemKind::REFERENCE),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "colors",
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<h2 id="title"></h2><p id="desc"></p><div aria-labelledby="title |"></div>"#,
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "desc",
+                    result_text: Some(
+                        r#"<h2 id="title"></h2><p id="desc"></p><div aria-labelledby="title desc"></div>"#,
+                    ),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "title",
+                    not_available: Some(true),
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<form id="login"></form><input form=|"#,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "login",
+                result_text: Some(r#"<form id="login"></form><input form="login""#),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn fragments() {
+    test_completion_for(
+        r##"<a name="top"></a><h2 id="intro"></h2><a href="#i|ntro"></a>"##,
+        Expected {
+            count: Some(2),
+            items: vec![
+                ItemDescription {
+                    label: "#top",
+                    result_text: Some(
+                        r##"<a name="top"></a><h2 id="intro"></h2><a href="#top"></a>"##,
+                    ),
+                    kind: Some(CompletionItemKind::REFERENCE),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "#intro",
+                    filter_text: Some("intro"),
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r##"<h2 id="intro"></h2><a href=#|"##,
+        Expected {
+            count: Some(1),
+            items: vec![ItemDescription {
+                label: "#intro",
+                result_text: Some(r##"<h2 id="intro"></h2><a href=#intro"##),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r##"<h2 id="intro"></h2><a href="|"></a>"##,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "#intro",
+                not_available: Some(true),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn language_tags() {
+    test_completion_for(
+        r#"<html lang="|"#,
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "en",
+                    result_text: Some(r#"<html lang="en"#),
+                    documentation: Some(Documentation::String("English".to_string())),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "en-US",
+                    not_available: Some(true),
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<html lang="en-|">"#,
+        Expected {
+            count: Some(8),
+            items: vec![ItemDescription {
+                label: "en-GB",
+                result_text: Some(r#"<html lang="en-GB">"#),
+                documentation: Some(Documentation::String(
+                    "English (United Kingdom)".to_string(),
+                )),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<link hreflang=|"#,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "fr",
+                result_text: Some(r#"<link hreflang="fr""#),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn meta() {
+    test_completion_for(
+        r#"<meta name="|">"#,
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "viewport",
+                    result_text: Some(r#"<meta name="viewport">"#),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "description",
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<meta http-equiv="|">"#,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "x-ua-compatible",
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<meta charset=|"#,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "utf-8",
+                result_text: Some(r#"<meta charset="utf-8""#),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<meta property="og:|">"#,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "og:title",
+                result_text: Some(r#"<meta property="og:title">"#),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<meta name="viewport" content="width=|">"#,
+        Expected {
+            count: Some(1),
+            items: vec![ItemDescription {
+                label: "width=device-width, initial-scale=1.0",
+                result_text: Some(
+                    r#"<meta name="viewport" content="width=${1:device-width}, initial-scale=${2:1.0}">"#,
+                ),
+                kind: Some(CompletionItemKind::SNIPPET),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<meta HTTP-EQUIV="Content-Type" content=|"#,
+        Expected {
+            count: Some(1),
+            items: vec![ItemDescription {
+                label: "text/html; charset=utf-8",
+                result_text: Some(
+                    r#"<meta HTTP-EQUIV="Content-Type" content="text/html; charset=${1:utf-8}""#,
+                ),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn dependent_attributes() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    for (value, label, expected) in [
+        (
+            r#"<link rel="preload" |"#,
+            "as",
+            Some("Required by rel=\"preload\""),
+        ),
+        (
+            r#"<link rel="icon" |"#,
+            "sizes",
+            Some("Recommended by rel=\"icon\""),
+        ),
+        (
+            r#"<link rel="icon" |"#,
+            "type",
+            Some("Recommended by rel=\"icon\""),
+        ),
+        (
+            r#"<link REL="Alternate stylesheet" |"#,
+            "hreflang",
+            Some("Recommended by rel=\"alternate\""),
+        ),
+        (r#"<link rel="stylesheet" |"#, "as", None),
+        (r#"<link rel="preload" |"#, "sizes", None),
+    ] {
+        let offset = value.find('|').unwrap();
+        let value = format!("{}{}", &value[..offset], &value[offset + 1..]);
+        let document = FullTextDocument::new("html".to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let list = ls
+            .do_complete(
+                &document,
+                &position,
+                &html_document,
+                DefaultDocumentContext,
+                None,
+                &data_manager,
+            )
+            .await;
+        let item = list.items.iter().find(|i| i.label == label).unwrap();
+        assert_eq!(item.detail.as_deref(), expected, "{}", label);
+        assert_eq!(
+            item.sort_text.as_ref().is_some_and(|s| s.starts_with('!')),
+            expected.is_some()
+        );
+    }
+
+    test_completion_for(
+        r#"<link rel="preload" as="|"#,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "font",
+                result_text: Some(r#"<link rel="preload" as="font"#),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn mime_types() {
+    test_completion_for(
+        r#"<input type="file" accept="|"#,
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "image/*",
+                    result_text: Some(r#"<input type="file" accept="image/*"#),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: ".png",
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<input accept="image/png, |">"#,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: ".jpg",
+                result_text: Some(r#"<input accept="image/png, .jpg">"#),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    for tag in ["source", "embed", "object"] {
+        test_completion_for(
+            &format!(r#"<{} type="|"#, tag),
+            Expected {
+                count: None,
+                items: vec![ItemDescription {
+                    label: "video/webm",
+                    ..Default::default()
+                }],
+            },
+            None,
+            None,
+        )
+        .await;
+    }
+    test_completion_for(
+        r#"<script type="|"></script>"#,
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "module",
+                    result_text: Some(r#"<script type="module"></script>"#),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "importmap",
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn input_type_attributes() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    for (value, label, expected_sort, expected_detail) in [
+        (r#"<input type="number" |"#, "min", Some('!'), None),
+        (r#"<input type="number" |"#, "step", Some('!'), None),
+        (
+            r#"<input type="number" |"#,
+            "maxlength",
+            Some('~'),
+            Some("Not applicable to type=\"number\""),
+        ),
+        (
+            r#"<input TYPE='Checkbox' |"#,
+            "placeholder",
+            Some('~'),
+            Some("Not applicable to type=\"Checkbox\""),
+        ),
+        (r#"<input type="number" |"#, "name", None, None),
+        (r#"<input |"#, "maxlength", None, None),
+        (r#"<input type="unknown" |"#, "maxlength", None, None),
+    ] {
+        let offset = value.find('|').unwrap();
+        let value = format!("{}{}", &value[..offset], &value[offset + 1..]);
+        let document = FullTextDocument::new("html".to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let list = ls
+            .do_complete(
+                &document,
+                &position,
+                &html_document,
+                DefaultDocumentContext,
+                None,
+                &data_manager,
+            )
+            .await;
+        let item = list.items.iter().find(|i| i.label == label).unwrap();
+        assert_eq!(
+            item.sort_text.as_ref().and_then(|s| s.chars().next()),
+            expected_sort,
+            "{}",
+            label
+        );
+        assert_eq!(item.detail.as_deref(), expected_detail, "{}", label);
+    }
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn element_snippets() {
+    fn settings(
+        element_snippets: bool,
+        attribute_default_value: Quotes,
+    ) -> CompletionConfiguration {
+        CompletionConfiguration {
+            element_snippets,
+            attribute_default_value,
+            ..Default::default()
+        }
+    }
+
+    test_completion_for(
+        "<|",
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "img",
+                    result_text: Some(r#"<img src="$1" alt="$2">"#),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "a",
+                    result_text: Some(r#"<a href="$1">$2</a>"#),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "div",
+                    result_text: Some(r#"<div>$1</div>"#),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "br",
+                    result_text: Some(r#"<br>"#),
+                    ..Default::default()
+                },
+            ],
+        },
+        Some(settings(true, Quotes::Double)),
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<i|",
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "img",
+                result_text: Some(r#"<img src='$1' alt='$2'>"#),
+                ..Default::default()
+            }],
+        },
+        Some(settings(true, Quotes::Single)),
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<i| src="a.png">"#,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "img",
+                result_text: Some(r#"<img src="a.png">"#),
+                ..Default::default()
+            }],
+        },
+        Some(settings(true, Quotes::Double)),
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<|",
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "img",
+                result_text: Some("<img"),
+                ..Default::default()
+            }],
+        },
+        Some(settings(false, Quotes::Double)),
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn comment_regions() {
+    test_completion_for(
+        "<div><!-- <| --></div>",
+        Expected {
+            count: Some(2),
+            items: vec![
+                ItemDescription {
+                    label: "#region",
+                    result_text: Some("<div><!-- #region $1 --></div>"),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "#endregion",
+                    result_text: Some("<div><!-- #endregion --></div>"),
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<!-- #reg|",
+        Expected {
+            count: Some(2),
+            items: vec![ItemDescription {
+                label: "#region",
+                result_text: Some("<!-- #region $1"),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<!--|-->",
+        Expected {
+            count: Some(2),
+            items: vec![ItemDescription {
+                label: "#endregion",
+                result_text: Some("<!--#endregion-->"),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<!-|-",
+        Expected {
+            count: Some(0),
+            items: vec![],
+        },
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn hidden_proposal_groups() {
+    fn settings(
+        hide_character_entities: bool,
+        hide_data_attributes: bool,
+        hide_doctype: bool,
+        hide_close_tags: bool,
+    ) -> Option<CompletionConfiguration> {
+        Some(CompletionConfiguration {
+            hide_character_entities,
+            hide_data_attributes,
+            hide_doctype,
+            hide_close_tags,
+            ..Default::default()
+        })
+    }
+
+    test_completion_for(
+        "<div>&am|</div>",
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "&amp;",
+                not_available: Some(true),
+                ..Default::default()
+            }],
+        },
+        settings(true, false, false, false),
+        None,
+    )
+    .await;
+    test_completion_for(
+        r#"<div data-foo="1"></div><div |"#,
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "data-foo",
+                    not_available: Some(true),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "data-",
+                    not_available: Some(true),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "class",
+                    ..Default::default()
+                },
+            ],
+        },
+        settings(false, true, false, false),
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<!|",
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "!DOCTYPE",
+                not_available: Some(true),
+                ..Default::default()
+            }],
+        },
+        settings(false, false, true, false),
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<div><|",
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "/div",
+                    not_available: Some(true),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "span",
+                    ..Default::default()
+                },
+            ],
+        },
+        settings(false, false, false, true),
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<div></|",
+        Expected {
+            count: Some(0),
+            items: vec![],
+        },
+        settings(false, false, false, true),
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn template_syntax() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    for (language_id, expected) in [("handlebars", true), ("html", false)] {
+        let value = "<div title={{ a > b }} ";
+        let document = FullTextDocument::new(language_id.to_string(), 0, value.to_string());
+        let position = document.position_at(value.len() as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let list = ls
+            .do_complete(
+                &document,
+                &position,
+                &html_document,
+                DefaultDocumentContext,
+                None,
+                &data_manager,
+            )
+            .await;
+        assert_eq!(
+            list.items.iter().any(|i| i.label == "class"),
+            expected,
+            "{}",
+            language_id
+        );
+    }
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn case_sensitive_languages() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let mut data_manager = HTMLDataManager::default();
+    data_manager.set_case_sensitive_languages(vec!["vue".to_string()]);
+    for (language_id, expected) in [("vue", true), ("html", false)] {
+        let value = "<Foo></foo><";
+        let document = FullTextDocument::new(language_id.to_string(), 0, value.to_string());
+        let position = document.position_at(value.len() as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let list = ls
+            .do_complete(
+                &document,
+                &position,
+                &html_document,
+                DefaultDocumentContext,
+                None,
+                &data_manager,
+            )
+            .await;
+        assert_eq!(
+            list.items.iter().any(|i| i.label == "/Foo"),
+            expected,
+            "{}",
+            language_id
+        );
+    }
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn case_sensitivity() {
+    test_completion_for(
+        "<LI></|",
+        Expected {
+            count: None,
+            items: vec![
+                ItemDescription {
+                    label: "/LI",
+                    result_text: Some("<LI></LI>"),
+                    ..Default::default()
+                },
+                ItemDescription {
+                    label: "/li",
+                    not_available: Some(true),
+                    ..Default::default()
+                },
+            ],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<lI></|",
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "/lI",
+                result_text: Some("<lI></lI>"),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<iNpUt |",
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "type",
+                result_text: Some(r#"<iNpUt type="$1""#),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<INPUT TYPE=|",
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "color",
+                result_text: Some(r#"<INPUT TYPE="color""#),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+    test_completion_for(
+        "<dIv>|",
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "</dIv>",
+                result_text: Some("<dIv>$0</dIv>"),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn handlebar_completion() {
+    test_completion_for(
+            r#"<script id="entry-template" type="text/x-handlebars-template"> <| </script>"#,
+            Expected {
+                count: None,
+                items: vec![ItemDescription {
+                    label: "div",
+                    result_text: Some(
+                        r#"<script id="entry-template" type="text/x-handlebars-template"> <div </script>"#,
+                    ),
+                    ..Default::default()
+                }],
+            },
+            None,
+            None,
+        ).await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn support_script_type() {
+    test_completion_for(
+        r#"<script id="html-template" type="text/html"> <| </script>"#,
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "div",
+                result_text: Some(r#"<script id="html-template" type="text/html"> <div </script>"#),
+                ..Default::default()
+            }],
+        },
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn complete_aria() {
+    let expected_aria_attributes = vec![
+        ItemDescription {
+            label: "aria-activedescendant",
+            ..Default::default()
         },
         ItemDescription {
             label: "aria-atomic",
@@ -1644,9 +3523,8 @@ async fn settings() {
             }],
         },
         Some(CompletionConfiguration {
-            hide_auto_complete_proposals: false,
-            attribute_default_value: Quotes::Double,
             provider: HashMap::from([("html5".to_string(), false)]),
+            ..Default::default()
         }),
         None,
     )
@@ -1662,9 +3540,7 @@ async fn settings() {
             }],
         },
         Some(CompletionConfiguration {
-            hide_auto_complete_proposals: false,
-            attribute_default_value: Quotes::Double,
-            provider: HashMap::new(),
+            ..Default::default()
         }),
         None,
     )
@@ -1680,9 +3556,8 @@ async fn settings() {
             }],
         },
         Some(CompletionConfiguration {
-            hide_auto_complete_proposals: false,
             attribute_default_value: Quotes::Single,
-            provider: HashMap::new(),
+            ..Default::default()
         }),
         None,
     )
@@ -1698,9 +3573,8 @@ async fn settings() {
             }],
         },
         Some(CompletionConfiguration {
-            hide_auto_complete_proposals: false,
             attribute_default_value: Quotes::None,
-            provider: HashMap::new(),
+            ..Default::default()
         }),
         None,
     )
@@ -1716,8 +3590,7 @@ fn do_quote_complete() {
         Some("'$1'".to_string()),
         Some(&CompletionConfiguration {
             attribute_default_value: Quotes::Single,
-            hide_auto_complete_proposals: false,
-            provider: HashMap::new(),
+            ..Default::default()
         }),
     );
     test_quote_completion(
@@ -1725,8 +3598,7 @@ fn do_quote_complete() {
         None,
         Some(&CompletionConfiguration {
             attribute_default_value: Quotes::None,
-            hide_auto_complete_proposals: false,
-            provider: HashMap::new(),
+            ..Default::default()
         }),
     );
     test_quote_completion("<a foo=|=", None, None);
@@ -1741,26 +3613,77 @@ fn do_quote_complete() {
         Some(r#""$1""#.to_string()),
         None,
     );
+
+    let auto = CompletionConfiguration {
+        attribute_default_value: Quotes::Auto,
+        ..Default::default()
+    };
+    test_quote_completion(
+        "<div id='a' class='b'><a title=\"c\" foo=|></a></div>",
+        Some("'$1'".to_string()),
+        Some(&auto),
+    );
+    test_quote_completion(
+        "<div id='a' class=\"b\"><a title=\"c\" foo=|></a></div>",
+        Some(r#""$1""#.to_string()),
+        Some(&auto),
+    );
+    test_quote_completion("<a foo=|", Some(r#""$1""#.to_string()), Some(&auto));
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn auto_quotes() {
+    test_completion_for(
+        "<div id='a'><div clas|",
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "class",
+                result_text: Some("<div id='a'><div class='$1'"),
+                ..Default::default()
+            }],
+        },
+        Some(CompletionConfiguration {
+            attribute_default_value: Quotes::Auto,
+            ..Default::default()
+        }),
+        None,
+    )
+    .await;
 }
 
 #[cfg(feature = "completion")]
 #[tokio::test]
 async fn do_tag_complete() {
-    test_tag_completion("<div>|", Some("$0</div>".to_string()));
-    test_tag_completion("<div>|</div>", None);
-    test_tag_completion(r#"<div class="">|"#, Some("$0</div>".to_string()));
-    test_tag_completion("<img>|", None);
-    test_tag_completion("<div><br></|", Some("div>".to_string()));
-    test_tag_completion("<div><br><span></span></|", Some("div>".to_string()));
+    test_tag_completion("<div>|", Some("$0</div>".to_string()), None);
+    test_tag_completion("<div>|</div>", None, None);
+    test_tag_completion(r#"<div class="">|"#, Some("$0</div>".to_string()), None);
+    test_tag_completion("<img>|", None, None);
+    test_tag_completion("<div><br></|", Some("div>".to_string()), None);
+    test_tag_completion("<div><br><span></span></|", Some("div>".to_string()), None);
     test_tag_completion(
         "<div><h1><br><span></span><img></| </h1></div>",
         Some("h1>".to_string()),
+        None,
     );
     test_tag_completion(
         "<ng-template><td><ng-template></|   </td> </ng-template>",
         Some("ng-template>".to_string()),
+        None,
     );
-    test_tag_completion("<div><br></|>", Some("div".to_string()));
+    test_tag_completion("<div><br></|>", Some("div".to_string()), None);
+    test_tag_completion("<textarea><b>|", None, None);
+    test_tag_completion("<title><b></|", None, None);
+    test_tag_completion("<div title=a>|", Some("$0</div>".to_string()), None);
+
+    let exclusions = CompletionConfiguration {
+        auto_close_exclusions: vec!["my-if".to_string()],
+        ..Default::default()
+    };
+    test_tag_completion("<my-if>|", None, Some(&exclusions));
+    test_tag_completion("<My-If></|", None, Some(&exclusions));
+    test_tag_completion("<div>|", Some("$0</div>".to_string()), Some(&exclusions));
 }
 
 #[cfg(feature = "completion")]
@@ -1780,3 +3703,526 @@ struct ItemDescription {
     filter_text: Option<&'static str>,
     not_available: Option<bool>,
 }
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn cancellation() {
+    let token = CancellationToken::new();
+    let settings = |token: &CancellationToken| CompletionConfiguration {
+        cancellation_token: Some(token.clone()),
+        ..Default::default()
+    };
+
+    test_completion_for(
+        "<|",
+        Expected {
+            count: None,
+            items: vec![ItemDescription {
+                label: "div",
+                result_text: Some("<div"),
+                ..Default::default()
+            }],
+        },
+        Some(settings(&token)),
+        None,
+    )
+    .await;
+
+    token.cancel();
+    test_completion_for(
+        "<|",
+        Expected {
+            count: Some(0),
+            items: vec![],
+        },
+        Some(settings(&token)),
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn builder() {
+    use std::sync::{Arc, Mutex};
+
+    use html_languageservice::participant::{
+        HtmlAttributeValueContext, HtmlContentContext, ICompletionParticipant, ParticipantError,
+    };
+
+    struct MustacheParticipant;
+
+    #[async_trait::async_trait]
+    impl ICompletionParticipant for MustacheParticipant {
+        async fn on_html_attribute_value(
+            &self,
+            _context: HtmlAttributeValueContext,
+        ) -> Result<Vec<CompletionItem>, ParticipantError> {
+            Err(ParticipantError::new("no attribute values"))
+        }
+
+        async fn on_html_content(
+            &self,
+            _context: HtmlContentContext,
+        ) -> Result<Vec<CompletionItem>, ParticipantError> {
+            Ok(vec![CompletionItem {
+                label: "{{ }}".to_string(),
+                ..Default::default()
+            }])
+        }
+    }
+
+    fn settings(hide_character_entities: bool) -> CompletionConfiguration {
+        CompletionConfiguration {
+            hide_character_entities,
+            ..Default::default()
+        }
+    }
+
+    let ls = HTMLLanguageService::builder()
+        .completion_participant(Box::new(MustacheParticipant))
+        .completion_settings(settings(true))
+        .build();
+    let data_manager = HTMLDataManager::default();
+    let value = "<div>&</div>";
+    let document = FullTextDocument::new("html".to_string(), 0, value.to_string());
+    let position = document.position_at(6);
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+
+    let list = ls
+        .do_complete(
+            &document,
+            &position,
+            &html_document,
+            DefaultDocumentContext,
+            None,
+            &data_manager,
+        )
+        .await;
+    let labels: Vec<&str> = list.items.iter().map(|i| i.label.as_str()).collect();
+    assert_eq!(labels, vec!["{{ }}"]);
+
+    // the settings of the request take precedence
+    let list = ls
+        .do_complete(
+            &document,
+            &position,
+            &html_document,
+            DefaultDocumentContext,
+            Some(&settings(false)),
+            &data_manager,
+        )
+        .await;
+    assert!(list.items.iter().any(|i| i.label == "{{ }}"));
+    assert!(list.items.iter().any(|i| i.label == "&amp;"));
+
+    // the default settings are used for the quotes too
+    let ls = HTMLLanguageService::builder()
+        .completion_settings(CompletionConfiguration {
+            attribute_default_value: Quotes::Single,
+            ..Default::default()
+        })
+        .build();
+    let document = FullTextDocument::new("html".to_string(), 0, "<a foo=".to_string());
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let quotes = ls.do_quote_complete(&document, &Position::new(0, 7), &html_document, None);
+    assert_eq!(quotes, Some("'$1'".to_string()));
+
+    // the failed participant is reported and the built-in items are kept
+    let errors = Arc::new(Mutex::new(vec![]));
+    let ls = HTMLLanguageService::builder()
+        .options(HTMLLanguageServiceOptions {
+            participant_error_handler: Some(Arc::new({
+                let errors = errors.clone();
+                move |error: &ParticipantError| {
+                    errors.lock().unwrap().push(error.message().to_string())
+                }
+            })),
+            ..Default::default()
+        })
+        .completion_participant(Box::new(MustacheParticipant))
+        .build();
+    let value = r#"<input type="">"#;
+    let document = FullTextDocument::new("html".to_string(), 0, value.to_string());
+    let position = document.position_at(13);
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let list = ls
+        .do_complete(
+            &document,
+            &position,
+            &html_document,
+            DefaultDocumentContext,
+            None,
+            &data_manager,
+        )
+        .await;
+    assert!(list.items.iter().any(|i| i.label == "checkbox"));
+    assert_eq!(*errors.lock().unwrap(), vec!["no attribute values"]);
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn participant_priority() {
+    use html_languageservice::participant::{
+        HtmlAttributeValueContext, HtmlContentContext, ICompletionParticipant, ParticipantError,
+    };
+
+    struct Participant {
+        priority: i32,
+        labels: &'static [&'static str],
+        hide_builtins: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl ICompletionParticipant for Participant {
+        async fn on_html_attribute_value(
+            &self,
+            _context: HtmlAttributeValueContext,
+        ) -> Result<Vec<CompletionItem>, ParticipantError> {
+            Ok(self
+                .labels
+                .iter()
+                .map(|label| CompletionItem::new_simple(label.to_string(), String::new()))
+                .collect())
+        }
+
+        async fn on_html_content(
+            &self,
+            _context: HtmlContentContext,
+        ) -> Result<Vec<CompletionItem>, ParticipantError> {
+            Ok(self
+                .labels
+                .iter()
+                .map(|label| CompletionItem::new_simple(label.to_string(), String::new()))
+                .collect())
+        }
+
+        fn priority(&self) -> i32 {
+            self.priority
+        }
+
+        fn hide_builtin_attribute_values(&self, _context: &HtmlAttributeValueContext) -> bool {
+            self.hide_builtins
+        }
+
+        fn hide_builtin_content(&self, _context: &HtmlContentContext) -> bool {
+            self.hide_builtins
+        }
+    }
+
+    async fn complete(ls: &HTMLLanguageService, value: &str) -> Vec<String> {
+        let data_manager = HTMLDataManager::default();
+        let offset = value.find('|').unwrap();
+        let value = value.replace('|', "");
+        let document = FullTextDocument::new("html".to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        ls.do_complete(
+            &document,
+            &position,
+            &html_document,
+            DefaultDocumentContext,
+            None,
+            &data_manager,
+        )
+        .await
+        .items
+        .into_iter()
+        .map(|item| item.label)
+        .collect()
+    }
+
+    let ls = HTMLLanguageService::builder()
+        .completion_participant(Box::new(Participant {
+            priority: -1,
+            labels: &["&amp;", "last"],
+            hide_builtins: false,
+        }))
+        .completion_participant(Box::new(Participant {
+            priority: 0,
+            labels: &["second", "shared"],
+            hide_builtins: false,
+        }))
+        .completion_participant(Box::new(Participant {
+            priority: 1,
+            labels: &["first", "shared"],
+            hide_builtins: false,
+        }))
+        .build();
+
+    let labels = complete(&ls, "<div>&|</div>").await;
+    assert_eq!(labels[..3], ["first", "shared", "second"]);
+    assert_eq!(labels.last().unwrap(), "last");
+    assert_eq!(labels.iter().filter(|l| *l == "shared").count(), 1);
+    assert_eq!(labels.iter().filter(|l| *l == "&amp;").count(), 1);
+    assert!(labels.iter().position(|l| l == "&amp;").unwrap() > 2);
+
+    let labels = complete(&ls, r#"<input type="|">"#).await;
+    assert_eq!(labels[..3], ["first", "shared", "second"]);
+    assert!(labels.iter().any(|l| l == "checkbox"));
+    assert_eq!(labels.last().unwrap(), "last");
+
+    let ls = HTMLLanguageService::builder()
+        .completion_participant(Box::new(Participant {
+            priority: 0,
+            labels: &["only"],
+            hide_builtins: true,
+        }))
+        .build();
+    assert_eq!(complete(&ls, "<div>&|</div>").await, vec!["only"]);
+    assert_eq!(complete(&ls, r#"<input type="|">"#).await, vec!["only"]);
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn complete_sync() {
+    use html_languageservice::participant::{
+        HtmlAttributeValueContext, HtmlContentContext, ICompletionParticipant, ParticipantError,
+    };
+
+    struct AsyncParticipant;
+
+    #[async_trait::async_trait]
+    impl ICompletionParticipant for AsyncParticipant {
+        async fn on_html_attribute_value(
+            &self,
+            _context: HtmlAttributeValueContext,
+        ) -> Result<Vec<CompletionItem>, ParticipantError> {
+            Ok(vec![])
+        }
+
+        async fn on_html_content(
+            &self,
+            _context: HtmlContentContext,
+        ) -> Result<Vec<CompletionItem>, ParticipantError> {
+            Ok(vec![CompletionItem::new_simple(
+                "participant".to_string(),
+                String::new(),
+            )])
+        }
+    }
+
+    let ls = HTMLLanguageService::builder()
+        .completion_participant(Box::new(AsyncParticipant))
+        .build();
+    let data_manager = HTMLDataManager::default();
+    let document = FullTextDocument::new("html".to_string(), 0, "<div>&</div>".to_string());
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let list = ls.do_complete_sync(
+        &document,
+        &Position::new(0, 6),
+        &html_document,
+        DefaultDocumentContext,
+        None,
+        &data_manager,
+    );
+    assert!(list.items.iter().any(|i| i.label == "&amp;"));
+    assert!(list.items.iter().all(|i| i.label != "participant"));
+
+    let list = ls.do_complete_sync(
+        &document,
+        &Position::new(0, 1),
+        &html_document,
+        DefaultDocumentContext,
+        None,
+        &data_manager,
+    );
+    assert!(list.items.iter().any(|i| i.label == "div"));
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn position_encoding() {
+    // `中` and `😀` are 1 and 2 UTF-16 code units, 3 and 4 UTF-8 bytes
+    let data_manager = HTMLDataManager::default();
+    let document = FullTextDocument::new("html".to_string(), 0, "中😀<di".to_string());
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let quote_document =
+        FullTextDocument::new("html".to_string(), 0, "中😀<div class=".to_string());
+    let quote_html_document =
+        HTMLLanguageService::parse_html_document(&quote_document, &data_manager);
+    for (encoding, p) in [
+        (PositionEncodingKind::UTF8, 7),
+        (PositionEncodingKind::UTF16, 3),
+        (PositionEncodingKind::UTF32, 2),
+    ] {
+        let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions {
+            position_encoding: Some(encoding),
+            ..Default::default()
+        });
+        let list = ls
+            .do_complete(
+                &document,
+                &Position::new(0, p + 3),
+                &html_document,
+                DefaultDocumentContext,
+                None,
+                &data_manager,
+            )
+            .await;
+        let item = list.items.iter().find(|item| item.label == "div").unwrap();
+        assert_eq!(
+            item.text_edit,
+            Some(CompletionTextEdit::Edit(TextEdit::new(
+                Range::new(Position::new(0, p + 1), Position::new(0, p + 3)),
+                "div".to_string()
+            )))
+        );
+
+        assert_eq!(
+            ls.do_quote_complete(
+                &quote_document,
+                &Position::new(0, p + 11),
+                &quote_html_document,
+                None
+            ),
+            Some(r#""$1""#.to_string())
+        );
+    }
+}
+
+#[cfg(feature = "completion")]
+#[tokio::test]
+async fn path_completion() {
+    use html_languageservice::MemoryFileSystemProvider;
+    use std::sync::Arc;
+
+    let provider = MemoryFileSystemProvider::new();
+    provider.add_file("file:///site/index.html", 0);
+    provider.add_file("file:///site/.hidden", 0);
+    provider.add_file("file:///site/images/a.png", 0);
+    provider.add_file("file:///site/images/b.png", 0);
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions {
+        file_system_provider: Some(Arc::new(provider)),
+        ..Default::default()
+    });
+    let uri = Url::parse("file:///site/index.html").unwrap();
+    let data_manager = HTMLDataManager::default();
+    let complete = |value: &str| {
+        let offset = value.find('|').unwrap();
+        let value = format!("{}{}", &value[..offset], &value[offset + 1..]);
+        let document = FullTextDocument::new("html".to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let ls = &ls;
+        let uri = &uri;
+        let data_manager = &data_manager;
+        async move {
+            let list = ls
+                .do_complete2(
+                    uri,
+                    &document,
+                    &position,
+                    &html_document,
+                    DefaultDocumentContext,
+                    None,
+                    data_manager,
+                )
+                .await;
+            let mut items: Vec<(String, String)> = list
+                .items
+                .into_iter()
+                .filter(|item| {
+                    item.kind == Some(CompletionItemKind::FILE)
+                        || item.kind == Some(CompletionItemKind::FOLDER)
+                })
+                .map(|item| {
+                    let Some(CompletionTextEdit::Edit(edit)) = item.text_edit else {
+                        panic!("{} has no text edit", item.label);
+                    };
+                    let start = document.offset_at(edit.range.start) as usize;
+                    let end = document.offset_at(edit.range.end) as usize;
+                    let content = document.get_content(None);
+                    (
+                        item.label,
+                        format!("{}{}{}", &content[..start], edit.new_text, &content[end..]),
+                    )
+                })
+                .collect();
+            items.sort();
+            (items, list.is_incomplete)
+        }
+    };
+
+    let (items, _) = complete(r#"<img src="|">"#).await;
+    assert_eq!(
+        items,
+        vec![
+            ("images/".to_string(), r#"<img src="images/">"#.to_string()),
+            (
+                "index.html".to_string(),
+                r#"<img src="index.html">"#.to_string()
+            ),
+        ]
+    );
+    let (items, _) = complete(r#"<img src="./images/x|.png">"#).await;
+    assert_eq!(
+        items,
+        vec![
+            (
+                "a.png".to_string(),
+                r#"<img src="./images/a.png">"#.to_string()
+            ),
+            (
+                "b.png".to_string(),
+                r#"<img src="./images/b.png">"#.to_string()
+            ),
+        ]
+    );
+    assert_eq!(complete(r#"<img src="http://|">"#).await.0, vec![]);
+    assert_eq!(complete(r#"<div title="|">"#).await.0, vec![]);
+    assert!(complete(r#"<img src="..|">"#).await.1);
+
+    // Every image candidate of `srcset`, but not the descriptors
+    let (items, _) = complete(r#"<img srcset="images/a.png 1x, images/|b 2x">"#).await;
+    assert_eq!(
+        items,
+        vec![
+            (
+                "a.png".to_string(),
+                r#"<img srcset="images/a.png 1x, images/a.png 2x">"#.to_string()
+            ),
+            (
+                "b.png".to_string(),
+                r#"<img srcset="images/a.png 1x, images/b.png 2x">"#.to_string()
+            ),
+        ]
+    );
+    let (items, _) = complete(r#"<img srcset="images/a.png 1x, |">"#).await;
+    assert_eq!(
+        items,
+        vec![
+            (
+                "images/".to_string(),
+                r#"<img srcset="images/a.png 1x, images/">"#.to_string()
+            ),
+            (
+                "index.html".to_string(),
+                r#"<img srcset="images/a.png 1x, index.html">"#.to_string()
+            ),
+        ]
+    );
+    assert_eq!(
+        complete(r#"<img srcset="images/a.png 1|x">"#).await.0,
+        vec![]
+    );
+    assert_eq!(complete(r#"<source srcset="images/|">"#).await.0.len(), 2);
+
+    // No path completion without the URI of the document
+    let document = FullTextDocument::new("html".to_string(), 0, r#"<img src="">"#.to_string());
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let list = ls
+        .do_complete(
+            &document,
+            &Position::new(0, 10),
+            &html_document,
+            DefaultDocumentContext,
+            None,
+            &data_manager,
+        )
+        .await;
+    assert!(list
+        .items
+        .iter()
+        .all(|item| item.kind != Some(CompletionItemKind::FILE)));
+}
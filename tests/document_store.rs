@@ -0,0 +1,109 @@
+use std::cell::Cell;
+
+use html_languageservice::{DocumentStore, HTMLDataManager};
+use lsp_types::{Position, Range, TextDocumentContentChangeEvent, Url};
+
+#[test]
+fn document_store() {
+    let mut data_manager = HTMLDataManager::default();
+    let mut store = DocumentStore::new();
+    let uri = Url::parse("file:///index.html").unwrap();
+    store.open(
+        uri.clone(),
+        "html".to_string(),
+        1,
+        "<div></div>".to_string(),
+    );
+
+    let (document, html_document) = store.get_html_document(&uri, &data_manager).unwrap();
+    assert_eq!(document.version(), 1);
+    assert_eq!(html_document.roots[0].tag.as_deref(), Some("div"));
+
+    let created = Cell::new(0);
+    let get_tags = |store: &mut DocumentStore, data_manager: &HTMLDataManager| {
+        store
+            .get_or_insert_cache(&uri, data_manager, |_, html_document| {
+                created.set(created.get() + 1);
+                html_document
+                    .roots
+                    .iter()
+                    .filter_map(|node| node.tag.as_ref().map(|tag| tag.to_string()))
+                    .collect::<Vec<_>>()
+            })
+            .cloned()
+    };
+    assert_eq!(
+        get_tags(&mut store, &data_manager),
+        Some(vec!["div".to_string()])
+    );
+    assert_eq!(
+        get_tags(&mut store, &data_manager),
+        Some(vec!["div".to_string()])
+    );
+
+    // incremental change
+    assert!(store.change(
+        &uri,
+        &[TextDocumentContentChangeEvent {
+            range: Some(Range::new(Position::new(0, 11), Position::new(0, 11))),
+            range_length: None,
+            text: "<p></p>".to_string(),
+        }],
+        2,
+    ));
+    assert_eq!(
+        get_tags(&mut store, &data_manager),
+        Some(vec!["div".to_string(), "p".to_string()])
+    );
+
+    // stale version
+    assert!(!store.change(
+        &uri,
+        &[TextDocumentContentChangeEvent {
+            range: None,
+            range_length: None,
+            text: "<span></span>".to_string(),
+        }],
+        2,
+    ));
+    assert_eq!(
+        store.get_document(&uri).unwrap().get_content(None),
+        "<div></div><p></p>"
+    );
+
+    // full change
+    assert!(store.change(
+        &uri,
+        &[TextDocumentContentChangeEvent {
+            range: None,
+            range_length: None,
+            text: "<span></span>".to_string(),
+        }],
+        3,
+    ));
+    assert_eq!(
+        get_tags(&mut store, &data_manager),
+        Some(vec!["span".to_string()])
+    );
+
+    // the data of `HTMLDataManager` is changed
+    data_manager.set_path_attributes(vec![]);
+    assert_eq!(
+        get_tags(&mut store, &data_manager),
+        Some(vec!["span".to_string()])
+    );
+    assert_eq!(created.get(), 4);
+
+    // the data manager is replaced by another one of the same version
+    store.invalidate();
+    assert_eq!(
+        get_tags(&mut store, &HTMLDataManager::default()),
+        Some(vec!["span".to_string()])
+    );
+    assert_eq!(created.get(), 5);
+
+    assert!(store.close(&uri));
+    assert!(!store.close(&uri));
+    assert!(store.get_html_document(&uri, &data_manager).is_none());
+    assert_eq!(get_tags(&mut store, &data_manager), None);
+}
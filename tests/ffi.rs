@@ -0,0 +1,72 @@
+#[cfg(feature = "ffi")]
+use std::ffi::{c_char, CStr, CString};
+
+#[cfg(feature = "ffi")]
+use html_languageservice::ffi::*;
+#[cfg(feature = "ffi")]
+use serde_json::{json, Value};
+
+#[cfg(feature = "ffi")]
+fn call(
+    f: unsafe extern "C" fn(*const HtmlLanguageServiceHandle, *const c_char) -> *mut c_char,
+    handle: *const HtmlLanguageServiceHandle,
+    request: &str,
+) -> Value {
+    let request = CString::new(request).unwrap();
+    unsafe {
+        let response = f(handle, request.as_ptr());
+        let value = serde_json::from_str(CStr::from_ptr(response).to_str().unwrap()).unwrap();
+        html_ls_string_free(response);
+        value
+    }
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn ffi() {
+    let handle = html_ls_new();
+
+    let response = call(
+        html_ls_parse,
+        handle,
+        r#"{ "text": "<div id=\"a\"><p></p></div>" }"#,
+    );
+    let roots = &response["result"];
+    assert_eq!(roots[0]["tag"], "div");
+    assert_eq!(roots[0]["attributes"], json!({ "id": "\"a\"" }));
+    assert_eq!(roots[0]["children"][0]["tag"], "p");
+    assert_eq!(
+        roots[0]["children"][0]["range"],
+        json!({
+            "start": { "line": 0, "character": 12 },
+            "end": { "line": 0, "character": 19 }
+        })
+    );
+
+    let response = call(
+        html_ls_hover,
+        handle,
+        r#"{ "text": "<div></div>", "position": { "line": 0, "character": 2 } }"#,
+    );
+    assert!(response["result"]["contents"]["value"]
+        .as_str()
+        .unwrap()
+        .contains("div"));
+
+    let response = call(
+        html_ls_complete,
+        handle,
+        r#"{ "text": "<", "position": { "line": 0, "character": 1 } }"#,
+    );
+    let items = response["result"]["items"].as_array().unwrap();
+    assert!(items.iter().any(|item| item["label"] == "div"));
+
+    let response = call(html_ls_hover, handle, r#"{ "text": "<div></div>" }"#);
+    assert_eq!(response["error"], "`position` is missing");
+    let response = call(html_ls_hover, handle, "{");
+    assert!(response["error"].is_string());
+    let response = call(html_ls_hover, std::ptr::null(), r#"{ "text": "" }"#);
+    assert!(response["error"].is_string());
+
+    unsafe { html_ls_free(handle) };
+}
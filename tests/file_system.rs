@@ -0,0 +1,116 @@
+#[cfg(feature = "fs_provider")]
+use html_languageservice::{FileSystemProvider, FileType, LocalFileSystemProvider};
+#[cfg(feature = "fs_provider")]
+use lsp_types::Url;
+
+#[cfg(feature = "fs_provider")]
+#[tokio::test]
+async fn local_file_system() {
+    let dir = std::env::temp_dir().join(format!("html-languageservice-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("images")).unwrap();
+    std::fs::write(dir.join("index.html"), "<div></div>").unwrap();
+    let uri = |path: &str| Url::from_file_path(dir.join(path)).unwrap().to_string();
+
+    let provider = LocalFileSystemProvider;
+
+    let stat = provider.stat(uri("index.html")).await;
+    assert_eq!(stat.file_type, FileType::File);
+    assert_eq!(stat.size, 11);
+    assert!(stat.mtime > 0);
+    assert_eq!(
+        provider.stat(uri("images")).await.file_type,
+        FileType::Directory
+    );
+    assert_eq!(
+        provider.stat(uri("missing.html")).await.file_type,
+        FileType::Unknown
+    );
+    assert_eq!(
+        provider
+            .stat("http://example.com/a.html".to_string())
+            .await
+            .file_type,
+        FileType::Unknown
+    );
+
+    let mut entries = provider.read_directory(uri("")).await;
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        entries,
+        vec![
+            ("images".to_string(), FileType::Directory),
+            ("index.html".to_string(), FileType::File),
+        ]
+    );
+    assert_eq!(provider.read_directory(uri("missing")).await, vec![]);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn memory_file_system() {
+    use html_languageservice::{FileSystemProvider, FileType, MemoryFileSystemProvider};
+
+    let provider = MemoryFileSystemProvider::new();
+    provider.add_file("file:///site/index.html", 11);
+    provider.add_file("file:///site/images/a.png", 100);
+    provider.add_directory("file:///site/empty/");
+
+    let stat = provider.stat("file:///site/index.html".to_string()).await;
+    assert_eq!(stat.file_type, FileType::File);
+    assert_eq!(stat.size, 11);
+    assert_eq!(
+        provider
+            .stat("file:///site/images/".to_string())
+            .await
+            .file_type,
+        FileType::Directory
+    );
+    assert_eq!(
+        provider.stat("file:///site".to_string()).await.file_type,
+        FileType::Directory
+    );
+    assert_eq!(
+        provider
+            .stat("file:///site/b.html".to_string())
+            .await
+            .file_type,
+        FileType::Unknown
+    );
+
+    assert_eq!(
+        provider.read_directory("file:///site/".to_string()).await,
+        vec![
+            ("empty".to_string(), FileType::Directory),
+            ("images".to_string(), FileType::Directory),
+            ("index.html".to_string(), FileType::File),
+        ]
+    );
+    assert_eq!(
+        provider.read_directory("file:///".to_string()).await,
+        vec![("site".to_string(), FileType::Directory)]
+    );
+    assert_eq!(
+        provider
+            .read_directory("file:///site/empty".to_string())
+            .await,
+        vec![]
+    );
+
+    assert!(provider.remove("file:///site/images"));
+    assert!(!provider.remove("file:///site/images"));
+    assert_eq!(
+        provider
+            .stat("file:///site/images/a.png".to_string())
+            .await
+            .file_type,
+        FileType::Unknown
+    );
+    assert_eq!(
+        provider.read_directory("file:///site".to_string()).await,
+        vec![
+            ("empty".to_string(), FileType::Directory),
+            ("index.html".to_string(), FileType::File),
+        ]
+    );
+}
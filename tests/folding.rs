@@ -1,9 +1,16 @@
 #[cfg(feature = "folding")]
-use html_languageservice::{FoldingRangeContext, HTMLDataManager, HTMLLanguageService};
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "folding")]
+use html_languageservice::{
+    participant::{IFoldingRangeParticipant, ParticipantError},
+    CancellationToken, FoldingRangeContext, FoldingRegionMarkers, HTMLDataManager,
+    HTMLLanguageService, HTMLLanguageServiceOptions,
+};
 #[cfg(feature = "folding")]
 use lsp_textdocument::FullTextDocument;
 #[cfg(feature = "folding")]
-use lsp_types::FoldingRangeKind;
+use lsp_types::{FoldingRange, FoldingRangeKind, PositionEncodingKind};
 
 #[cfg(feature = "folding")]
 fn assert_ranges(
@@ -14,9 +21,13 @@ fn assert_ranges(
 ) {
     let document = FullTextDocument::new("json".to_string(), 1, lines.join("\n"));
     let actual = HTMLLanguageService::get_folding_ranges(
-        document,
-        FoldingRangeContext { range_limit },
+        &document,
+        FoldingRangeContext {
+            range_limit,
+            ..Default::default()
+        },
         &HTMLDataManager::default(),
+        None,
     );
 
     let mut actual_ranges = vec![];
@@ -123,6 +134,31 @@ fn fold_self_closing_tags() {
     );
 }
 
+#[cfg(feature = "folding")]
+#[test]
+fn fold_start_tags() {
+    assert_ranges(
+        &[
+            "<div>",                  // 0
+            "<my-component",          // 1
+            r#"  title="a""#,         // 2
+            r#"  :items="list""#,     // 3
+            "></my-component>",       // 4
+            r#"<my-component a="1""#, // 5
+            r#"  b="2">"#,            // 6
+            "</my-component>",        // 7
+            "<my-component",          // 8
+            r#"  a="1">"#,            // 9
+            "text",                   // 10
+            "</my-component>",        // 11
+            "</div>",                 // 12
+        ],
+        &[r(0, 11), r(1, 3), r(5, 6), r(8, 10)],
+        None,
+        None,
+    );
+}
+
 #[cfg(feature = "folding")]
 #[test]
 fn fold_comment() {
@@ -314,6 +350,206 @@ fn test_limit() {
     assert_ranges(&input, &[r(0, 19)], Some("limit 1"), Some(1));
 }
 
+#[cfg(feature = "folding")]
+#[test]
+fn fold_template_syntax() {
+    let input = [
+        "<div>",         // 0
+        "{% if a <b %}", // 1
+        "<p>",           // 2
+        "text",          // 3
+        "</p>",          // 4
+        "{% endif %}",   // 5
+        "</div>",        // 6
+    ];
+    let document = FullTextDocument::new("jinja".to_string(), 1, input.join("\n"));
+    let actual = HTMLLanguageService::get_folding_ranges(
+        &document,
+        FoldingRangeContext::default(),
+        &HTMLDataManager::default(),
+        None,
+    );
+    let mut actual_ranges: Vec<ExpectedIndentRange> = actual
+        .iter()
+        .map(|r| ExpectedIndentRange::new(r.start_line, r.end_line, r.kind.clone()))
+        .collect();
+    actual_ranges.sort_by_key(|r| r.start_line);
+    assert_eq!(actual_ranges, vec![r(0, 5), r(2, 3)]);
+}
+
+#[cfg(feature = "folding")]
+#[test]
+fn fold_custom_regions() {
+    let input = [
+        "<!-- BEGIN header -->", // 0
+        "<div></div>",           // 1
+        "<!-- #region -->",      // 2
+        "<!-- #endregion -->",   // 3
+        "<!-- END -->",          // 4
+        "<!-- END -->",          // 5
+    ];
+    let document = FullTextDocument::new("html".to_string(), 1, input.join("\n"));
+    let actual = HTMLLanguageService::get_folding_ranges(
+        &document,
+        FoldingRangeContext {
+            region_markers: vec![FoldingRegionMarkers {
+                start: r"^\s*BEGIN\b".to_string(),
+                end: r"^\s*END\b".to_string(),
+            }],
+            ..Default::default()
+        },
+        &HTMLDataManager::default(),
+        None,
+    );
+    let mut actual_ranges: Vec<ExpectedIndentRange> = actual
+        .iter()
+        .map(|r| ExpectedIndentRange::new(r.start_line, r.end_line, r.kind.clone()))
+        .collect();
+    actual_ranges.sort_by_key(|r| r.start_line);
+    assert_eq!(actual_ranges, vec![rr(0, 4), rr(2, 3)]);
+
+    let actual = HTMLLanguageService::get_folding_ranges(
+        &document,
+        FoldingRangeContext::default(),
+        &HTMLDataManager::default(),
+        None,
+    );
+    let actual_ranges: Vec<ExpectedIndentRange> = actual
+        .iter()
+        .map(|r| ExpectedIndentRange::new(r.start_line, r.end_line, r.kind.clone()))
+        .collect();
+    assert_eq!(actual_ranges, vec![rr(2, 3)]);
+}
+
+#[cfg(feature = "folding")]
+#[test]
+fn folding_range_participants() {
+    struct BraceFolding;
+
+    impl IFoldingRangeParticipant for BraceFolding {
+        fn get_folding_ranges(
+            &self,
+            language: &str,
+            content: &str,
+        ) -> Result<Vec<FoldingRange>, ParticipantError> {
+            if language != "css" && language != "javascript" {
+                return Ok(vec![]);
+            }
+            let mut ranges = vec![];
+            let mut stack = vec![];
+            for (line, text) in content.lines().enumerate() {
+                if text.ends_with('{') {
+                    stack.push(line as u32);
+                } else if text.starts_with('}') {
+                    if let Some(start_line) = stack.pop() {
+                        if line as u32 > start_line + 1 {
+                            ranges.push(FoldingRange {
+                                start_line,
+                                end_line: line as u32 - 1,
+                                ..Default::default()
+                            });
+                        }
+                    }
+                }
+            }
+            Ok(ranges)
+        }
+    }
+
+    struct BrokenFolding;
+
+    impl IFoldingRangeParticipant for BrokenFolding {
+        fn get_folding_ranges(
+            &self,
+            language: &str,
+            _content: &str,
+        ) -> Result<Vec<FoldingRange>, ParticipantError> {
+            Err(ParticipantError::new(format!(
+                "{} isn't supported",
+                language
+            )))
+        }
+    }
+
+    let input = [
+        "<html>",                 // 0
+        "<style>",                // 1
+        "a {",                    // 2
+        "  color: red;",          // 3
+        "}",                      // 4
+        "b { color: blue; }",     // 5
+        "</style>",               // 6
+        "<script>function f() {", // 7
+        "  return 1;",            // 8
+        "}",                      // 9
+        "</script>",              // 10
+        "</html>",                // 11
+    ];
+    let document = FullTextDocument::new("html".to_string(), 1, input.join("\n"));
+    let errors = Arc::new(Mutex::new(vec![]));
+    let ls = HTMLLanguageService::builder()
+        .options(HTMLLanguageServiceOptions {
+            participant_error_handler: Some(Arc::new({
+                let errors = errors.clone();
+                move |error: &ParticipantError| {
+                    errors.lock().unwrap().push(error.message().to_string())
+                }
+            })),
+            ..Default::default()
+        })
+        .folding_range_participant(Box::new(BrokenFolding))
+        .folding_range_participant(Box::new(BraceFolding))
+        .build();
+    let actual = ls.get_folding_ranges_with_participants(
+        &document,
+        FoldingRangeContext::default(),
+        &HTMLDataManager::default(),
+    );
+    assert_eq!(
+        errors.lock().unwrap().drain(..).collect::<Vec<_>>(),
+        vec!["css isn't supported", "javascript isn't supported"]
+    );
+    let mut actual_ranges: Vec<ExpectedIndentRange> = actual
+        .iter()
+        .map(|r| ExpectedIndentRange::new(r.start_line, r.end_line, r.kind.clone()))
+        .collect();
+    actual_ranges.sort_by_key(|r| r.start_line);
+    assert_eq!(actual_ranges, vec![r(0, 10), r(1, 5), r(2, 3), r(7, 9)]);
+
+    let actual = ls.get_folding_ranges_with_participants(
+        &document,
+        FoldingRangeContext {
+            range_limit: Some(3),
+            ..Default::default()
+        },
+        &HTMLDataManager::default(),
+    );
+    let mut actual_ranges: Vec<ExpectedIndentRange> = actual
+        .iter()
+        .map(|r| ExpectedIndentRange::new(r.start_line, r.end_line, r.kind.clone()))
+        .collect();
+    actual_ranges.sort_by_key(|r| r.start_line);
+    assert_eq!(actual_ranges, vec![r(0, 10), r(1, 5), r(7, 9)]);
+
+    // the language of the script is its type
+    errors.lock().unwrap().clear();
+    let document = FullTextDocument::new(
+        "html".to_string(),
+        1,
+        r#"<script type="application/json">{}</script><script type="text/typescript">a;</script>"#
+            .to_string(),
+    );
+    ls.get_folding_ranges_with_participants(
+        &document,
+        FoldingRangeContext::default(),
+        &HTMLDataManager::default(),
+    );
+    assert_eq!(
+        *errors.lock().unwrap(),
+        vec!["json isn't supported", "typescript isn't supported"]
+    );
+}
+
 #[cfg(feature = "folding")]
 #[derive(PartialEq, Debug)]
 struct ExpectedIndentRange {
@@ -332,3 +568,136 @@ impl ExpectedIndentRange {
         }
     }
 }
+
+#[cfg(feature = "folding")]
+#[test]
+fn cancellation() {
+    let document = FullTextDocument::new("html".to_string(), 1, "<div>\n\n</div>".to_string());
+    let token = CancellationToken::new();
+    let context = FoldingRangeContext {
+        cancellation_token: Some(token.clone()),
+        ..Default::default()
+    };
+    let data_manager = HTMLDataManager::default();
+    assert_eq!(
+        HTMLLanguageService::get_folding_ranges(&document, context.clone(), &data_manager, None)
+            .len(),
+        1
+    );
+    token.cancel();
+    assert!(
+        HTMLLanguageService::get_folding_ranges(&document, context, &data_manager, None).is_empty()
+    );
+}
+
+#[cfg(feature = "folding")]
+#[test]
+fn streaming() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let content = [
+        "<div>",       // 0
+        "  <p>",       // 1
+        "    a",       // 2
+        "  </p>",      // 3
+        "  <span",     // 4
+        "    a=\"b\"", // 5
+        "  >",         // 6
+        "  </span>",   // 7
+        "  <!--",      // 8
+        "  -->",       // 9
+        "</div>",      // 10
+    ]
+    .join("\n");
+    let document = FullTextDocument::new("html".to_string(), 1, content);
+    let data_manager = HTMLDataManager::default();
+    let ranges = HTMLLanguageService::get_folding_ranges(
+        &document,
+        FoldingRangeContext::default(),
+        &data_manager,
+        ls.position_encoding(),
+    );
+    assert_eq!(ranges.len(), 4);
+
+    let mut chunks: Vec<Vec<FoldingRange>> = vec![];
+    ls.get_folding_ranges_streaming(
+        &document,
+        FoldingRangeContext::default(),
+        &data_manager,
+        1,
+        |chunk| chunks.push(chunk),
+    );
+    assert_eq!(chunks.len(), 4);
+    assert_eq!(chunks.concat(), ranges);
+
+    let context = FoldingRangeContext {
+        range_limit: Some(2),
+        ..Default::default()
+    };
+    let mut chunks: Vec<Vec<FoldingRange>> = vec![];
+    ls.get_folding_ranges_streaming(&document, context.clone(), &data_manager, 10, |chunk| {
+        chunks.push(chunk)
+    });
+    assert_eq!(
+        chunks,
+        vec![HTMLLanguageService::get_folding_ranges(
+            &document,
+            context,
+            &data_manager,
+            ls.position_encoding()
+        )]
+    );
+}
+
+#[cfg(feature = "folding")]
+#[test]
+fn position_encoding() {
+    struct ScriptFolding;
+
+    impl IFoldingRangeParticipant for ScriptFolding {
+        fn get_folding_ranges(
+            &self,
+            _language: &str,
+            _content: &str,
+        ) -> Result<Vec<FoldingRange>, ParticipantError> {
+            Ok(vec![FoldingRange {
+                start_line: 0,
+                start_character: Some(0),
+                end_line: 1,
+                end_character: Some(0),
+                ..Default::default()
+            }])
+        }
+    }
+
+    // `中` and `😀` are 1 and 2 UTF-16 code units, 3 and 4 UTF-8 bytes,
+    // the script is too short to be folded as an element
+    let document = FullTextDocument::new(
+        "html".to_string(),
+        0,
+        "中😀<script>{\n}</script>".to_string(),
+    );
+    for (encoding, p) in [
+        (PositionEncodingKind::UTF8, 7),
+        (PositionEncodingKind::UTF16, 3),
+        (PositionEncodingKind::UTF32, 2),
+    ] {
+        let ls = HTMLLanguageService::builder()
+            .options(HTMLLanguageServiceOptions {
+                position_encoding: Some(encoding),
+                ..Default::default()
+            })
+            .folding_range_participant(Box::new(ScriptFolding))
+            .build();
+        let ranges = ls.get_folding_ranges_with_participants(
+            &document,
+            FoldingRangeContext::default(),
+            &HTMLDataManager::default(),
+        );
+        let range = ranges
+            .iter()
+            .find(|range| range.start_character.is_some())
+            .unwrap();
+        assert_eq!(range.start_character, Some(p + "<script>".len() as u32));
+        assert_eq!(range.end_character, Some(0));
+    }
+}
@@ -1,5 +1,14 @@
 #[cfg(feature = "formatter")]
-use html_languageservice::{HTMLFormatConfiguration, HTMLLanguageService};
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "formatter")]
+use html_languageservice::participant::{IFormatterParticipant, ParticipantError};
+#[cfg(feature = "formatter")]
+use html_languageservice::{
+    CancellationToken, HTMLDataManager, HTMLFormatConfiguration, HTMLLanguageService,
+    HTMLLanguageServiceOptions, HtmlAttributeQuotes, HtmlEndOfLine, HtmlVoidElementStyle,
+    HtmlWrapAttributes,
+};
 #[cfg(feature = "formatter")]
 use lsp_textdocument::FullTextDocument;
 #[cfg(feature = "formatter")]
@@ -7,6 +16,7 @@ use lsp_types::*;
 
 #[cfg(feature = "formatter")]
 fn format(unformatted: &str, expected: &str, options: &HTMLFormatConfiguration) {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
     let range_start = unformatted.find('|');
     let range_end = unformatted.rfind('|');
     let mut range = None;
@@ -28,7 +38,7 @@ fn format(unformatted: &str, expected: &str, options: &HTMLFormatConfiguration)
         FullTextDocument::new("html".to_string(), 0, unformatted.to_string())
     };
 
-    let edits = HTMLLanguageService::format(&document, range, &options);
+    let edits = ls.format(&document, range, Some(options), &HTMLDataManager::default());
 
     let content = document.get_content(None);
     let mut formatted = content.to_string();
@@ -346,3 +356,850 @@ fn range() {
     };
     format(&unformatted, &expected, &options);
 }
+
+#[cfg(feature = "formatter")]
+#[test]
+fn wrap_attributes() {
+    let unformatted = [
+        r#"<div class="foo" id="bar">"#, // wrap
+        r#"<img src="foo">"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        wrap_attributes: HtmlWrapAttributes::Force,
+        ..Default::default()
+    };
+    let expected = [
+        r#"<div class="foo""#,
+        r#"  id="bar">"#,
+        r#"  <img src="foo" />"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    format(&unformatted, &expected, &options);
+
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        wrap_attributes: HtmlWrapAttributes::ForceAligned,
+        ..Default::default()
+    };
+    let expected = [
+        r#"<div class="foo""#,
+        r#"     id="bar">"#,
+        r#"  <img src="foo" />"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    format(&unformatted, &expected, &options);
+
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        wrap_attributes: HtmlWrapAttributes::ForceExpandMultiline,
+        ..Default::default()
+    };
+    let expected = [
+        r#"<div"#,
+        r#"  class="foo""#,
+        r#"  id="bar""#,
+        r#">"#,
+        r#"  <img src="foo" />"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    format(&unformatted, &expected, &options);
+
+    let unformatted = [
+        r#"<input type="text" name="foo" value="bar" disabled>"#, // wrap
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        wrap_line_length: Some(30),
+        wrap_attributes: HtmlWrapAttributes::AlignedMultiple,
+        ..Default::default()
+    };
+    let expected = [
+        r#"<input type="text" name="foo""#,
+        r#"       value="bar" disabled />"#,
+    ]
+    .join("\n");
+    format(&unformatted, &expected, &options);
+
+    let unformatted = [
+        r#"<input type="text""#, // wrap
+        r#"  name="foo" value="bar">"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        wrap_attributes: HtmlWrapAttributes::Preserve,
+        ..Default::default()
+    };
+    let expected = [r#"<input type="text""#, r#"  name="foo" value="bar" />"#].join("\n");
+    format(&unformatted, &expected, &options);
+
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        wrap_attributes: HtmlWrapAttributes::PreserveAligned,
+        ..Default::default()
+    };
+    let expected = [
+        r#"<input type="text""#,
+        r#"       name="foo" value="bar" />"#,
+    ]
+    .join("\n");
+    format(&unformatted, &expected, &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn unformatted() {
+    let unformatted = [
+        r#"<div>"#,
+        r#"<pre  class="foo">  a   b"#,
+        r#"    c  </pre>"#,
+        r#"<span  id="bar">  a   b  </span>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let expected = [
+        r#"<div>"#,
+        r#"  <pre class="foo">  a   b"#,
+        r#"    c  </pre>"#,
+        r#"  <span id="bar">a b</span>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+
+    let expected = [
+        r#"<div>"#,
+        r#"  <pre class="foo">  a   b"#,
+        r#"    c  </pre>"#,
+        r#"  <span  id="bar">  a   b  </span>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        unformatted: vec!["span".to_string()],
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn indent_inner_html() {
+    let unformatted = [
+        r#"<html>"#,
+        r#"<head>"#,
+        r#"<title>foo</title>"#,
+        r#"</head>"#,
+        r#"</html>"#,
+    ]
+    .join("\n");
+    let expected = [
+        r#"<html>"#,
+        r#"<head>"#,
+        r#"  <title>foo</title>"#,
+        r#"</head>"#,
+        r#"</html>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        extra_liners: vec![],
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+
+    let expected = [
+        r#"<html>"#,
+        r#"  <head>"#,
+        r#"    <title>foo</title>"#,
+        r#"  </head>"#,
+        r#"</html>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        extra_liners: vec![],
+        indent_inner_html: true,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn indent_handlebars() {
+    let unformatted = [
+        r#"<div>"#,
+        r#"{{#if foo}}"#,
+        r#"<span>foo</span>"#,
+        r#"{{else}}"#,
+        r#"bar"#,
+        r#"{{/if}}"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let expected = [
+        r#"<div>"#,
+        r#"  {{#if foo}}"#,
+        r#"  <span>foo</span>"#,
+        r#"  {{else}}"#,
+        r#"  bar"#,
+        r#"  {{/if}}"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+
+    let expected = [
+        r#"<div>"#,
+        r#"  {{#if foo}}"#,
+        r#"    <span>foo</span>"#,
+        r#"  {{else}}"#,
+        r#"    bar"#,
+        r#"  {{/if}}"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        indent_handlebars: true,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn void_element_style() {
+    let unformatted = [
+        r#"<div>"#, // wrap
+        r#"<br>"#,
+        r#"<img src="foo"/>"#,
+        r#"<span/>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let expected = [
+        r#"<div>"#,
+        r#"  <br />"#,
+        r#"  <img src="foo" />"#,
+        r#"  <span />"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+
+    let expected = [
+        r#"<div>"#,
+        r#"  <br>"#,
+        r#"  <img src="foo">"#,
+        r#"  <span />"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        void_element_style: HtmlVoidElementStyle::NoSlash,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+
+    let expected = [
+        r#"<div>"#,
+        r#"  <br>"#,
+        r#"  <img src="foo" />"#,
+        r#"  <span />"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        void_element_style: HtmlVoidElementStyle::Preserve,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn attribute_quotes() {
+    let unformatted = r#"<div class='foo' id=bar title='say "hi"' data-x="it's"></div>"#;
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    format(unformatted, unformatted, &options);
+
+    let expected = r#"<div class="foo" id="bar" title="say &quot;hi&quot;" data-x="it's"></div>"#;
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        attribute_quotes: HtmlAttributeQuotes::Double,
+        ..Default::default()
+    };
+    format(unformatted, expected, &options);
+
+    let expected = r#"<div class='foo' id='bar' title='say "hi"' data-x='it&#39;s'></div>"#;
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        attribute_quotes: HtmlAttributeQuotes::Single,
+        ..Default::default()
+    };
+    format(unformatted, expected, &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn template_syntax() {
+    let unformatted = [
+        r#"<div   {% if a %}hidden{% endif %}  class="foo">"#,
+        r#"{% if a < b %}"#,
+        r#"<span  id="bar">{{  a  }}   b</span>"#,
+        r#"{% endif %}"#,
+        r#"<?php  echo "<br>";"#,
+        r#"    $a = 1; ?>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let expected = [
+        r#"<div   {% if a %}hidden{% endif %}  class="foo">"#,
+        r#"  {% if a < b %}"#,
+        r#"  <span id="bar">{{  a  }} b</span>"#,
+        r#"  {% endif %}"#,
+        r#"  <?php  echo "<br>";"#,
+        r#"    $a = 1; ?>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn formatter_participants() {
+    struct CssFormatter;
+
+    impl IFormatterParticipant for CssFormatter {
+        fn format_embedded(
+            &self,
+            language: &str,
+            content: &str,
+            _indent_level: usize,
+            _options: &HTMLFormatConfiguration,
+        ) -> Result<Option<String>, ParticipantError> {
+            if language == "css" {
+                Ok(Some(
+                    content
+                        .split(';')
+                        .map(|v| v.trim())
+                        .filter(|v| !v.is_empty())
+                        .map(|v| format!("{};", v))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                ))
+            } else {
+                Ok(None)
+            }
+        }
+    }
+
+    struct BrokenFormatter;
+
+    impl IFormatterParticipant for BrokenFormatter {
+        fn format_embedded(
+            &self,
+            language: &str,
+            _content: &str,
+            _indent_level: usize,
+            _options: &HTMLFormatConfiguration,
+        ) -> Result<Option<String>, ParticipantError> {
+            Err(ParticipantError::new(format!(
+                "{} isn't supported",
+                language
+            )))
+        }
+    }
+
+    let unformatted = [
+        r#"<div>"#,
+        r#"<style>a { color: red;  b { color: blue; </style>"#,
+        r#"<script>  let a  = 1;</script>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let expected = [
+        r#"<div>"#,
+        r#"  <style>"#,
+        r#"    a { color: red;"#,
+        r#"    b { color: blue;"#,
+        r#"  </style>"#,
+        r#"  <script>  let a  = 1;</script>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    let document = FullTextDocument::new("html".to_string(), 0, unformatted);
+    let ls = HTMLLanguageService::builder()
+        .formatter_participant(Box::new(CssFormatter))
+        .build();
+    let edits = ls.format(&document, None, Some(&options), &HTMLDataManager::default());
+    assert_eq!(edits[0].new_text, expected);
+
+    // the failed participant is skipped
+    let errors = Arc::new(Mutex::new(vec![]));
+    let ls = HTMLLanguageService::builder()
+        .options(HTMLLanguageServiceOptions {
+            participant_error_handler: Some(Arc::new({
+                let errors = errors.clone();
+                move |error: &ParticipantError| {
+                    errors.lock().unwrap().push(error.message().to_string())
+                }
+            })),
+            ..Default::default()
+        })
+        .formatter_participant(Box::new(BrokenFormatter))
+        .formatter_participant(Box::new(CssFormatter))
+        .build();
+    let edits = ls.format(&document, None, Some(&options), &HTMLDataManager::default());
+    assert_eq!(edits[0].new_text, expected);
+    assert_eq!(
+        *errors.lock().unwrap(),
+        vec!["css isn't supported", "javascript isn't supported"]
+    );
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn end_of_line() {
+    let unformatted = "<div>\r\n<span>a</span>\n<br>\r\n</div>";
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    let expected = "<div>\r\n  <span>a</span>\r\n  <br />\r\n</div>";
+    format(unformatted, expected, &options);
+
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        end_of_line: HtmlEndOfLine::Lf,
+        insert_final_newline: true,
+        ..Default::default()
+    };
+    let expected = "<div>\n  <span>a</span>\n  <br />\n</div>\n";
+    format(unformatted, expected, &options);
+
+    let unformatted = "<div>\n<br>\n\n<br>\n</div>";
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        indent_empty_lines: true,
+        ..Default::default()
+    };
+    let expected = "<div>\n  <br />\n  \n  <br />\n</div>";
+    format(unformatted, expected, &options);
+
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        indent_empty_lines: true,
+        trim_trailing_whitespace: true,
+        end_of_line: HtmlEndOfLine::CrLf,
+        ..Default::default()
+    };
+    let expected = "<div>\r\n  <br />\r\n\r\n  <br />\r\n</div>";
+    format(unformatted, expected, &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn extra_liners() {
+    let unformatted = [
+        r#"<html>"#,
+        r#"<head>"#,
+        r#"<title>foo</title>"#,
+        r#"</head>"#,
+        r#""#,
+        r#"<body>"#,
+        r#"<div>foo</div>"#,
+        r#"</body>"#,
+        r#"</html>"#,
+    ]
+    .join("\n");
+    let expected = [
+        r#"<html>"#,
+        r#""#,
+        r#"<head>"#,
+        r#"  <title>foo</title>"#,
+        r#"</head>"#,
+        r#""#,
+        r#"<body>"#,
+        r#"  <div>foo</div>"#,
+        r#"</body>"#,
+        r#""#,
+        r#"</html>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+
+    let expected = [
+        r#"<html>"#,
+        r#"<head>"#,
+        r#"  <title>foo</title>"#,
+        r#"</head>"#,
+        r#""#,
+        r#"<body>"#,
+        r#""#,
+        r#"  <div>foo</div>"#,
+        r#"</body>"#,
+        r#"</html>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        extra_liners: vec!["div".to_string()],
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn inline_elements() {
+    let unformatted = [
+        r#"<div>"#,
+        r#"foo  <b>bar</b>, <a  href="x">baz</a>qux"#,
+        r#"  <span>a</span>"#,
+        r#"<p>text <code>x</code></p>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let expected = [
+        r#"<div>"#,
+        r#"  foo <b>bar</b>, <a href="x">baz</a>qux"#,
+        r#"  <span>a</span>"#,
+        r#"  <p>text <code>x</code></p>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+
+    let expected = [
+        r#"<div>"#,
+        r#"  foo"#,
+        r#"  <b>bar</b>,"#,
+        r#"  <a href="x">baz</a>qux"#,
+        r#"  <span>a</span>"#,
+        r#"  <p>text"#,
+        r#"    <code>x</code>"#,
+        r#"  </p>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        inline: vec![],
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn idempotence() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let documents = [
+        "<div>foo<span>a</span>bar</div>",
+        "<div>foo\n<span>a</span>bar</div>",
+        "<div>a<div>b</div>c</div>",
+        "<div><p>a</p><p>b</p></div>",
+        "<div><br><br></div>",
+        "<ul><li>a</li><li>b <a href=x>c</a></li></ul>",
+        "<html><head><title>x</title></head><body><div>a</div></body></html>",
+        "<p>text <code>x</code> more <i>y</i>\n</p>",
+        "<div>a <b>b\nc</b> d</div>",
+        "<p>\n  a\n\n\n\n  b\n</p>",
+        "<button class='x'  disabled>Go</button>",
+        "<div>{{#each xs}}\n<p>{{this}}</p>\n{{/each}}</div>",
+        "<pre>\n  a\n b</pre>",
+        "<div class=\"a\" id=\"b\" title=\"cccccccccccccccccccccccccccccccccccc\">x</div>",
+    ];
+    let options = || {
+        [
+            HTMLFormatConfiguration {
+                tab_size: 2,
+                ..Default::default()
+            },
+            HTMLFormatConfiguration {
+                tab_size: 2,
+                inline: vec![],
+                extra_liners: vec![],
+                ..Default::default()
+            },
+            HTMLFormatConfiguration {
+                tab_size: 2,
+                wrap_line_length: Some(20),
+                wrap_attributes: HtmlWrapAttributes::AlignedMultiple,
+                ..Default::default()
+            },
+            HTMLFormatConfiguration {
+                tab_size: 2,
+                wrap_attributes: HtmlWrapAttributes::ForceExpandMultiline,
+                attribute_quotes: HtmlAttributeQuotes::Double,
+                indent_handlebars: true,
+                ..Default::default()
+            },
+        ]
+    };
+    let checked = options().map(|options| HTMLFormatConfiguration {
+        check_idempotence: true,
+        ..options
+    });
+    for (options, checked) in options().into_iter().zip(checked) {
+        for content in documents {
+            let document = FullTextDocument::new("html".to_string(), 0, content.to_string());
+            let edits = ls.format(&document, None, Some(&options), &HTMLDataManager::default());
+            assert_eq!(
+                ls.format(&document, None, Some(&checked), &HTMLDataManager::default()),
+                edits
+            );
+            let formatted = edits
+                .first()
+                .map_or(content.to_string(), |edit| edit.new_text.clone());
+            let document = FullTextDocument::new("html".to_string(), 1, formatted);
+            let edits = ls.format(&document, None, Some(&options), &HTMLDataManager::default());
+            assert!(edits.is_empty(), "{:?}", document.get_content(None));
+        }
+    }
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn nested_range() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let unformatted = [
+        r#"<div>"#,
+        r#"    <section>"#,
+        r#"        |<ul><li>a</li></ul>|"#,
+        r#"    </section>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let expected = [
+        r#"<div>"#,
+        r#"    <section>"#,
+        r#"      <ul>"#,
+        r#"        <li>a</li>"#,
+        r#"      </ul>"#,
+        r#"    </section>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+
+    let unformatted = [
+        r#"<div>"#,
+        r#"|<p>a</p>"#,
+        r#"<div>"#,
+        r#"<span>b</span>|"#,
+        r#"</div>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let expected = [
+        r#"<div>"#,
+        r#"  <p>a</p>"#,
+        r#"  <div>"#,
+        r#"    <span>b</span>"#,
+        r#"</div>"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    format(&unformatted, &expected, &options);
+
+    let content = "<div>\n  <p>a</p>\n</div>";
+    let document = FullTextDocument::new("html".to_string(), 0, content.to_string());
+    let range = Range::new(Position::new(1, 2), Position::new(1, 10));
+    assert!(ls
+        .format(
+            &document,
+            Some(range),
+            Some(&options),
+            &HTMLDataManager::default()
+        )
+        .is_empty());
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn unclosed_elements() {
+    let unformatted = [
+        r#"<div>"#, // wrap
+        r#"<p>a"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let expected = [
+        r#"<div>"#, // wrap
+        r#"  <p>a"#,
+        r#"</div>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn root_nodes() {
+    let unformatted = [
+        r#"<!DOCTYPE html>"#, // wrap
+        r#"<div>a</div><div>b</div>"#,
+        r#"text <b>c</b>"#,
+    ]
+    .join("\n");
+    let expected = [
+        r#"<!DOCTYPE html>"#, // wrap
+        r#"<div>a</div>"#,
+        r#"<div>b</div>"#,
+        r#"text <b>c</b>"#,
+    ]
+    .join("\n");
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    format(&unformatted, &expected, &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn cancellation() {
+    let token = CancellationToken::new();
+    let options = HTMLFormatConfiguration {
+        cancellation_token: Some(token.clone()),
+        ..Default::default()
+    };
+    format(
+        "<div><p>a</p></div>",
+        "<div>\n    <p>a</p>\n</div>",
+        &options,
+    );
+    token.cancel();
+    format("<div><p>a</p></div>", "<div><p>a</p></div>", &options);
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn range_with_custom_data() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    use html_languageservice::language_facts::data_provider::HTMLDataProvider;
+
+    let data =
+        serde_json::from_str(r#"{ "version": 1.1, "tags": [{ "name": "x-icon", "void": true }] }"#)
+            .unwrap();
+    let mut data_manager = HTMLDataManager::default();
+    data_manager.set_data_providers(
+        true,
+        vec![Box::new(HTMLDataProvider::new("custom".to_string(), data))],
+    );
+    let content = "<div>\n<x-icon><p>a</p>\n</div>";
+    let document = FullTextDocument::new("html".to_string(), 0, content.to_string());
+    let options = HTMLFormatConfiguration {
+        tab_size: 2,
+        ..Default::default()
+    };
+    let range = Range::new(Position::new(1, 0), Position::new(1, 16));
+    let edits = ls.format(&document, Some(range), Some(&options), &data_manager);
+    assert_eq!(edits[0].new_text, "  <x-icon />\n  <p>a</p>");
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn position_encoding() {
+    // `中` and `😀` are 1 and 2 UTF-16 code units, 3 and 4 UTF-8 bytes
+    let document = FullTextDocument::new(
+        "html".to_string(),
+        0,
+        "<div>\n中😀<p>a</p>   <p>b</p>\n</div>".to_string(),
+    );
+    let data_manager = HTMLDataManager::default();
+    let options = HTMLFormatConfiguration::default();
+    for (encoding, p) in [
+        (PositionEncodingKind::UTF8, 7),
+        (PositionEncodingKind::UTF16, 3),
+        (PositionEncodingKind::UTF32, 2),
+    ] {
+        let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions {
+            position_encoding: Some(encoding),
+            ..Default::default()
+        });
+        let edits = ls.format(&document, None, Some(&options), &data_manager);
+        assert_eq!(edits[0].range.end, Position::new(2, 6));
+
+        // `<p>a</p>   <p>b</p>` after `中😀`
+        let range = Range::new(Position::new(1, p), Position::new(1, p + 19));
+        let edits = ls.format(&document, Some(range), Some(&options), &data_manager);
+        assert_eq!(edits[0].range, range);
+        assert_eq!(edits[0].new_text, "<p>a</p>\n    <p>b</p>");
+    }
+}
+
+#[cfg(feature = "formatter")]
+#[test]
+fn builder_format_settings() {
+    let ls = HTMLLanguageService::builder()
+        .format_settings(HTMLFormatConfiguration {
+            tab_size: 2,
+            ..Default::default()
+        })
+        .build();
+    let document = FullTextDocument::new("html".to_string(), 0, "<div><p>a</p></div>".to_string());
+    let data_manager = HTMLDataManager::default();
+
+    let edits = ls.format(&document, None, None, &data_manager);
+    assert_eq!(edits[0].new_text, "<div>\n  <p>a</p>\n</div>");
+
+    // the options of the request take precedence
+    let options = HTMLFormatConfiguration::default();
+    let edits = ls.format(&document, None, Some(&options), &data_manager);
+    assert_eq!(edits[0].new_text, "<div>\n    <p>a</p>\n</div>");
+}
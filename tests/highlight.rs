@@ -2,6 +2,8 @@
 use html_languageservice::{HTMLDataManager, HTMLLanguageService};
 #[cfg(feature = "highlight")]
 use lsp_textdocument::FullTextDocument;
+#[cfg(feature = "highlight")]
+use lsp_types::{Position, PositionEncodingKind, Range};
 
 #[cfg(feature = "highlight")]
 fn assert_highlights(value: &str, expected_matches: &[usize], element_name: Option<&str>) {
@@ -15,7 +17,7 @@ fn assert_highlights(value: &str, expected_matches: &[usize], element_name: Opti
     let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
 
     let hightlights =
-        HTMLLanguageService::find_document_highlights(&document, &position, &html_document);
+        HTMLLanguageService::find_document_highlights(&document, &position, &html_document, None);
     assert_eq!(hightlights.len(), expected_matches.len());
 
     for (i, hightlight) in hightlights.iter().enumerate() {
@@ -119,3 +121,48 @@ fn case_insensivity() {
 fn incomplete() {
     assert_highlights("<div><ol><li></li></ol></p></|div>", &[1, 29], Some("div"));
 }
+
+#[cfg(feature = "highlight")]
+#[test]
+fn attribute_names() {
+    assert_highlights(
+        r#"<div tab|index="1"><input tabindex = "2"></div><p TabIndex></p>"#,
+        &[5, 25, 49],
+        Some("tabindex"),
+    );
+    assert_highlights(
+        r#"<div |style="color: red"><p style=""></p></div>"#,
+        &[5, 27],
+        Some("style"),
+    );
+    assert_highlights(r#"<div class="a">tab|index</div>"#, &[], None);
+}
+
+#[cfg(feature = "highlight")]
+#[test]
+fn position_encoding() {
+    // `中` and `😀` are 1 and 2 UTF-16 code units, 3 and 4 UTF-8 bytes
+    let document = FullTextDocument::new("html".to_string(), 0, "中😀<div></div>".to_string());
+    let data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    for (encoding, p) in [
+        (PositionEncodingKind::UTF8, 7),
+        (PositionEncodingKind::UTF16, 3),
+        (PositionEncodingKind::UTF32, 2),
+    ] {
+        let highlights = HTMLLanguageService::find_document_highlights(
+            &document,
+            &Position::new(0, p + 2),
+            &html_document,
+            Some(&encoding),
+        );
+        let ranges: Vec<Range> = highlights.iter().map(|h| h.range).collect();
+        assert_eq!(
+            ranges,
+            vec![
+                Range::new(Position::new(0, p + 1), Position::new(0, p + 4)),
+                Range::new(Position::new(0, p + 7), Position::new(0, p + 10)),
+            ]
+        );
+    }
+}
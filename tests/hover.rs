@@ -1,12 +1,22 @@
+#[cfg(feature = "hover")]
+use std::sync::{Arc, Mutex};
+
 #[cfg(feature = "hover")]
 use lsp_textdocument::FullTextDocument;
 #[cfg(feature = "hover")]
-use lsp_types::{HoverContents, MarkupContent, MarkupKind};
+use lsp_types::{
+    ClientCapabilities, Hover, HoverContents, MarkupContent, MarkupKind, Position,
+    PositionEncodingKind, Range,
+};
 
 #[cfg(feature = "hover")]
 use html_languageservice::{
-    language_facts::data_manager::HTMLDataManager, HTMLLanguageService, HTMLLanguageServiceOptions,
-    HoverSettings,
+    language_facts::{data_manager::HTMLDataManager, data_provider::GenerateDocumentationItem},
+    participant::{
+        HtmlAttributeNameContext, HtmlAttributeValueContext, HtmlContentContext, HtmlTagContext,
+        IHoverParticipant, ParticipantError,
+    },
+    HTMLLanguageService, HTMLLanguageServiceOptions, HoverSettings,
 };
 
 #[cfg(feature = "hover")]
@@ -193,6 +203,30 @@ async fn single() {
     )
     .await;
 
+    assert_hover_range(
+        "<html>&#16|9;</html>",
+        HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::PlainText,
+            value: "Character entity representing '\u{00A9}', unicode equivalent 'U+00A9'"
+                .to_string(),
+        }),
+        "#169;",
+        None,
+        None,
+    )
+    .await;
+    assert_hover_range(
+        "<html>&|#x27;</html>",
+        HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::PlainText,
+            value: "Character entity representing ''', unicode equivalent 'U+0027'".to_string(),
+        }),
+        "#x27;",
+        None,
+        None,
+    )
+    .await;
+
     let no_description = MarkupContent {
         kind: MarkupKind::Markdown,
         value: "[MDN Reference](https://developer.mozilla.org/docs/Web/HTML/Element/html)"
@@ -203,10 +237,7 @@ async fn single() {
         HoverContents::Markup(no_description),
         "html",
         None,
-        Some(HoverSettings {
-            documentation: false,
-            references: true,
-        }),
+        Some(HoverSettings::new(false, true)),
     )
     .await;
 
@@ -219,10 +250,614 @@ async fn single() {
         HoverContents::Markup(no_references),
         "html",
         None,
-        Some(HoverSettings {
-            documentation: true,
-            references: false,
+        Some(HoverSettings::new(true, false)),
+    )
+    .await;
+}
+
+#[cfg(feature = "hover")]
+#[tokio::test]
+async fn doctype() {
+    let html5_content = MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: "The HTML5 doctype, the browsers render the document in standards mode.".to_string(),
+    };
+    assert_hover(
+        "<!DOC|TYPE html><html></html>",
+        Some(html5_content.clone()),
+        Some(0),
+    )
+    .await;
+    assert_hover("<!doctype ht|ml>", Some(html5_content.clone()), Some(0)).await;
+    assert_hover_range(
+        "<!DOCTYPE html|>\n<html></html>",
+        HoverContents::Markup(html5_content),
+        "<!DOCTYPE html>",
+        None,
+        None,
+    )
+    .await;
+
+    let legacy_content = MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: "Legacy doctype, the browsers render the document in almost standards mode. Use `<!DOCTYPE html>` instead.".to_string(),
+    };
+    assert_hover(
+        r#"<html><!DOCTYPE HTML PUBLIC "-//W3C//DTD HTML 4.01 Transitional//EN" "http://www.w3.org/TR/html4/loose.d|td"></html>"#,
+        Some(legacy_content),
+        Some(6),
+    )
+    .await;
+    let quirks_content = MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: "Legacy doctype, the browsers render the document in quirks mode. Use `<!DOCTYPE html>` instead.".to_string(),
+    };
+    assert_hover(
+        r#"<!DOCTYPE HTML PUBLIC "-//W3C//DTD HTML 3.2 Final//EN"|>"#,
+        Some(quirks_content),
+        Some(0),
+    )
+    .await;
+
+    let plaintext_options = HTMLLanguageServiceOptions {
+        client_capabilities: Some(ClientCapabilities::default()),
+        ..Default::default()
+    };
+    assert_hover_range(
+        r#"<!DOCTYPE HTML PUBLIC "-//W3C//DTD HTML 3.2 Final//EN"|>"#,
+        HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::PlainText,
+            value: "Legacy doctype, the browsers render the document in quirks mode. Use <!DOCTYPE html> instead.".to_string(),
+        }),
+        r#"<!DOCTYPE HTML PUBLIC "-//W3C//DTD HTML 3.2 Final//EN">"#,
+        Some(plaintext_options),
+        None,
+    )
+    .await;
+
+    assert_hover("<!DOCTYPE html>\n<div>te|xt</div>", None, None).await;
+}
+
+#[cfg(feature = "hover")]
+#[tokio::test]
+async fn content_model() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    let document = FullTextDocument::new("html".to_string(), 0, "<ul><li></li></ul>".to_string());
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    for (content_model, expected) in [(true, true), (false, false)] {
+        let hover = ls
+            .do_hover(
+                &document,
+                &document.position_at(5),
+                &html_document,
+                Some(HoverSettings::default().with_content_model(content_model)),
+                &data_manager,
+            )
+            .await
+            .unwrap();
+        if let HoverContents::Markup(contents) = hover.contents {
+            assert!(contents
+                .value
+                .starts_with("The li element represents a list item."));
+            assert_eq!(
+                contents
+                    .value
+                    .ends_with("**Permitted parents**: An `ol`, `ul` or `menu` element."),
+                expected
+            );
+            assert_eq!(
+                contents.value.contains("**Content categories**: None."),
+                expected
+            );
+        } else {
+            panic!("hover contents should be MarkupContent");
+        }
+    }
+}
+
+#[cfg(feature = "hover")]
+#[tokio::test]
+async fn attribute_name_participant() {
+    struct AlpineParticipant;
+
+    #[async_trait::async_trait]
+    impl IHoverParticipant for AlpineParticipant {
+        async fn on_html_attribute_value(
+            &self,
+            _context: HtmlAttributeValueContext,
+        ) -> Result<Option<Hover>, ParticipantError> {
+            Ok(None)
+        }
+
+        async fn on_html_content(
+            &self,
+            _context: HtmlContentContext,
+        ) -> Result<Option<Hover>, ParticipantError> {
+            Err(ParticipantError::new("no content hover"))
+        }
+
+        async fn on_html_attribute_name(
+            &self,
+            context: HtmlAttributeNameContext,
+        ) -> Result<Option<Hover>, ParticipantError> {
+            Ok(if context.attribute.starts_with("x-") {
+                Some(Hover {
+                    contents: HoverContents::Markup(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value: format!(
+                            "Alpine.js directive `{}` on `{}`",
+                            context.attribute, context.tag
+                        ),
+                    }),
+                    range: Some(context.range),
+                })
+            } else {
+                None
+            })
+        }
+    }
+
+    let errors = Arc::new(Mutex::new(vec![]));
+    let ls = HTMLLanguageService::builder()
+        .options(HTMLLanguageServiceOptions {
+            participant_error_handler: Some(Arc::new({
+                let errors = errors.clone();
+                move |error: &ParticipantError| errors.lock().unwrap().push(error.to_string())
+            })),
+            ..Default::default()
+        })
+        .hover_participant(Box::new(AlpineParticipant))
+        .build();
+    let data_manager = HTMLDataManager::default();
+    let document = FullTextDocument::new(
+        "html".to_string(),
+        0,
+        r#"<div x-data="{ open: false }" class="a"></div>"#.to_string(),
+    );
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+
+    let hover = ls
+        .do_hover(
+            &document,
+            &Position::new(0, 7),
+            &html_document,
+            None,
+            &data_manager,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        hover.contents,
+        HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: "Alpine.js directive `x-data` on `div`".to_string(),
+        })
+    );
+    assert_eq!(document.get_content(hover.range), "x-data");
+
+    // fall back to the data providers
+    let hover = ls
+        .do_hover(
+            &document,
+            &Position::new(0, 32),
+            &html_document,
+            None,
+            &data_manager,
+        )
+        .await
+        .unwrap();
+    if let HoverContents::Markup(contents) = hover.contents {
+        assert!(contents.value.contains("class"));
+    } else {
+        panic!("hover contents should be MarkupContent");
+    }
+
+    // the error of the participant is reported
+    let document = FullTextDocument::new("html".to_string(), 0, "<div>text</div>".to_string());
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let hover = ls
+        .do_hover(
+            &document,
+            &Position::new(0, 7),
+            &html_document,
+            None,
+            &data_manager,
+        )
+        .await;
+    assert_eq!(hover, None);
+    assert_eq!(
+        *errors.lock().unwrap(),
+        vec!["participant failed: no content hover"]
+    );
+}
+
+#[cfg(feature = "hover")]
+#[tokio::test]
+async fn tag_participant() {
+    struct ComponentParticipant;
+
+    #[async_trait::async_trait]
+    impl IHoverParticipant for ComponentParticipant {
+        async fn on_html_attribute_value(
+            &self,
+            _context: HtmlAttributeValueContext,
+        ) -> Result<Option<Hover>, ParticipantError> {
+            Ok(None)
+        }
+
+        async fn on_html_content(
+            &self,
+            _context: HtmlContentContext,
+        ) -> Result<Option<Hover>, ParticipantError> {
+            Ok(None)
+        }
+
+        async fn on_html_tag(
+            &self,
+            context: HtmlTagContext,
+        ) -> Result<Option<Hover>, ParticipantError> {
+            Ok(context.tag.contains('-').then(|| Hover {
+                contents: HoverContents::Markup(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: format!(
+                        "[{}](file:///src/components/{}.js) {}",
+                        context.tag,
+                        context.tag,
+                        if context.open { "start" } else { "end" }
+                    ),
+                }),
+                range: Some(context.range),
+            }))
+        }
+    }
+
+    let ls = HTMLLanguageService::builder()
+        .hover_participant(Box::new(ComponentParticipant))
+        .build();
+    let data_manager = HTMLDataManager::default();
+    let document = FullTextDocument::new(
+        "html".to_string(),
+        0,
+        "<div><my-button></my-button></div>".to_string(),
+    );
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let hover = async |character| {
+        ls.do_hover(
+            &document,
+            &Position::new(0, character),
+            &html_document,
+            None,
+            &data_manager,
+        )
+        .await
+    };
+
+    let start = hover(8).await.unwrap();
+    assert_eq!(
+        start.contents,
+        HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: "[my-button](file:///src/components/my-button.js) start".to_string(),
+        })
+    );
+    assert_eq!(document.get_content(start.range), "my-button");
+    let end = hover(20).await.unwrap();
+    assert!(matches!(
+        end.contents,
+        HoverContents::Markup(MarkupContent { value, .. }) if value.ends_with(" end")
+    ));
+
+    // fall back to the data providers
+    let div = hover(2).await.unwrap();
+    assert!(matches!(
+        div.contents,
+        HoverContents::Markup(MarkupContent { value, .. }) if value.contains("The div element")
+    ));
+}
+
+#[cfg(feature = "hover")]
+#[tokio::test]
+async fn doc_renderer() {
+    let ls_options = HTMLLanguageServiceOptions {
+        doc_renderer: Some(Arc::new(|item: GenerateDocumentationItem| MarkupContent {
+            kind: MarkupKind::PlainText,
+            value: format!(
+                "{} reference(s), deprecated: {}",
+                item.references.map_or(0, |r| r.len()),
+                item.deprecated
+            ),
+        })),
+        ..Default::default()
+    };
+    assert_hover_range(
+        "<ht|ml></html>",
+        HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::PlainText,
+            value: "1 reference(s), deprecated: false".to_string(),
+        }),
+        "html",
+        Some(ls_options),
+        None,
+    )
+    .await;
+}
+
+#[cfg(feature = "hover")]
+#[tokio::test]
+async fn data_attribute() {
+    assert_hover(
+        "<div data-id=\"1\">\n<p |data-id=\"2\" data-other></p></div>",
+        Some(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: "`data-id` is used 2 times in the document, first at line 1".to_string(),
         }),
+        Some(21),
+    )
+    .await;
+    assert_hover(
+        "<div>\n<p |data-other></p></div>",
+        Some(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: "`data-other` is used 1 time in the document, first at line 2".to_string(),
+        }),
+        Some(9),
+    )
+    .await;
+}
+
+#[cfg(feature = "hover")]
+#[tokio::test]
+async fn case_sensitive_languages() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let mut data_manager = HTMLDataManager::default();
+    data_manager.set_case_sensitive_languages(vec!["vue".to_string()]);
+    for (language_id, expected) in [("vue", false), ("html", true)] {
+        let document =
+            FullTextDocument::new(language_id.to_string(), 0, "<Button></Button>".to_string());
+        let position = document.position_at(2);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let hover = ls
+            .do_hover(&document, &position, &html_document, None, &data_manager)
+            .await;
+        assert_eq!(hover.is_some(), expected, "{}", language_id);
+    }
+}
+
+#[cfg(feature = "hover")]
+#[tokio::test]
+async fn aria_role() {
+    let navigation_content = MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: "A landmark containing a collection of navigational elements (usually links) for navigating the document or related documents.".to_string(),
+    };
+    assert_hover(
+        r#"<div role="navi|gation"></div>"#,
+        Some(navigation_content),
+        Some(10),
+    )
+    .await;
+}
+
+#[cfg(feature = "hover")]
+#[tokio::test]
+async fn baseline() {
+    let mut data_manager = HTMLDataManager::default();
+    data_manager
+        .add_data_provider_from_json(
+            r#"{
+                "version": 1.1,
+                "tags": [
+                    {
+                        "name": "my-widely",
+                        "description": "Widely.",
+                        "browsers": ["C1", "CA18", "E12", "FF1", "FFA4", "S3", "SM1"],
+                        "status": { "baseline": "high", "baseline_low_date": "2015-07-29", "baseline_high_date": "2018-01-29" }
+                    },
+                    {
+                        "name": "my-limited",
+                        "description": "Limited.",
+                        "browsers": ["C37", "CA37", "E79", "FF98", "FFA98"],
+                        "status": { "baseline": false }
+                    },
+                    {
+                        "name": "my-legacy",
+                        "description": "Legacy.",
+                        "browsers": ["IE9", "O10"]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    for (value, baseline, expected) in [
+        (
+            "<my-wid|ely>",
+            true,
+            "Widely.\n\n_Widely available across major browsers (Baseline since 2015)_",
+        ),
+        (
+            "<my-lim|ited>",
+            true,
+            "Limited.\n\n_Limited availability across major browsers (Not fully implemented in Safari, Safari (iOS))_",
+        ),
+        ("<my-leg|acy>", true, "Legacy.\n\n_Supported in IE 9, Opera 10_"),
+        ("<my-wid|ely>", false, "Widely."),
+    ] {
+        let offset = value.find('|').unwrap();
+        let value = format!("{}{}", &value[..offset], &value[offset + 1..]);
+        let document = FullTextDocument::new("html".to_string(), 0, value);
+        let position = document.position_at(offset as u32);
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let hover = ls
+            .do_hover(
+                &document,
+                &position,
+                &html_document,
+                Some(HoverSettings::default().with_baseline(baseline)),
+                &data_manager,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            hover.contents,
+            HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: expected.to_string(),
+            })
+        );
+    }
+}
+
+#[cfg(all(feature = "hover", feature = "svg_data"))]
+#[tokio::test]
+async fn svg() {
+    let circle_content = MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: "The `circle` element is an SVG basic shape, used to draw circles based on a center point and a radius.".to_string()
+            + "\n\n"
+            + "[MDN Reference](https://developer.mozilla.org/docs/Web/SVG/Element/circle)",
+    };
+    assert_hover("<svg><cir|cle/></svg>", Some(circle_content), Some(6)).await;
+
+    let view_box_content = MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: "The position and dimension, in user space, of the SVG viewport, as `min-x min-y width height`.".to_string(),
+    };
+    assert_hover(
+        r#"<svg view|Box="0 0 10 10"></svg>"#,
+        Some(view_box_content),
+        Some(5),
+    )
+    .await;
+
+    // The SVG data isn't used outside of `<svg>`
+    assert_hover("<cir|cle/>", None, None).await;
+    assert_hover("<svg></svg><cir|cle/>", None, None).await;
+    assert_hover(
+        r#"<div><circle str|oke-linecap="round"/></div>"#,
+        None,
+        None,
+    )
+    .await;
+}
+
+#[cfg(all(feature = "hover", feature = "mathml_data"))]
+#[tokio::test]
+async fn mathml() {
+    let mfrac_content = MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: "The `mfrac` element is used to display fractions. It can also be used to mark up fraction-like objects such as binomial coefficients and Legendre symbols.".to_string()
+            + "\n\n"
+            + "[MDN Reference](https://developer.mozilla.org/docs/Web/MathML/Element/mfrac)",
+    };
+    assert_hover(
+        "<math><mf|rac><mn>1</mn><mn>2</mn></mfrac></math>",
+        Some(mfrac_content),
+        Some(7),
     )
     .await;
+
+    // The MathML data isn't used outside of `<math>`
+    assert_hover("<mf|rac></mfrac>", None, None).await;
+    assert_hover("<math></math><mo stret|chy></mo>", None, None).await;
+}
+
+#[cfg(all(feature = "hover", feature = "angular_data"))]
+#[tokio::test]
+async fn angular() {
+    let ng_class_content = MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: "Adds and removes CSS classes on an HTML element.".to_string()
+            + "\n\n"
+            + "[Angular Reference](https://angular.dev/api/common/NgClass)",
+    };
+    assert_hover(
+        r#"<div [ngCl|ass]="{ active: true }"></div>"#,
+        Some(ng_class_content),
+        Some(5),
+    )
+    .await;
+}
+
+#[cfg(feature = "hover")]
+#[tokio::test]
+async fn builder() {
+    let settings = HoverSettings::new(false, false).with_baseline(false);
+    let data_manager = HTMLDataManager::default();
+    let document = FullTextDocument::new("html".to_string(), 0, "<div></div>".to_string());
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let position = Position::new(0, 2);
+
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let hover = ls
+        .do_hover(&document, &position, &html_document, None, &data_manager)
+        .await
+        .unwrap();
+    let short = ls
+        .do_hover(
+            &document,
+            &position,
+            &html_document,
+            Some(settings.clone()),
+            &data_manager,
+        )
+        .await
+        .unwrap();
+    assert_ne!(hover, short);
+
+    let ls = HTMLLanguageService::builder()
+        .hover_settings(settings)
+        .build();
+    let hover = ls
+        .do_hover(&document, &position, &html_document, None, &data_manager)
+        .await
+        .unwrap();
+    assert_eq!(hover, short);
+
+    // the settings of the request take precedence
+    let hover = ls
+        .do_hover(
+            &document,
+            &position,
+            &html_document,
+            Some(HoverSettings::default().with_content_model(true)),
+            &data_manager,
+        )
+        .await
+        .unwrap();
+    assert_ne!(hover, short);
+}
+
+#[cfg(feature = "hover")]
+#[tokio::test]
+async fn position_encoding() {
+    // `中` and `😀` are 1 and 2 UTF-16 code units, 3 and 4 UTF-8 bytes
+    let document = FullTextDocument::new("html".to_string(), 0, "中😀<div></div>".to_string());
+    let data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    for (encoding, p) in [
+        (PositionEncodingKind::UTF8, 7),
+        (PositionEncodingKind::UTF16, 3),
+        (PositionEncodingKind::UTF32, 2),
+    ] {
+        let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions {
+            position_encoding: Some(encoding),
+            ..Default::default()
+        });
+        let hover = ls
+            .do_hover(
+                &document,
+                &Position::new(0, p + 2),
+                &html_document,
+                None,
+                &data_manager,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            hover.range,
+            Some(Range::new(Position::new(0, p + 1), Position::new(0, p + 4)))
+        );
+    }
 }
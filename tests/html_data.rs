@@ -0,0 +1,287 @@
+use html_languageservice::{
+    html_data::{Baseline, Description, HTMLDataV1},
+    language_facts::{
+        custom_elements_manifest::parse_custom_elements_manifest,
+        data_provider::{GenerateDocumentationSetting, HTMLDataProvider, IHTMLDataProvider},
+    },
+    HTMLDataManager,
+};
+
+#[test]
+fn custom_data_v1_1() {
+    let json = r#"{
+        "version": 1.1,
+        "tags": [
+            {
+                "name": "my-dialog",
+                "description": { "kind": "markdown", "value": "A **dialog**" },
+                "attributes": [
+                    {
+                        "name": "mode",
+                        "values": [
+                            {
+                                "name": "modal",
+                                "description": { "kind": "markdown", "value": "Blocks the page" },
+                                "references": [{ "name": "Docs", "url": "https://example.com/modal" }],
+                                "browsers": ["C37", "FF98"],
+                                "status": { "baseline": "low", "baseline_low_date": "2022-03-14" }
+                            }
+                        ]
+                    }
+                ],
+                "browsers": ["C37", "FF98", "S15.4"],
+                "status": {
+                    "baseline": "high",
+                    "baseline_low_date": "2022-03-14",
+                    "baseline_high_date": "2024-09-14"
+                }
+            }
+        ],
+        "globalAttributes": [
+            { "name": "my-flag", "status": { "baseline": false } }
+        ]
+    }"#;
+    let data: HTMLDataV1 = serde_json::from_str(json).unwrap();
+
+    let tag = &data.tags.as_ref().unwrap()[0];
+    assert_eq!(tag.browsers.as_ref().unwrap().len(), 3);
+    let status = tag.status.as_ref().unwrap();
+    assert_eq!(status.baseline, Baseline::High);
+    assert_eq!(status.baseline_high_date.as_deref(), Some("2024-09-14"));
+
+    let value = &tag.attributes[0].values.as_ref().unwrap()[0];
+    assert!(matches!(
+        value.description,
+        Some(Description::MarkupContent(_))
+    ));
+    assert_eq!(value.references.as_ref().unwrap()[0].name, "Docs");
+    assert_eq!(value.status.as_ref().unwrap().baseline, Baseline::Low);
+
+    let global_attribute = &data.global_attributes.as_ref().unwrap()[0];
+    assert_eq!(
+        global_attribute.status.as_ref().unwrap().baseline,
+        Baseline::False
+    );
+
+    let round_trip: HTMLDataV1 =
+        serde_json::from_value(serde_json::to_value(&data).unwrap()).unwrap();
+    assert_eq!(
+        round_trip.tags.unwrap()[0]
+            .status
+            .as_ref()
+            .unwrap()
+            .baseline,
+        Baseline::High
+    );
+
+    let provider = HTMLDataProvider::new("custom".to_string(), data);
+    let values = provider.provide_values("my-dialog", "mode");
+    assert_eq!(values[0].browsers.as_ref().unwrap(), &vec!["C37", "FF98"]);
+
+    assert!(serde_json::from_str::<Baseline>(r#""medium""#).is_err());
+}
+
+#[test]
+fn replace_data_provider() {
+    let provider = |tag: &str| -> Box<dyn IHTMLDataProvider> {
+        let data = serde_json::from_str(&format!(
+            r#"{{ "version": 1.1, "tags": [{{ "name": "{}" }}] }}"#,
+            tag
+        ))
+        .unwrap();
+        Box::new(HTMLDataProvider::new("custom".to_string(), data))
+    };
+
+    let mut data_manager = HTMLDataManager::new(false, None);
+    let version = data_manager.get_version();
+    assert!(data_manager
+        .set_data_provider("custom", provider("my-old"))
+        .is_none());
+    assert!(data_manager.get_version() > version);
+
+    let version = data_manager.get_version();
+    let old = data_manager.set_data_provider("custom", provider("my-new"));
+    assert_eq!(old.unwrap().provide_tags()[0].name, "my-old");
+    assert!(data_manager.get_version() > version);
+
+    let providers = data_manager.get_data_providers();
+    assert_eq!(providers.len(), 1);
+    assert_eq!(providers[0].provide_tags()[0].name, "my-new");
+
+    assert!(data_manager.remove_data_provider("custom").is_some());
+    assert!(data_manager.remove_data_provider("custom").is_none());
+    assert!(data_manager.get_data_providers().is_empty());
+}
+
+#[test]
+fn custom_elements_manifest() {
+    let data = parse_custom_elements_manifest(
+        r#"{
+            "schemaVersion": "1.0.0",
+            "modules": [
+                {
+                    "kind": "javascript-module",
+                    "path": "src/my-button.js",
+                    "declarations": [
+                        {
+                            "kind": "class",
+                            "name": "MyButton",
+                            "customElement": true,
+                            "tagName": "my-button",
+                            "description": "A button of the design system.",
+                            "attributes": [
+                                {
+                                    "name": "size",
+                                    "description": "The size of the button.",
+                                    "type": { "text": "'small' | 'large'" },
+                                    "default": "'small'"
+                                }
+                            ],
+                            "slots": [
+                                { "name": "", "description": "The label of the button." },
+                                { "name": "icon", "description": "The icon before the label." }
+                            ],
+                            "events": [{ "name": "toggle", "description": "Fired when toggled." }]
+                        },
+                        { "kind": "function", "name": "helper" }
+                    ]
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let tags = data.tags.unwrap();
+    assert_eq!(tags.len(), 1);
+    let tag = &tags[0];
+    assert_eq!(tag.name, "my-button");
+    match tag.description.as_ref().unwrap() {
+        Description::MarkupContent(content) => assert_eq!(
+            content.value,
+            "A button of the design system.\n\n**Slots**\n\n- (default): The label of the button.\n- `icon`: The icon before the label.\n\n**Events**\n\n- `toggle`: Fired when toggled."
+        ),
+        _ => panic!("description should be markdown"),
+    }
+
+    let attribute = &tag.attributes[0];
+    assert_eq!(attribute.name, "size");
+    match attribute.description.as_ref().unwrap() {
+        Description::MarkupContent(content) => assert_eq!(
+            content.value,
+            "The size of the button.\n\nDefault: `'small'`"
+        ),
+        _ => panic!("description should be markdown"),
+    }
+    let values: Vec<&str> = attribute
+        .values
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|v| v.name.as_str())
+        .collect();
+    assert_eq!(values, vec!["small", "large"]);
+}
+
+#[test]
+fn global_attributes_and_value_sets() {
+    let mut data_manager = HTMLDataManager::default();
+    data_manager
+        .add_data_provider_from_json(
+            r#"{
+                "version": 1.1,
+                "globalAttributes": [{ "name": "my-theme", "valueSet": "my-themes" }],
+                "valueSets": [
+                    { "name": "my-themes", "values": [{ "name": "dark" }, { "name": "light" }] },
+                    { "name": "b", "values": [{ "name": "my-bool" }] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+    let provider = &data_manager.get_data_providers()[0];
+    assert_eq!(provider.get_id(), "html5");
+    let names: Vec<&str> = provider
+        .provide_global_attributes()
+        .iter()
+        .map(|a| a.name.as_str())
+        .collect();
+    assert!(names.contains(&"class"));
+    assert!(!names.contains(&"my-theme"));
+    assert_eq!(provider.get_value_set("b").unwrap()[0].name, "true");
+    assert!(provider.get_value_set("my-themes").is_none());
+
+    let names: Vec<&str> = data_manager
+        .get_global_attributes("html")
+        .iter()
+        .map(|a| a.name.as_str())
+        .collect();
+    assert!(names.contains(&"class"));
+    assert!(names.contains(&"my-theme"));
+
+    let values: Vec<&str> = data_manager
+        .get_value_set("my-themes", "html")
+        .iter()
+        .map(|v| v.name.as_str())
+        .collect();
+    assert_eq!(values, vec!["dark", "light"]);
+    let values: Vec<&str> = data_manager
+        .get_value_set("b", "html")
+        .iter()
+        .map(|v| v.name.as_str())
+        .collect();
+    assert!(values.contains(&"true"));
+    assert!(values.contains(&"my-bool"));
+    assert!(data_manager.get_value_set("unknown", "html").is_empty());
+}
+
+#[test]
+fn inline_data_ids() {
+    let mut data_manager = HTMLDataManager::new(false, None);
+    let json = r#"{ "version": 1.1, "tags": [{ "name": "my-tag" }] }"#;
+    data_manager.add_data_provider_from_json(json).unwrap();
+    data_manager.add_data_provider_from_json(json).unwrap();
+    data_manager.remove_data_provider("custom-data-1");
+    data_manager.add_data_provider_from_json(json).unwrap();
+    let ids: Vec<&str> = data_manager
+        .get_data_providers()
+        .iter()
+        .map(|p| p.get_id())
+        .collect();
+    assert_eq!(ids, vec!["custom-data-2", "custom-data-3"]);
+}
+
+#[test]
+fn documentation_cache() {
+    let provider = |description: &str| -> Box<dyn IHTMLDataProvider> {
+        let data = serde_json::from_str(&format!(
+            r#"{{ "version": 1.1, "tags": [{{ "name": "my-tag", "description": "{}" }}] }}"#,
+            description
+        ))
+        .unwrap();
+        Box::new(HTMLDataProvider::new("custom".to_string(), data))
+    };
+    let setting = GenerateDocumentationSetting {
+        documentation: true,
+        references: true,
+        baseline: true,
+        does_support_markdown: true,
+    };
+    let documentation = |data_manager: &HTMLDataManager, setting| {
+        let tag = &data_manager.get_data_providers()[0].provide_tags()[0];
+        data_manager
+            .generate_documentation("custom", &["my-tag"], tag, setting)
+            .map(|d| d.value)
+    };
+
+    let mut data_manager = HTMLDataManager::new(false, Some(vec![provider("Old")]));
+    assert_eq!(documentation(&data_manager, setting).unwrap(), "Old");
+    assert_eq!(documentation(&data_manager, setting).unwrap(), "Old");
+    let no_documentation = GenerateDocumentationSetting {
+        documentation: false,
+        ..setting
+    };
+    assert_eq!(documentation(&data_manager, no_documentation), None);
+
+    data_manager.set_data_provider("custom", provider("New"));
+    assert_eq!(documentation(&data_manager, setting).unwrap(), "New");
+}
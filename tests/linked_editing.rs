@@ -1,10 +1,21 @@
 #[cfg(feature = "linked_editing")]
-use html_languageservice::{HTMLDataManager, HTMLLanguageService};
+use html_languageservice::{HTMLDataManager, HTMLLanguageService, LinkedEditingConfiguration};
 #[cfg(feature = "linked_editing")]
 use lsp_textdocument::FullTextDocument;
+#[cfg(feature = "linked_editing")]
+use lsp_types::{Position, PositionEncodingKind, Range};
 
 #[cfg(feature = "linked_editing")]
 fn test_linked_editing(content: &str, expected: Vec<(usize, &str)>) {
+    test_linked_editing_with_settings(content, expected, None);
+}
+
+#[cfg(feature = "linked_editing")]
+fn test_linked_editing_with_settings(
+    content: &str,
+    expected: Vec<(usize, &str)>,
+    settings: Option<&LinkedEditingConfiguration>,
+) {
     let offset = content.find('|').unwrap();
     let value = format!("{}{}", &content[..offset], &content[offset + 1..]);
 
@@ -13,8 +24,13 @@ fn test_linked_editing(content: &str, expected: Vec<(usize, &str)>) {
     let html_document =
         HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
 
-    let synced_regions =
-        HTMLLanguageService::find_linked_editing_ranges(&document, position, &html_document);
+    let synced_regions = HTMLLanguageService::find_linked_editing_ranges(
+        &document,
+        position,
+        &html_document,
+        settings,
+        None,
+    );
 
     if synced_regions.is_none() {
         if expected.len() > 0 {
@@ -68,3 +84,78 @@ fn linked_editing() {
     test_linked_editing("<|></>", vec![(1, ""), (4, "")]);
     test_linked_editing("<><div></div></|>", vec![(1, ""), (15, "")]);
 }
+
+#[cfg(feature = "linked_editing")]
+#[test]
+fn tag_name_pattern() {
+    let settings = LinkedEditingConfiguration {
+        tag_name_pattern: Some(r"[\w.@:-]+".to_string()),
+        ..Default::default()
+    };
+    test_linked_editing("<my@c|omp></my@comp>", vec![]);
+    test_linked_editing_with_settings(
+        "<my@c|omp></my@comp>",
+        vec![(1, "my@comp"), (11, "my@comp")],
+        Some(&settings),
+    );
+    test_linked_editing_with_settings(
+        "<Foo.B|ar></Foo.Bar>",
+        vec![(1, "Foo.Bar"), (11, "Foo.Bar")],
+        Some(&settings),
+    );
+    test_linked_editing_with_settings(
+        "<div></div|>",
+        vec![(1, "div"), (7, "div")],
+        Some(&settings),
+    );
+}
+
+#[cfg(feature = "linked_editing")]
+#[test]
+fn include_open_bracket() {
+    let settings = LinkedEditingConfiguration {
+        include_open_bracket: true,
+        ..Default::default()
+    };
+    test_linked_editing_with_settings("|<div></div>", vec![], None);
+    test_linked_editing_with_settings(
+        "|<div></div>",
+        vec![(1, "div"), (7, "div")],
+        Some(&settings),
+    );
+    test_linked_editing_with_settings(
+        "<div>|</div>",
+        vec![(1, "div"), (7, "div")],
+        Some(&settings),
+    );
+    test_linked_editing_with_settings("<div><|/div>", vec![], Some(&settings));
+}
+
+#[cfg(feature = "linked_editing")]
+#[test]
+fn position_encoding() {
+    // `中` and `😀` are 1 and 2 UTF-16 code units, 3 and 4 UTF-8 bytes
+    let document = FullTextDocument::new("html".to_string(), 0, "中😀<div></div>".to_string());
+    let html_document =
+        HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
+    for (encoding, p) in [
+        (PositionEncodingKind::UTF8, 7),
+        (PositionEncodingKind::UTF16, 3),
+        (PositionEncodingKind::UTF32, 2),
+    ] {
+        let ranges = HTMLLanguageService::find_linked_editing_ranges(
+            &document,
+            Position::new(0, p + 2),
+            &html_document,
+            None,
+            Some(&encoding),
+        );
+        assert_eq!(
+            ranges,
+            Some(vec![
+                Range::new(Position::new(0, p + 1), Position::new(0, p + 4)),
+                Range::new(Position::new(0, p + 7), Position::new(0, p + 10)),
+            ])
+        );
+    }
+}
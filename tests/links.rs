@@ -1,9 +1,12 @@
 #[cfg(feature = "links")]
-use html_languageservice::{DocumentContext, HTMLDataManager, HTMLLanguageService};
+use html_languageservice::{
+    DefaultDocumentContext, DocumentContext, HTMLDataManager, HTMLLanguageService,
+    HTMLLanguageServiceOptions, WorkspaceDocumentContext,
+};
 #[cfg(feature = "links")]
 use lsp_textdocument::FullTextDocument;
 #[cfg(feature = "links")]
-use lsp_types::{DocumentLink, Position, Range, Url};
+use lsp_types::{DocumentLink, Position, PositionEncodingKind, Range, Url, WorkspaceFolder};
 
 #[cfg(feature = "links")]
 struct LinkDocumentContent;
@@ -38,11 +41,14 @@ fn test_link_creation(model_url: &str, token_content: &str, expected: Option<&st
     let uri = Url::parse(model_url).unwrap();
     let document = FullTextDocument::new(language_id, 0, format!(r#"<a href="{}""#, token_content));
     let mut data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
     let links = HTMLLanguageService::find_document_links(
         &uri,
         &document,
+        &html_document,
         &LinkDocumentContent,
         &mut data_manager,
+        None,
     );
     assert_eq!(
         if links.len() > 0 {
@@ -59,16 +65,43 @@ fn test_link_detection(value: &str, expected_links: Vec<DocumentLink>) {
     let uri = Url::parse("file:///test/data/abc/test.html").unwrap();
     let document = FullTextDocument::new("html".to_string(), 0, value.to_string());
     let mut data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
     let links = HTMLLanguageService::find_document_links(
         &uri,
         &document,
+        &html_document,
         &LinkDocumentContent,
         &mut data_manager,
+        None,
     );
 
     assert_eq!(links, expected_links);
 }
 
+#[cfg(feature = "links")]
+#[test]
+fn template_syntax() {
+    let uri = Url::parse("file:///test/data/abc/test.html").unwrap();
+    let data_manager = HTMLDataManager::default();
+    for (language_id, expected) in [("erb", 1), ("html", 2)] {
+        let document = FullTextDocument::new(
+            language_id.to_string(),
+            0,
+            r#"<a href="<%= url %>"></a><a href="foo.html"></a>"#.to_string(),
+        );
+        let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+        let links = HTMLLanguageService::find_document_links(
+            &uri,
+            &document,
+            &html_document,
+            &LinkDocumentContent,
+            &data_manager,
+            None,
+        );
+        assert_eq!(links.len(), expected, "{}", language_id);
+    }
+}
+
 #[cfg(feature = "links")]
 #[test]
 fn link_creation() {
@@ -357,3 +390,297 @@ fn local_targets() {
         }],
     );
 }
+
+#[cfg(feature = "links")]
+#[test]
+fn srcset() {
+    let link = |start: u32, end: u32, path: &str| DocumentLink {
+        range: Range::new(Position::new(0, start), Position::new(0, end)),
+        target: Some(Url::parse(&format!("file:///test/data/abc/{}", path)).unwrap()),
+        tooltip: None,
+        data: None,
+    };
+    test_link_detection(
+        r#"<img srcset="a.png, b.png 2x,c.png 300w">"#,
+        vec![
+            link(13, 18, "a.png"),
+            link(20, 25, "b.png"),
+            link(29, 34, "c.png"),
+        ],
+    );
+    test_link_detection(r#"<source srcset=a.png>"#, vec![link(15, 20, "a.png")]);
+    test_link_detection(
+        r#"<img srcset="a.png,, b.png">"#,
+        vec![link(13, 18, "a.png"), link(21, 26, "b.png")],
+    );
+    test_link_detection(
+        r#"<link rel="preload" as="image" imagesrcset="x.png 1x, y.png 2x">"#,
+        vec![link(44, 49, "x.png"), link(54, 59, "y.png")],
+    );
+    test_link_detection(r#"<div srcset="a.png">"#, vec![]);
+}
+
+#[cfg(feature = "links")]
+#[test]
+fn css_urls() {
+    let link = |line: u32, start: u32, end: u32, path: &str| DocumentLink {
+        range: Range::new(Position::new(line, start), Position::new(line, end)),
+        target: Some(Url::parse(&format!("file:///test/data/abc/{}", path)).unwrap()),
+        tooltip: None,
+        data: None,
+    };
+    test_link_detection(
+        r#"<div style="background: url(a.png)">"#,
+        vec![link(0, 28, 33, "a.png")],
+    );
+    test_link_detection(
+        r#"<div style='background: url("a.png"), URL( b.png )'>"#,
+        vec![link(0, 29, 34, "a.png"), link(0, 43, 48, "b.png")],
+    );
+    test_link_detection(
+        "<style>\n@import url('base.css');\nbody { background: url(img/bg.png) }\n</style>",
+        vec![link(1, 13, 21, "base.css"), link(2, 23, 33, "img/bg.png")],
+    );
+    test_link_detection(
+        r#"<div style="mask: url(data:image/png;base64,AAA); --my-url(a.png)">"#,
+        vec![],
+    );
+    test_link_detection(r#"<div title="url(a.png)">"#, vec![]);
+}
+
+#[cfg(feature = "links")]
+#[test]
+fn meta_refresh_and_itemtype() {
+    let link = |start: u32, end: u32, target: &str| DocumentLink {
+        range: Range::new(Position::new(0, start), Position::new(0, end)),
+        target: Some(Url::parse(target).unwrap()),
+        tooltip: None,
+        data: None,
+    };
+    test_link_detection(
+        r#"<meta http-equiv="refresh" content="5; url=/next.html">"#,
+        vec![link(43, 53, "file:///next.html")],
+    );
+    test_link_detection(
+        r#"<meta content="0;URL='next.html'" http-equiv="Refresh">"#,
+        vec![link(22, 31, "file:///test/data/abc/next.html")],
+    );
+    test_link_detection(
+        r#"<meta http-equiv="refresh" content="3, next.html">"#,
+        vec![link(39, 48, "file:///test/data/abc/next.html")],
+    );
+    test_link_detection(r#"<meta http-equiv="refresh" content="5">"#, vec![]);
+    test_link_detection(r#"<meta name="refresh" content="5; url=a.html">"#, vec![]);
+    test_link_detection(
+        r#"<div itemscope itemtype="https://schema.org/Person https://schema.org/Thing">"#,
+        vec![
+            link(25, 50, "https://schema.org/Person"),
+            link(51, 75, "https://schema.org/Thing"),
+        ],
+    );
+    test_link_detection(
+        r#"<link rel="manifest" href="app.webmanifest">"#,
+        vec![link(27, 42, "file:///test/data/abc/app.webmanifest")],
+    );
+}
+
+#[cfg(feature = "links")]
+#[test]
+fn custom_path_attributes() {
+    let link = |start: u32, end: u32, path: &str| DocumentLink {
+        range: Range::new(Position::new(0, start), Position::new(0, end)),
+        target: Some(Url::parse(&format!("file:///test/data/abc/{}", path)).unwrap()),
+        tooltip: None,
+        data: None,
+    };
+    let uri = Url::parse("file:///test/data/abc/test.html").unwrap();
+    let document = FullTextDocument::new(
+        "html".to_string(),
+        0,
+        r#"<img data-src="a.png"><div data-background="b.png"><my-player poster-url="c.png">"#
+            .to_string(),
+    );
+    let mut data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let links = HTMLLanguageService::find_document_links(
+        &uri,
+        &document,
+        &html_document,
+        &LinkDocumentContent,
+        &data_manager,
+        None,
+    );
+    assert_eq!(links, vec![]);
+
+    data_manager.set_path_attributes(vec![
+        ("img".to_string(), "data-src".to_string()),
+        ("*".to_string(), "data-background".to_string()),
+        ("my-player".to_string(), "poster-url".to_string()),
+    ]);
+    let links = HTMLLanguageService::find_document_links(
+        &uri,
+        &document,
+        &html_document,
+        &LinkDocumentContent,
+        &data_manager,
+        None,
+    );
+    assert_eq!(
+        links,
+        vec![
+            link(15, 20, "a.png"),
+            link(44, 49, "b.png"),
+            link(74, 79, "c.png"),
+        ]
+    );
+}
+
+#[cfg(feature = "links")]
+#[test]
+fn default_document_context() {
+    let context = DefaultDocumentContext;
+    for (reference, base) in [
+        ("a.js", "http://model/x/1.html"),
+        ("./a2.js", "http://model/x/1.html"),
+        ("/b.js", "http://model/x/1.html"),
+        ("../c.js", "file:///test/data/abc/test.html"),
+        ("d.js?v=1#a", "file:///test/data/abc/test.html"),
+    ] {
+        assert_eq!(
+            context.resolve_reference(reference, base),
+            LinkDocumentContent.resolve_reference(reference, base),
+            "{}",
+            reference
+        );
+    }
+    assert_eq!(context.resolve_reference("a.js", "not a uri"), None);
+}
+
+#[cfg(feature = "links")]
+#[test]
+fn workspace_document_context() {
+    let context = WorkspaceDocumentContext::from(&HTMLLanguageServiceOptions {
+        workspace_folders: Some(vec![
+            WorkspaceFolder {
+                uri: Url::parse("file:///work/site").unwrap(),
+                name: "site".to_string(),
+            },
+            WorkspaceFolder {
+                uri: Url::parse("file:///work/site/docs/").unwrap(),
+                name: "docs".to_string(),
+            },
+        ]),
+        ..Default::default()
+    });
+    assert_eq!(
+        context.resolve_reference("/img/a.png", "file:///work/site/pages/index.html"),
+        Some("file:///work/site/img/a.png".to_string())
+    );
+    assert_eq!(
+        context.resolve_reference("/img/a.png", "file:///work/site/docs/index.html"),
+        Some("file:///work/site/docs/img/a.png".to_string())
+    );
+    assert_eq!(
+        context.resolve_reference("/img/a.png", "file:///work/site2/index.html"),
+        Some("file:///img/a.png".to_string())
+    );
+    assert_eq!(
+        context.resolve_reference("img/a.png", "file:///work/site/pages/index.html"),
+        Some("file:///work/site/pages/img/a.png".to_string())
+    );
+
+    let uri = Url::parse("file:///work/site/pages/index.html").unwrap();
+    let document = FullTextDocument::new(
+        "html".to_string(),
+        0,
+        r#"<a href="/about.html">"#.to_string(),
+    );
+    let html_document =
+        HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
+    let links = HTMLLanguageService::find_document_links(
+        &uri,
+        &document,
+        &html_document,
+        &context,
+        &HTMLDataManager::default(),
+        None,
+    );
+    assert_eq!(
+        links[0].target,
+        Some(Url::parse("file:///work/site/about.html").unwrap())
+    );
+}
+
+#[cfg(feature = "links")]
+#[test]
+fn streaming() {
+    let uri = Url::parse("file:///site/index.html").unwrap();
+    let content = r##"<div id="top"><a href="a.html"></a><img src="b.png" srcset="c.png 2x"><a href="#top"></a></div>"##;
+    let document = FullTextDocument::new("html".to_string(), 0, content.to_string());
+    let data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let links = HTMLLanguageService::find_document_links(
+        &uri,
+        &document,
+        &html_document,
+        &LinkDocumentContent,
+        &data_manager,
+        None,
+    );
+    assert_eq!(links.len(), 4);
+
+    let mut chunks: Vec<Vec<DocumentLink>> = vec![];
+    HTMLLanguageService::find_document_links_streaming(
+        &uri,
+        &document,
+        &html_document,
+        &LinkDocumentContent,
+        &data_manager,
+        None,
+        3,
+        |chunk| chunks.push(chunk),
+    );
+    assert_eq!(
+        chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>(),
+        vec![3, 1]
+    );
+    assert_eq!(chunks.concat(), links);
+}
+
+#[cfg(feature = "links")]
+#[test]
+fn position_encoding() {
+    // `中` and `😀` are 1 and 2 UTF-16 code units, 3 and 4 UTF-8 bytes
+    let uri = Url::parse("file:///test/index.html").unwrap();
+    let document = FullTextDocument::new(
+        "html".to_string(),
+        0,
+        "中😀<a href=\"#x\"></a>\n中<p id=\"x\"></p>".to_string(),
+    );
+    let html_document =
+        HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
+    for (encoding, p, q) in [
+        (PositionEncodingKind::UTF8, 7, 3),
+        (PositionEncodingKind::UTF16, 3, 1),
+        (PositionEncodingKind::UTF32, 2, 1),
+    ] {
+        let links = HTMLLanguageService::find_document_links(
+            &uri,
+            &document,
+            &html_document,
+            &DefaultDocumentContext,
+            &HTMLDataManager::default(),
+            Some(&encoding),
+        );
+        assert_eq!(
+            links,
+            vec![DocumentLink {
+                range: Range::new(Position::new(0, p + 9), Position::new(0, p + 11)),
+                // the line and the column of the value of `id`, starting from 1
+                target: Some(Url::parse(&format!("{}#2,{}", uri, q + 7)).unwrap()),
+                tooltip: None,
+                data: None,
+            }]
+        );
+    }
+}
@@ -2,6 +2,8 @@
 use html_languageservice::{HTMLDataManager, HTMLLanguageService};
 #[cfg(feature = "matching_tag_position")]
 use lsp_textdocument::FullTextDocument;
+#[cfg(feature = "matching_tag_position")]
+use lsp_types::{Position, PositionEncodingKind, Range};
 
 #[cfg(feature = "matching_tag_position")]
 fn test_matching_tag_position(content: &str) {
@@ -20,7 +22,7 @@ fn test_matching_tag_position(content: &str) {
         HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
 
     let mirror_position =
-        HTMLLanguageService::find_matching_tag_position(&document, position, &html_document)
+        HTMLLanguageService::find_matching_tag_position(&document, position, &html_document, None)
             .expect("Failed to find mirror position");
 
     assert_eq!(
@@ -50,3 +52,122 @@ fn matching_position() {
     test_matching_tag_position("<div$ ></div|>");
     test_matching_tag_position(r#"<div$ id="foo"></div|>"#);
 }
+
+#[cfg(feature = "matching_tag_position")]
+fn test_matching_tag_ranges(content: &str, expected: Vec<(usize, &str)>) {
+    let offset = content.find('|').unwrap();
+    let value = format!("{}{}", &content[..offset], &content[offset + 1..]);
+
+    let document = FullTextDocument::new("html".to_string(), 0, value);
+    let position = document.position_at(offset as u32);
+    let html_document =
+        HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
+
+    let actual: Vec<(usize, &str)> =
+        HTMLLanguageService::find_matching_tag_ranges(&document, position, &html_document, None)
+            .map_or(vec![], |(start, end)| vec![start, end])
+            .into_iter()
+            .map(|range| {
+                (
+                    document.offset_at(range.start) as usize,
+                    document.get_content(Some(range)),
+                )
+            })
+            .collect();
+
+    assert_eq!(actual, expected, "{}", content);
+}
+
+#[cfg(feature = "matching_tag_position")]
+#[test]
+fn matching_ranges() {
+    test_matching_tag_ranges("<|div></div>", vec![(1, "div"), (7, "div")]);
+    test_matching_tag_ranges("<div|></div>", vec![(1, "div"), (7, "div")]);
+    test_matching_tag_ranges("<div></d|iv>", vec![(1, "div"), (7, "div")]);
+    test_matching_tag_ranges(
+        r#"<div><span| id="foo">a</span></div>"#,
+        vec![(6, "span"), (23, "span")],
+    );
+    test_matching_tag_ranges("<div>|</div>", vec![]);
+    test_matching_tag_ranges("<br|>", vec![]);
+}
+
+#[cfg(feature = "matching_tag_position")]
+#[test]
+fn all_tag_pairs() {
+    let document = FullTextDocument::new(
+        "html".to_string(),
+        0,
+        "<div><p>a</p><br><span/></div>\n<ul><li></li></ul>".to_string(),
+    );
+    let html_document =
+        HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
+
+    let actual: Vec<((u32, &str), (u32, &str))> =
+        HTMLLanguageService::get_all_tag_pairs(&document, &html_document, None)
+            .into_iter()
+            .map(|(start, end)| {
+                (
+                    (
+                        document.offset_at(start.start),
+                        document.get_content(Some(start)),
+                    ),
+                    (
+                        document.offset_at(end.start),
+                        document.get_content(Some(end)),
+                    ),
+                )
+            })
+            .collect();
+
+    assert_eq!(
+        actual,
+        vec![
+            ((1, "div"), (26, "div")),
+            ((6, "p"), (11, "p")),
+            ((32, "ul"), (46, "ul")),
+            ((36, "li"), (41, "li")),
+        ]
+    );
+}
+
+#[cfg(feature = "matching_tag_position")]
+#[test]
+fn position_encoding() {
+    // `中` and `😀` are 1 and 2 UTF-16 code units, 3 and 4 UTF-8 bytes
+    let document = FullTextDocument::new("html".to_string(), 0, "中😀<div></div>".to_string());
+    let html_document =
+        HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
+    for (encoding, p) in [
+        (PositionEncodingKind::UTF8, 7),
+        (PositionEncodingKind::UTF16, 3),
+        (PositionEncodingKind::UTF32, 2),
+    ] {
+        assert_eq!(
+            HTMLLanguageService::find_matching_tag_position(
+                &document,
+                Position::new(0, p + 2),
+                &html_document,
+                Some(&encoding)
+            ),
+            Some(Position::new(0, p + 8))
+        );
+        let ranges = (
+            Range::new(Position::new(0, p + 1), Position::new(0, p + 4)),
+            Range::new(Position::new(0, p + 7), Position::new(0, p + 10)),
+        );
+        assert_eq!(
+            HTMLLanguageService::find_matching_tag_ranges(
+                &document,
+                Position::new(0, p + 8),
+                &html_document,
+                Some(&encoding)
+            ),
+            Some(ranges)
+        );
+        assert_eq!(
+            HTMLLanguageService::get_all_tag_pairs(&document, &html_document, Some(&encoding)),
+            vec![ranges]
+        );
+    }
+}
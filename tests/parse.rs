@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use html_languageservice::{
     parser::{
-        html_document::{HTMLDocument, Node, NodeAttribute},
+        html_document::{HTMLDocument, Node, NodeAttribute, SourceStr},
         html_parse::*,
         html_scanner::TokenType,
     },
@@ -22,7 +22,7 @@ fn to_json(node: &Node) -> NodeJSON {
         children.push(to_json(child));
     }
     NodeJSON {
-        tag: node.tag.clone().unwrap_or_default(),
+        tag: node.tag.as_deref().unwrap_or_default().to_string(),
         start: node.start,
         end: node.end,
         end_tag_start: node.end_tag_start,
@@ -37,8 +37,12 @@ fn to_json_with_attributes(node: &Node) -> NodeJSONWithAttributes {
         children.push(to_json_with_attributes(child))
     }
     NodeJSONWithAttributes {
-        tag: node.tag.clone().unwrap_or_default(),
-        attributes: node.attributes.clone(),
+        tag: node.tag.as_deref().unwrap_or_default().to_string(),
+        attributes: node
+            .attributes
+            .iter()
+            .map(|(name, attribute)| (name.to_string(), attribute.clone()))
+            .collect(),
         children,
     }
 }
@@ -46,7 +50,7 @@ fn to_json_with_attributes(node: &Node) -> NodeJSONWithAttributes {
 fn assert_document(input: &str, expected: Vec<NodeJSON>) {
     let document = parse(input);
     let mut nodes = vec![];
-    for root in &document.roots {
+    for root in document.roots.iter() {
         nodes.push(to_json(root))
     }
     assert_eq!(nodes, expected)
@@ -56,7 +60,7 @@ fn assert_node_before(input: &str, offset: usize, expected_tag: Option<&str>) {
     let document = parse(input);
     let node = document.find_node_before(offset, &mut vec![]);
     if let Some(node) = node {
-        assert_eq!(node.tag, Some(expected_tag.unwrap_or_default().to_string()));
+        assert_eq!(node.tag.as_deref(), Some(expected_tag.unwrap_or_default()));
     } else {
         assert_eq!(None, expected_tag);
     }
@@ -78,7 +82,7 @@ fn assert_find_token_type_in_node(input: &str, offset: usize, expected_token_typ
 fn assert_attributes(input: &str, expected: Vec<NodeJSONWithAttributes>) {
     let document = parse(input);
     let mut nodes = vec![];
-    for root in &document.roots {
+    for root in document.roots.iter() {
         nodes.push(to_json_with_attributes(root));
     }
     assert_eq!(nodes, expected);
@@ -487,18 +491,18 @@ fn attributes() {
             attributes: HashMap::from([
                 (
                     "class".to_string(),
-                    NodeAttribute::new(Some(r#""these are my-classes""#.to_string()), 5),
+                    NodeAttribute::new(Some(r#""these are my-classes""#.into()), 5),
                 ),
                 (
                     "id".to_string(),
-                    NodeAttribute::new(Some(r#""test""#.to_string()), 34),
+                    NodeAttribute::new(Some(r#""test""#.into()), 34),
                 ),
             ]),
             children: vec![NodeJSONWithAttributes {
                 tag: "span".to_string(),
                 attributes: HashMap::from([(
                     "aria-describedby".to_string(),
-                    NodeAttribute::new(Some(r#""test""#.to_string()), 50),
+                    NodeAttribute::new(Some(r#""test""#.into()), 50),
                 )]),
                 children: vec![],
             }],
@@ -517,7 +521,7 @@ fn attributes_without_value() {
                 ("checked".to_string(), NodeAttribute::new(None, 5)),
                 (
                     "id".to_string(),
-                    NodeAttribute::new(Some(r#""test""#.to_string()), 13),
+                    NodeAttribute::new(Some(r#""test""#.into()), 13),
                 ),
             ]),
             children: vec![],
@@ -525,6 +529,80 @@ fn attributes_without_value() {
     );
 }
 
+#[test]
+fn attribute_value_spans() {
+    let input = r#"<div id="a"><p title='b c'></p></div>"#;
+    let document = HTMLParser::parse(input, "html", &HTMLDataManager::default());
+    let id = document.roots[0].attributes["id"].value.as_ref().unwrap();
+    assert_eq!(id, "\"a\"");
+    assert_eq!(&input[id.span()], id.as_str());
+    let title = document.roots[0].children[0].attributes["title"]
+        .value
+        .as_ref()
+        .unwrap();
+    assert_eq!(title.span(), 21..26);
+    assert_eq!(title, &SourceStr::from("'b c'"));
+}
+
+#[test]
+fn template_syntax() {
+    let input = "<div title={{ a > b }}><p>{{ x <y }}</p></div>";
+    let data_manager = HTMLDataManager::new(true, None);
+    let document = HTMLParser::parse(input, "handlebars", &data_manager);
+    let nodes: Vec<NodeJSONWithAttributes> =
+        document.roots.iter().map(to_json_with_attributes).collect();
+    assert_eq!(
+        nodes,
+        vec![NodeJSONWithAttributes {
+            tag: "div".to_string(),
+            attributes: HashMap::from([(
+                "title".to_string(),
+                NodeAttribute::new(Some("{{ a > b }}".into()), 5),
+            )]),
+            children: vec![NodeJSONWithAttributes {
+                tag: "p".to_string(),
+                attributes: HashMap::new(),
+                children: vec![],
+            }],
+        }],
+    );
+
+    let document = HTMLParser::parse(input, "html", &data_manager);
+    assert_eq!(
+        document.roots[0].children[0].children[0].tag.as_deref(),
+        Some("y")
+    );
+}
+
+#[test]
+fn names() {
+    let input = "<DIV Class=a><Äb></äB><p></P></DIV>";
+    let document = parse(input);
+    let div = &document.roots[0];
+    assert_eq!(div.tag.as_deref(), Some("DIV"));
+    assert!(div.closed);
+    assert!(div.attributes.contains_key("Class"));
+    assert!(div.children.iter().all(|child| child.closed));
+    assert_eq!(div.children[0].end_tag_start, Some(18));
+}
+
+#[test]
+fn case_sensitive() {
+    let input = "<Foo><foo></Foo>";
+    let mut data_manager = HTMLDataManager::new(true, None);
+    let document = HTMLParser::parse(input, "vue", &data_manager);
+    assert_eq!(document.roots[0].children[0].end_tag_start, Some(10));
+    assert!(!document.roots[0].closed);
+
+    data_manager.set_case_sensitive_languages(vec!["vue".to_string()]);
+    let document = HTMLParser::parse(input, "vue", &data_manager);
+    let foo = &document.roots[0];
+    assert_eq!(foo.tag.as_deref(), Some("Foo"));
+    assert_eq!(foo.end_tag_start, Some(10));
+    assert_eq!(foo.children[0].tag.as_deref(), Some("foo"));
+    assert!(!foo.children[0].closed);
+}
+
 #[derive(PartialEq, Debug)]
 struct NodeJSON {
     tag: String,
@@ -541,3 +619,136 @@ struct NodeJSONWithAttributes {
     attributes: HashMap<String, NodeAttribute>,
     children: Vec<NodeJSONWithAttributes>,
 }
+
+#[test]
+fn limits() {
+    let mut data_manager = HTMLDataManager::new(true, None);
+    let input = "<div><p><b></b></p><p a b c></p></div><span></span>";
+    let document = HTMLParser::parse(input, "html", &data_manager);
+    assert!(!document.truncated);
+
+    data_manager.set_parser_limits(ParserLimits {
+        max_depth: Some(2),
+        ..Default::default()
+    });
+    let document = HTMLParser::parse(input, "html", &data_manager);
+    assert!(document.truncated);
+    assert_eq!(document.roots.len(), 2);
+    let div = &document.roots[0];
+    assert_eq!(div.children.len(), 2);
+    assert!(div.children[0].children.is_empty());
+    assert!(div.children[0].closed);
+    assert_eq!(div.children[1].attribute_names_by_order().len(), 3);
+    assert!(div.closed);
+    assert_eq!(document.roots[1].tag.as_deref(), Some("span"));
+
+    data_manager.set_parser_limits(ParserLimits {
+        max_nodes: Some(4),
+        ..Default::default()
+    });
+    let document = HTMLParser::parse(input, "html", &data_manager);
+    assert!(document.truncated);
+    assert_eq!(document.roots.len(), 1);
+    assert_eq!(document.roots[0].children.len(), 2);
+    assert!(document.roots[0].closed);
+
+    data_manager.set_parser_limits(ParserLimits {
+        max_attributes: Some(2),
+        ..Default::default()
+    });
+    let document = HTMLParser::parse(input, "html", &data_manager);
+    assert!(document.truncated);
+    assert_eq!(document.roots.len(), 2);
+    let mut names = document.roots[0].children[1].attribute_names_by_order();
+    names.sort();
+    assert_eq!(names, vec!["a", "b"]);
+
+    let deep = "<div>".repeat(100_000);
+    data_manager.set_parser_limits(ParserLimits {
+        max_depth: Some(100),
+        ..Default::default()
+    });
+    let document = HTMLParser::parse(&deep, "html", &data_manager);
+    assert!(document.truncated);
+    assert_eq!(
+        document
+            .find_node_at(deep.len() - 1, &mut vec![])
+            .unwrap()
+            .start,
+        99 * 5
+    );
+
+    data_manager.set_parser_limits(ParserLimits::default());
+    let document = HTMLParser::parse(&deep, "html", &data_manager);
+    assert!(document.truncated);
+    assert_eq!(
+        document
+            .find_node_at(deep.len() - 1, &mut vec![])
+            .unwrap()
+            .start,
+        (ParserLimits::DEFAULT_MAX_DEPTH - 1) * 5
+    );
+
+    data_manager.set_parser_limits(ParserLimits::unlimited());
+    let document = HTMLParser::parse(input, "html", &data_manager);
+    assert!(!document.truncated);
+}
+
+#[test]
+fn limits_after_deep_subtree() {
+    let mut data_manager = HTMLDataManager::new(true, None);
+    data_manager.set_parser_limits(ParserLimits {
+        max_depth: Some(2),
+        ..Default::default()
+    });
+    // The dropped elements have the same tags as the parsed ones, and some aren't closed
+    let input =
+        "<div><p><div><p><b><br><img/></b></p><div></div></p><p>a</p></div><span><p></p></span>";
+    let document = HTMLParser::parse(input, "html", &data_manager);
+    assert!(document.truncated);
+    assert_eq!(
+        document.roots.iter().map(to_json).collect::<Vec<_>>(),
+        vec![
+            NodeJSON {
+                tag: "div".to_string(),
+                start: 0,
+                end: 66,
+                end_tag_start: Some(60),
+                closed: true,
+                children: vec![
+                    NodeJSON {
+                        tag: "p".to_string(),
+                        start: 5,
+                        end: 52,
+                        end_tag_start: Some(48),
+                        closed: true,
+                        children: vec![],
+                    },
+                    NodeJSON {
+                        tag: "p".to_string(),
+                        start: 52,
+                        end: 60,
+                        end_tag_start: Some(56),
+                        closed: true,
+                        children: vec![],
+                    },
+                ],
+            },
+            NodeJSON {
+                tag: "span".to_string(),
+                start: 66,
+                end: 86,
+                end_tag_start: Some(79),
+                closed: true,
+                children: vec![NodeJSON {
+                    tag: "p".to_string(),
+                    start: 72,
+                    end: 79,
+                    end_tag_start: Some(75),
+                    closed: true,
+                    children: vec![],
+                }],
+            },
+        ]
+    );
+}
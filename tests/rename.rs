@@ -3,7 +3,7 @@ use html_languageservice::{HTMLDataManager, HTMLLanguageService};
 #[cfg(feature = "rename")]
 use lsp_textdocument::FullTextDocument;
 #[cfg(feature = "rename")]
-use lsp_types::{TextEdit, Url};
+use lsp_types::{Position, PositionEncodingKind, Range, TextEdit, Url};
 
 #[cfg(feature = "rename")]
 fn test_rename(value: &str, new_name: &str, expected: &str) {
@@ -17,8 +17,14 @@ fn test_rename(value: &str, new_name: &str, expected: &str) {
     let html_document =
         HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
 
-    let workspace_edit =
-        HTMLLanguageService::do_rename(uri.clone(), &document, position, new_name, &html_document);
+    let workspace_edit = HTMLLanguageService::do_rename(
+        uri.clone(),
+        &document,
+        position,
+        new_name,
+        &html_document,
+        None,
+    );
 
     if workspace_edit.is_none()
         || workspace_edit
@@ -51,8 +57,14 @@ fn test_no_rename(value: &str, new_name: &str) {
     let html_document =
         HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
 
-    let workspace_edit =
-        HTMLLanguageService::do_rename(uri.clone(), &document, position, new_name, &html_document);
+    let workspace_edit = HTMLLanguageService::do_rename(
+        uri.clone(),
+        &document,
+        position,
+        new_name,
+        &html_document,
+        None,
+    );
 
     assert!(
         workspace_edit.is_none() || workspace_edit.is_some_and(|v| v.changes.is_none()),
@@ -121,3 +133,39 @@ fn rename_unmatched_tag() {
     test_rename("<div><|h1></div>", "h2", "<div><h2></div>");
     test_rename("<|div><h1></h1></div>", "span", "<span><h1></h1></span>");
 }
+
+#[cfg(feature = "rename")]
+#[test]
+fn position_encoding() {
+    // `中` and `😀` are 1 and 2 UTF-16 code units, 3 and 4 UTF-8 bytes
+    let document = FullTextDocument::new("html".to_string(), 0, "中😀<div></div>".to_string());
+    let uri = Url::parse("test://test/test.html").unwrap();
+    let html_document =
+        HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
+    for (encoding, p) in [
+        (PositionEncodingKind::UTF8, 7),
+        (PositionEncodingKind::UTF16, 3),
+        (PositionEncodingKind::UTF32, 2),
+    ] {
+        let workspace_edit = HTMLLanguageService::do_rename(
+            uri.clone(),
+            &document,
+            Position::new(0, p + 2),
+            "span",
+            &html_document,
+            Some(&encoding),
+        )
+        .unwrap();
+        let ranges: Vec<Range> = workspace_edit.changes.unwrap()[&uri]
+            .iter()
+            .map(|edit| edit.range)
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                Range::new(Position::new(0, p + 1), Position::new(0, p + 4)),
+                Range::new(Position::new(0, p + 7), Position::new(0, p + 10)),
+            ]
+        );
+    }
+}
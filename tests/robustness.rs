@@ -0,0 +1,300 @@
+#![cfg(feature = "full")]
+
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use html_languageservice::{
+    DefaultDocumentContext, FoldingRangeContext, HTMLDataManager, HTMLFormatConfiguration,
+    HTMLLanguageService, HTMLLanguageServiceOptions, HtmlAttributeQuotes, HtmlEndOfLine,
+    HtmlVoidElementStyle, HtmlWrapAttributes,
+};
+use lsp_textdocument::FullTextDocument;
+use lsp_types::{Position, Range, Url};
+
+/// The futures of the service are ready without the participants
+fn now<F: Future>(future: F) -> F::Output {
+    match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("the future is pending"),
+    }
+}
+
+/// Run every service at every position of the text, they must not panic
+fn exercise(text: &str, language_id: &str) {
+    let line_count =
+        FullTextDocument::new(language_id.to_string(), 0, text.to_string()).line_count();
+    let mut positions = vec![];
+    for line in 0..=line_count {
+        for character in 0..=text.len() as u32 + 1 {
+            positions.push(Position::new(line, character));
+        }
+    }
+    exercise_at(text, language_id, &positions);
+}
+
+/// Run every service, and the services at the positions
+fn exercise_at(text: &str, language_id: &str, positions: &[Position]) {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    let uri = Url::parse("file:///test/index.html").unwrap();
+    let document = FullTextDocument::new(language_id.to_string(), 0, text.to_string());
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+
+    let regions = ls.get_document_regions(&document);
+    for language_id in ["css", "javascript"] {
+        regions.get_embedded_document(&document, language_id);
+    }
+    HTMLLanguageService::find_document_links(
+        &uri,
+        &document,
+        &html_document,
+        &DefaultDocumentContext,
+        &data_manager,
+        ls.position_encoding(),
+    );
+    HTMLLanguageService::find_document_symbols(
+        &uri,
+        &document,
+        &html_document,
+        &data_manager,
+        None,
+        ls.position_encoding(),
+    );
+    HTMLLanguageService::find_document_symbols2(
+        &document,
+        &html_document,
+        &data_manager,
+        None,
+        ls.position_encoding(),
+    );
+    HTMLLanguageService::get_folding_ranges(
+        &document,
+        FoldingRangeContext::default(),
+        &data_manager,
+        ls.position_encoding(),
+    );
+    HTMLLanguageService::get_all_tag_pairs(&document, &html_document, ls.position_encoding());
+    for wrap_attributes in [
+        HtmlWrapAttributes::Auto,
+        HtmlWrapAttributes::Force,
+        HtmlWrapAttributes::ForceAligned,
+        HtmlWrapAttributes::ForceExpandMultiline,
+        HtmlWrapAttributes::AlignedMultiple,
+        HtmlWrapAttributes::Preserve,
+        HtmlWrapAttributes::PreserveAligned,
+    ] {
+        let options = HTMLFormatConfiguration {
+            wrap_line_length: Some(8),
+            wrap_attributes,
+            attribute_quotes: HtmlAttributeQuotes::Single,
+            void_element_style: HtmlVoidElementStyle::Preserve,
+            indent_handlebars: true,
+            max_preserve_new_lines: Some(1),
+            end_of_line: HtmlEndOfLine::CrLf,
+            ..Default::default()
+        };
+        ls.format(&document, None, Some(&options), &data_manager);
+    }
+    ls.format(
+        &document,
+        None,
+        Some(&HTMLFormatConfiguration::default()),
+        &data_manager,
+    );
+
+    for (i, position) in positions.iter().enumerate() {
+        let position = *position;
+        now(ls.do_complete(
+            &document,
+            &position,
+            &html_document,
+            DefaultDocumentContext,
+            None,
+            &data_manager,
+        ));
+        now(ls.do_hover(&document, &position, &html_document, None, &data_manager));
+        ls.do_quote_complete(&document, &position, &html_document, None);
+        ls.do_tag_complete(&document, &position, &html_document, None, &data_manager);
+        HTMLLanguageService::find_document_highlights(
+            &document,
+            &position,
+            &html_document,
+            ls.position_encoding(),
+        );
+        HTMLLanguageService::do_rename(
+            uri.clone(),
+            &document,
+            position,
+            "x",
+            &html_document,
+            ls.position_encoding(),
+        );
+        HTMLLanguageService::find_matching_tag_position(
+            &document,
+            position,
+            &html_document,
+            ls.position_encoding(),
+        );
+        HTMLLanguageService::find_matching_tag_ranges(
+            &document,
+            position,
+            &html_document,
+            ls.position_encoding(),
+        );
+        HTMLLanguageService::find_linked_editing_ranges(
+            &document,
+            position,
+            &html_document,
+            None,
+            ls.position_encoding(),
+        );
+        regions.language_at_position(&document, position);
+        if let Some(next) = positions.get(i + 1) {
+            ls.format(
+                &document,
+                Some(Range::new(position, *next)),
+                Some(&HTMLFormatConfiguration::default()),
+                &data_manager,
+            );
+        }
+    }
+    HTMLLanguageService::get_selection_ranges(
+        &document,
+        &positions.to_vec(),
+        &html_document,
+        ls.position_encoding(),
+    );
+}
+
+const PIECES: [&str; 67] = [
+    "<",
+    ">",
+    "</",
+    "/>",
+    "<!--",
+    "-->",
+    "<!DOCTYPE ",
+    "<?",
+    "?>",
+    "<![CDATA[",
+    "div",
+    "p",
+    "a",
+    "input",
+    "script",
+    "style",
+    "title",
+    "é",
+    "日本",
+    "🎉",
+    "\u{0301}",
+    "&",
+    "&amp",
+    "&#",
+    "x",
+    ";",
+    "=",
+    "\"",
+    "'",
+    "`",
+    " ",
+    "\n",
+    "\r\n",
+    "\t",
+    "class",
+    "id",
+    "href",
+    "src",
+    "style=",
+    "on",
+    "{{",
+    "}}",
+    "<%",
+    "%>",
+    "#",
+    "/",
+    "-",
+    "\u{feff}",
+    "<a href=\"#",
+    "<input type=",
+    "<img srcset=\"",
+    "<div style=\"",
+    "<svg>",
+    "<math>",
+    "<template>",
+    "&#x",
+    "&#128512;",
+    "\u{a0}",
+    "<br/>",
+    "{%",
+    "%}",
+    "<textarea>",
+    "<select>",
+    "<option>",
+    "<label for=",
+    "aria-",
+    "data-",
+];
+
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 as usize
+    }
+}
+
+#[test]
+fn regressions() {
+    for text in [
+        "<",
+        "a < b",
+        "<div><</div>",
+        "x日本style=</-->style&amp</script\u{feff}/",
+        "<a href=\">",
+        "<a href='>",
+        "<div class= \"x\">",
+        "<p>🎉é\u{0301}</p>",
+    ] {
+        for language_id in ["html", "handlebars"] {
+            exercise(text, language_id);
+        }
+    }
+}
+
+#[test]
+fn arbitrary_input() {
+    let mut random = Random(0x2545f4914f6cdd1d);
+    for _ in 0..50 {
+        let len = random.next() % 12;
+        let text: String = (0..len)
+            .map(|_| PIECES[random.next() % PIECES.len()])
+            .collect();
+        let language_id = ["html", "handlebars", "vue"][random.next() % 3];
+        exercise(&text, language_id);
+    }
+}
+
+#[test]
+fn deep_nesting() {
+    let depth = 5_000;
+    for text in [
+        "<div>\n".repeat(depth),
+        format!("{}{}", "<div>\n".repeat(depth), "</div>\n".repeat(depth)),
+        "<a href=\"#x\" id=x class=y><span>\n".repeat(depth),
+        "<ul><li>\n".repeat(depth),
+    ] {
+        let lines = text.lines().count() as u32;
+        let positions: Vec<Position> = [0, 1, lines / 2, lines - 1, lines]
+            .into_iter()
+            .flat_map(|line| [Position::new(line, 0), Position::new(line, 3)])
+            .collect();
+        exercise_at(&text, "html", &positions);
+    }
+}
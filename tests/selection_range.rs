@@ -2,6 +2,8 @@
 use html_languageservice::{HTMLDataManager, HTMLLanguageService};
 #[cfg(feature = "selection_range")]
 use lsp_textdocument::FullTextDocument;
+#[cfg(feature = "selection_range")]
+use lsp_types::{Position, PositionEncodingKind, Range};
 
 #[cfg(feature = "selection_range")]
 fn assert_ranges(content: &str, expected: Vec<(u32, &str)>) {
@@ -15,7 +17,7 @@ fn assert_ranges(content: &str, expected: Vec<(u32, &str)>) {
         HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
 
     let actual_ranges =
-        HTMLLanguageService::get_selection_ranges(&document, &vec![position], &html_document);
+        HTMLLanguageService::get_selection_ranges(&document, &vec![position], &html_document, None);
 
     assert_eq!(actual_ranges.len(), 1);
 
@@ -243,12 +245,119 @@ fn unmatching_tags() {
 
 #[cfg(feature = "selection_range")]
 #[test]
-fn unhandled() {
-    // We do not handle comments. This semantic selection is handled by VS Code's default provider, which returns
-    // - foo
-    // - <!-- foo -->
-    assert_ranges("<!-- f|oo -->", vec![(6, "")]);
-
-    // Same for DOCTYPE
-    assert_ranges("<!DOCTYPE h|tml>", vec![(11, "")]);
+fn comments() {
+    assert_ranges("<!-- f|oo -->", vec![(5, "foo"), (0, "<!-- foo -->")]);
+    assert_ranges("<!--| foo -->", vec![(0, "<!-- foo -->")]);
+    assert_ranges(
+        "<div><!-- f|oo --></div>",
+        vec![
+            (10, "foo"),
+            (5, "<!-- foo -->"),
+            (0, "<div><!-- foo --></div>"),
+        ],
+    );
+}
+
+#[cfg(feature = "selection_range")]
+#[test]
+fn doctype() {
+    assert_ranges(
+        "<!DOCTYPE h|tml>",
+        vec![(10, "html"), (0, "<!DOCTYPE html>")],
+    );
+    assert_ranges(
+        "<!DOCTYPE h|tml>\n<html></html>",
+        vec![(10, "html"), (0, "<!DOCTYPE html>")],
+    );
+}
+
+#[cfg(feature = "selection_range")]
+#[test]
+fn words() {
+    assert_ranges(
+        "<div>foo b|ar</div>",
+        vec![(9, "bar"), (5, "foo bar"), (0, "<div>foo bar</div>")],
+    );
+    assert_ranges(
+        "<div>foo |bar</div>",
+        vec![(9, "bar"), (5, "foo bar"), (0, "<div>foo bar</div>")],
+    );
+    assert_ranges(
+        "<div>foo, |  bar</div>",
+        vec![(5, "foo,   bar"), (0, "<div>foo,   bar</div>")],
+    );
+    assert_ranges(
+        "<div><p>a</p> foo b|ar</div>",
+        vec![
+            (18, "bar"),
+            (5, "<p>a</p> foo bar"),
+            (0, "<div><p>a</p> foo bar</div>"),
+        ],
+    );
+}
+
+#[cfg(feature = "selection_range")]
+#[test]
+fn multiple_positions() {
+    let content = r#"<div class="foo">foo bar<!-- baz --></div><p>qux</p>"#;
+    let document = FullTextDocument::new("html".to_string(), 0, content.to_string());
+    let html_document =
+        HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
+    let positions: Vec<_> = [8, 18, 22, 30, 46, 22]
+        .into_iter()
+        .map(|offset| document.position_at(offset))
+        .collect();
+
+    let actual_ranges =
+        HTMLLanguageService::get_selection_ranges(&document, &positions, &html_document, None);
+
+    assert_eq!(actual_ranges.len(), positions.len());
+    // `foo` of the content
+    assert_eq!(
+        actual_ranges[1].range,
+        lsp_types::Range::new(document.position_at(17), document.position_at(20))
+    );
+    for (position, actual) in positions.iter().zip(actual_ranges) {
+        let expected = HTMLLanguageService::get_selection_ranges(
+            &document,
+            &vec![*position],
+            &html_document,
+            None,
+        );
+        assert_eq!(vec![actual], expected);
+    }
+}
+
+#[cfg(feature = "selection_range")]
+#[test]
+fn position_encoding() {
+    // `中` and `😀` are 1 and 2 UTF-16 code units, 3 and 4 UTF-8 bytes
+    let document = FullTextDocument::new("html".to_string(), 0, "中😀<div></div>".to_string());
+    let html_document =
+        HTMLLanguageService::parse_html_document(&document, &HTMLDataManager::default());
+    for (encoding, p) in [
+        (PositionEncodingKind::UTF8, 7),
+        (PositionEncodingKind::UTF16, 3),
+        (PositionEncodingKind::UTF32, 2),
+    ] {
+        let mut ranges = vec![];
+        let mut selection_range = HTMLLanguageService::get_selection_ranges(
+            &document,
+            &vec![Position::new(0, p + 2)],
+            &html_document,
+            Some(&encoding),
+        )
+        .pop();
+        while let Some(range) = selection_range {
+            ranges.push(range.range);
+            selection_range = range.parent.map(|parent| *parent);
+        }
+        assert_eq!(
+            ranges,
+            vec![
+                Range::new(Position::new(0, p + 1), Position::new(0, p + 4)),
+                Range::new(Position::new(0, p), Position::new(0, p + 11)),
+            ]
+        );
+    }
 }
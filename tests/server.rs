@@ -0,0 +1,179 @@
+#[cfg(feature = "server")]
+use html_languageservice::server::{AutoInsertKind, AutoInsertParams, HTMLLanguageServer};
+#[cfg(feature = "server")]
+use lsp_types::*;
+#[cfg(feature = "server")]
+use serde_json::json;
+#[cfg(feature = "server")]
+use tower_lsp::LanguageServer;
+
+#[cfg(feature = "server")]
+async fn initialize(server: &HTMLLanguageServer, initialization_options: serde_json::Value) {
+    let result = server
+        .initialize(InitializeParams {
+            initialization_options: Some(initialization_options),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        result.capabilities.text_document_sync,
+        Some(TextDocumentSyncCapability::Kind(
+            TextDocumentSyncKind::INCREMENTAL
+        ))
+    );
+    assert!(result.capabilities.completion_provider.is_some());
+    assert!(result.capabilities.document_formatting_provider.is_some());
+    server.initialized(InitializedParams {}).await;
+}
+
+#[cfg(feature = "server")]
+fn uri() -> Url {
+    Url::parse("file:///test.html").unwrap()
+}
+
+#[cfg(feature = "server")]
+fn text_document_position(line: u32, character: u32) -> TextDocumentPositionParams {
+    TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier { uri: uri() },
+        position: Position::new(line, character),
+    }
+}
+
+#[cfg(feature = "server")]
+#[tokio::test]
+async fn requests() {
+    let (service, _) = HTMLLanguageServer::service();
+    let server = service.inner();
+    initialize(server, json!({})).await;
+
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri(),
+                language_id: "html".to_string(),
+                version: 1,
+                text: "<div><p></p></div>".to_string(),
+            },
+        })
+        .await;
+    let hover = server
+        .hover(HoverParams {
+            text_document_position_params: text_document_position(0, 2),
+            work_done_progress_params: Default::default(),
+        })
+        .await
+        .unwrap();
+    assert!(hover.is_some());
+
+    let edits = server
+        .formatting(DocumentFormattingParams {
+            text_document: TextDocumentIdentifier { uri: uri() },
+            options: FormattingOptions {
+                tab_size: 2,
+                insert_spaces: true,
+                ..Default::default()
+            },
+            work_done_progress_params: Default::default(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(edits.len(), 1);
+
+    let symbols = server
+        .document_symbol(DocumentSymbolParams {
+            text_document: TextDocumentIdentifier { uri: uri() },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })
+        .await
+        .unwrap();
+    assert!(matches!(symbols, Some(DocumentSymbolResponse::Flat(_))));
+
+    server
+        .did_change(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: uri(),
+                version: 2,
+            },
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: Some(Range::new(Position::new(0, 12), Position::new(0, 12))),
+                range_length: None,
+                text: "<".to_string(),
+            }],
+        })
+        .await;
+
+    let completion = server
+        .completion(CompletionParams {
+            text_document_position: text_document_position(0, 13),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+            context: None,
+        })
+        .await
+        .unwrap();
+    let Some(CompletionResponse::List(list)) = completion else {
+        panic!("completion should be a list");
+    };
+    assert!(list.items.iter().any(|item| item.label == "span"));
+
+    server
+        .did_close(DidCloseTextDocumentParams {
+            text_document: TextDocumentIdentifier { uri: uri() },
+        })
+        .await;
+    let hover = server
+        .hover(HoverParams {
+            text_document_position_params: text_document_position(0, 2),
+            work_done_progress_params: Default::default(),
+        })
+        .await
+        .unwrap();
+    assert!(hover.is_none());
+}
+
+#[cfg(feature = "server")]
+#[tokio::test]
+async fn settings() {
+    let (service, _) = HTMLLanguageServer::service();
+    let server = service.inner();
+    initialize(
+        server,
+        json!({ "html": { "completion": { "attributeDefaultValue": "singlequotes" } } }),
+    )
+    .await;
+    server
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri(),
+                language_id: "html".to_string(),
+                version: 1,
+                text: "<div id=></div>".to_string(),
+            },
+        })
+        .await;
+
+    let auto_insert = |kind| AutoInsertParams {
+        kind,
+        text_document: TextDocumentIdentifier { uri: uri() },
+        position: Position::new(0, 8),
+    };
+    let quote = server
+        .auto_insert(auto_insert(AutoInsertKind::AutoQuote))
+        .await
+        .unwrap();
+    assert_eq!(quote.as_deref(), Some("'$1'"));
+
+    server
+        .did_change_configuration(DidChangeConfigurationParams {
+            settings: json!({ "html": { "autoCreateQuotes": false } }),
+        })
+        .await;
+    let quote = server
+        .auto_insert(auto_insert(AutoInsertKind::AutoQuote))
+        .await
+        .unwrap();
+    assert_eq!(quote, None);
+}
@@ -0,0 +1,104 @@
+#[cfg(feature = "completion")]
+use html_languageservice::CompletionConfiguration;
+use html_languageservice::{HTMLLanguageService, HTMLLanguageServiceOptions};
+#[cfg(all(feature = "completion", feature = "hover"))]
+use lsp_types::HoverProviderCapability;
+use lsp_types::PositionEncodingKind;
+
+#[test]
+fn position_encoding() {
+    for encoding in [
+        PositionEncodingKind::UTF8,
+        PositionEncodingKind::UTF16,
+        PositionEncodingKind::UTF32,
+    ] {
+        let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions {
+            position_encoding: Some(encoding.clone()),
+            ..Default::default()
+        });
+        assert_eq!(ls.server_capabilities().position_encoding, Some(encoding));
+    }
+
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    assert_eq!(ls.server_capabilities().position_encoding, None);
+}
+
+#[cfg(all(feature = "completion", feature = "hover"))]
+#[test]
+fn features() {
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions {
+        position_encoding: Some(PositionEncodingKind::UTF8),
+        ..Default::default()
+    });
+    let capabilities = ls.server_capabilities();
+
+    assert_eq!(
+        capabilities.position_encoding,
+        Some(PositionEncodingKind::UTF8)
+    );
+    let completion = capabilities.completion_provider.unwrap();
+    assert_eq!(completion.resolve_provider, Some(false));
+    let trigger_characters = completion.trigger_characters.unwrap();
+    for c in ["<", "\"", "=", "/"] {
+        assert!(trigger_characters.contains(&c.to_string()), "{}", c);
+    }
+    assert_eq!(
+        capabilities.hover_provider,
+        Some(HoverProviderCapability::Simple(true))
+    );
+
+    assert_eq!(
+        capabilities.document_formatting_provider.is_some(),
+        cfg!(feature = "formatter")
+    );
+    assert_eq!(
+        capabilities.document_range_formatting_provider.is_some(),
+        cfg!(feature = "formatter")
+    );
+    assert_eq!(
+        capabilities.document_highlight_provider.is_some(),
+        cfg!(feature = "highlight")
+    );
+    assert_eq!(
+        capabilities.document_link_provider.is_some(),
+        cfg!(feature = "links")
+    );
+    assert_eq!(
+        capabilities.document_symbol_provider.is_some(),
+        cfg!(feature = "symbols")
+    );
+    assert_eq!(
+        capabilities.folding_range_provider.is_some(),
+        cfg!(feature = "folding")
+    );
+    assert_eq!(
+        capabilities.selection_range_provider.is_some(),
+        cfg!(feature = "selection_range")
+    );
+    assert_eq!(
+        capabilities.rename_provider.is_some(),
+        cfg!(feature = "rename")
+    );
+    assert_eq!(
+        capabilities.linked_editing_range_provider.is_some(),
+        cfg!(feature = "linked_editing")
+    );
+    assert!(capabilities.text_document_sync.is_none());
+}
+
+#[cfg(feature = "completion")]
+#[test]
+fn lazy_documentation() {
+    let ls = HTMLLanguageService::builder()
+        .completion_settings(CompletionConfiguration {
+            lazy_documentation: true,
+            ..Default::default()
+        })
+        .build();
+    let capabilities = ls.server_capabilities();
+    assert_eq!(
+        capabilities.completion_provider.unwrap().resolve_provider,
+        Some(true)
+    );
+    assert_eq!(capabilities.position_encoding, None);
+}
@@ -2,11 +2,16 @@
 use std::vec;
 
 #[cfg(feature = "symbols")]
-use html_languageservice::{HTMLDataManager, HTMLLanguageService};
+use html_languageservice::{
+    HTMLDataManager, HTMLLanguageService, SymbolsConfiguration, WorkspaceDocuments,
+};
 #[cfg(feature = "symbols")]
 use lsp_textdocument::FullTextDocument;
 #[cfg(feature = "symbols")]
-use lsp_types::{DocumentSymbol, Location, Position, Range, SymbolInformation, SymbolKind, Url};
+use lsp_types::{
+    DocumentSymbol, Location, Position, PositionEncodingKind, Range, SymbolInformation, SymbolKind,
+    SymbolTag, Url,
+};
 
 #[cfg(feature = "symbols")]
 const TEST_URL: &'static str = "test://test/test.html";
@@ -15,19 +20,32 @@ const TEST_URL: &'static str = "test://test/test.html";
 fn test_symbol_informations_for(value: &str, expected: Vec<SymbolInformation>) {
     let document = FullTextDocument::new("html".to_string(), 0, value.to_string());
     let uri = Url::parse(&TEST_URL).unwrap();
-    let html_document =
-        HTMLLanguageService::parse_html_document(&document, &mut HTMLDataManager::default());
-    let symbols = HTMLLanguageService::find_document_symbols(&uri, &document, &html_document);
-    assert_eq!(symbols, expected);
+    let data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let symbols = HTMLLanguageService::find_document_symbols(
+        &uri,
+        &document,
+        &html_document,
+        &data_manager,
+        None,
+        None,
+    );
+    assert_eq!(symbols, (expected, false));
 }
 
 #[cfg(feature = "symbols")]
 fn test_document_symbols_for(value: &str, expected: Vec<DocumentSymbol>) {
     let document = FullTextDocument::new("html".to_string(), 0, value.to_string());
-    let html_document =
-        HTMLLanguageService::parse_html_document(&document, &mut HTMLDataManager::default());
-    let symbols = HTMLLanguageService::find_document_symbols2(&document, &html_document);
-    assert_eq!(symbols, expected);
+    let data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let symbols = HTMLLanguageService::find_document_symbols2(
+        &document,
+        &html_document,
+        &data_manager,
+        None,
+        None,
+    );
+    assert_eq!(symbols, (expected, false));
 }
 
 #[cfg(feature = "symbols")]
@@ -69,7 +87,7 @@ fn simple() {
             #[allow(deprecated)]
             SymbolInformation {
                 name: "input#test.checkbox".to_string(),
-                kind: SymbolKind::FIELD,
+                kind: SymbolKind::PROPERTY,
                 tags: None,
                 deprecated: None,
                 location: Location::new(
@@ -115,7 +133,7 @@ fn simple() {
                     DocumentSymbol {
                         name: "input#test.checkbox".to_string(),
                         detail: None,
-                        kind: SymbolKind::FIELD,
+                        kind: SymbolKind::PROPERTY,
                         tags: None,
                         deprecated: None,
                         range: Range::new(Position::new(0, 5), Position::new(0, 47)),
@@ -403,3 +421,390 @@ fn no_attributes() {
         ],
     );
 }
+
+#[cfg(feature = "symbols")]
+#[test]
+fn deprecated_tags() {
+    let uri = Url::parse(&TEST_URL).unwrap();
+    let content = "<CENTER><p></p></CENTER>";
+
+    test_symbol_informations_for(
+        content,
+        vec![
+            #[allow(deprecated)]
+            SymbolInformation {
+                name: "CENTER".to_string(),
+                kind: SymbolKind::FIELD,
+                tags: Some(vec![SymbolTag::DEPRECATED]),
+                deprecated: None,
+                location: Location::new(
+                    uri.clone(),
+                    Range::new(Position::new(0, 0), Position::new(0, 24)),
+                ),
+                container_name: None,
+            },
+            #[allow(deprecated)]
+            SymbolInformation {
+                name: "p".to_string(),
+                kind: SymbolKind::FIELD,
+                tags: None,
+                deprecated: None,
+                location: Location::new(
+                    uri.clone(),
+                    Range::new(Position::new(0, 8), Position::new(0, 15)),
+                ),
+                container_name: Some("CENTER".to_string()),
+            },
+        ],
+    );
+
+    test_document_symbols_for(
+        "<font></font>",
+        vec![
+            #[allow(deprecated)]
+            DocumentSymbol {
+                name: "font".to_string(),
+                detail: None,
+                kind: SymbolKind::FIELD,
+                tags: Some(vec![SymbolTag::DEPRECATED]),
+                deprecated: None,
+                range: Range::new(Position::new(0, 0), Position::new(0, 13)),
+                selection_range: Range::new(Position::new(0, 0), Position::new(0, 13)),
+                children: Some(vec![]),
+            },
+        ],
+    );
+}
+
+#[cfg(feature = "symbols")]
+#[test]
+fn symbol_kinds() {
+    let content =
+        "<header><h1></h1></header><form><input><button></button></form><script></script>";
+    let document = FullTextDocument::new("html".to_string(), 0, content.to_string());
+    let data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let get_kinds = |settings: Option<&SymbolsConfiguration>| {
+        let (symbols, _) = HTMLLanguageService::find_document_symbols(
+            &Url::parse(TEST_URL).unwrap(),
+            &document,
+            &html_document,
+            &data_manager,
+            settings,
+            None,
+        );
+        symbols
+            .into_iter()
+            .map(|symbol| (symbol.name, symbol.kind))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        get_kinds(None),
+        vec![
+            ("header".to_string(), SymbolKind::NAMESPACE),
+            ("h1".to_string(), SymbolKind::STRING),
+            ("form".to_string(), SymbolKind::OBJECT),
+            ("input".to_string(), SymbolKind::PROPERTY),
+            ("button".to_string(), SymbolKind::PROPERTY),
+            ("script".to_string(), SymbolKind::FUNCTION),
+        ]
+    );
+
+    let settings = SymbolsConfiguration {
+        symbol_kinds: [
+            ("h1".to_string(), SymbolKind::KEY),
+            ("button".to_string(), SymbolKind::EVENT),
+        ]
+        .into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        get_kinds(Some(&settings)),
+        vec![
+            ("header".to_string(), SymbolKind::NAMESPACE),
+            ("h1".to_string(), SymbolKind::KEY),
+            ("form".to_string(), SymbolKind::OBJECT),
+            ("input".to_string(), SymbolKind::PROPERTY),
+            ("button".to_string(), SymbolKind::EVENT),
+            ("script".to_string(), SymbolKind::FUNCTION),
+        ]
+    );
+}
+
+#[cfg(feature = "symbols")]
+#[test]
+fn max_symbols() {
+    let content = "<div><p></p><p></p></div><span></span>";
+    let document = FullTextDocument::new("html".to_string(), 0, content.to_string());
+    let data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let get_names = |max_symbols: Option<usize>| {
+        let settings = SymbolsConfiguration {
+            max_symbols,
+            ..Default::default()
+        };
+        let (symbols, exceeded) = HTMLLanguageService::find_document_symbols(
+            &Url::parse(TEST_URL).unwrap(),
+            &document,
+            &html_document,
+            &data_manager,
+            Some(&settings),
+            None,
+        );
+        let (_, exceeded2) = HTMLLanguageService::find_document_symbols2(
+            &document,
+            &html_document,
+            &data_manager,
+            Some(&settings),
+            None,
+        );
+        assert_eq!(exceeded, exceeded2);
+        (
+            symbols
+                .into_iter()
+                .map(|symbol| symbol.name)
+                .collect::<Vec<_>>(),
+            exceeded,
+        )
+    };
+
+    assert_eq!(
+        get_names(None),
+        (
+            vec!["div", "p", "p", "span"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            false
+        )
+    );
+    assert_eq!(
+        get_names(Some(4)),
+        (
+            vec!["div", "p", "p", "span"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            false
+        )
+    );
+    assert_eq!(
+        get_names(Some(2)),
+        (
+            vec!["div", "p"].into_iter().map(String::from).collect(),
+            true
+        )
+    );
+    assert_eq!(get_names(Some(0)), (vec![], true));
+}
+
+#[cfg(feature = "symbols")]
+#[test]
+fn attributes_in_detail() {
+    let content = r#"<button id="save" type="submit" name='save' title="Save it" disabled></button><p class="a"></p>"#;
+    let document = FullTextDocument::new("html".to_string(), 0, content.to_string());
+    let data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let get_details = |attributes_in_detail: bool| {
+        let settings = SymbolsConfiguration {
+            attributes_in_detail,
+            ..Default::default()
+        };
+        let (symbols, _) = HTMLLanguageService::find_document_symbols2(
+            &document,
+            &html_document,
+            &data_manager,
+            Some(&settings),
+            None,
+        );
+        symbols
+            .into_iter()
+            .map(|symbol| (symbol.name, symbol.detail))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        get_details(true),
+        vec![
+            (
+                "button#save".to_string(),
+                Some(r#"type=submit name=save title="Save it" disabled"#.to_string())
+            ),
+            ("p.a".to_string(), None),
+        ]
+    );
+    assert_eq!(
+        get_details(false),
+        vec![("button#save".to_string(), None), ("p.a".to_string(), None),]
+    );
+}
+
+#[cfg(feature = "symbols")]
+#[test]
+fn workspace_symbols() {
+    let data_manager = HTMLDataManager::default();
+    let mut workspace = WorkspaceDocuments::new();
+    let index = Url::parse("file:///site/index.html").unwrap();
+    let about = Url::parse("file:///site/about.html").unwrap();
+    workspace.update(
+        index.clone(),
+        FullTextDocument::new(
+            "html".to_string(),
+            0,
+            r#"<div id="main-nav"></div><nav class="menu"></nav>"#.to_string(),
+        ),
+        &data_manager,
+        None,
+    );
+    workspace.update(
+        about.clone(),
+        FullTextDocument::new(
+            "html".to_string(),
+            0,
+            r#"<main><p class="Main"></p></main>"#.to_string(),
+        ),
+        &data_manager,
+        None,
+    );
+
+    let get_symbols = |workspace: &WorkspaceDocuments, query: &str| {
+        workspace
+            .find_workspace_symbols(query)
+            .into_iter()
+            .map(|symbol| (symbol.location.uri.to_string(), symbol.name))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        get_symbols(&workspace, "main"),
+        vec![
+            (about.to_string(), "main".to_string()),
+            (about.to_string(), "p.Main".to_string()),
+            (index.to_string(), "div#main-nav".to_string()),
+        ]
+    );
+    assert_eq!(
+        get_symbols(&workspace, "mnav"),
+        vec![(index.to_string(), "div#main-nav".to_string())]
+    );
+    assert_eq!(
+        get_symbols(&workspace, "nv.mn"),
+        vec![(index.to_string(), "nav.menu".to_string())]
+    );
+    assert_eq!(get_symbols(&workspace, "").len(), 4);
+    assert!(get_symbols(&workspace, "table").is_empty());
+
+    assert!(workspace.get(&index).is_some());
+    assert!(workspace.remove(&index));
+    assert!(workspace.get(&index).is_none());
+    assert_eq!(workspace.uris().collect::<Vec<_>>(), vec![&about]);
+    assert_eq!(get_symbols(&workspace, "nav"), vec![]);
+}
+
+#[cfg(feature = "symbols")]
+#[test]
+fn streaming() {
+    let uri = Url::parse(TEST_URL).unwrap();
+    let content = "<div><p></p><p></p></div><span></span><ul><li></li></ul>";
+    let document = FullTextDocument::new("html".to_string(), 0, content.to_string());
+    let data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+
+    let (symbols, _) = HTMLLanguageService::find_document_symbols(
+        &uri,
+        &document,
+        &html_document,
+        &data_manager,
+        None,
+        None,
+    );
+    let mut chunks = vec![];
+    let exceeded = HTMLLanguageService::find_document_symbols_streaming(
+        &uri,
+        &document,
+        &html_document,
+        &data_manager,
+        None,
+        None,
+        4,
+        |chunk| chunks.push(chunk),
+    );
+    assert!(!exceeded);
+    assert_eq!(
+        chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>(),
+        vec![4, 2]
+    );
+    assert_eq!(chunks.concat(), symbols);
+
+    let settings = SymbolsConfiguration {
+        max_symbols: Some(4),
+        ..Default::default()
+    };
+    let (symbols2, _) = HTMLLanguageService::find_document_symbols2(
+        &document,
+        &html_document,
+        &data_manager,
+        Some(&settings),
+        None,
+    );
+    let mut chunks = vec![];
+    let exceeded = HTMLLanguageService::find_document_symbols2_streaming(
+        &document,
+        &html_document,
+        &data_manager,
+        Some(&settings),
+        None,
+        1,
+        |chunk| chunks.push(chunk),
+    );
+    assert!(exceeded);
+    assert_eq!(
+        chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>(),
+        vec![1, 1]
+    );
+    assert_eq!(chunks.concat(), symbols2);
+}
+
+#[cfg(feature = "symbols")]
+#[test]
+fn position_encoding() {
+    // `中` and `😀` are 1 and 2 UTF-16 code units, 3 and 4 UTF-8 bytes
+    let uri = Url::parse(TEST_URL).unwrap();
+    let content = "中😀<div></div>";
+    let document = FullTextDocument::new("html".to_string(), 0, content.to_string());
+    let data_manager = HTMLDataManager::default();
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    for (encoding, p) in [
+        (PositionEncodingKind::UTF8, 7),
+        (PositionEncodingKind::UTF16, 3),
+        (PositionEncodingKind::UTF32, 2),
+    ] {
+        let range = Range::new(Position::new(0, p), Position::new(0, p + 11));
+        let (symbols, _) = HTMLLanguageService::find_document_symbols(
+            &uri,
+            &document,
+            &html_document,
+            &data_manager,
+            None,
+            Some(&encoding),
+        );
+        assert_eq!(symbols[0].location.range, range);
+        let (symbols, _) = HTMLLanguageService::find_document_symbols2(
+            &document,
+            &html_document,
+            &data_manager,
+            None,
+            Some(&encoding),
+        );
+        assert_eq!(symbols[0].range, range);
+
+        let mut workspace = WorkspaceDocuments::with_position_encoding(encoding);
+        let document = FullTextDocument::new("html".to_string(), 0, content.to_string());
+        workspace.update(uri.clone(), document, &data_manager, None);
+        assert_eq!(
+            workspace.find_workspace_symbols("div")[0].location.range,
+            range
+        );
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(all(feature = "completion", feature = "hover"))]
+use html_languageservice::DefaultDocumentContext;
+use html_languageservice::{
+    DocumentStore, HTMLDataManager, HTMLLanguageService, HTMLLanguageServiceOptions,
+    MemoryFileSystemProvider,
+};
+#[cfg(all(feature = "completion", feature = "hover"))]
+use lsp_textdocument::FullTextDocument;
+#[cfg(all(feature = "completion", feature = "hover"))]
+use lsp_types::{Position, Url};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn send_sync() {
+    assert_send_sync::<HTMLLanguageService>();
+    assert_send_sync::<HTMLLanguageServiceOptions>();
+    assert_send_sync::<HTMLDataManager>();
+    assert_send_sync::<DocumentStore>();
+    assert_send_sync::<MemoryFileSystemProvider>();
+}
+
+#[cfg(all(feature = "completion", feature = "hover"))]
+#[test]
+fn send_futures() {
+    fn assert_send<T: Send>(_: &T) {}
+
+    let ls = HTMLLanguageService::new(&HTMLLanguageServiceOptions::default());
+    let data_manager = HTMLDataManager::default();
+    let document = FullTextDocument::new("html".to_string(), 0, "<div></div>".to_string());
+    let html_document = HTMLLanguageService::parse_html_document(&document, &data_manager);
+    let position = Position::new(0, 1);
+    assert_send(&ls.do_complete(
+        &document,
+        &position,
+        &html_document,
+        DefaultDocumentContext,
+        None,
+        &data_manager,
+    ));
+    assert_send(&ls.do_complete2(
+        &Url::parse("file:///index.html").unwrap(),
+        &document,
+        &position,
+        &html_document,
+        DefaultDocumentContext,
+        None,
+        &data_manager,
+    ));
+    assert_send(&ls.do_hover(&document, &position, &html_document, None, &data_manager));
+}